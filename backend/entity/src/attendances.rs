@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
-use super::sea_orm_active_enums::Status;
+use super::sea_orm_active_enums::AttendanceStatus;
 use chrono::NaiveDate;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -13,7 +13,7 @@ pub struct Model {
     pub foundation_id: i64,
     pub class_subject_id: i64,
     pub date: NaiveDate,
-    pub status: Status,
+    pub status: AttendanceStatus,
     #[sea_orm(column_type = "Text", nullable)]
     pub notes: Option<String>,
     pub created_at: DateTimeUtc,
@@ -25,3 +25,9 @@ pub struct Model {
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {}
+// ✅ Implementasi trait SoftDelete
+impl crate::traits::soft_delete::SoftDelete for Entity {
+    fn deleted_at_col() -> Column {
+        Column::DeletedAt
+    }
+}
@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
-use super::sea_orm_active_enums::Status;
+use super::sea_orm_active_enums::ScheduleStatus;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -16,7 +16,7 @@ pub struct Model {
     pub is_auto_generated: Option<i8>,
     pub generation_batch_id: Option<String>,
     pub conflict_score: Option<i32>,
-    pub status: Option<Status>,
+    pub status: Option<ScheduleStatus>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
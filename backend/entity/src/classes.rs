@@ -10,6 +10,7 @@ pub struct Model {
     pub foundation_id: i64,
     pub name: String,
     pub class_level_id: Option<i32>,
+    pub homeroom_teacher_id: Option<i64>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 
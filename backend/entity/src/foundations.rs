@@ -23,6 +23,8 @@ pub struct Model {
     pub phone: Option<String>,
     pub email: Option<String>,
     pub is_active: Option<i8>,
+    pub require_email_verification: i8,
+    pub require_two_factor: i8,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     pub deleted_at: Option<DateTimeUtc>,
@@ -5,7 +5,10 @@ pub mod prelude;
 pub mod academic_calendars;
 pub mod academic_years;
 pub mod applicants;
+pub mod attendance_alert_settings;
+pub mod attendance_alerts;
 pub mod attendances;
+pub mod calendar_tokens;
 pub mod character_assessments;
 pub mod character_traits;
 pub mod class_levels;
@@ -18,32 +21,42 @@ pub mod employees;
 pub mod extracurricular_activities;
 pub mod extracurricular_enrollments;
 pub mod foundations;
+pub mod grade_change_requests;
+pub mod grade_histories;
+pub mod grade_weights;
 pub mod grades;
+pub mod grading_scales;
 pub mod homeroom_teachers;
 pub mod levels;
 pub mod members;
 pub mod notifications;
+pub mod payroll_runs;
 pub mod permissions;
 pub mod positions;
+pub mod refresh_tokens;
 pub mod report_cards;
 pub mod role_permissions;
 pub mod role_users;
 pub mod roles;
+pub mod room_bookings;
 pub mod room_facilities;
 pub mod room_types;
 pub mod rooms;
 pub mod salary_grade;
+pub mod salary_grade_promotions;
 pub mod salary_payment;
 pub mod schedule_generation_logs;
 pub mod scopes;
 pub mod sea_orm_active_enums;
 pub mod semesters;
 pub mod settings;
+pub mod staff_attendances;
 pub mod student_enrollments;
 pub mod students;
 pub mod subject_preferences;
 pub mod subject_room_requirements;
 pub mod subjects;
+pub mod substitutions;
 pub mod teacher_assignment;
 pub mod teacher_availability;
 pub mod teacher_salaries;
@@ -54,6 +67,9 @@ pub mod teachers_payments;
 pub mod time_slots;
 pub mod unit_types;
 pub mod units;
+pub mod user_action_tokens;
 pub mod user_permissions;
 pub mod user_profiles;
+pub mod user_recovery_codes;
+pub mod user_two_factors;
 pub mod users;
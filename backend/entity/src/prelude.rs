@@ -2,7 +2,10 @@
 
 pub use super::academic_calendars::Entity as AcademicCalendars;
 pub use super::academic_years::Entity as AcademicYears;
+pub use super::attendance_alert_settings::Entity as AttendanceAlertSettings;
+pub use super::attendance_alerts::Entity as AttendanceAlerts;
 pub use super::attendances::Entity as Attendances;
+pub use super::calendar_tokens::Entity as CalendarTokens;
 pub use super::character_assessments::Entity as CharacterAssessments;
 pub use super::character_traits::Entity as CharacterTraits;
 pub use super::class_levels::Entity as ClassLevels;
@@ -15,18 +18,26 @@ pub use super::extracurricular_activities::Entity as ExtracurricularActivities;
 pub use super::extracurricular_enrollments::Entity as ExtracurricularEnrollments;
 pub use super::facilities::Entity as Facilities;
 pub use super::foundations::Entity as Foundations;
+pub use super::grade_change_requests::Entity as GradeChangeRequests;
+pub use super::grade_histories::Entity as GradeHistories;
+pub use super::grade_weights::Entity as GradeWeights;
 pub use super::grades::Entity as Grades;
+pub use super::grading_scales::Entity as GradingScales;
 pub use super::homeroom_teachers::Entity as HomeroomTeachers;
 pub use super::levels::Entity as Levels;
 pub use super::members::Entity as Members;
 pub use super::notifications::Entity as Notifications;
+pub use super::payroll_runs::Entity as PayrollRuns;
 pub use super::permissions::Entity as Permissions;
+pub use super::refresh_tokens::Entity as RefreshTokens;
 pub use super::report_cards::Entity as ReportCards;
 pub use super::role_permissions::Entity as RolePermissions;
 pub use super::role_users::Entity as RoleUsers;
 pub use super::roles::Entity as Roles;
+pub use super::room_bookings::Entity as RoomBookings;
 pub use super::room_facilities::Entity as RoomFacilities;
 pub use super::rooms::Entity as Rooms;
+pub use super::salary_grade_promotions::Entity as SalaryGradePromotions;
 pub use super::salary_grades::Entity as SalaryGrades;
 pub use super::salary_payments::Entity as SalaryPayments;
 pub use super::schedule_generation_logs::Entity as ScheduleGenerationLogs;
@@ -34,10 +45,12 @@ pub use super::scopes::Entity as Scopes;
 pub use super::sea_orm_active_enums::EmploymentStatus;
 pub use super::semesters::Entity as Semesters;
 pub use super::settings::Entity as Settings;
+pub use super::staff_attendances::Entity as StaffAttendances;
 pub use super::students::Entity as Students;
 pub use super::subject_preferences::Entity as SubjectPreferences;
 pub use super::subject_room_requirements::Entity as SubjectRoomRequirements;
 pub use super::subjects::Entity as Subjects;
+pub use super::substitutions::Entity as Substitutions;
 pub use super::teacher_assignments::Entity as TeacherAssignments;
 pub use super::teacher_availability::Entity as TeacherAvailability;
 pub use super::teacher_salaries::Entity as TeacherSalaries;
@@ -46,5 +59,8 @@ pub use super::teachers::Entity as Teachers;
 pub use super::time_slots::Entity as Timeslots;
 pub use super::unit_types::Entity as UnitTypes;
 pub use super::units::Entity as Units;
+pub use super::user_action_tokens::Entity as UserActionTokens;
 pub use super::user_permissions::Entity as UserPermissions;
+pub use super::user_recovery_codes::Entity as UserRecoveryCodes;
+pub use super::user_two_factors::Entity as UserTwoFactors;
 pub use super::users::Entity as Users;
@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
-use super::sea_orm_active_enums::Status;
+use super::sea_orm_active_enums::ReportCardStatus;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -22,7 +22,7 @@ pub struct Model {
     pub homeroom_teacher_notes: Option<String>,
     #[sea_orm(column_type = "Text", nullable)]
     pub principal_notes: Option<String>,
-    pub status: Option<Status>,
+    pub status: Option<ReportCardStatus>,
     pub finalized_at: Option<DateTimeUtc>,
     pub finalized_by: Option<i64>,
     pub printed_count: Option<i32>,
@@ -35,3 +35,9 @@ pub struct Model {
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 impl ActiveModelBehavior for ActiveModel {}
+// ✅ Implementasi trait SoftDelete
+impl crate::traits::soft_delete::SoftDelete for Entity {
+    fn deleted_at_col() -> Column {
+        Column::DeletedAt
+    }
+}
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "salary_payments")]
 pub struct Model {
-    #[sea_orm(primary_key, auto_increment = false)]
+    #[sea_orm(primary_key)]
     pub id: i64,
     pub teacher_salary_id: i64,
+    pub payroll_run_id: Option<i64>,
     pub payment_period_month: i32,
     pub payment_period_year: i32,
     #[sea_orm(column_type = "Decimal(Some((15, 2)))")]
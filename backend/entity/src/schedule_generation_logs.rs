@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
-use super::sea_orm_active_enums::Status;
+use super::sea_orm_active_enums::GenerationStatus;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -20,7 +20,7 @@ pub struct Model {
     pub success_rate: Option<Decimal>,
     pub conflicts_detected: Option<Json>,
     pub execution_time_ms: Option<i32>,
-    pub status: Option<Status>,
+    pub status: Option<GenerationStatus>,
     #[sea_orm(column_type = "Text", nullable)]
     pub error_message: Option<String>,
     pub created_by: Option<i64>,
@@ -175,3 +175,141 @@ pub enum Status {
     #[sea_orm(string_value = "dropped")]
     Dropped,
 }
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "schedule_status")]
+pub enum ScheduleStatus {
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    #[sea_orm(string_value = "published")]
+    Published,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "generation_status")]
+pub enum GenerationStatus {
+    #[sea_orm(string_value = "success")]
+    Success,
+    #[sea_orm(string_value = "partial")]
+    Partial,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+    #[sea_orm(string_value = "published")]
+    Published,
+    #[sea_orm(string_value = "rolled_back")]
+    RolledBack,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "booking_purpose")]
+pub enum BookingPurpose {
+    #[sea_orm(string_value = "event")]
+    Event,
+    #[sea_orm(string_value = "exam")]
+    Exam,
+    #[sea_orm(string_value = "meeting")]
+    Meeting,
+    #[sea_orm(string_value = "other")]
+    Other,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "attendance_status")]
+pub enum AttendanceStatus {
+    #[sea_orm(string_value = "present")]
+    Present,
+    #[sea_orm(string_value = "late")]
+    Late,
+    #[sea_orm(string_value = "sick")]
+    Sick,
+    #[sea_orm(string_value = "permission")]
+    Permission,
+    #[sea_orm(string_value = "absent")]
+    Absent,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "report_card_status")]
+pub enum ReportCardStatus {
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    #[sea_orm(string_value = "reviewed")]
+    Reviewed,
+    #[sea_orm(string_value = "finalized")]
+    Finalized,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "grade_change_status")]
+pub enum GradeChangeStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    Rejected,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "staff_attendance_status"
+)]
+pub enum StaffAttendanceStatus {
+    #[sea_orm(string_value = "present")]
+    Present,
+    #[sea_orm(string_value = "late")]
+    Late,
+    #[sea_orm(string_value = "sick")]
+    Sick,
+    #[sea_orm(string_value = "leave")]
+    Leave,
+    #[sea_orm(string_value = "absent")]
+    Absent,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "payroll_status")]
+pub enum PayrollStatus {
+    #[sea_orm(string_value = "draft")]
+    Draft,
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    #[sea_orm(string_value = "paid")]
+    Paid,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "promotion_status")]
+pub enum PromotionStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    Rejected,
+}
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "user_token_purpose")]
+pub enum UserTokenPurpose {
+    #[sea_orm(string_value = "password_reset")]
+    PasswordReset,
+    #[sea_orm(string_value = "email_verification")]
+    EmailVerification,
+    #[sea_orm(string_value = "two_factor_challenge")]
+    TwoFactorChallenge,
+}
@@ -17,6 +17,7 @@ pub struct Model {
     pub class_id: Option<i64>,
     pub parent_name: Option<String>,
     pub parent_phone: Option<String>,
+    pub guardian_user_id: Option<i64>,
     pub enrollment_date: Option<DateTimeUtc>,
     pub graduation_date: Option<DateTimeUtc>,
     pub created_at: DateTimeUtc,
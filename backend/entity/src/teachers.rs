@@ -27,6 +27,10 @@ pub struct Model {
     pub end_date: Option<NaiveDate>,
     pub salary: Option<String>,
     pub employment_status: Option<EmploymentStatus>,
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub bank_account_name: Option<String>,
     // DateTimeUtc
     // "2024-01-15T10:30:45.123456789Z"
     // └─ tahun─┘ │ │  │  │  └─ nanodetik
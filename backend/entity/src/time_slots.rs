@@ -10,6 +10,7 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub foundation_id: i64,
+    pub unit_id: Option<i64>,
     pub name: String,
     pub day_of_week: i8,
     pub start_time: Time,
@@ -13,6 +13,16 @@ pub struct Model {
     pub unit_type: Option<String>,
     pub class_level_id: Option<i32>,
     pub level_id: Option<i32>,
+    pub head_employee_id: Option<i64>,
+    #[sea_orm(column_type = "Decimal(Some((10, 7)))", nullable)]
+    pub latitude: Option<Decimal>,
+    #[sea_orm(column_type = "Decimal(Some((10, 7)))", nullable)]
+    pub longitude: Option<Decimal>,
+    pub geofence_radius_meters: Option<i32>,
+    pub work_start_time: Option<Time>,
+    pub late_tolerance_minutes: i32,
+    pub utc_offset_minutes: i32,
+    pub work_days_per_week: i8,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     #[sea_orm(column_type = "Text", nullable)]
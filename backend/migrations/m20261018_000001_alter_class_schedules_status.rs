@@ -0,0 +1,48 @@
+// ============================================
+// Migration: class_schedules.status draft/published
+// ============================================
+// migrations/m20261018_000001_alter_class_schedules_status.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ClassSchedules::Table)
+                    .modify_column(
+                        ColumnDef::new(ClassSchedules::Status)
+                            .enumeration(
+                                Alias::new("schedule_status"),
+                                [Alias::new("draft"), Alias::new("published")],
+                            )
+                            .null()
+                            .default("draft"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ClassSchedules::Table)
+                    .modify_column(ColumnDef::new(ClassSchedules::Status).string_len(20).null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ClassSchedules {
+    Table,
+    Status,
+}
@@ -0,0 +1,57 @@
+// ============================================
+// Migration: schedule_generation_logs.status run lifecycle
+// ============================================
+// migrations/m20261018_000002_alter_schedule_generation_logs_status.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduleGenerationLogs::Table)
+                    .modify_column(
+                        ColumnDef::new(ScheduleGenerationLogs::Status)
+                            .enumeration(
+                                Alias::new("generation_status"),
+                                [
+                                    Alias::new("success"),
+                                    Alias::new("partial"),
+                                    Alias::new("failed"),
+                                    Alias::new("published"),
+                                    Alias::new("rolled_back"),
+                                ],
+                            )
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ScheduleGenerationLogs::Table)
+                    .modify_column(
+                        ColumnDef::new(ScheduleGenerationLogs::Status)
+                            .string_len(20)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ScheduleGenerationLogs {
+    Table,
+    Status,
+}
@@ -0,0 +1,63 @@
+// ============================================
+// Migration: unit-specific time slot grids
+// ============================================
+// migrations/m20261018_000003_add_unit_id_to_time_slots.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TimeSlots::Table)
+                    .add_column(ColumnDef::new(TimeSlots::UnitId).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_time_slots_foundation_unit_day")
+                    .table(TimeSlots::Table)
+                    .col(TimeSlots::FoundationId)
+                    .col(TimeSlots::UnitId)
+                    .col(TimeSlots::DayOfWeek)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_time_slots_foundation_unit_day")
+                    .table(TimeSlots::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TimeSlots::Table)
+                    .drop_column(TimeSlots::UnitId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum TimeSlots {
+    Table,
+    FoundationId,
+    UnitId,
+    DayOfWeek,
+}
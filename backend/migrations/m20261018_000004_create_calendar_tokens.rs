@@ -0,0 +1,81 @@
+// ============================================
+// Migration: secret tokens for iCalendar feeds
+// ============================================
+// migrations/m20261018_000004_create_calendar_tokens.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CalendarTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CalendarTokens::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CalendarTokens::UserId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CalendarTokens::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CalendarTokens::Token)
+                            .string_len(64)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(CalendarTokens::LastUsedAt).timestamp().null())
+                    .col(ColumnDef::new(CalendarTokens::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(CalendarTokens::UpdatedAt).timestamp().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_calendar_tokens_user")
+                            .from(CalendarTokens::Table, CalendarTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CalendarTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CalendarTokens {
+    Table,
+    Id,
+    UserId,
+    FoundationId,
+    Token,
+    LastUsedAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
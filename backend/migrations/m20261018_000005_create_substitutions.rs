@@ -0,0 +1,100 @@
+// ============================================
+// Migration: dated substitute-teacher overrides
+// ============================================
+// migrations/m20261018_000005_create_substitutions.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Substitutions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Substitutions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Substitutions::FoundationId).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Substitutions::ClassScheduleId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Substitutions::TimeSlotId).big_integer().not_null())
+                    .col(ColumnDef::new(Substitutions::Date).date().not_null())
+                    .col(
+                        ColumnDef::new(Substitutions::AbsentTeacherId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Substitutions::SubstituteTeacherId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Substitutions::Reason).string_len(255).null())
+                    .col(ColumnDef::new(Substitutions::CreatedBy).big_integer().null())
+                    .col(ColumnDef::new(Substitutions::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(Substitutions::UpdatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(Substitutions::DeletedAt).timestamp().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_substitutions_class_schedule")
+                            .from(Substitutions::Table, Substitutions::ClassScheduleId)
+                            .to(ClassSchedules::Table, ClassSchedules::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_substitutions_foundation_date")
+                    .table(Substitutions::Table)
+                    .col(Substitutions::FoundationId)
+                    .col(Substitutions::Date)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Substitutions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Substitutions {
+    Table,
+    Id,
+    FoundationId,
+    ClassScheduleId,
+    TimeSlotId,
+    Date,
+    AbsentTeacherId,
+    SubstituteTeacherId,
+    Reason,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(Iden)]
+enum ClassSchedules {
+    Table,
+    Id,
+}
@@ -0,0 +1,105 @@
+// ============================================
+// Migration: ad-hoc room bookings (events, exams, meetings)
+// ============================================
+// migrations/m20261018_000006_create_room_bookings.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoomBookings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RoomBookings::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RoomBookings::FoundationId).big_integer().not_null())
+                    .col(ColumnDef::new(RoomBookings::RoomId).big_integer().not_null())
+                    .col(ColumnDef::new(RoomBookings::Title).string_len(150).not_null())
+                    .col(
+                        ColumnDef::new(RoomBookings::Purpose)
+                            .enumeration(
+                                Alias::new("booking_purpose"),
+                                [
+                                    Alias::new("event"),
+                                    Alias::new("exam"),
+                                    Alias::new("meeting"),
+                                    Alias::new("other"),
+                                ],
+                            )
+                            .not_null()
+                            .default("event"),
+                    )
+                    .col(ColumnDef::new(RoomBookings::Date).date().not_null())
+                    .col(ColumnDef::new(RoomBookings::StartTime).time().not_null())
+                    .col(ColumnDef::new(RoomBookings::EndTime).time().not_null())
+                    .col(ColumnDef::new(RoomBookings::Attendees).integer().null())
+                    .col(ColumnDef::new(RoomBookings::Notes).text().null())
+                    .col(ColumnDef::new(RoomBookings::BookedBy).big_integer().null())
+                    .col(ColumnDef::new(RoomBookings::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(RoomBookings::UpdatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(RoomBookings::DeletedAt).timestamp().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_room_bookings_room")
+                            .from(RoomBookings::Table, RoomBookings::RoomId)
+                            .to(Rooms::Table, Rooms::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_room_bookings_room_date")
+                    .table(RoomBookings::Table)
+                    .col(RoomBookings::RoomId)
+                    .col(RoomBookings::Date)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RoomBookings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum RoomBookings {
+    Table,
+    Id,
+    FoundationId,
+    RoomId,
+    Title,
+    Purpose,
+    Date,
+    StartTime,
+    EndTime,
+    Attendees,
+    Notes,
+    BookedBy,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(Iden)]
+enum Rooms {
+    Table,
+    Id,
+}
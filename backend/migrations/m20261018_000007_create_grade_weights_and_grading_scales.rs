@@ -0,0 +1,152 @@
+// ============================================
+// Migration: per-subject grade weights & foundation grading scale
+// ============================================
+// migrations/m20261018_000007_create_grade_weights_and_grading_scales.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GradeWeights::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GradeWeights::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(GradeWeights::FoundationId).big_integer().not_null())
+                    .col(ColumnDef::new(GradeWeights::SubjectId).big_integer().not_null())
+                    .col(weight(GradeWeights::AttendanceWeight))
+                    .col(weight(GradeWeights::AssignmentWeight))
+                    .col(weight(GradeWeights::QuizWeight))
+                    .col(weight(GradeWeights::MidtermWeight))
+                    .col(weight(GradeWeights::FinalWeight))
+                    .col(ColumnDef::new(GradeWeights::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(GradeWeights::UpdatedAt).timestamp().not_null())
+                    .index(
+                        Index::create()
+                            .name("uq_grade_weights_foundation_subject")
+                            .col(GradeWeights::FoundationId)
+                            .col(GradeWeights::SubjectId)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_grade_weights_subject")
+                            .from(GradeWeights::Table, GradeWeights::SubjectId)
+                            .to(Subjects::Table, Subjects::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GradingScales::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GradingScales::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(GradingScales::FoundationId).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(GradingScales::MinScore)
+                            .decimal_len(5, 2)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GradingScales::GradeLetter).string_len(5).not_null())
+                    .col(
+                        ColumnDef::new(GradingScales::Predicate)
+                            .enumeration(
+                                Alias::new("predicate"),
+                                [
+                                    Alias::new("Sangat Baik"),
+                                    Alias::new("Baik"),
+                                    Alias::new("Cukup"),
+                                    Alias::new("Kurang"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GradingScales::CreatedAt).timestamp().not_null())
+                    .col(ColumnDef::new(GradingScales::UpdatedAt).timestamp().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_grading_scales_foundation")
+                    .table(GradingScales::Table)
+                    .col(GradingScales::FoundationId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GradingScales::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GradeWeights::Table).to_owned())
+            .await
+    }
+}
+
+fn weight(column: GradeWeights) -> ColumnDef {
+    ColumnDef::new(column)
+        .decimal_len(5, 2)
+        .not_null()
+        .default(0)
+        .to_owned()
+}
+
+#[derive(Iden, Clone, Copy)]
+enum GradeWeights {
+    Table,
+    Id,
+    FoundationId,
+    SubjectId,
+    AttendanceWeight,
+    AssignmentWeight,
+    QuizWeight,
+    MidtermWeight,
+    FinalWeight,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum GradingScales {
+    Table,
+    Id,
+    FoundationId,
+    MinScore,
+    GradeLetter,
+    Predicate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Subjects {
+    Table,
+    Id,
+}
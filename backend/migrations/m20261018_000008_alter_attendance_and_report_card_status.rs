@@ -0,0 +1,114 @@
+// ============================================
+// Migration: dedicated status enums for attendances & report_cards
+// ============================================
+// migrations/m20261018_000008_alter_attendance_and_report_card_status.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Attendances::Table)
+                    .modify_column(
+                        ColumnDef::new(Attendances::Status)
+                            .enumeration(
+                                Alias::new("attendance_status"),
+                                [
+                                    Alias::new("present"),
+                                    Alias::new("late"),
+                                    Alias::new("sick"),
+                                    Alias::new("permission"),
+                                    Alias::new("absent"),
+                                ],
+                            )
+                            .not_null()
+                            .default("present"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ReportCards::Table)
+                    .modify_column(
+                        ColumnDef::new(ReportCards::Status)
+                            .enumeration(
+                                Alias::new("report_card_status"),
+                                [
+                                    Alias::new("draft"),
+                                    Alias::new("reviewed"),
+                                    Alias::new("finalized"),
+                                ],
+                            )
+                            .null()
+                            .default("draft"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Satu rapor per siswa per semester
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_report_cards_student_semester")
+                    .table(ReportCards::Table)
+                    .col(ReportCards::StudentId)
+                    .col(ReportCards::SemesterId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_report_cards_student_semester")
+                    .table(ReportCards::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ReportCards::Table)
+                    .modify_column(ColumnDef::new(ReportCards::Status).string_len(20).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Attendances::Table)
+                    .modify_column(ColumnDef::new(Attendances::Status).string_len(20).not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Attendances {
+    Table,
+    Status,
+}
+
+#[derive(Iden)]
+enum ReportCards {
+    Table,
+    StudentId,
+    SemesterId,
+    Status,
+}
@@ -0,0 +1,46 @@
+// ============================================
+// Migration: one character assessment per student, semester & trait
+// ============================================
+// migrations/m20261018_000009_add_character_assessment_unique_key.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_character_assessments_student_semester_trait")
+                    .table(CharacterAssessments::Table)
+                    .col(CharacterAssessments::StudentId)
+                    .col(CharacterAssessments::SemesterId)
+                    .col(CharacterAssessments::CharacterTraitId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_character_assessments_student_semester_trait")
+                    .table(CharacterAssessments::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CharacterAssessments {
+    Table,
+    StudentId,
+    SemesterId,
+    CharacterTraitId,
+}
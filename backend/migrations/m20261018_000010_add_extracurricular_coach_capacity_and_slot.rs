@@ -0,0 +1,164 @@
+// ============================================
+// Migration: extracurricular coach, capacity & schedule slot,
+// guardian account of a student
+// ============================================
+// migrations/m20261018_000010_add_extracurricular_coach_capacity_and_slot.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExtracurricularActivities::Table)
+                    .add_column(
+                        ColumnDef::new(ExtracurricularActivities::CoachId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(ExtracurricularActivities::TimeSlotId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(ExtracurricularActivities::RoomId)
+                            .big_integer()
+                            .null(),
+                    )
+                    // NULL = tanpa batas peserta
+                    .add_column(
+                        ColumnDef::new(ExtracurricularActivities::MaxParticipants)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_extracurricular_activities_time_slot")
+                    .table(ExtracurricularActivities::Table)
+                    .col(ExtracurricularActivities::TimeSlotId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_extracurricular_enrollments_student_activity_semester")
+                    .table(ExtracurricularEnrollments::Table)
+                    .col(ExtracurricularEnrollments::StudentId)
+                    .col(ExtracurricularEnrollments::ExtracurricularId)
+                    .col(ExtracurricularEnrollments::SemesterId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Akun orang tua/wali yang boleh mendaftarkan siswa
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Students::Table)
+                    .add_column(
+                        ColumnDef::new(Students::GuardianUserId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_students_guardian_user")
+                    .table(Students::Table)
+                    .col(Students::GuardianUserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_students_guardian_user")
+                    .table(Students::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Students::Table)
+                    .drop_column(Students::GuardianUserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_extracurricular_enrollments_student_activity_semester")
+                    .table(ExtracurricularEnrollments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_extracurricular_activities_time_slot")
+                    .table(ExtracurricularActivities::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ExtracurricularActivities::Table)
+                    .drop_column(ExtracurricularActivities::CoachId)
+                    .drop_column(ExtracurricularActivities::TimeSlotId)
+                    .drop_column(ExtracurricularActivities::RoomId)
+                    .drop_column(ExtracurricularActivities::MaxParticipants)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum ExtracurricularActivities {
+    Table,
+    CoachId,
+    TimeSlotId,
+    RoomId,
+    MaxParticipants,
+}
+
+#[derive(Iden)]
+enum ExtracurricularEnrollments {
+    Table,
+    StudentId,
+    ExtracurricularId,
+    SemesterId,
+}
+
+#[derive(Iden)]
+enum Students {
+    Table,
+    GuardianUserId,
+}
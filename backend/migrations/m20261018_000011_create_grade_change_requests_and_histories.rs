@@ -0,0 +1,279 @@
+// ============================================
+// Migration: grade change requests, grade history & unit head
+// ============================================
+// migrations/m20261018_000011_create_grade_change_requests_and_histories.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Kepala unit yang menyetujui perubahan nilai
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Units::Table)
+                    .add_column(ColumnDef::new(Units::HeadEmployeeId).big_integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GradeChangeRequests::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::GradeId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    // Nilai usulan; NULL = komponen tidak diubah
+                    .col(score(GradeChangeRequests::AttendanceScore))
+                    .col(score(GradeChangeRequests::AssignmentScore))
+                    .col(score(GradeChangeRequests::QuizScore))
+                    .col(score(GradeChangeRequests::MidtermScore))
+                    .col(score(GradeChangeRequests::FinalScore))
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::Reason)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::Status)
+                            .enumeration(
+                                Alias::new("grade_change_status"),
+                                [
+                                    Alias::new("pending"),
+                                    Alias::new("approved"),
+                                    Alias::new("rejected"),
+                                    Alias::new("cancelled"),
+                                ],
+                            )
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::RequestedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::ReviewedBy)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::ReviewedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::ReviewNotes)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeChangeRequests::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_grade_change_requests_grade")
+                            .from(GradeChangeRequests::Table, GradeChangeRequests::GradeId)
+                            .to(Grades::Table, Grades::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_grade_change_requests_foundation_status")
+                    .table(GradeChangeRequests::Table)
+                    .col(GradeChangeRequests::FoundationId)
+                    .col(GradeChangeRequests::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Salinan nilai sebelum setiap perubahan yang disetujui
+        manager
+            .create_table(
+                Table::create()
+                    .table(GradeHistories::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GradeHistories::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::GradeId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::ChangeRequestId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(score(GradeHistories::AttendanceScore))
+                    .col(score(GradeHistories::AssignmentScore))
+                    .col(score(GradeHistories::QuizScore))
+                    .col(score(GradeHistories::MidtermScore))
+                    .col(score(GradeHistories::FinalScore))
+                    .col(
+                        ColumnDef::new(GradeHistories::FinalGrade)
+                            .decimal_len(5, 2)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::GradeLetter)
+                            .string_len(5)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::Predicate)
+                            .enumeration(
+                                Alias::new("predicate"),
+                                [
+                                    Alias::new("Sangat Baik"),
+                                    Alias::new("Baik"),
+                                    Alias::new("Cukup"),
+                                    Alias::new("Kurang"),
+                                ],
+                            )
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::ChangedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GradeHistories::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_grade_histories_grade")
+                            .from(GradeHistories::Table, GradeHistories::GradeId)
+                            .to(Grades::Table, Grades::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_grade_histories_grade")
+                    .table(GradeHistories::Table)
+                    .col(GradeHistories::GradeId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GradeHistories::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GradeChangeRequests::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Units::Table)
+                    .drop_column(Units::HeadEmployeeId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+fn score<T: IntoIden>(column: T) -> ColumnDef {
+    ColumnDef::new(column).decimal_len(5, 2).null().to_owned()
+}
+
+#[derive(Iden)]
+enum Units {
+    Table,
+    HeadEmployeeId,
+}
+
+#[derive(Iden)]
+enum GradeChangeRequests {
+    Table,
+    Id,
+    FoundationId,
+    GradeId,
+    AttendanceScore,
+    AssignmentScore,
+    QuizScore,
+    MidtermScore,
+    FinalScore,
+    Reason,
+    Status,
+    RequestedBy,
+    ReviewedBy,
+    ReviewedAt,
+    ReviewNotes,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum GradeHistories {
+    Table,
+    Id,
+    GradeId,
+    ChangeRequestId,
+    AttendanceScore,
+    AssignmentScore,
+    QuizScore,
+    MidtermScore,
+    FinalScore,
+    FinalGrade,
+    GradeLetter,
+    Predicate,
+    ChangedBy,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Grades {
+    Table,
+    Id,
+}
@@ -0,0 +1,47 @@
+// ============================================
+// Migration: one attendance row per student per class session
+// ============================================
+// migrations/m20261018_000012_add_attendance_roll_call_unique_key.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Absensi massal melakukan upsert berdasarkan kunci ini
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_attendances_student_class_subject_date")
+                    .table(Attendances::Table)
+                    .col(Attendances::StudentId)
+                    .col(Attendances::ClassSubjectId)
+                    .col(Attendances::Date)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_attendances_student_class_subject_date")
+                    .table(Attendances::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Attendances {
+    Table,
+    StudentId,
+    ClassSubjectId,
+    Date,
+}
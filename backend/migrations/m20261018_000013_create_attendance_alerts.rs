@@ -0,0 +1,184 @@
+// ============================================
+// Migration: chronic absence alerts & homeroom teacher of a class
+// ============================================
+// migrations/m20261018_000013_create_attendance_alerts.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Wali kelas yang menerima peringatan absensi
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Classes::Table)
+                    .add_column(
+                        ColumnDef::new(Classes::HomeroomTeacherId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(AttendanceAlertSettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::FoundationId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    // Persentase ketidakhadiran (0 - 100)
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::AbsenceThreshold)
+                            .decimal_len(5, 2)
+                            .not_null(),
+                    )
+                    // Jumlah pertemuan minimal sebelum peringatan dikirim
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::MinSessions)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlertSettings::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Satu peringatan per siswa per semester
+        manager
+            .create_table(
+                Table::create()
+                    .table(AttendanceAlerts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::StudentId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::SemesterId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::ClassId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::AbsenceRate)
+                            .decimal_len(5, 2)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::NotifiedUserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AttendanceAlerts::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uk_attendance_alerts_student_semester")
+                            .col(AttendanceAlerts::StudentId)
+                            .col(AttendanceAlerts::SemesterId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AttendanceAlerts::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AttendanceAlertSettings::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Classes::Table)
+                    .drop_column(Classes::HomeroomTeacherId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Classes {
+    Table,
+    HomeroomTeacherId,
+}
+
+#[derive(Iden)]
+enum AttendanceAlertSettings {
+    Table,
+    Id,
+    FoundationId,
+    AbsenceThreshold,
+    MinSessions,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum AttendanceAlerts {
+    Table,
+    Id,
+    FoundationId,
+    StudentId,
+    SemesterId,
+    ClassId,
+    AbsenceRate,
+    NotifiedUserId,
+    CreatedAt,
+}
@@ -0,0 +1,221 @@
+// ============================================
+// Migration: staff clock-in/out & unit presence settings
+// ============================================
+// migrations/m20261018_000014_create_staff_attendances.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Lokasi unit (geofence) dan jam masuk untuk deteksi terlambat
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Units::Table)
+                    .add_column(ColumnDef::new(Units::Latitude).decimal_len(10, 7).null())
+                    .add_column(ColumnDef::new(Units::Longitude).decimal_len(10, 7).null())
+                    // NULL = tanpa pengecekan lokasi
+                    .add_column(ColumnDef::new(Units::GeofenceRadiusMeters).integer().null())
+                    .add_column(ColumnDef::new(Units::WorkStartTime).time().null())
+                    .add_column(
+                        ColumnDef::new(Units::LateToleranceMinutes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    // Zona waktu lokal unit dalam menit dari UTC (WIB = 420)
+                    .add_column(
+                        ColumnDef::new(Units::UtcOffsetMinutes)
+                            .integer()
+                            .not_null()
+                            .default(420),
+                    )
+                    // 5 = Senin-Jumat, 6 = Senin-Sabtu
+                    .add_column(
+                        ColumnDef::new(Units::WorkDaysPerWeek)
+                            .tiny_integer()
+                            .not_null()
+                            .default(5),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(StaffAttendances::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StaffAttendances::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    // Akun pegawai atau guru
+                    .col(
+                        ColumnDef::new(StaffAttendances::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::UnitId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(StaffAttendances::Date).date().not_null())
+                    .col(
+                        ColumnDef::new(StaffAttendances::Status)
+                            .enumeration(
+                                Alias::new("staff_attendance_status"),
+                                [
+                                    Alias::new("present"),
+                                    Alias::new("late"),
+                                    Alias::new("sick"),
+                                    Alias::new("leave"),
+                                    Alias::new("absent"),
+                                ],
+                            )
+                            .not_null()
+                            .default("present"),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockInAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockOutAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockInLatitude)
+                            .decimal_len(10, 7)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockInLongitude)
+                            .decimal_len(10, 7)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockOutLatitude)
+                            .decimal_len(10, 7)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::ClockOutLongitude)
+                            .decimal_len(10, 7)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::LateMinutes)
+                            .integer()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(StaffAttendances::Notes).text().null())
+                    // Diisi bila dicatat manual oleh admin (sakit/cuti/alpa)
+                    .col(
+                        ColumnDef::new(StaffAttendances::RecordedBy)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StaffAttendances::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uk_staff_attendances_user_date")
+                            .col(StaffAttendances::UserId)
+                            .col(StaffAttendances::Date)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_staff_attendances_foundation_date")
+                    .table(StaffAttendances::Table)
+                    .col(StaffAttendances::FoundationId)
+                    .col(StaffAttendances::Date)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StaffAttendances::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Units::Table)
+                    .drop_column(Units::Latitude)
+                    .drop_column(Units::Longitude)
+                    .drop_column(Units::GeofenceRadiusMeters)
+                    .drop_column(Units::WorkStartTime)
+                    .drop_column(Units::LateToleranceMinutes)
+                    .drop_column(Units::UtcOffsetMinutes)
+                    .drop_column(Units::WorkDaysPerWeek)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Units {
+    Table,
+    Latitude,
+    Longitude,
+    GeofenceRadiusMeters,
+    WorkStartTime,
+    LateToleranceMinutes,
+    UtcOffsetMinutes,
+    WorkDaysPerWeek,
+}
+
+#[derive(Iden)]
+enum StaffAttendances {
+    Table,
+    Id,
+    FoundationId,
+    UserId,
+    UnitId,
+    Date,
+    Status,
+    ClockInAt,
+    ClockOutAt,
+    ClockInLatitude,
+    ClockInLongitude,
+    ClockOutLatitude,
+    ClockOutLongitude,
+    LateMinutes,
+    Notes,
+    RecordedBy,
+    CreatedAt,
+    UpdatedAt,
+}
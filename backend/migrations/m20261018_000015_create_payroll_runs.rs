@@ -0,0 +1,216 @@
+// ============================================
+// Migration: monthly payroll runs
+// ============================================
+// migrations/m20261018_000015_create_payroll_runs.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PayrollRuns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PayrollRuns::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PayrollRuns::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PayrollRuns::PeriodMonth)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PayrollRuns::PeriodYear)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PayrollRuns::Status)
+                            .enumeration(
+                                Alias::new("payroll_status"),
+                                [
+                                    Alias::new("draft"),
+                                    Alias::new("approved"),
+                                    Alias::new("paid"),
+                                ],
+                            )
+                            .not_null()
+                            .default("draft"),
+                    )
+                    // Aturan bonus/potongan kehadiran yang dipakai saat run dihitung
+                    .col(money(PayrollRuns::LatePenaltyPerDay))
+                    // NULL = potongan proporsional gaji pokok / hari kerja
+                    .col(money(PayrollRuns::AbsentPenaltyPerDay))
+                    .col(money(PayrollRuns::PerfectAttendanceBonus))
+                    .col(money(PayrollRuns::TotalGross))
+                    .col(money(PayrollRuns::TotalNet))
+                    .col(
+                        ColumnDef::new(PayrollRuns::CreatedBy)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PayrollRuns::ApprovedBy).big_integer().null())
+                    .col(ColumnDef::new(PayrollRuns::ApprovedAt).timestamp().null())
+                    .col(ColumnDef::new(PayrollRuns::PaidBy).big_integer().null())
+                    .col(ColumnDef::new(PayrollRuns::PaidAt).timestamp().null())
+                    .col(ColumnDef::new(PayrollRuns::PaymentDate).date().null())
+                    .col(
+                        ColumnDef::new(PayrollRuns::PaymentMethod)
+                            .string_len(50)
+                            .null(),
+                    )
+                    .col(ColumnDef::new(PayrollRuns::Notes).text().null())
+                    .col(
+                        ColumnDef::new(PayrollRuns::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PayrollRuns::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    // Satu run per bulan per yayasan
+                    .index(
+                        Index::create()
+                            .name("uk_payroll_runs_foundation_period")
+                            .col(PayrollRuns::FoundationId)
+                            .col(PayrollRuns::PeriodYear)
+                            .col(PayrollRuns::PeriodMonth)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Id salary_payments sebelumnya diisi manual
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SalaryPayments::Table)
+                    .modify_column(
+                        ColumnDef::new(SalaryPayments::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment(),
+                    )
+                    .add_column(
+                        ColumnDef::new(SalaryPayments::PayrollRunId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_salary_payments_payroll_run")
+                    .table(SalaryPayments::Table)
+                    .col(SalaryPayments::PayrollRunId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_salary_payments_salary_period")
+                    .table(SalaryPayments::Table)
+                    .col(SalaryPayments::TeacherSalaryId)
+                    .col(SalaryPayments::PaymentPeriodYear)
+                    .col(SalaryPayments::PaymentPeriodMonth)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uk_salary_payments_salary_period")
+                    .table(SalaryPayments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_salary_payments_payroll_run")
+                    .table(SalaryPayments::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SalaryPayments::Table)
+                    .drop_column(SalaryPayments::PayrollRunId)
+                    .modify_column(ColumnDef::new(SalaryPayments::Id).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PayrollRuns::Table).to_owned())
+            .await
+    }
+}
+
+fn money<T: IntoIden>(column: T) -> ColumnDef {
+    ColumnDef::new(column).decimal_len(15, 2).null().to_owned()
+}
+
+#[derive(Iden)]
+enum PayrollRuns {
+    Table,
+    Id,
+    FoundationId,
+    PeriodMonth,
+    PeriodYear,
+    Status,
+    LatePenaltyPerDay,
+    AbsentPenaltyPerDay,
+    PerfectAttendanceBonus,
+    TotalGross,
+    TotalNet,
+    CreatedBy,
+    ApprovedBy,
+    ApprovedAt,
+    PaidBy,
+    PaidAt,
+    PaymentDate,
+    PaymentMethod,
+    Notes,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum SalaryPayments {
+    Table,
+    Id,
+    PayrollRunId,
+    TeacherSalaryId,
+    PaymentPeriodMonth,
+    PaymentPeriodYear,
+}
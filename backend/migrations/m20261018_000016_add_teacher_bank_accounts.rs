@@ -0,0 +1,58 @@
+// ============================================
+// Migration: teacher bank account for salary transfers
+// ============================================
+// migrations/m20261018_000016_add_teacher_bank_accounts.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Teachers::Table)
+                    // Kode bank tujuan (kliring/SKN) untuk file transfer massal
+                    .add_column(ColumnDef::new(Teachers::BankCode).string_len(10).null())
+                    .add_column(ColumnDef::new(Teachers::BankName).string_len(100).null())
+                    .add_column(
+                        ColumnDef::new(Teachers::BankAccountNumber)
+                            .string_len(30)
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Teachers::BankAccountName)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Teachers::Table)
+                    .drop_column(Teachers::BankCode)
+                    .drop_column(Teachers::BankName)
+                    .drop_column(Teachers::BankAccountNumber)
+                    .drop_column(Teachers::BankAccountName)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Teachers {
+    Table,
+    BankCode,
+    BankName,
+    BankAccountNumber,
+    BankAccountName,
+}
@@ -0,0 +1,244 @@
+// ============================================
+// Migration: salary grade promotions
+// ============================================
+// migrations/m20261018_000017_create_salary_grade_promotions.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Id golongan & riwayat gaji sebelumnya diisi manual
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SalaryGrades::Table)
+                    .modify_column(
+                        ColumnDef::new(SalaryGrades::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TeacherSalaries::Table)
+                    .modify_column(
+                        ColumnDef::new(TeacherSalaries::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SalaryGradePromotions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::TeacherId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::TeacherAssignmentId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    // Gaji yang berlaku saat usulan dibuat; NULL = belum pernah punya gaji
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::FromTeacherSalaryId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::FromSalaryGradeId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::ToSalaryGradeId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::YearsOfService)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::Qualification)
+                            .string_len(100)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::Status)
+                            .enumeration(
+                                Alias::new("promotion_status"),
+                                [
+                                    Alias::new("pending"),
+                                    Alias::new("approved"),
+                                    Alias::new("rejected"),
+                                ],
+                            )
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::EffectiveDate)
+                            .date()
+                            .not_null(),
+                    )
+                    // Baris teacher_salaries baru yang dibuat saat disetujui
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::NewTeacherSalaryId)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::ReviewedBy)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::ReviewedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::ReviewNotes)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalaryGradePromotions::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_salary_grade_promotions_assignment")
+                            .from(
+                                SalaryGradePromotions::Table,
+                                SalaryGradePromotions::TeacherAssignmentId,
+                            )
+                            .to(TeacherAssignments::Table, TeacherAssignments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_salary_grade_promotions_to_grade")
+                            .from(
+                                SalaryGradePromotions::Table,
+                                SalaryGradePromotions::ToSalaryGradeId,
+                            )
+                            .to(SalaryGrades::Table, SalaryGrades::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_salary_grade_promotions_foundation_status")
+                    .table(SalaryGradePromotions::Table)
+                    .col(SalaryGradePromotions::FoundationId)
+                    .col(SalaryGradePromotions::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SalaryGradePromotions::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TeacherSalaries::Table)
+                    .modify_column(ColumnDef::new(TeacherSalaries::Id).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SalaryGrades::Table)
+                    .modify_column(ColumnDef::new(SalaryGrades::Id).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum SalaryGradePromotions {
+    Table,
+    Id,
+    FoundationId,
+    TeacherId,
+    TeacherAssignmentId,
+    FromTeacherSalaryId,
+    FromSalaryGradeId,
+    ToSalaryGradeId,
+    YearsOfService,
+    Qualification,
+    Status,
+    EffectiveDate,
+    NewTeacherSalaryId,
+    ReviewedBy,
+    ReviewedAt,
+    ReviewNotes,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum SalaryGrades {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum TeacherSalaries {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum TeacherAssignments {
+    Table,
+    Id,
+}
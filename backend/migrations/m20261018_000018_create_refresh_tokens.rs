@@ -0,0 +1,173 @@
+// ============================================
+// Migration: server-side refresh tokens (rotation & sessions)
+// ============================================
+// migrations/m20261018_000018_create_refresh_tokens.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RefreshTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RefreshTokens::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::FoundationId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::Jti)
+                            .string_len(36)
+                            .not_null(),
+                    )
+                    // Satu family = satu sesi login; setiap rotasi menambah baris baru
+                    .col(
+                        ColumnDef::new(RefreshTokens::FamilyId)
+                            .string_len(36)
+                            .not_null(),
+                    )
+                    // SHA-256 (hex) dari refresh token; token asli tidak pernah disimpan
+                    .col(
+                        ColumnDef::new(RefreshTokens::TokenHash)
+                            .char_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::ReplacedByJti)
+                            .string_len(36)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::UserAgent)
+                            .string_len(255)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::IpAddress)
+                            .string_len(45)
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::SessionStartedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::IssuedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::LastUsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::ExpiresAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::RevokedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshTokens::RevokedReason)
+                            .string_len(30)
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_refresh_tokens_user")
+                            .from(RefreshTokens::Table, RefreshTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_refresh_tokens_jti")
+                    .table(RefreshTokens::Table)
+                    .col(RefreshTokens::Jti)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_refresh_tokens_family")
+                    .table(RefreshTokens::Table)
+                    .col(RefreshTokens::FamilyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_refresh_tokens_user_revoked")
+                    .table(RefreshTokens::Table)
+                    .col(RefreshTokens::UserId)
+                    .col(RefreshTokens::RevokedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RefreshTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum RefreshTokens {
+    Table,
+    Id,
+    UserId,
+    FoundationId,
+    Jti,
+    FamilyId,
+    TokenHash,
+    ReplacedByJti,
+    UserAgent,
+    IpAddress,
+    SessionStartedAt,
+    IssuedAt,
+    LastUsedAt,
+    ExpiresAt,
+    RevokedAt,
+    RevokedReason,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,152 @@
+// ============================================
+// Migration: password reset & email verification tokens,
+// foundation email verification setting
+// ============================================
+// migrations/m20261018_000019_create_user_action_tokens.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserActionTokens::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserActionTokens::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserActionTokens::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserActionTokens::Purpose)
+                            .enumeration(
+                                Alias::new("user_token_purpose"),
+                                [
+                                    Alias::new("password_reset"),
+                                    Alias::new("email_verification"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    // jti dari token bertanda tangan yang dikirim lewat email
+                    .col(
+                        ColumnDef::new(UserActionTokens::Jti)
+                            .string_len(36)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserActionTokens::ExpiresAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    // Terisi saat token dipakai atau digantikan token baru
+                    .col(
+                        ColumnDef::new(UserActionTokens::UsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserActionTokens::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_action_tokens_user")
+                            .from(UserActionTokens::Table, UserActionTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_user_action_tokens_jti")
+                    .table(UserActionTokens::Table)
+                    .col(UserActionTokens::Jti)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_action_tokens_user_purpose")
+                    .table(UserActionTokens::Table)
+                    .col(UserActionTokens::UserId)
+                    .col(UserActionTokens::Purpose)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Login ditolak untuk akun yang emailnya belum diverifikasi
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Foundations::Table)
+                    .add_column(
+                        ColumnDef::new(Foundations::RequireEmailVerification)
+                            .tiny_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Foundations::Table)
+                    .drop_column(Foundations::RequireEmailVerification)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserActionTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum UserActionTokens {
+    Table,
+    Id,
+    UserId,
+    Purpose,
+    Jti,
+    ExpiresAt,
+    UsedAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Foundations {
+    Table,
+    RequireEmailVerification,
+}
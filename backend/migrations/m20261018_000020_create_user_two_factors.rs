@@ -0,0 +1,204 @@
+// ============================================
+// Migration: TOTP two-factor authentication, recovery codes &
+// foundation 2FA mandate
+// ============================================
+// migrations/m20261018_000020_create_user_two_factors.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTwoFactors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserTwoFactors::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactors::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    // Secret TOTP dalam base32 (RFC 4648, tanpa padding)
+                    .col(
+                        ColumnDef::new(UserTwoFactors::Secret)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    // NULL = enrollment belum dikonfirmasi dengan kode pertama
+                    .col(
+                        ColumnDef::new(UserTwoFactors::EnabledAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    // Time step terakhir yang dipakai; kode yang sama tidak bisa dipakai ulang
+                    .col(
+                        ColumnDef::new(UserTwoFactors::LastUsedStep)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactors::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserTwoFactors::UpdatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_two_factors_user")
+                            .from(UserTwoFactors::Table, UserTwoFactors::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_user_two_factors_user")
+                    .table(UserTwoFactors::Table)
+                    .col(UserTwoFactors::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserRecoveryCodes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    // SHA-256 (hex) dari kode; kode asli hanya ditampilkan sekali
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::CodeHash)
+                            .char_len(64)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::UsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(UserRecoveryCodes::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_recovery_codes_user")
+                            .from(UserRecoveryCodes::Table, UserRecoveryCodes::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_recovery_codes_user")
+                    .table(UserRecoveryCodes::Table)
+                    .col(UserRecoveryCodes::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Permission sensitif hanya berlaku bila pemiliknya sudah mengaktifkan 2FA
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Foundations::Table)
+                    .add_column(
+                        ColumnDef::new(Foundations::RequireTwoFactor)
+                            .tiny_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Foundations::Table)
+                    .drop_column(Foundations::RequireTwoFactor)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserRecoveryCodes::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UserTwoFactors::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum UserTwoFactors {
+    Table,
+    Id,
+    UserId,
+    Secret,
+    EnabledAt,
+    LastUsedStep,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum UserRecoveryCodes {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Foundations {
+    Table,
+    RequireTwoFactor,
+}
@@ -0,0 +1,131 @@
+// ============================================
+// Migration: single-use 2FA challenges with attempt limit &
+// salted recovery code hashes
+// ============================================
+// migrations/m20261018_000021_harden_two_factor_challenges.rs
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Challenge login 2FA dicatat seperti token email agar sekali pakai
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserActionTokens::Table)
+                    .modify_column(
+                        ColumnDef::new(UserActionTokens::Purpose)
+                            .enumeration(
+                                Alias::new("user_token_purpose"),
+                                [
+                                    Alias::new("password_reset"),
+                                    Alias::new("email_verification"),
+                                    Alias::new("two_factor_challenge"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    // Kode salah pada challenge; challenge hangus setelah batas tercapai
+                    .add_column(
+                        ColumnDef::new(UserActionTokens::FailedAttempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Hash bcrypt (ber-salt) menggantikan SHA-256 polos; kode lama tidak bisa
+        // diverifikasi lagi sehingga dihapus dan pengguna membuat kode baru
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(UserRecoveryCodes::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserRecoveryCodes::Table)
+                    .modify_column(
+                        ColumnDef::new(UserRecoveryCodes::CodeHash)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(UserRecoveryCodes::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserRecoveryCodes::Table)
+                    .modify_column(
+                        ColumnDef::new(UserRecoveryCodes::CodeHash)
+                            .char_len(64)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(UserActionTokens::Table)
+                    .and_where(Expr::col(UserActionTokens::Purpose).eq("two_factor_challenge"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserActionTokens::Table)
+                    .drop_column(UserActionTokens::FailedAttempts)
+                    .modify_column(
+                        ColumnDef::new(UserActionTokens::Purpose)
+                            .enumeration(
+                                Alias::new("user_token_purpose"),
+                                [
+                                    Alias::new("password_reset"),
+                                    Alias::new("email_verification"),
+                                ],
+                            )
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum UserActionTokens {
+    Table,
+    Purpose,
+    FailedAttempts,
+}
+
+#[derive(Iden)]
+enum UserRecoveryCodes {
+    Table,
+    CodeHash,
+}
@@ -3,20 +3,35 @@ use crate::modules::academic_years::AcademicYearService;
 use crate::modules::applicants::ApplicantService;
 use crate::modules::attendances::AttendanceService;
 use crate::modules::auth::AuthService;
+use crate::modules::calendars::CalendarService;
+use crate::modules::character_traits::CharacterTraitService;
 use crate::modules::class_levels::ClassLevelService;
 use crate::modules::classes::ClassService;
 use crate::modules::departments::DepartmentService;
 use crate::modules::employees::EmployeeService;
+use crate::modules::extracurriculars::ExtracurricularService;
 use crate::modules::foundations::FoundationService;
+use crate::modules::grades::GradeService;
+use crate::modules::payrolls::PayrollService;
 use crate::modules::permissions::PermissionService;
 use crate::modules::positions::PositionService;
+use crate::modules::report_cards::ReportCardService;
 use crate::modules::roles::RoleService;
+use crate::modules::room_bookings::RoomBookingService;
 use crate::modules::rooms::RoomService;
+use crate::modules::salary_grades::SalaryGradeService;
+use crate::modules::schedule_generation_logs::ScheduleGenerationLogService;
+use crate::modules::schedules::ScheduleService;
 use crate::modules::semesters::SemesterService;
 use crate::modules::settings::SettingService;
+use crate::modules::staff_attendances::StaffAttendanceService;
 use crate::modules::students::StudentService;
+use crate::modules::subject_preferences::SubjectPreferenceService;
 use crate::modules::subjects::SubjectService;
+use crate::modules::substitutions::SubstitutionService;
+use crate::modules::teacher_availability::TeacherAvailabilityService;
 use crate::modules::teachers::TeacherService;
+use crate::modules::time_slots::TimeSlotService;
 use crate::modules::unit_types::UnitTypeService;
 use crate::modules::units::UnitService;
 use crate::modules::user_profiles::UserProfileService;
@@ -45,6 +60,21 @@ pub struct AppState {
     pub unit_type_service: Arc<UnitTypeService>,
     pub user_profile_service: Arc<UserProfileService>,
     pub user_service: Arc<UserService>,
+    pub schedule_service: Arc<ScheduleService>,
+    pub schedule_generation_log_service: Arc<ScheduleGenerationLogService>,
+    pub time_slot_service: Arc<TimeSlotService>,
+    pub teacher_availability_service: Arc<TeacherAvailabilityService>,
+    pub subject_preference_service: Arc<SubjectPreferenceService>,
+    pub calendar_service: Arc<CalendarService>,
+    pub substitution_service: Arc<SubstitutionService>,
+    pub room_booking_service: Arc<RoomBookingService>,
+    pub grade_service: Arc<GradeService>,
+    pub report_card_service: Arc<ReportCardService>,
+    pub character_trait_service: Arc<CharacterTraitService>,
+    pub extracurricular_service: Arc<ExtracurricularService>,
+    pub staff_attendance_service: Arc<StaffAttendanceService>,
+    pub payroll_service: Arc<PayrollService>,
+    pub salary_grade_service: Arc<SalaryGradeService>,
 }
 impl AppState {
     pub fn new(
@@ -70,6 +100,21 @@ impl AppState {
         unit_type_service: UnitTypeService,
         user_profile_service: UserProfileService,
         user_service: UserService,
+        schedule_service: ScheduleService,
+        schedule_generation_log_service: ScheduleGenerationLogService,
+        time_slot_service: TimeSlotService,
+        teacher_availability_service: TeacherAvailabilityService,
+        subject_preference_service: SubjectPreferenceService,
+        calendar_service: CalendarService,
+        substitution_service: SubstitutionService,
+        room_booking_service: RoomBookingService,
+        grade_service: GradeService,
+        report_card_service: ReportCardService,
+        character_trait_service: CharacterTraitService,
+        extracurricular_service: ExtracurricularService,
+        staff_attendance_service: StaffAttendanceService,
+        payroll_service: PayrollService,
+        salary_grade_service: SalaryGradeService,
     ) -> Self {
         Self {
             academic_year_service: Arc::new(academic_year_service),
@@ -94,6 +139,21 @@ impl AppState {
             unit_type_service: Arc::new(unit_type_service),
             user_profile_service: Arc::new(user_profile_service),
             user_service: Arc::new(user_service),
+            schedule_service: Arc::new(schedule_service),
+            schedule_generation_log_service: Arc::new(schedule_generation_log_service),
+            time_slot_service: Arc::new(time_slot_service),
+            teacher_availability_service: Arc::new(teacher_availability_service),
+            subject_preference_service: Arc::new(subject_preference_service),
+            calendar_service: Arc::new(calendar_service),
+            substitution_service: Arc::new(substitution_service),
+            room_booking_service: Arc::new(room_booking_service),
+            grade_service: Arc::new(grade_service),
+            report_card_service: Arc::new(report_card_service),
+            character_trait_service: Arc::new(character_trait_service),
+            extracurricular_service: Arc::new(extracurricular_service),
+            staff_attendance_service: Arc::new(staff_attendance_service),
+            payroll_service: Arc::new(payroll_service),
+            salary_grade_service: Arc::new(salary_grade_service),
         }
     }
 }
@@ -50,20 +50,35 @@
             crate::modules::applicants::docs::ApplicantsApiDoc,
             crate::modules::attendances::docs::AttendancesApiDoc,
             crate::modules::auth::docs::AuthApiDoc,
+            crate::modules::calendars::docs::CalendarsApiDoc,
+            crate::modules::character_traits::docs::CharacterTraitsApiDoc,
             crate::modules::class_levels::docs::ClassLevelsApiDoc,
             crate::modules::classes::docs::ClassesApiDoc,
             crate::modules::departments::docs::DepartmentsApiDoc,
             crate::modules::employees::docs::EmployeesApiDoc,
+            crate::modules::extracurriculars::docs::ExtracurricularsApiDoc,
             crate::modules::foundations::docs::FoundationsApiDoc,
+            crate::modules::grades::docs::GradesApiDoc,
+            crate::modules::payrolls::docs::PayrollsApiDoc,
             crate::modules::permissions::docs::PermissionsApiDoc,
             crate::modules::positions::docs::PositionsApiDoc,
+            crate::modules::report_cards::docs::ReportCardsApiDoc,
             crate::modules::roles::docs::RolesApiDoc,
+            crate::modules::room_bookings::docs::RoomBookingsApiDoc,
             crate::modules::rooms::docs::RoomsApiDoc,
+            crate::modules::salary_grades::docs::SalaryGradesApiDoc,
+            crate::modules::schedule_generation_logs::docs::ScheduleGenerationLogsApiDoc,
+            crate::modules::schedules::docs::SchedulesApiDoc,
             crate::modules::semesters::docs::SemestersApiDoc,
             crate::modules::settings::docs::SettingsApiDoc,
+            crate::modules::staff_attendances::docs::StaffAttendancesApiDoc,
             crate::modules::students::docs::StudentsApiDoc,
+            crate::modules::subject_preferences::docs::SubjectPreferencesApiDoc,
             crate::modules::subjects::docs::SubjectsApiDoc,
+            crate::modules::substitutions::docs::SubstitutionsApiDoc,
+            crate::modules::teacher_availability::docs::TeacherAvailabilityApiDoc,
             crate::modules::teachers::docs::TeachersApiDoc,
+            crate::modules::time_slots::docs::TimeSlotsApiDoc,
             crate::modules::unit_types::docs::UnitTypesApiDoc,
             crate::modules::units::docs::UnitsApiDoc,
             crate::modules::user_profiles::docs::UserProfilesApiDoc,
@@ -26,6 +26,7 @@ async fn main() -> std::io::Result<()> {
     env_logger::init();
     // tracing_subscriber::fmt::init();
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    utils::jwt::ensure_secrets().expect("Invalid JWT configuration");
 
     let db = Database::new(&database_url)
         .await
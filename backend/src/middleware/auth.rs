@@ -1,11 +1,16 @@
 // backend/src/middleware/auth.rs
-use crate::utils::jwt::verify_token;
+// Selain tanda tangan token, akun dicek ke database tiap request: akun nonaktif/terhapus
+// dan token yang terbit sebelum `users.tokens_valid_after` langsung ditolak.
+use crate::{utils::jwt::verify_token, AppState};
 use actix_web::{
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
-    Error, HttpMessage, Result,
+    web, Error, HttpMessage, Result,
 };
 use futures_util::future::LocalBoxFuture;
-use std::future::{ready, Ready};
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
 
 #[derive(Clone, Debug)]
 pub struct AuthContext {
@@ -14,11 +19,17 @@ pub struct AuthContext {
     pub permissions: Vec<String>,
 }
 
+impl AuthContext {
+    pub fn has_permission(&self, code: &str) -> bool {
+        self.permissions.iter().any(|p| p == code)
+    }
+}
+
 pub struct JwtMiddleware;
 
 impl<S, B> Transform<S, ServiceRequest> for JwtMiddleware
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -29,17 +40,19 @@ where
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
     fn new_transform(&self, service: S) -> Self::Future {
-        ready(Ok(JwtMiddlewareService { service }))
+        ready(Ok(JwtMiddlewareService {
+            service: Rc::new(service),
+        }))
     }
 }
 
 pub struct JwtMiddlewareService<S> {
-    service: S,
+    service: Rc<S>,
 }
 
 impl<S, B> Service<ServiceRequest> for JwtMiddlewareService<S>
 where
-    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
     S::Future: 'static,
     B: 'static,
 {
@@ -70,6 +83,14 @@ where
             Some(token) => {
                 match verify_token(&token) {
                     Ok(claims) => {
+                        let Some(app_state) = req.app_data::<web::Data<AppState>>().cloned() else {
+                            return Box::pin(async move {
+                                Err(actix_web::error::ErrorInternalServerError(
+                                    "App state is not configured",
+                                ))
+                            });
+                        };
+
                         // insert seluruh claims
                         // req.extensions_mut().insert(claims);
                         let auth_context = AuthContext {
@@ -96,9 +117,11 @@ where
                         // };
                         // req.extensions_mut().insert(auth_context);
 
-                        let fut = self.service.call(req);
+                        let service = self.service.clone();
                         Box::pin(async move {
-                            let res = fut.await?;
+                            app_state.auth_service.authorize_access(&claims).await?;
+
+                            let res = service.call(req).await?;
                             Ok(res)
                         })
                     }
@@ -1,2 +1,3 @@
 pub mod auth;
+pub mod permission;
 pub mod swagger_auth;
@@ -0,0 +1,153 @@
+// backend/src/middleware/permission.rs
+// Guard kode permission dari AuthContext; dipasang di dalam scope yang sudah
+// dibungkus JwtMiddleware, mis.
+//   .route("/{id}", web::delete().to(handler::delete).wrap(require_permission("students.delete")))
+use crate::{errors::AppError, middleware::auth::AuthContext};
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    Error, HttpMessage, Result,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Requirement {
+    /// Cukup salah satu kode dimiliki
+    Any(Vec<String>),
+    /// Semua kode harus dimiliki
+    All(Vec<String>),
+}
+
+impl Requirement {
+    pub fn is_satisfied_by(&self, granted: &[String]) -> bool {
+        let has = |code: &String| granted.iter().any(|p| p == code);
+        match self {
+            Requirement::Any(codes) => codes.iter().any(has),
+            Requirement::All(codes) => codes.iter().all(has),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Requirement::Any(codes) => codes.join(" or "),
+            Requirement::All(codes) => codes.join(" and "),
+        }
+    }
+}
+
+pub fn require_permission(code: &str) -> PermissionGuard {
+    require_any_permission(&[code])
+}
+
+pub fn require_any_permission(codes: &[&str]) -> PermissionGuard {
+    PermissionGuard::new(Requirement::Any(to_owned(codes)))
+}
+
+pub fn require_all_permissions(codes: &[&str]) -> PermissionGuard {
+    PermissionGuard::new(Requirement::All(to_owned(codes)))
+}
+
+fn to_owned(codes: &[&str]) -> Vec<String> {
+    codes.iter().map(|code| code.to_string()).collect()
+}
+
+#[derive(Clone)]
+pub struct PermissionGuard {
+    requirement: Rc<Requirement>,
+}
+
+impl PermissionGuard {
+    pub fn new(requirement: Requirement) -> Self {
+        Self {
+            requirement: Rc::new(requirement),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for PermissionGuard
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = PermissionGuardService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(PermissionGuardService {
+            service,
+            requirement: self.requirement.clone(),
+        }))
+    }
+}
+
+pub struct PermissionGuardService<S> {
+    service: S,
+    requirement: Rc<Requirement>,
+}
+
+impl<S, B> Service<ServiceRequest> for PermissionGuardService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // Tanpa AuthContext berarti guard dipasang di luar JwtMiddleware
+        let allowed = match req.extensions().get::<AuthContext>() {
+            Some(auth) => self.requirement.is_satisfied_by(&auth.permissions),
+            None => {
+                return Box::pin(async move {
+                    Err(actix_web::error::ErrorUnauthorized("No token provided"))
+                })
+            }
+        };
+
+        if !allowed {
+            let message = format!("Missing permission: {}", self.requirement.describe());
+            return Box::pin(async move { Err(AppError::forbidden(message).into()) });
+        }
+
+        let fut = self.service.call(req);
+        Box::pin(fut)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_and_all_requirements() {
+        let granted = vec!["students.read".to_string(), "students.delete".to_string()];
+
+        assert!(Requirement::Any(to_owned(&["students.delete"])).is_satisfied_by(&granted));
+        assert!(
+            Requirement::Any(to_owned(&["payrolls.manage", "students.read"]))
+                .is_satisfied_by(&granted)
+        );
+        assert!(!Requirement::Any(to_owned(&["payrolls.manage"])).is_satisfied_by(&granted));
+
+        assert!(
+            Requirement::All(to_owned(&["students.read", "students.delete"]))
+                .is_satisfied_by(&granted)
+        );
+        assert!(
+            !Requirement::All(to_owned(&["students.read", "payrolls.manage"]))
+                .is_satisfied_by(&granted)
+        );
+        assert!(!Requirement::Any(vec![]).is_satisfied_by(&granted));
+    }
+}
@@ -2,7 +2,10 @@
 // ============================================================================
 // routes.rs - Route Configuration
 // ============================================================================
-use crate::{middleware::auth::JwtMiddleware, modules::academic_years::handler};
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::academic_years::handler,
+};
 use actix_web::web;
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -16,6 +19,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             )
             .route("/{id}", web::get().to(handler::get_by_id))
             .route("/{id}", web::put().to(handler::update))
-            .route("/{id}", web::delete().to(handler::delete)),
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("academic_years.delete")),
+            ),
     );
 }
@@ -0,0 +1,139 @@
+// ============================================================================
+// backend/src/modules/attendances/analytics.rs
+// analytics.rs - Rekap & ambang ketidakhadiran (pure, tanpa akses database)
+// ============================================================================
+use super::dto::SummaryPeriod;
+use chrono::{Datelike, Duration, NaiveDate};
+use entity::sea_orm_active_enums::AttendanceStatus;
+use std::collections::BTreeMap;
+
+/// Ambang bawaan bila yayasan belum mengatur sendiri
+pub const DEFAULT_ABSENCE_THRESHOLD: f64 = 10.0;
+pub const DEFAULT_MIN_SESSIONS: i32 = 10;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusCounts {
+    pub present: u32,
+    pub late: u32,
+    pub sick: u32,
+    pub permission: u32,
+    pub absent: u32,
+}
+
+impl StatusCounts {
+    pub fn add(&mut self, status: &AttendanceStatus) {
+        match status {
+            AttendanceStatus::Present => self.present += 1,
+            AttendanceStatus::Late => self.late += 1,
+            AttendanceStatus::Sick => self.sick += 1,
+            AttendanceStatus::Permission => self.permission += 1,
+            AttendanceStatus::Absent => self.absent += 1,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.present + self.late + self.sick + self.permission + self.absent
+    }
+
+    /// Sakit, izin dan alpa sama-sama dihitung tidak hadir; terlambat tetap hadir
+    pub fn absences(&self) -> u32 {
+        self.sick + self.permission + self.absent
+    }
+
+    pub fn attendance_rate(&self) -> f64 {
+        rate(self.present + self.late, self.total())
+    }
+
+    pub fn absence_rate(&self) -> f64 {
+        rate(self.absences(), self.total())
+    }
+}
+
+fn rate(part: u32, total: u32) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (f64::from(part) * 10000.0 / f64::from(total)).round() / 100.0
+}
+
+/// Awal periode rekap: Senin untuk mingguan, tanggal 1 untuk bulanan
+pub fn period_start(
+    date: NaiveDate,
+    period: SummaryPeriod,
+    semester_start: NaiveDate,
+) -> NaiveDate {
+    match period {
+        SummaryPeriod::Week => {
+            date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        }
+        SummaryPeriod::Month => date.with_day(1).unwrap_or(date),
+        SummaryPeriod::Semester => semester_start,
+    }
+}
+
+/// Rekap status per periode, diurutkan dari periode paling awal
+pub fn summarize<'a>(
+    rows: impl IntoIterator<Item = (NaiveDate, &'a AttendanceStatus)>,
+    period: SummaryPeriod,
+    semester_start: NaiveDate,
+) -> Vec<(NaiveDate, StatusCounts)> {
+    let mut buckets: BTreeMap<NaiveDate, StatusCounts> = BTreeMap::new();
+    for (date, status) in rows {
+        buckets
+            .entry(period_start(date, period, semester_start))
+            .or_default()
+            .add(status);
+    }
+    buckets.into_iter().collect()
+}
+
+/// Peringatan hanya setelah cukup pertemuan agar satu kali absen di awal semester tidak memicu
+pub fn crosses_threshold(counts: &StatusCounts, threshold: f64, min_sessions: i32) -> bool {
+    i64::from(counts.total()) >= i64::from(min_sessions) && counts.absence_rate() >= threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_by_week_and_month() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let semester_start = date(7, 13);
+        let rows = [
+            (date(7, 29), AttendanceStatus::Present), // Rabu
+            (date(7, 31), AttendanceStatus::Absent),  // Jumat
+            (date(8, 3), AttendanceStatus::Late),     // Senin minggu berikutnya
+        ];
+        let iter = || rows.iter().map(|(d, s)| (*d, s));
+
+        let weekly = summarize(iter(), SummaryPeriod::Week, semester_start);
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly[0].0, date(7, 27));
+        assert_eq!(weekly[0].1.absent, 1);
+        assert_eq!(weekly[1].0, date(8, 3));
+
+        let monthly = summarize(iter(), SummaryPeriod::Month, semester_start);
+        assert_eq!(monthly[0].0, date(7, 1));
+        assert_eq!(monthly[0].1.total(), 2);
+
+        let whole = summarize(iter(), SummaryPeriod::Semester, semester_start);
+        assert_eq!(whole, vec![(semester_start, whole[0].1)]);
+        assert_eq!(whole[0].1.attendance_rate(), 66.67);
+        assert_eq!(whole[0].1.absence_rate(), 33.33);
+    }
+
+    #[test]
+    fn test_threshold_needs_minimum_sessions() {
+        let counts = StatusCounts {
+            present: 7,
+            sick: 1,
+            absent: 2,
+            ..Default::default()
+        };
+        assert_eq!(counts.absence_rate(), 30.0);
+        assert!(crosses_threshold(&counts, 10.0, 10));
+        assert!(!crosses_threshold(&counts, 10.0, 11));
+        assert!(!crosses_threshold(&counts, 30.01, 10));
+    }
+}
@@ -1,7 +1,12 @@
 // ============================================================================
 // docs.rs - OpenAPI Documentation
 // ============================================================================
-use super::dto::{CreateAttendanceRequest, AttendanceResponse, UpdateAttendanceRequest};
+use super::dto::{
+    AlertSettingsResponse, AttendanceBucket, AttendanceResponse, AttendanceSummaryQuery,
+    ChronicAbsenceQuery, ChronicAbsenceResponse, ClassAttendanceSummary, CreateAttendanceRequest,
+    RollCallEntry, RollCallRequest, RollCallResponse, SetAlertSettingsRequest,
+    StudentAttendanceRate, StudentAttendanceSummary, SummaryPeriod, UpdateAttendanceRequest,
+};
 use super::handler;
 use crate::utils::pagination::{PaginatedResponse, PaginationParams};
 use utoipa::OpenApi;
@@ -14,18 +19,37 @@ use utoipa::OpenApi;
         handler::create,
         handler::update,
         handler::delete,
+        handler::roll_call,
+        handler::student_summary,
+        handler::class_summary,
+        handler::chronic_absences,
+        handler::get_alert_settings,
+        handler::set_alert_settings,
     ),
     components(
         schemas(
             AttendanceResponse,
             CreateAttendanceRequest,
             UpdateAttendanceRequest,
+            RollCallEntry,
+            RollCallRequest,
+            RollCallResponse,
+            SummaryPeriod,
+            AttendanceSummaryQuery,
+            AttendanceBucket,
+            StudentAttendanceSummary,
+            StudentAttendanceRate,
+            ClassAttendanceSummary,
+            ChronicAbsenceQuery,
+            ChronicAbsenceResponse,
+            AlertSettingsResponse,
+            SetAlertSettingsRequest,
             PaginatedResponse<AttendanceResponse>,
             PaginationParams,
         )
     ),
     tags(
-        (name = "Attendance ", description = "Attendance management endpoints")
+        (name = "Attendance ", description = "Attendance management, roll call and absence analytics endpoints")
     ),
     modifiers(&SecurityAddon)
 )]
@@ -1,8 +1,9 @@
 // backend/src/modules/attendances/dto.rs
+use super::analytics::StatusCounts;
 use chrono::NaiveDate;
-use entity::sea_orm_active_enums::Status;
+use entity::sea_orm_active_enums::AttendanceStatus;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AttendanceResponse {
@@ -11,7 +12,7 @@ pub struct AttendanceResponse {
     pub student_id: i64,
     pub class_subject_id: i64,
     pub date: NaiveDate,
-    pub status: Status,
+    pub status: AttendanceStatus,
     pub notes: Option<String>,
     pub created_at: String,
     pub updated_at: String, // ← Tambah ini (good practice)
@@ -23,7 +24,7 @@ pub struct CreateAttendanceRequest {
     pub student_id: i64,
     pub class_subject_id: i64,
     pub date: NaiveDate,
-    pub status: Status,
+    pub status: AttendanceStatus,
     pub notes: Option<String>,
 }
 
@@ -33,10 +34,180 @@ pub struct UpdateAttendanceRequest {
     pub student_id: i64,
     pub class_subject_id: i64,
     pub date: NaiveDate,
-    pub status: Status,
+    pub status: AttendanceStatus,
     pub notes: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RollCallEntry {
+    #[validate(range(min = 1))]
+    pub student_id: i64,
+    pub status: AttendanceStatus,
+    #[validate(length(max = 500))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RollCallRequest {
+    #[validate(range(min = 1))]
+    pub class_subject_id: i64,
+    pub date: NaiveDate,
+    /// Hanya siswa yang tidak hadir/terlambat; siswa lain di roster dicatat hadir
+    #[validate(nested)]
+    #[serde(default)]
+    pub entries: Vec<RollCallEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RollCallResponse {
+    pub class_subject_id: i64,
+    pub date: NaiveDate,
+    pub present: usize,
+    pub late: usize,
+    pub sick: usize,
+    pub permission: usize,
+    pub absent: usize,
+    pub records: Vec<AttendanceResponse>,
+}
+
+impl RollCallResponse {
+    pub fn new(class_subject_id: i64, date: NaiveDate, records: Vec<AttendanceResponse>) -> Self {
+        let count =
+            |status: AttendanceStatus| records.iter().filter(|r| r.status == status).count();
+        Self {
+            class_subject_id,
+            date,
+            present: count(AttendanceStatus::Present),
+            late: count(AttendanceStatus::Late),
+            sick: count(AttendanceStatus::Sick),
+            permission: count(AttendanceStatus::Permission),
+            absent: count(AttendanceStatus::Absent),
+            records,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryPeriod {
+    Week,
+    #[default]
+    Month,
+    Semester,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct AttendanceSummaryQuery {
+    pub semester_id: i64,
+    /// week, month (default) atau semester
+    pub period: Option<SummaryPeriod>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AttendanceBucket {
+    /// Senin (mingguan), tanggal 1 (bulanan) atau awal semester
+    pub period_start: NaiveDate,
+    pub present: u32,
+    pub late: u32,
+    pub sick: u32,
+    pub permission: u32,
+    pub absent: u32,
+    pub total: u32,
+    pub attendance_rate: f64,
+    pub absence_rate: f64,
+}
+
+impl AttendanceBucket {
+    pub fn new(period_start: NaiveDate, counts: &StatusCounts) -> Self {
+        Self {
+            period_start,
+            present: counts.present,
+            late: counts.late,
+            sick: counts.sick,
+            permission: counts.permission,
+            absent: counts.absent,
+            total: counts.total(),
+            attendance_rate: counts.attendance_rate(),
+            absence_rate: counts.absence_rate(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentAttendanceSummary {
+    pub student_id: i64,
+    pub semester_id: i64,
+    pub period: SummaryPeriod,
+    pub overall: AttendanceBucket,
+    pub buckets: Vec<AttendanceBucket>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentAttendanceRate {
+    pub student_id: i64,
+    pub student_name: String,
+    pub class_id: Option<i64>,
+    pub sessions: u32,
+    pub absences: u32,
+    pub attendance_rate: f64,
+    pub absence_rate: f64,
+}
+
+impl StudentAttendanceRate {
+    pub fn new(student: &entity::students::Model, counts: &StatusCounts) -> Self {
+        Self {
+            student_id: student.id,
+            student_name: student.name.clone(),
+            class_id: student.class_id,
+            sessions: counts.total(),
+            absences: counts.absences(),
+            attendance_rate: counts.attendance_rate(),
+            absence_rate: counts.absence_rate(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassAttendanceSummary {
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub period: SummaryPeriod,
+    pub overall: AttendanceBucket,
+    pub buckets: Vec<AttendanceBucket>,
+    pub students: Vec<StudentAttendanceRate>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ChronicAbsenceQuery {
+    pub semester_id: i64,
+    pub class_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChronicAbsenceResponse {
+    pub semester_id: i64,
+    pub absence_threshold: f64,
+    pub min_sessions: i32,
+    /// Diurutkan dari tingkat ketidakhadiran tertinggi
+    pub students: Vec<StudentAttendanceRate>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertSettingsResponse {
+    pub absence_threshold: f64,
+    pub min_sessions: i32,
+    /// true bila yayasan belum mengatur dan nilai bawaan yang dipakai
+    pub is_default: bool,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetAlertSettingsRequest {
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub absence_threshold: f64,
+    #[validate(range(min = 1))]
+    pub min_sessions: i32,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AttendanceListResponse {
     pub data: Vec<AttendanceResponse>,
@@ -1,9 +1,14 @@
 // ============================================================================
 // handler.rs - HTTP Handlers
 // ============================================================================
-use super::dto::{AttendanceResponse, CreateAttendanceRequest, UpdateAttendanceRequest};
+use super::dto::{
+    AlertSettingsResponse, AttendanceResponse, AttendanceSummaryQuery, ChronicAbsenceQuery,
+    ChronicAbsenceResponse, ClassAttendanceSummary, CreateAttendanceRequest, RollCallRequest,
+    RollCallResponse, SetAlertSettingsRequest, StudentAttendanceSummary, UpdateAttendanceRequest,
+};
 use crate::app_state::AppState;
 use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
 use crate::utils::pagination::{PaginatedResponse, PaginationParams};
 use actix_web::{web, HttpResponse};
 
@@ -133,3 +138,161 @@ pub async fn delete(
     app_state.attendance_service.delete(id.into_inner()).await?;
     Ok(HttpResponse::NoContent().finish())
 }
+
+/// Take attendance of a whole class session (roll call)
+#[utoipa::path(
+    post,
+    path = "/api/attendances/roll_call",
+    request_body = RollCallRequest,
+    responses(
+        (status = 200, description = "Attendance saved for every student of the class", body = RollCallResponse),
+        (status = 400, description = "Semester not active, date outside semester or student not in class"),
+        (status = 404, description = "Class subject not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn roll_call(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<RollCallRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .roll_call(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Attendance rates of a student per week, month or semester
+#[utoipa::path(
+    get,
+    path = "/api/attendances/summary/students/{student_id}",
+    params(
+        ("student_id" = i64, Path, description = "Student ID"),
+        AttendanceSummaryQuery
+    ),
+    responses(
+        (status = 200, description = "Status breakdown and rates per period", body = StudentAttendanceSummary),
+        (status = 404, description = "Student or semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn student_summary(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    student_id: web::Path<i64>,
+    query: web::Query<AttendanceSummaryQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .student_summary(
+            student_id.into_inner(),
+            query.into_inner(),
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Attendance rates of a class per week, month or semester
+#[utoipa::path(
+    get,
+    path = "/api/attendances/summary/classes/{class_id}",
+    params(
+        ("class_id" = i64, Path, description = "Class ID"),
+        AttendanceSummaryQuery
+    ),
+    responses(
+        (status = 200, description = "Status breakdown per period and rate of each student", body = ClassAttendanceSummary),
+        (status = 404, description = "Class or semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn class_summary(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    class_id: web::Path<i64>,
+    query: web::Query<AttendanceSummaryQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .class_summary(
+            class_id.into_inner(),
+            query.into_inner(),
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Students whose absence rate crosses the threshold
+#[utoipa::path(
+    get,
+    path = "/api/attendances/chronic_absences",
+    params(ChronicAbsenceQuery),
+    responses(
+        (status = 200, description = "Students above the threshold, highest rate first", body = ChronicAbsenceResponse),
+        (status = 404, description = "Semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn chronic_absences(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<ChronicAbsenceQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .chronic_absences(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get absence alert threshold
+#[utoipa::path(
+    get,
+    path = "/api/attendances/alert_settings",
+    responses(
+        (status = 200, description = "Configured or default threshold", body = AlertSettingsResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn get_alert_settings(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .get_alert_settings(auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Set absence alert threshold
+#[utoipa::path(
+    put,
+    path = "/api/attendances/alert_settings",
+    request_body = SetAlertSettingsRequest,
+    responses(
+        (status = 200, description = "Threshold saved", body = AlertSettingsResponse),
+        (status = 400, description = "Bad request")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Attendance "
+)]
+pub async fn set_alert_settings(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<SetAlertSettingsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .attendance_service
+        .set_alert_settings(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
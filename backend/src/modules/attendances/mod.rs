@@ -2,10 +2,12 @@
 // ============================================================================
 // mod.rs - Module Entry Point
 // ============================================================================
+pub mod analytics;
 pub mod docs;
 pub mod dto;
 pub mod handler;
 pub mod repository;
+pub mod roll_call;
 pub mod routes;
 pub mod service;
 
@@ -4,9 +4,17 @@
 use crate::config::database::Database;
 use crate::errors::AppError;
 use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
 use entity::attendances::{self, Entity as Attendance};
+use entity::{
+    attendance_alert_settings, attendance_alerts, class_subjects, classes, notifications,
+    semesters, students, teachers,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::sea_query::Query;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
 };
 
 #[derive(Clone)]
@@ -122,4 +130,245 @@ impl AttendanceRepository {
 
         Ok(())
     }
+
+    /// Find class subject of a foundation (not deleted)
+    pub async fn find_class_subject(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find_by_id(id)
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semester of a foundation (not deleted)
+    pub async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .filter(semesters::Column::FoundationId.eq(foundation_id))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active students of a class
+    pub async fn find_students_by_class(
+        &self,
+        class_id: i64,
+    ) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::ClassId.eq(class_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find attendance rows of a class session, including deleted ones
+    pub async fn find_by_session(
+        &self,
+        class_subject_id: i64,
+        date: NaiveDate,
+    ) -> Result<Vec<attendances::Model>, AppError> {
+        Attendance::find()
+            .filter(attendances::Column::ClassSubjectId.eq(class_subject_id))
+            .filter(attendances::Column::Date.eq(date))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert new and update existing attendance rows in a single transaction
+    pub async fn save_many(
+        &self,
+        models: Vec<attendances::ActiveModel>,
+    ) -> Result<Vec<attendances::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(models.len());
+        for model in models {
+            let row = if !model.id.is_not_set() {
+                model.update(&txn).await
+            } else {
+                model.insert(&txn).await
+            }
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Find student of a foundation (not deleted)
+    pub async fn find_student(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<students::Model>, AppError> {
+        students::Entity::find_by_id(id)
+            .filter(students::Column::FoundationId.eq(foundation_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find students by IDs
+    pub async fn find_students(&self, ids: Vec<i64>) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::Id.is_in(ids))
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class of a foundation (not deleted)
+    pub async fn find_class(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<classes::Model>, AppError> {
+        classes::Entity::find_by_id(id)
+            .filter(classes::Column::FoundationId.eq(foundation_id))
+            .filter(classes::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher by ID
+    pub async fn find_teacher(&self, id: i64) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find attendance rows of a semester, optionally of one class and/or one student
+    pub async fn find_by_semester(
+        &self,
+        semester_id: i64,
+        class_id: Option<i64>,
+        student_id: Option<i64>,
+    ) -> Result<Vec<attendances::Model>, AppError> {
+        let mut class_subject_ids = Query::select()
+            .column(class_subjects::Column::Id)
+            .from(class_subjects::Entity)
+            .and_where(class_subjects::Column::SemesterId.eq(semester_id))
+            .and_where(class_subjects::Column::DeletedAt.is_null())
+            .to_owned();
+        if let Some(class_id) = class_id {
+            class_subject_ids.and_where(class_subjects::Column::ClassId.eq(class_id));
+        }
+
+        let mut query = Attendance::find()
+            .filter(attendances::Column::ClassSubjectId.in_subquery(class_subject_ids))
+            .filter(attendances::Column::DeletedAt.is_null());
+        if let Some(student_id) = student_id {
+            query = query.filter(attendances::Column::StudentId.eq(student_id));
+        }
+
+        query
+            .order_by_asc(attendances::Column::Date)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find alert settings of a foundation
+    pub async fn find_alert_settings(
+        &self,
+        foundation_id: i64,
+    ) -> Result<Option<attendance_alert_settings::Model>, AppError> {
+        attendance_alert_settings::Entity::find()
+            .filter(attendance_alert_settings::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Create or update alert settings of a foundation
+    pub async fn upsert_alert_settings(
+        &self,
+        foundation_id: i64,
+        absence_threshold: Decimal,
+        min_sessions: i32,
+    ) -> Result<attendance_alert_settings::Model, AppError> {
+        let now = chrono::Utc::now();
+        let mut active: attendance_alert_settings::ActiveModel =
+            match self.find_alert_settings(foundation_id).await? {
+                Some(model) => model.into(),
+                None => attendance_alert_settings::ActiveModel {
+                    foundation_id: Set(foundation_id),
+                    created_at: Set(now),
+                    ..Default::default()
+                },
+            };
+        active.absence_threshold = Set(absence_threshold);
+        active.min_sessions = Set(min_sessions);
+        active.updated_at = Set(now);
+
+        if !active.id.is_not_set() {
+            active.update(self.conn()).await
+        } else {
+            active.insert(self.conn()).await
+        }
+        .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Students that already got an alert in the semester
+    pub async fn find_alerted_students(&self, semester_id: i64) -> Result<Vec<i64>, AppError> {
+        let alerts = attendance_alerts::Entity::find()
+            .filter(attendance_alerts::Column::SemesterId.eq(semester_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(alerts.into_iter().map(|a| a.student_id).collect())
+    }
+
+    /// Record alerts together with the notifications they send
+    pub async fn create_alerts(
+        &self,
+        alerts: Vec<(attendance_alerts::ActiveModel, notifications::ActiveModel)>,
+    ) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        for (alert, notification) in alerts {
+            alert
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            notification
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
 }
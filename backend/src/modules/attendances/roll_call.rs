@@ -0,0 +1,62 @@
+// ============================================================================
+// backend/src/modules/attendances/roll_call.rs
+// roll_call.rs - Aturan absensi massal (pure, tanpa akses database)
+// ============================================================================
+use super::dto::RollCallEntry;
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::AttendanceStatus;
+use std::collections::HashMap;
+
+/// Status akhir tiap siswa di roster; yang tidak disebut dianggap hadir
+pub fn resolve(
+    roster: &[i64],
+    entries: &[RollCallEntry],
+) -> Vec<(i64, AttendanceStatus, Option<String>)> {
+    let listed: HashMap<i64, &RollCallEntry> = entries.iter().map(|e| (e.student_id, e)).collect();
+
+    roster
+        .iter()
+        .map(|student_id| match listed.get(student_id) {
+            Some(entry) => (*student_id, entry.status.clone(), entry.notes.clone()),
+            None => (*student_id, AttendanceStatus::Present, None),
+        })
+        .collect()
+}
+
+/// Tanggal absensi harus berada di dalam rentang semester
+pub fn within_semester(date: NaiveDate, start_date: NaiveDate, end_date: NaiveDate) -> bool {
+    date >= start_date && date <= end_date
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlisted_students_default_to_present() {
+        let entries = vec![RollCallEntry {
+            student_id: 2,
+            status: AttendanceStatus::Sick,
+            notes: Some("Demam".to_string()),
+        }];
+
+        let resolved = resolve(&[1, 2, 3], &entries);
+        assert_eq!(resolved.len(), 3);
+        assert_eq!(resolved[0], (1, AttendanceStatus::Present, None));
+        assert_eq!(
+            resolved[1],
+            (2, AttendanceStatus::Sick, Some("Demam".to_string()))
+        );
+        assert_eq!(resolved[2], (3, AttendanceStatus::Present, None));
+
+        let start = NaiveDate::from_ymd_opt(2026, 7, 13).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 12, 19).unwrap();
+        assert!(within_semester(start, start, end));
+        assert!(within_semester(end, start, end));
+        assert!(!within_semester(
+            NaiveDate::from_ymd_opt(2026, 12, 20).unwrap(),
+            start,
+            end
+        ));
+    }
+}
@@ -1,7 +1,10 @@
 // ============================================================================
 // routes.rs - Route Configuration
 // ============================================================================
-use crate::{middleware::auth::JwtMiddleware, modules::attendances::handler};
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::attendances::handler,
+};
 use actix_web::web;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -10,8 +13,34 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .wrap(JwtMiddleware)
             .route("", web::post().to(handler::create))
             .route("", web::get().to(handler::get_all))
+            .route("/roll_call", web::post().to(handler::roll_call))
+            .route(
+                "/summary/students/{student_id}",
+                web::get().to(handler::student_summary),
+            )
+            .route(
+                "/summary/classes/{class_id}",
+                web::get().to(handler::class_summary),
+            )
+            .route(
+                "/chronic_absences",
+                web::get().to(handler::chronic_absences),
+            )
+            .route(
+                "/alert_settings",
+                web::get().to(handler::get_alert_settings),
+            )
+            .route(
+                "/alert_settings",
+                web::put().to(handler::set_alert_settings),
+            )
             .route("/{id}", web::get().to(handler::get_by_id))
             .route("/{id}", web::put().to(handler::update))
-            .route("/{id}", web::delete().to(handler::delete)),
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("attendances.delete")),
+            ),
     );
 }
@@ -2,12 +2,21 @@
 // backend/src/modules/attendances/service.rs
 // service.rs - Business Logic Only
 // ============================================================================
-use super::dto::{AttendanceResponse, CreateAttendanceRequest, UpdateAttendanceRequest};
+use super::analytics::{self, StatusCounts};
+use super::dto::{
+    AlertSettingsResponse, AttendanceBucket, AttendanceResponse, AttendanceSummaryQuery,
+    ChronicAbsenceQuery, ChronicAbsenceResponse, ClassAttendanceSummary, CreateAttendanceRequest,
+    RollCallRequest, RollCallResponse, SetAlertSettingsRequest, StudentAttendanceRate,
+    StudentAttendanceSummary, SummaryPeriod, UpdateAttendanceRequest,
+};
 use super::repository::AttendanceRepository;
+use super::roll_call;
 use crate::errors::AppError;
 use crate::utils::pagination::{PaginatedResponse, PaginationParams};
-use entity::attendances;
+use entity::{attendance_alerts, attendances, notifications, semesters};
+use sea_orm::prelude::Decimal;
 use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
 use validator::Validate;
 
 #[derive(Clone)]
@@ -175,4 +184,414 @@ impl AttendanceService {
 
         self.repository.delete(id).await
     }
+
+    /// Take attendance of a whole class session at once
+    pub async fn roll_call(
+        &self,
+        request: RollCallRequest,
+        foundation_id: i64,
+    ) -> Result<RollCallResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let class_subject = self
+            .repository
+            .find_class_subject(request.class_subject_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class subject not found".to_string()))?;
+
+        // Business rule: hanya di semester aktif dan di dalam rentang tanggalnya
+        let semester = self
+            .repository
+            .find_semester(class_subject.semester_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+        if semester.is_active == 0 {
+            return Err(AppError::validation(format!(
+                "Semester {} is not active",
+                semester.name
+            )));
+        }
+        if !roll_call::within_semester(request.date, semester.start_date, semester.end_date) {
+            return Err(AppError::validation(format!(
+                "Date {} is outside semester {} ({} - {})",
+                request.date, semester.name, semester.start_date, semester.end_date
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = request.entries.iter().find(|e| !seen.insert(e.student_id)) {
+            return Err(AppError::validation(format!(
+                "Student {} is listed more than once",
+                duplicate.student_id
+            )));
+        }
+
+        let roster: Vec<i64> = self
+            .repository
+            .find_students_by_class(class_subject.class_id)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        if let Some(entry) = request
+            .entries
+            .iter()
+            .find(|e| !roster.contains(&e.student_id))
+        {
+            return Err(AppError::validation(format!(
+                "Student {} is not in class {}",
+                entry.student_id, class_subject.class_id
+            )));
+        }
+
+        let mut existing: HashMap<i64, attendances::Model> = self
+            .repository
+            .find_by_session(class_subject.id, request.date)
+            .await?
+            .into_iter()
+            .map(|a| (a.student_id, a))
+            .collect();
+
+        let now = chrono::Utc::now();
+        let models = roll_call::resolve(&roster, &request.entries)
+            .into_iter()
+            .map(|(student_id, status, notes)| {
+                let mut model = match existing.remove(&student_id) {
+                    Some(row) => attendances::ActiveModel {
+                        id: Set(row.id),
+                        deleted_at: Set(None),
+                        ..Default::default()
+                    },
+                    None => attendances::ActiveModel {
+                        foundation_id: Set(foundation_id),
+                        student_id: Set(student_id),
+                        class_subject_id: Set(class_subject.id),
+                        date: Set(request.date),
+                        created_at: Set(now),
+                        ..Default::default()
+                    },
+                };
+                model.status = Set(status);
+                model.notes = Set(notes);
+                model.updated_at = Set(now);
+                model
+            })
+            .collect();
+
+        let saved = self.repository.save_many(models).await?;
+
+        // Absensi sudah tersimpan; kegagalan peringatan tidak membatalkannya
+        if let Err(e) = self
+            .check_absence_alerts(class_subject.class_id, &semester, foundation_id)
+            .await
+        {
+            log::warn!(
+                "Absence alert check failed for class {}: {}",
+                class_subject.class_id,
+                e
+            );
+        }
+
+        Ok(RollCallResponse::new(
+            class_subject.id,
+            request.date,
+            AttendanceResponse::from_vec(saved),
+        ))
+    }
+
+    /// Attendance rates of a student per period in a semester
+    pub async fn student_summary(
+        &self,
+        student_id: i64,
+        query: AttendanceSummaryQuery,
+        foundation_id: i64,
+    ) -> Result<StudentAttendanceSummary, AppError> {
+        self.repository
+            .find_student(student_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Student not found".to_string()))?;
+        let semester = self.find_semester(query.semester_id, foundation_id).await?;
+        let period = query.period.unwrap_or_default();
+
+        let rows = self
+            .repository
+            .find_by_semester(semester.id, None, Some(student_id))
+            .await?;
+        let (overall, buckets) = bucketize(&rows, period, &semester);
+
+        Ok(StudentAttendanceSummary {
+            student_id,
+            semester_id: semester.id,
+            period,
+            overall,
+            buckets,
+        })
+    }
+
+    /// Attendance rates of a class per period, with the rate of each student
+    pub async fn class_summary(
+        &self,
+        class_id: i64,
+        query: AttendanceSummaryQuery,
+        foundation_id: i64,
+    ) -> Result<ClassAttendanceSummary, AppError> {
+        self.repository
+            .find_class(class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+        let semester = self.find_semester(query.semester_id, foundation_id).await?;
+        let period = query.period.unwrap_or_default();
+
+        let rows = self
+            .repository
+            .find_by_semester(semester.id, Some(class_id), None)
+            .await?;
+        let (overall, buckets) = bucketize(&rows, period, &semester);
+        let students = self.student_rates(&rows).await?;
+
+        Ok(ClassAttendanceSummary {
+            class_id,
+            semester_id: semester.id,
+            period,
+            overall,
+            buckets,
+            students,
+        })
+    }
+
+    /// Students whose absence rate crosses the foundation threshold
+    pub async fn chronic_absences(
+        &self,
+        query: ChronicAbsenceQuery,
+        foundation_id: i64,
+    ) -> Result<ChronicAbsenceResponse, AppError> {
+        let semester = self.find_semester(query.semester_id, foundation_id).await?;
+        let settings = self.get_alert_settings(foundation_id).await?;
+
+        let rows = self
+            .repository
+            .find_by_semester(semester.id, query.class_id, None)
+            .await?;
+        let counts = counts_per_student(&rows);
+        let mut students: Vec<StudentAttendanceRate> = self
+            .student_rates(&rows)
+            .await?
+            .into_iter()
+            .filter(|rate| {
+                counts.get(&rate.student_id).is_some_and(|c| {
+                    analytics::crosses_threshold(
+                        c,
+                        settings.absence_threshold,
+                        settings.min_sessions,
+                    )
+                })
+            })
+            .collect();
+        students.sort_by(|a, b| b.absence_rate.total_cmp(&a.absence_rate));
+
+        Ok(ChronicAbsenceResponse {
+            semester_id: semester.id,
+            absence_threshold: settings.absence_threshold,
+            min_sessions: settings.min_sessions,
+            students,
+        })
+    }
+
+    /// Get absence alert settings of the foundation
+    pub async fn get_alert_settings(
+        &self,
+        foundation_id: i64,
+    ) -> Result<AlertSettingsResponse, AppError> {
+        let settings = self.repository.find_alert_settings(foundation_id).await?;
+
+        Ok(match settings {
+            Some(model) => AlertSettingsResponse {
+                absence_threshold: model.absence_threshold.try_into().unwrap_or_default(),
+                min_sessions: model.min_sessions,
+                is_default: false,
+            },
+            None => AlertSettingsResponse {
+                absence_threshold: analytics::DEFAULT_ABSENCE_THRESHOLD,
+                min_sessions: analytics::DEFAULT_MIN_SESSIONS,
+                is_default: true,
+            },
+        })
+    }
+
+    /// Set absence alert settings of the foundation
+    pub async fn set_alert_settings(
+        &self,
+        request: SetAlertSettingsRequest,
+        foundation_id: i64,
+    ) -> Result<AlertSettingsResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let threshold = Decimal::try_from(request.absence_threshold)
+            .unwrap_or_default()
+            .round_dp(2);
+        self.repository
+            .upsert_alert_settings(foundation_id, threshold, request.min_sessions)
+            .await?;
+
+        self.get_alert_settings(foundation_id).await
+    }
+
+    /// Notify the homeroom teacher once per semester for each student crossing the threshold
+    async fn check_absence_alerts(
+        &self,
+        class_id: i64,
+        semester: &semesters::Model,
+        foundation_id: i64,
+    ) -> Result<(), AppError> {
+        // Tanpa wali kelas belum ada yang bisa diberi tahu; dicek lagi di absensi berikutnya
+        let Some(class) = self.repository.find_class(class_id, foundation_id).await? else {
+            return Ok(());
+        };
+        let Some(teacher_id) = class.homeroom_teacher_id else {
+            return Ok(());
+        };
+        let Some(teacher) = self.repository.find_teacher(teacher_id).await? else {
+            return Ok(());
+        };
+        let user_id = i32::try_from(teacher.user_id)
+            .map_err(|_| AppError::internal("User ID out of range"))?;
+
+        let settings = self.get_alert_settings(foundation_id).await?;
+        let rows = self
+            .repository
+            .find_by_semester(semester.id, Some(class_id), None)
+            .await?;
+        let alerted: HashSet<i64> = self
+            .repository
+            .find_alerted_students(semester.id)
+            .await?
+            .into_iter()
+            .collect();
+
+        let crossing: HashMap<i64, StatusCounts> = counts_per_student(&rows)
+            .into_iter()
+            .filter(|(student_id, counts)| {
+                !alerted.contains(student_id)
+                    && analytics::crosses_threshold(
+                        counts,
+                        settings.absence_threshold,
+                        settings.min_sessions,
+                    )
+            })
+            .collect();
+        if crossing.is_empty() {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now();
+        let alerts = self
+            .repository
+            .find_students(crossing.keys().copied().collect())
+            .await?
+            .into_iter()
+            .map(|student| {
+                let rate = crossing[&student.id].absence_rate();
+                let alert = attendance_alerts::ActiveModel {
+                    foundation_id: Set(foundation_id),
+                    student_id: Set(student.id),
+                    semester_id: Set(semester.id),
+                    class_id: Set(class_id),
+                    absence_rate: Set(Decimal::try_from(rate).unwrap_or_default().round_dp(2)),
+                    notified_user_id: Set(teacher.user_id),
+                    created_at: Set(now),
+                    ..Default::default()
+                };
+                let notification = notifications::ActiveModel {
+                    user_id: Set(user_id),
+                    title: Set("Peringatan ketidakhadiran".to_string()),
+                    message: Set(format!(
+                        "{} ({}) tidak hadir {:.2}% pertemuan di semester {} (ambang {:.2}%).",
+                        student.name, class.name, rate, semester.name, settings.absence_threshold
+                    )),
+                    icon: Set(Some("alert-triangle".to_string())),
+                    link: Set(Some(format!(
+                        "/attendances/summary/students/{}?semester_id={}",
+                        student.id, semester.id
+                    ))),
+                    was_read: Set(Some(0)),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+                (alert, notification)
+            })
+            .collect();
+
+        self.repository.create_alerts(alerts).await
+    }
+
+    async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<semesters::Model, AppError> {
+        self.repository
+            .find_semester(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))
+    }
+
+    async fn student_rates(
+        &self,
+        rows: &[attendances::Model],
+    ) -> Result<Vec<StudentAttendanceRate>, AppError> {
+        let counts = counts_per_student(rows);
+        if counts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let students = self
+            .repository
+            .find_students(counts.keys().copied().collect())
+            .await?;
+
+        Ok(students
+            .iter()
+            .filter_map(|s| counts.get(&s.id).map(|c| StudentAttendanceRate::new(s, c)))
+            .collect())
+    }
+}
+
+fn counts_per_student(rows: &[attendances::Model]) -> HashMap<i64, StatusCounts> {
+    let mut counts: HashMap<i64, StatusCounts> = HashMap::new();
+    for row in rows {
+        counts.entry(row.student_id).or_default().add(&row.status);
+    }
+    counts
+}
+
+fn bucketize(
+    rows: &[attendances::Model],
+    period: SummaryPeriod,
+    semester: &semesters::Model,
+) -> (AttendanceBucket, Vec<AttendanceBucket>) {
+    let mut overall = StatusCounts::default();
+    for row in rows {
+        overall.add(&row.status);
+    }
+
+    let buckets = analytics::summarize(
+        rows.iter().map(|r| (r.date, &r.status)),
+        period,
+        semester.start_date,
+    )
+    .iter()
+    .map(|(start, counts)| AttendanceBucket::new(*start, counts))
+    .collect();
+
+    (
+        AttendanceBucket::new(semester.start_date, &overall),
+        buckets,
+    )
 }
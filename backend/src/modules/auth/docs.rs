@@ -10,6 +10,20 @@ use utoipa::OpenApi;
         handler::register,
         handler::login,
         handler::refresh,
+        handler::logout,
+        handler::logout_all,
+        handler::sessions,
+        handler::revoke_session,
+        handler::forgot_password,
+        handler::reset_password,
+        handler::verify_email,
+        handler::resend_verification,
+        handler::verify_two_factor,
+        handler::two_factor_status,
+        handler::setup_two_factor,
+        handler::enable_two_factor,
+        handler::disable_two_factor,
+        handler::regenerate_recovery_codes,
     ),
     components(
         schemas(
@@ -19,6 +33,19 @@ use utoipa::OpenApi;
             dto::UserInfo,
             dto::RefreshTokenRequest,
             dto::RefreshTokenResponse,
+            dto::SessionResponse,
+            dto::ForgotPasswordRequest,
+            dto::ResetPasswordRequest,
+            dto::VerifyEmailRequest,
+            dto::ResendVerificationRequest,
+            dto::LoginResponse,
+            dto::TwoFactorChallengeResponse,
+            dto::TwoFactorVerifyRequest,
+            dto::TwoFactorCodeRequest,
+            dto::DisableTwoFactorRequest,
+            dto::TwoFactorStatusResponse,
+            dto::TwoFactorSetupResponse,
+            dto::RecoveryCodesResponse,
         )
     ),
     tags(
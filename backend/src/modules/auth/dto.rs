@@ -1,5 +1,6 @@
 // src/modules/auth/dto.rs
 use crate::modules::users::dto::UserResponse;
+use entity::refresh_tokens;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -31,6 +32,23 @@ pub struct AuthResponse {
     pub token_type: String,
     pub expires_in: i64,
     pub user: UserResponse,
+    /// Yayasan mewajibkan 2FA: permission sensitif ditahan sampai 2FA diaktifkan
+    pub two_factor_setup_required: bool,
+}
+
+/// Langkah kedua login: kirim kode TOTP/recovery bersama `challenge_token`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorChallengeResponse {
+    pub two_factor_required: bool,
+    pub challenge_token: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum LoginResponse {
+    Authenticated(AuthResponse),
+    TwoFactorRequired(TwoFactorChallengeResponse),
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -46,6 +64,32 @@ pub struct RefreshTokenRequest {
     pub refresh_token: String,
 }
 
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResendVerificationRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RefreshTokenResponse {
     pub access_token: String,
@@ -53,3 +97,74 @@ pub struct RefreshTokenResponse {
     pub token_type: String,
     pub expires_in: i64,
 }
+
+/// Satu sesi login (satu perangkat) milik pengguna
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SessionResponse {
+    /// ID family refresh token; dipakai untuk mencabut sesi
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub signed_in_at: String,
+    pub last_active_at: String,
+    pub expires_at: String,
+}
+
+impl From<refresh_tokens::Model> for SessionResponse {
+    fn from(model: refresh_tokens::Model) -> Self {
+        Self {
+            id: model.family_id,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            signed_in_at: model.session_started_at.to_string(),
+            last_active_at: model.issued_at.to_string(),
+            expires_at: model.expires_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TwoFactorVerifyRequest {
+    #[validate(length(min = 1, message = "Challenge token is required"))]
+    pub challenge_token: String,
+    /// Kode 6 digit dari aplikasi authenticator
+    pub code: Option<String>,
+    /// Dipakai bila perangkat authenticator hilang; tiap kode hanya berlaku sekali
+    pub recovery_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    #[validate(length(equal = 6, message = "Code must be 6 digits"))]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct DisableTwoFactorRequest {
+    #[validate(length(min = 1, message = "Password is required"))]
+    pub password: String,
+    #[validate(length(equal = 6, message = "Code must be 6 digits"))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorStatusResponse {
+    pub enabled: bool,
+    /// Secret sudah dibuat tetapi belum dikonfirmasi dengan kode
+    pub pending_setup: bool,
+    /// Yayasan mewajibkan 2FA untuk permission yang dimiliki pengguna ini
+    pub required: bool,
+    pub recovery_codes_remaining: u64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorSetupResponse {
+    pub secret: String,
+    /// URI `otpauth://` untuk ditampilkan sebagai QR code
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
@@ -0,0 +1,69 @@
+// ============================================================================
+// backend/src/modules/auth/emails.rs
+// emails.rs - Isi email reset password & verifikasi (pure, tanpa akses database)
+// ============================================================================
+use crate::utils::mailer::Email;
+use entity::users;
+
+pub const PASSWORD_RESET_TTL_SECONDS: i64 = 60 * 60;
+pub const EMAIL_VERIFICATION_TTL_SECONDS: i64 = 48 * 60 * 60;
+
+/// Tautan ke halaman frontend yang membawa token
+pub fn action_link(base_url: &str, path: &str, token: &str) -> String {
+    format!("{}{}?token={}", base_url.trim_end_matches('/'), path, token)
+}
+
+/// Login ditolak bila yayasan mewajibkan verifikasi dan email belum diverifikasi
+pub fn must_verify(require_email_verification: i8, is_verified: Option<i8>) -> bool {
+    require_email_verification != 0 && is_verified != Some(1)
+}
+
+pub fn password_reset(user: &users::Model, link: &str) -> Email {
+    Email {
+        to: user.email.clone(),
+        to_name: Some(user.name.clone()),
+        subject: "Reset password".into(),
+        body: format!(
+            "Halo {},\n\n\
+             Kami menerima permintaan untuk mengatur ulang password akun Anda.\n\
+             Buka tautan berikut dalam {} menit:\n\n{}\n\n\
+             Abaikan email ini bila Anda tidak memintanya.",
+            user.name,
+            PASSWORD_RESET_TTL_SECONDS / 60,
+            link
+        ),
+    }
+}
+
+pub fn email_verification(user: &users::Model, link: &str) -> Email {
+    Email {
+        to: user.email.clone(),
+        to_name: Some(user.name.clone()),
+        subject: "Verifikasi email".into(),
+        body: format!(
+            "Halo {},\n\n\
+             Konfirmasi alamat email Anda dengan membuka tautan berikut dalam {} jam:\n\n{}",
+            user.name,
+            EMAIL_VERIFICATION_TTL_SECONDS / 3600,
+            link
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_and_verification_rule() {
+        assert_eq!(
+            action_link("https://app.example.com/", "/reset-password", "abc"),
+            "https://app.example.com/reset-password?token=abc"
+        );
+
+        assert!(!must_verify(0, Some(0)));
+        assert!(must_verify(1, Some(0)));
+        assert!(must_verify(1, None));
+        assert!(!must_verify(1, Some(1)));
+    }
+}
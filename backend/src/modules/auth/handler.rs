@@ -1,10 +1,16 @@
 // src/modules/auth/handler.rs
 use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
 use crate::modules::auth::dto::{
-    AuthResponse, LoginRequest, RefreshTokenRequest, RefreshTokenResponse, RegisterRequest,
+    AuthResponse, DisableTwoFactorRequest, ForgotPasswordRequest, LoginRequest, LoginResponse,
+    RecoveryCodesResponse, RefreshTokenRequest, RefreshTokenResponse, RegisterRequest,
+    ResendVerificationRequest, ResetPasswordRequest, SessionResponse, TwoFactorCodeRequest,
+    TwoFactorSetupResponse, TwoFactorStatusResponse, TwoFactorVerifyRequest, VerifyEmailRequest,
 };
+use crate::modules::auth::service::ClientInfo;
 use crate::utils::response::ApiResponse;
-use actix_web::{web, HttpResponse, Responder};
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Responder};
 use serde_json::json;
 use validator::Validate;
 
@@ -45,13 +51,15 @@ pub async fn register(
     tag = "auth",
     request_body = LoginRequest,
     responses(
-        (status = 200, description = "Login successful", body = AuthResponse),
+        (status = 200, description = "Login successful, or a two-factor challenge when 2FA is enabled", body = LoginResponse),
         (status = 400, description = "Validation error"),
-        (status = 401, description = "Invalid credentials")
+        (status = 401, description = "Invalid credentials"),
+        (status = 403, description = "Email address is not verified")
     )
 )]
 pub async fn login(
     app_state: web::Data<AppState>,
+    req: HttpRequest,
     payload: web::Json<LoginRequest>,
 ) -> impl Responder {
     // Validate input
@@ -62,8 +70,15 @@ pub async fn login(
         }));
     }
 
-    match app_state.auth_service.login(payload.into_inner()).await {
+    match app_state
+        .auth_service
+        .login(payload.into_inner(), client_info(&req))
+        .await
+    {
         Ok(response) => HttpResponse::Ok().json(ApiResponse::success(response)),
+        Err(e @ AppError::Forbidden(_)) => {
+            HttpResponse::Forbidden().json(ApiResponse::<()>::error(e.to_string()))
+        }
         Err(e) => HttpResponse::Unauthorized().json(ApiResponse::<()>::error(e.to_string())),
     }
 }
@@ -76,19 +91,390 @@ pub async fn login(
     request_body = RefreshTokenRequest,
     responses(
         (status = 200, description = "Token refreshed successfully", body = RefreshTokenResponse),
-        (status = 401, description = "Invalid refresh token")
+        (status = 401, description = "Invalid, expired, revoked or reused refresh token")
     )
 )]
 pub async fn refresh(
     app_state: web::Data<AppState>,
+    req: HttpRequest,
     payload: web::Json<RefreshTokenRequest>,
 ) -> impl Responder {
     match app_state
         .auth_service
-        .refresh_token(payload.refresh_token.clone())
+        .refresh_token(payload.refresh_token.clone(), client_info(&req))
         .await
     {
         Ok(response) => HttpResponse::Ok().json(ApiResponse::success(response)),
         Err(e) => HttpResponse::Unauthorized().json(ApiResponse::<()>::error(e.to_string())),
     }
 }
+
+/// Logout handler: revoke the session of the given refresh token
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    tag = "auth",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 204, description = "Logged out"),
+        (status = 401, description = "Invalid refresh token")
+    )
+)]
+pub async fn logout(
+    app_state: web::Data<AppState>,
+    payload: web::Json<RefreshTokenRequest>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .auth_service
+        .logout(payload.into_inner().refresh_token)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Log out all devices of the current user
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout_all",
+    tag = "auth",
+    responses(
+        (status = 204, description = "All sessions revoked"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn logout_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    app_state.auth_service.logout_all(auth.user_id).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// List active sessions of the current user
+#[utoipa::path(
+    get,
+    path = "/api/auth/sessions",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Active sessions", body = Vec<SessionResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn sessions(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let sessions = app_state.auth_service.sessions(auth.user_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(sessions)))
+}
+
+/// Revoke one session of the current user
+#[utoipa::path(
+    delete,
+    path = "/api/auth/sessions/{id}",
+    tag = "auth",
+    params(
+        ("id" = String, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Session not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_session(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .auth_service
+        .revoke_session(auth.user_id, &id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Request a password reset link
+#[utoipa::path(
+    post,
+    path = "/api/auth/forgot_password",
+    tag = "auth",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset link sent if the email is registered"),
+        (status = 400, description = "Validation error")
+    )
+)]
+pub async fn forgot_password(
+    app_state: web::Data<AppState>,
+    payload: web::Json<ForgotPasswordRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    app_state
+        .auth_service
+        .forgot_password(&payload.email)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        "If the email is registered, a reset link has been sent",
+    )))
+}
+
+/// Set a new password with a reset token
+#[utoipa::path(
+    post,
+    path = "/api/auth/reset_password",
+    tag = "auth",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password changed; all sessions revoked"),
+        (status = 400, description = "Validation error or invalid/expired token")
+    )
+)]
+pub async fn reset_password(
+    app_state: web::Data<AppState>,
+    payload: web::Json<ResetPasswordRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    app_state
+        .auth_service
+        .reset_password(payload.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success("Password has been reset")))
+}
+
+/// Verify email address with a verification token
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify_email",
+    tag = "auth",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email verified"),
+        (status = 400, description = "Invalid or expired token")
+    )
+)]
+pub async fn verify_email(
+    app_state: web::Data<AppState>,
+    payload: web::Json<VerifyEmailRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    app_state.auth_service.verify_email(&payload.token).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success("Email has been verified")))
+}
+
+/// Send the verification link again
+#[utoipa::path(
+    post,
+    path = "/api/auth/resend_verification",
+    tag = "auth",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Verification link sent if the account is unverified"),
+        (status = 400, description = "Validation error")
+    )
+)]
+pub async fn resend_verification(
+    app_state: web::Data<AppState>,
+    payload: web::Json<ResendVerificationRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    app_state
+        .auth_service
+        .resend_verification(&payload.email)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(
+        "If the account is unverified, a verification link has been sent",
+    )))
+}
+
+/// Second login step: exchange the challenge token and a TOTP or recovery code for tokens
+#[utoipa::path(
+    post,
+    path = "/api/auth/two_factor/verify",
+    tag = "auth",
+    request_body = TwoFactorVerifyRequest,
+    responses(
+        (status = 200, description = "Login successful", body = AuthResponse),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid challenge or two-factor code")
+    )
+)]
+pub async fn verify_two_factor(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<TwoFactorVerifyRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    let response = app_state
+        .auth_service
+        .verify_two_factor(payload.into_inner(), client_info(&req))
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(response)))
+}
+
+/// Two-factor status of the current user
+#[utoipa::path(
+    get,
+    path = "/api/auth/two_factor",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Two-factor status", body = TwoFactorStatusResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn two_factor_status(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let status = app_state
+        .auth_service
+        .two_factor_status(auth.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(status)))
+}
+
+/// Start two-factor enrollment: returns a new secret and otpauth URI
+#[utoipa::path(
+    post,
+    path = "/api/auth/two_factor/setup",
+    tag = "auth",
+    responses(
+        (status = 200, description = "Pending secret created", body = TwoFactorSetupResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn setup_two_factor(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let setup = app_state
+        .auth_service
+        .setup_two_factor(auth.user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(setup)))
+}
+
+/// Confirm enrollment with the first code; returns the recovery codes once
+#[utoipa::path(
+    post,
+    path = "/api/auth/two_factor/enable",
+    tag = "auth",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor enabled", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid two-factor code"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Two-factor setup not started"),
+        (status = 409, description = "Two-factor authentication is already enabled")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn enable_two_factor(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    payload: web::Json<TwoFactorCodeRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    let codes = app_state
+        .auth_service
+        .enable_two_factor(auth.user_id, &payload.code)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(codes)))
+}
+
+/// Turn off two-factor authentication
+#[utoipa::path(
+    post,
+    path = "/api/auth/two_factor/disable",
+    tag = "auth",
+    request_body = DisableTwoFactorRequest,
+    responses(
+        (status = 204, description = "Two-factor disabled"),
+        (status = 400, description = "Invalid two-factor code"),
+        (status = 401, description = "Unauthorized or incorrect password"),
+        (status = 404, description = "Two-factor authentication is not enabled")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn disable_two_factor(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    payload: web::Json<DisableTwoFactorRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    app_state
+        .auth_service
+        .disable_two_factor(auth.user_id, payload.into_inner())
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Replace all recovery codes of the current user
+#[utoipa::path(
+    post,
+    path = "/api/auth/two_factor/recovery_codes",
+    tag = "auth",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes", body = RecoveryCodesResponse),
+        (status = 400, description = "Invalid two-factor code"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Two-factor authentication is not enabled")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn regenerate_recovery_codes(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    payload: web::Json<TwoFactorCodeRequest>,
+) -> Result<HttpResponse, AppError> {
+    payload.validate()?;
+
+    let codes = app_state
+        .auth_service
+        .regenerate_recovery_codes(auth.user_id, &payload.code)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(codes)))
+}
+
+fn client_info(req: &HttpRequest) -> ClientInfo {
+    let user_agent = req
+        .headers()
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.chars().take(255).collect());
+
+    ClientInfo {
+        user_agent,
+        ip_address: req
+            .connection_info()
+            .realip_remote_addr()
+            .map(|addr| addr.chars().take(45).collect()),
+    }
+}
@@ -1,10 +1,13 @@
 // src/modules/auth/mod.rs
 pub mod docs;
 pub mod dto;
+pub mod emails;
 pub mod handler;
 pub mod repository;
+pub mod rotation;
 pub mod routes;
 pub mod service;
+pub mod totp;
 
 // Re-export commonly used items
 pub use repository::AuthRepository;
@@ -12,9 +15,13 @@ pub use service::AuthService;
 
 // Helper untuk initialize service dengan dependencies
 use crate::config::database::Database;
+use crate::utils::mailer;
 
 pub fn init_service(db: Database) -> AuthService {
     let repository = AuthRepository::new(db);
-    AuthService::new(repository)
+    AuthService::new(
+        repository,
+        mailer::from_env().expect("Invalid mail configuration"),
+    )
 }
 // File: backend/src/modules/academic_years/repository.rs
@@ -1,10 +1,20 @@
 // src/modules/auth/repository.rs
 use crate::config::database::Database;
 use crate::errors::AppError;
+use crate::modules::auth::rotation::RevokeReason;
 use crate::utils::password;
+use chrono::{DateTime, Utc};
 use entity::roles::{self as roles, Entity as Roles};
+use entity::sea_orm_active_enums::UserTokenPurpose;
 use entity::users::{self as users, Entity as User};
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, Set};
+use entity::{
+    foundations, refresh_tokens, user_action_tokens, user_recovery_codes, user_two_factors,
+};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, ExprTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
 #[derive(Clone)]
 pub struct AuthRepository {
     db: Database,
@@ -95,4 +105,502 @@ impl AuthRepository {
             .map(|count| count > 0)
             .map_err(AppError::from)
     }
+
+    /// Find refresh token record by its jti
+    pub async fn find_refresh_token(
+        &self,
+        jti: &str,
+    ) -> Result<Option<refresh_tokens::Model>, AppError> {
+        refresh_tokens::Entity::find()
+            .filter(refresh_tokens::Column::Jti.eq(jti))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Save refresh token of a new session
+    pub async fn insert_refresh_token(
+        &self,
+        model: refresh_tokens::ActiveModel,
+    ) -> Result<refresh_tokens::Model, AppError> {
+        model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Mark the current token as rotated and save its replacement.
+    /// Returns `None` when another request already rotated the same token.
+    pub async fn rotate_refresh_token(
+        &self,
+        current: &refresh_tokens::Model,
+        replacement: refresh_tokens::ActiveModel,
+        now: DateTime<Utc>,
+    ) -> Result<Option<refresh_tokens::Model>, AppError> {
+        let replacement_jti = replacement
+            .jti
+            .try_as_ref()
+            .cloned()
+            .ok_or_else(|| AppError::internal("Replacement token has no jti"))?;
+
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(refresh_tokens::Column::RevokedAt, Expr::value(now))
+            .col_expr(
+                refresh_tokens::Column::RevokedReason,
+                Expr::value(RevokeReason::Rotated.as_str()),
+            )
+            .col_expr(
+                refresh_tokens::Column::ReplacedByJti,
+                Expr::value(replacement_jti),
+            )
+            .col_expr(refresh_tokens::Column::LastUsedAt, Expr::value(now))
+            .filter(refresh_tokens::Column::Id.eq(current.id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            txn.rollback()
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            return Ok(None);
+        }
+
+        let saved = replacement
+            .insert(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(saved))
+    }
+
+    /// Revoke every still-active token of a session family
+    pub async fn revoke_family(
+        &self,
+        family_id: &str,
+        reason: RevokeReason,
+    ) -> Result<u64, AppError> {
+        refresh_tokens::Entity::update_many()
+            .col_expr(refresh_tokens::Column::RevokedAt, Expr::value(Utc::now()))
+            .col_expr(
+                refresh_tokens::Column::RevokedReason,
+                Expr::value(reason.as_str()),
+            )
+            .filter(refresh_tokens::Column::FamilyId.eq(family_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.conn())
+            .await
+            .map(|res| res.rows_affected)
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Revoke every still-active refresh token of a user and move the access token
+    /// cutoff forward so tokens issued before now are rejected too
+    pub async fn revoke_user_tokens(
+        &self,
+        user_id: i64,
+        reason: RevokeReason,
+    ) -> Result<u64, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let now = Utc::now();
+
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(refresh_tokens::Column::RevokedAt, Expr::value(now))
+            .col_expr(
+                refresh_tokens::Column::RevokedReason,
+                Expr::value(reason.as_str()),
+            )
+            .filter(refresh_tokens::Column::UserId.eq(user_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        User::update_many()
+            .col_expr(users::Column::TokensValidAfter, Expr::value(now))
+            .filter(users::Column::Id.eq(user_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Active (unrevoked, unexpired) tokens of a user; one per session family
+    pub async fn find_active_sessions(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<refresh_tokens::Model>, AppError> {
+        refresh_tokens::Entity::find()
+            .filter(refresh_tokens::Column::UserId.eq(user_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .filter(refresh_tokens::Column::ExpiresAt.gt(Utc::now()))
+            .order_by_desc(refresh_tokens::Column::IssuedAt)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn find_foundation(&self, id: i64) -> Result<Option<foundations::Model>, AppError> {
+        foundations::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Save an emailed token; older unused tokens of the same purpose stop working
+    pub async fn create_action_token(
+        &self,
+        user_id: i64,
+        purpose: UserTokenPurpose,
+        jti: String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<user_action_tokens::Model, AppError> {
+        let now = Utc::now();
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        user_action_tokens::Entity::update_many()
+            .col_expr(user_action_tokens::Column::UsedAt, Expr::value(now))
+            .filter(user_action_tokens::Column::UserId.eq(user_id))
+            .filter(user_action_tokens::Column::Purpose.eq(purpose.clone()))
+            .filter(user_action_tokens::Column::UsedAt.is_null())
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let token = user_action_tokens::ActiveModel {
+            user_id: Set(user_id),
+            purpose: Set(purpose),
+            jti: Set(jti),
+            expires_at: Set(expires_at),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(token)
+    }
+
+    pub async fn find_action_token(
+        &self,
+        jti: &str,
+    ) -> Result<Option<user_action_tokens::Model>, AppError> {
+        user_action_tokens::Entity::find()
+            .filter(user_action_tokens::Column::Jti.eq(jti))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Mark a token used without touching the user (2FA challenge).
+    /// Returns `false` when the token was already used by another request.
+    pub async fn use_action_token(&self, id: i64) -> Result<bool, AppError> {
+        user_action_tokens::Entity::update_many()
+            .col_expr(user_action_tokens::Column::UsedAt, Expr::value(Utc::now()))
+            .filter(user_action_tokens::Column::Id.eq(id))
+            .filter(user_action_tokens::Column::UsedAt.is_null())
+            .exec(self.conn())
+            .await
+            .map(|res| res.rows_affected > 0)
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Count a wrong code on a token; the token is burned once `max_attempts` is reached
+    pub async fn record_failed_attempt(&self, id: i64, max_attempts: i32) -> Result<(), AppError> {
+        user_action_tokens::Entity::update_many()
+            // used_at dulu: MySQL mengevaluasi SET dari kiri, jadi CASE harus melihat nilai lama
+            .col_expr(
+                user_action_tokens::Column::UsedAt,
+                Expr::case(
+                    Expr::col(user_action_tokens::Column::FailedAttempts).gte(max_attempts - 1),
+                    Expr::value(Utc::now()),
+                )
+                .finally(Expr::col(user_action_tokens::Column::UsedAt))
+                .into(),
+            )
+            .col_expr(
+                user_action_tokens::Column::FailedAttempts,
+                Expr::col(user_action_tokens::Column::FailedAttempts).add(1),
+            )
+            .filter(user_action_tokens::Column::Id.eq(id))
+            .filter(user_action_tokens::Column::UsedAt.is_null())
+            .exec(self.conn())
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Mark the token used and apply its change to the user in one transaction.
+    /// Returns `false` when the token was already used by another request.
+    pub async fn consume_action_token(
+        &self,
+        token: &user_action_tokens::Model,
+        user: users::ActiveModel,
+    ) -> Result<bool, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let result = user_action_tokens::Entity::update_many()
+            .col_expr(user_action_tokens::Column::UsedAt, Expr::value(Utc::now()))
+            .filter(user_action_tokens::Column::Id.eq(token.id))
+            .filter(user_action_tokens::Column::UsedAt.is_null())
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if result.rows_affected == 0 {
+            txn.rollback()
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            return Ok(false);
+        }
+
+        user.update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    pub async fn find_two_factor(
+        &self,
+        user_id: i64,
+    ) -> Result<Option<user_two_factors::Model>, AppError> {
+        user_two_factors::Entity::find()
+            .filter(user_two_factors::Column::UserId.eq(user_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert or update the (pending) TOTP enrollment of a user
+    pub async fn save_two_factor(
+        &self,
+        model: user_two_factors::ActiveModel,
+    ) -> Result<user_two_factors::Model, AppError> {
+        let result = if !model.id.is_not_set() {
+            model.update(self.conn()).await
+        } else {
+            model.insert(self.conn()).await
+        };
+
+        result.map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Record the time step of an accepted code.
+    /// Returns `false` when the same or a later step was already used.
+    pub async fn use_two_factor_step(&self, id: i64, step: i64) -> Result<bool, AppError> {
+        user_two_factors::Entity::update_many()
+            .col_expr(user_two_factors::Column::LastUsedStep, Expr::value(step))
+            .filter(user_two_factors::Column::Id.eq(id))
+            .filter(
+                sea_orm::Condition::any()
+                    .add(user_two_factors::Column::LastUsedStep.is_null())
+                    .add(user_two_factors::Column::LastUsedStep.lt(step)),
+            )
+            .exec(self.conn())
+            .await
+            .map(|res| res.rows_affected > 0)
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Activate 2FA and replace the recovery codes in one transaction
+    pub async fn enable_two_factor(
+        &self,
+        model: user_two_factors::ActiveModel,
+        user_id: i64,
+        code_hashes: Vec<String>,
+    ) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        model
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        Self::replace_recovery_codes_in(&txn, user_id, code_hashes).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn replace_recovery_codes(
+        &self,
+        user_id: i64,
+        code_hashes: Vec<String>,
+    ) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Self::replace_recovery_codes_in(&txn, user_id, code_hashes).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    async fn replace_recovery_codes_in(
+        txn: &sea_orm::DatabaseTransaction,
+        user_id: i64,
+        code_hashes: Vec<String>,
+    ) -> Result<(), AppError> {
+        user_recovery_codes::Entity::delete_many()
+            .filter(user_recovery_codes::Column::UserId.eq(user_id))
+            .exec(txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let now = Utc::now();
+        let codes = code_hashes
+            .into_iter()
+            .map(|code_hash| user_recovery_codes::ActiveModel {
+                user_id: Set(user_id),
+                code_hash: Set(code_hash),
+                created_at: Set(now),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        if !codes.is_empty() {
+            user_recovery_codes::Entity::insert_many(codes)
+                .exec(txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_unused_recovery_codes(
+        &self,
+        user_id: i64,
+    ) -> Result<Vec<user_recovery_codes::Model>, AppError> {
+        user_recovery_codes::Entity::find()
+            .filter(user_recovery_codes::Column::UserId.eq(user_id))
+            .filter(user_recovery_codes::Column::UsedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Spend one recovery code; `false` when it was already used by another request
+    pub async fn use_recovery_code(&self, id: i64) -> Result<bool, AppError> {
+        user_recovery_codes::Entity::update_many()
+            .col_expr(user_recovery_codes::Column::UsedAt, Expr::value(Utc::now()))
+            .filter(user_recovery_codes::Column::Id.eq(id))
+            .filter(user_recovery_codes::Column::UsedAt.is_null())
+            .exec(self.conn())
+            .await
+            .map(|res| res.rows_affected > 0)
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn count_recovery_codes(&self, user_id: i64) -> Result<u64, AppError> {
+        user_recovery_codes::Entity::find()
+            .filter(user_recovery_codes::Column::UserId.eq(user_id))
+            .filter(user_recovery_codes::Column::UsedAt.is_null())
+            .count(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Remove the TOTP secret and recovery codes of a user
+    pub async fn delete_two_factor(&self, user_id: i64) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        user_recovery_codes::Entity::delete_many()
+            .filter(user_recovery_codes::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        user_two_factors::Entity::delete_many()
+            .filter(user_two_factors::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_failed_attempt_burns_challenge_at_limit() {
+        let connection = MockDatabase::new(DatabaseBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let repository = AuthRepository::new(Database {
+            connection: Arc::new(connection),
+        });
+
+        repository.record_failed_attempt(7, 5).await.unwrap();
+
+        let log = repository.conn().clone().into_transaction_log();
+        let statement = &log[0].statements()[0];
+        assert_eq!(
+            statement.sql,
+            "UPDATE `user_action_tokens` \
+             SET `used_at` = (CASE WHEN (`failed_attempts` >= ?) THEN ? ELSE `used_at` END), \
+             `failed_attempts` = `failed_attempts` + ? \
+             WHERE `user_action_tokens`.`id` = ? AND `user_action_tokens`.`used_at` IS NULL"
+        );
+    }
 }
@@ -0,0 +1,123 @@
+// ============================================================================
+// backend/src/modules/auth/rotation.rs
+// rotation.rs - Aturan rotasi refresh token (pure, tanpa akses database)
+// ============================================================================
+use chrono::{DateTime, Utc};
+use entity::refresh_tokens;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevokeReason {
+    /// Sudah ditukar dengan token baru; dipakai lagi berarti token bocor
+    Rotated,
+    Logout,
+    LogoutAll,
+    ReuseDetected,
+    AccountDisabled,
+    Admin,
+    PasswordReset,
+}
+
+impl RevokeReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RevokeReason::Rotated => "rotated",
+            RevokeReason::Logout => "logout",
+            RevokeReason::LogoutAll => "logout_all",
+            RevokeReason::ReuseDetected => "reuse_detected",
+            RevokeReason::AccountDisabled => "account_disabled",
+            RevokeReason::Admin => "admin",
+            RevokeReason::PasswordReset => "password_reset",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshCheck {
+    Rotate,
+    /// Token yang sudah dirotasi dipakai lagi: seluruh family harus dicabut
+    Reused,
+    Revoked,
+    Expired,
+    Mismatch,
+}
+
+/// Hanya hash yang disimpan, jadi bocornya tabel tidak membocorkan token
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+pub fn check(record: &refresh_tokens::Model, token_hash: &str, now: DateTime<Utc>) -> RefreshCheck {
+    if record.token_hash != token_hash {
+        return RefreshCheck::Mismatch;
+    }
+
+    match (record.revoked_at, record.revoked_reason.as_deref()) {
+        (Some(_), Some(reason)) if reason == RevokeReason::Rotated.as_str() => RefreshCheck::Reused,
+        (Some(_), _) => RefreshCheck::Revoked,
+        (None, _) if record.expires_at <= now => RefreshCheck::Expired,
+        (None, _) => RefreshCheck::Rotate,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_rotated_token_counts_as_reuse() {
+        let now = Utc::now();
+        let token = "header.payload.signature";
+        let record = refresh_tokens::Model {
+            id: 1,
+            user_id: 7,
+            foundation_id: 1,
+            jti: "a".into(),
+            family_id: "f".into(),
+            token_hash: hash_token(token),
+            replaced_by_jti: None,
+            user_agent: None,
+            ip_address: None,
+            session_started_at: now,
+            issued_at: now,
+            last_used_at: None,
+            expires_at: now + Duration::days(7),
+            revoked_at: None,
+            revoked_reason: None,
+        };
+
+        assert_eq!(hash_token(token).len(), 64);
+        assert_eq!(
+            check(&record, &hash_token(token), now),
+            RefreshCheck::Rotate
+        );
+        assert_eq!(
+            check(&record, &hash_token("other"), now),
+            RefreshCheck::Mismatch
+        );
+        assert_eq!(
+            check(&record, &hash_token(token), now + Duration::days(8)),
+            RefreshCheck::Expired
+        );
+
+        let rotated = refresh_tokens::Model {
+            revoked_at: Some(now),
+            revoked_reason: Some(RevokeReason::Rotated.as_str().into()),
+            ..record.clone()
+        };
+        assert_eq!(
+            check(&rotated, &hash_token(token), now),
+            RefreshCheck::Reused
+        );
+
+        let logged_out = refresh_tokens::Model {
+            revoked_reason: Some(RevokeReason::Logout.as_str().into()),
+            ..rotated
+        };
+        assert_eq!(
+            check(&logged_out, &hash_token(token), now),
+            RefreshCheck::Revoked
+        );
+    }
+}
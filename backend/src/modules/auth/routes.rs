@@ -1,5 +1,5 @@
 // src/modules/auth/routes.rs
-use crate::modules::auth::handler;
+use crate::{middleware::auth::JwtMiddleware, modules::auth::handler};
 use actix_web::web;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -7,6 +7,62 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         web::scope("/api/auth")
             .route("/register", web::post().to(handler::register))
             .route("/login", web::post().to(handler::login))
-            .route("/refresh", web::post().to(handler::refresh)),
+            .route("/refresh", web::post().to(handler::refresh))
+            .route("/logout", web::post().to(handler::logout))
+            .route("/forgot_password", web::post().to(handler::forgot_password))
+            .route("/reset_password", web::post().to(handler::reset_password))
+            .route("/verify_email", web::post().to(handler::verify_email))
+            .route(
+                "/resend_verification",
+                web::post().to(handler::resend_verification),
+            )
+            .route(
+                "/two_factor/verify",
+                web::post().to(handler::verify_two_factor),
+            )
+            .route(
+                "/two_factor",
+                web::get()
+                    .to(handler::two_factor_status)
+                    .wrap(JwtMiddleware),
+            )
+            .route(
+                "/two_factor/setup",
+                web::post()
+                    .to(handler::setup_two_factor)
+                    .wrap(JwtMiddleware),
+            )
+            .route(
+                "/two_factor/enable",
+                web::post()
+                    .to(handler::enable_two_factor)
+                    .wrap(JwtMiddleware),
+            )
+            .route(
+                "/two_factor/disable",
+                web::post()
+                    .to(handler::disable_two_factor)
+                    .wrap(JwtMiddleware),
+            )
+            .route(
+                "/two_factor/recovery_codes",
+                web::post()
+                    .to(handler::regenerate_recovery_codes)
+                    .wrap(JwtMiddleware),
+            )
+            .route(
+                "/logout_all",
+                web::post().to(handler::logout_all).wrap(JwtMiddleware),
+            )
+            .route(
+                "/sessions",
+                web::get().to(handler::sessions).wrap(JwtMiddleware),
+            )
+            .route(
+                "/sessions/{id}",
+                web::delete()
+                    .to(handler::revoke_session)
+                    .wrap(JwtMiddleware),
+            ),
     );
 }
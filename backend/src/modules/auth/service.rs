@@ -3,22 +3,53 @@ use crate::modules::permissions::PermissionService;
 use crate::{
     errors::AppError,
     modules::auth::{
-        dto::{AuthResponse, LoginRequest, RefreshTokenResponse, RegisterRequest},
+        dto::{
+            AuthResponse, DisableTwoFactorRequest, LoginRequest, LoginResponse,
+            RecoveryCodesResponse, RefreshTokenResponse, RegisterRequest, ResetPasswordRequest,
+            SessionResponse, TwoFactorChallengeResponse, TwoFactorSetupResponse,
+            TwoFactorStatusResponse, TwoFactorVerifyRequest,
+        },
+        emails,
         repository::AuthRepository,
+        rotation::{self, RefreshCheck, RevokeReason},
+        totp,
     },
     modules::users::dto::UserResponse,
-    utils::{jwt, password},
+    utils::{
+        jwt,
+        mailer::{Email, Mailer},
+        password,
+    },
 };
-use std::env;
+use chrono::{DateTime, Duration, Utc};
+use entity::sea_orm_active_enums::UserTokenPurpose;
+use entity::{refresh_tokens, roles, user_action_tokens, user_two_factors, users};
+use sea_orm::{ActiveEnum, IntoActiveModel, Set};
+use std::{env, sync::Arc};
+
+/// Perangkat yang meminta token; disimpan bersama refresh token untuk daftar sesi
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+/// Token pasangan baru beserta baris refresh_tokens yang belum disimpan
+struct IssuedTokens {
+    access_token: String,
+    refresh_token: String,
+    record: refresh_tokens::ActiveModel,
+}
 
 #[derive(Clone)]
 pub struct AuthService {
     repository: AuthRepository,
+    mailer: Arc<dyn Mailer>,
 }
 
 impl AuthService {
-    pub fn new(repository: AuthRepository) -> Self {
-        Self { repository }
+    pub fn new(repository: AuthRepository, mailer: Arc<dyn Mailer>) -> Self {
+        Self { repository, mailer }
     }
 
     pub async fn register(&self, body: RegisterRequest) -> Result<UserResponse, AppError> {
@@ -33,11 +64,17 @@ impl AuthService {
             .create_user(body.name, body.email, body.password)
             .await?;
 
+        self.send_verification(&user).await;
+
         Ok(UserResponse::from_entity(&user))
     }
 
     // ✅ FIX: Login method yang benar
-    pub async fn login(&self, body: LoginRequest) -> Result<AuthResponse, AppError> {
+    pub async fn login(
+        &self,
+        body: LoginRequest,
+        client: ClientInfo,
+    ) -> Result<LoginResponse, AppError> {
         // 1. Fetch user dengan roles sekalian (hanya 1x query)
         let (user, roles) = self
             .repository
@@ -50,8 +87,281 @@ impl AuthService {
         if !valid {
             return Err(AppError::Unauthorized("Invalid credentials".into()));
         }
+        if !can_sign_in(&user) {
+            return Err(AppError::Unauthorized("Account is inactive".into()));
+        }
+
+        let require_verification = self
+            .repository
+            .find_foundation(user.foundation_id)
+            .await?
+            .map_or(0, |foundation| foundation.require_email_verification);
+        if emails::must_verify(require_verification, user.is_verified) {
+            return Err(AppError::forbidden("Email address is not verified"));
+        }
+
+        // 3. 2FA aktif: token baru terbit setelah kode diverifikasi.
+        //    Challenge dicatat seperti token email agar sekali pakai & bisa dihanguskan.
+        if self.two_factor_enabled(user.id).await?.is_some() {
+            let claims = jwt::ActionClaims::new(
+                user.id,
+                &UserTokenPurpose::TwoFactorChallenge.to_value(),
+                totp::CHALLENGE_TTL_SECONDS,
+            );
+            let challenge_token = jwt::create_action_token(&claims)?;
+            self.repository
+                .create_action_token(
+                    user.id,
+                    UserTokenPurpose::TwoFactorChallenge,
+                    claims.jti,
+                    Utc::now() + Duration::seconds(totp::CHALLENGE_TTL_SECONDS),
+                )
+                .await?;
+
+            return Ok(LoginResponse::TwoFactorRequired(
+                TwoFactorChallengeResponse {
+                    two_factor_required: true,
+                    challenge_token,
+                    expires_in: totp::CHALLENGE_TTL_SECONDS,
+                },
+            ));
+        }
+
+        Ok(LoginResponse::Authenticated(
+            self.complete_login(&user, &roles, &client).await?,
+        ))
+    }
+
+    /// Langkah kedua login: tukar challenge token + kode TOTP/recovery dengan token sesi
+    pub async fn verify_two_factor(
+        &self,
+        body: TwoFactorVerifyRequest,
+        client: ClientInfo,
+    ) -> Result<AuthResponse, AppError> {
+        let invalid = || AppError::Unauthorized("Invalid or expired challenge".into());
+
+        let purpose = UserTokenPurpose::TwoFactorChallenge;
+        let claims = jwt::verify_action_token(&body.challenge_token, &purpose.to_value())
+            .map_err(|_| invalid())?;
+        let challenge = self
+            .repository
+            .find_action_token(&claims.jti)
+            .await?
+            .filter(|record| {
+                record.user_id == claims.user_id
+                    && record.purpose == purpose
+                    && record.used_at.is_none()
+                    && record.expires_at > Utc::now()
+            })
+            .ok_or_else(invalid)?;
+
+        let (user, roles) = self
+            .repository
+            .find_by_id_with_roles(claims.user_id)
+            .await?
+            .filter(|(user, _)| can_sign_in(user))
+            .ok_or_else(invalid)?;
+        let two_factor = self
+            .two_factor_enabled(user.id)
+            .await?
+            .ok_or_else(invalid)?;
+
+        let accepted = match (body.code.as_deref(), body.recovery_code.as_deref()) {
+            (Some(code), _) => self.accept_code(&two_factor, code).await?,
+            (None, Some(recovery_code)) => {
+                self.accept_recovery_code(user.id, recovery_code).await?
+            }
+            (None, None) => {
+                return Err(AppError::validation("Code or recovery code is required"));
+            }
+        };
+        if !accepted {
+            self.repository
+                .record_failed_attempt(challenge.id, totp::MAX_CHALLENGE_ATTEMPTS)
+                .await?;
+            return Err(AppError::Unauthorized("Invalid two-factor code".into()));
+        }
+
+        // Challenge hanya bisa ditukar sekali
+        if !self.repository.use_action_token(challenge.id).await? {
+            return Err(invalid());
+        }
+
+        self.complete_login(&user, &roles, &client).await
+    }
+
+    pub async fn two_factor_status(
+        &self,
+        user_id: i64,
+    ) -> Result<TwoFactorStatusResponse, AppError> {
+        let user = self.find_active_user(user_id).await?;
+        let two_factor = self.repository.find_two_factor(user_id).await?;
+        let (_, setup_required) = self.permissions_for(&user).await?;
+        let enabled = two_factor
+            .as_ref()
+            .is_some_and(|tf| tf.enabled_at.is_some());
+
+        Ok(TwoFactorStatusResponse {
+            enabled,
+            pending_setup: two_factor.is_some() && !enabled,
+            required: setup_required || enabled && self.two_factor_mandated(&user).await?,
+            recovery_codes_remaining: if enabled {
+                self.repository.count_recovery_codes(user_id).await?
+            } else {
+                0
+            },
+        })
+    }
+
+    /// Buat secret baru (belum aktif sampai dikonfirmasi lewat `enable_two_factor`)
+    pub async fn setup_two_factor(&self, user_id: i64) -> Result<TwoFactorSetupResponse, AppError> {
+        let user = self.find_active_user(user_id).await?;
+        let existing = self.repository.find_two_factor(user_id).await?;
+        if existing.as_ref().is_some_and(|tf| tf.enabled_at.is_some()) {
+            return Err(AppError::conflict(
+                "Two-factor authentication is already enabled",
+            ));
+        }
+
+        let secret = totp::generate_secret();
+        let now = Utc::now();
+        let model = match existing {
+            Some(tf) => {
+                let mut model = tf.into_active_model();
+                model.secret = Set(secret.clone());
+                model.last_used_step = Set(None);
+                model.updated_at = Set(now);
+                model
+            }
+            None => user_two_factors::ActiveModel {
+                user_id: Set(user_id),
+                secret: Set(secret.clone()),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            },
+        };
+        self.repository.save_two_factor(model).await?;
+
+        let issuer = env::var("TOTP_ISSUER").unwrap_or_else(|_| "Silsilah".into());
+        Ok(TwoFactorSetupResponse {
+            otpauth_uri: totp::provisioning_uri(&issuer, &user.email, &secret),
+            secret,
+        })
+    }
+
+    /// Konfirmasi enrollment dengan kode pertama; recovery code hanya ditampilkan di sini
+    pub async fn enable_two_factor(
+        &self,
+        user_id: i64,
+        code: &str,
+    ) -> Result<RecoveryCodesResponse, AppError> {
+        let two_factor = self
+            .repository
+            .find_two_factor(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Two-factor setup not started"))?;
+        if two_factor.enabled_at.is_some() {
+            return Err(AppError::conflict(
+                "Two-factor authentication is already enabled",
+            ));
+        }
+
+        let step = totp::verify(
+            &two_factor.secret,
+            code,
+            Utc::now().timestamp(),
+            two_factor.last_used_step,
+        )
+        .ok_or_else(|| AppError::validation("Invalid two-factor code"))?;
+
+        let recovery_codes = totp::generate_recovery_codes();
+        let mut model = two_factor.into_active_model();
+        model.enabled_at = Set(Some(Utc::now()));
+        model.last_used_step = Set(Some(step));
+        model.updated_at = Set(Utc::now());
+        self.repository
+            .enable_two_factor(model, user_id, Self::hash_recovery_codes(&recovery_codes)?)
+            .await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+
+    /// Ganti seluruh recovery code; yang lama langsung tidak berlaku
+    pub async fn regenerate_recovery_codes(
+        &self,
+        user_id: i64,
+        code: &str,
+    ) -> Result<RecoveryCodesResponse, AppError> {
+        let two_factor = self
+            .two_factor_enabled(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Two-factor authentication is not enabled"))?;
+        if !self.accept_code(&two_factor, code).await? {
+            return Err(AppError::validation("Invalid two-factor code"));
+        }
+
+        let recovery_codes = totp::generate_recovery_codes();
+        self.repository
+            .replace_recovery_codes(user_id, Self::hash_recovery_codes(&recovery_codes)?)
+            .await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+
+    /// Matikan 2FA; butuh password dan kode yang masih berlaku
+    pub async fn disable_two_factor(
+        &self,
+        user_id: i64,
+        body: DisableTwoFactorRequest,
+    ) -> Result<(), AppError> {
+        let user = self.find_active_user(user_id).await?;
+        if !password::verify(&body.password, &user.password)? {
+            return Err(AppError::unauthorized("Incorrect password"));
+        }
+
+        let two_factor = self
+            .two_factor_enabled(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Two-factor authentication is not enabled"))?;
+        if !self.accept_code(&two_factor, &body.code).await? {
+            return Err(AppError::validation("Invalid two-factor code"));
+        }
+
+        self.repository.delete_two_factor(user_id).await
+    }
+
+    async fn complete_login(
+        &self,
+        user: &users::Model,
+        roles: &[roles::Model],
+        client: &ClientInfo,
+    ) -> Result<AuthResponse, AppError> {
+        let (permissions, two_factor_setup_required) = self.permissions_for(user).await?;
 
-        // 3. Resolve permissions
+        // Refresh token membuka family (sesi) baru
+        let issued = Self::issue_tokens(
+            user.id,
+            user.foundation_id,
+            permissions,
+            client,
+            uuid::Uuid::new_v4().to_string(),
+            Utc::now(),
+        )?;
+        self.repository.insert_refresh_token(issued.record).await?;
+
+        Ok(AuthResponse {
+            user: UserResponse::from_user_with_roles(user, roles),
+            access_token: issued.access_token,
+            refresh_token: issued.refresh_token,
+            token_type: "Bearer".into(),
+            expires_in: self.get_token_expiration(),
+            two_factor_setup_required,
+        })
+    }
+
+    /// Permission untuk token, beserta penanda bahwa permission sensitif ditahan karena 2FA belum aktif
+    async fn permissions_for(&self, user: &users::Model) -> Result<(Vec<String>, bool), AppError> {
         let permissions = PermissionService::resolve_user_permissions(
             self.repository.conn(),
             user.id,
@@ -61,59 +371,458 @@ impl AuthService {
         .into_iter()
         .collect::<Vec<_>>();
 
-        // 4. Create JWT claims dengan permissions
-        let access_claims = jwt::Claims::new(
+        let require_two_factor = self
+            .repository
+            .find_foundation(user.foundation_id)
+            .await?
+            .map_or(0, |foundation| foundation.require_two_factor);
+        if require_two_factor == 0 || !totp::holds_sensitive(&permissions) {
+            return Ok((permissions, false));
+        }
+
+        let enabled = self.two_factor_enabled(user.id).await?.is_some();
+        Ok((
+            totp::effective_permissions(permissions, require_two_factor, enabled),
+            !enabled,
+        ))
+    }
+
+    async fn two_factor_mandated(&self, user: &users::Model) -> Result<bool, AppError> {
+        let require_two_factor = self
+            .repository
+            .find_foundation(user.foundation_id)
+            .await?
+            .map_or(0, |foundation| foundation.require_two_factor);
+        if require_two_factor == 0 {
+            return Ok(false);
+        }
+
+        let permissions = PermissionService::resolve_user_permissions(
+            self.repository.conn(),
             user.id,
             user.foundation_id,
-            "access".into(),
-            permissions.clone(),
-        );
+        )
+        .await?
+        .into_iter()
+        .collect::<Vec<_>>();
+        Ok(totp::holds_sensitive(&permissions))
+    }
 
-        let refresh_claims =
-            jwt::Claims::new(user.id, user.foundation_id, "refresh".into(), permissions);
+    async fn two_factor_enabled(
+        &self,
+        user_id: i64,
+    ) -> Result<Option<user_two_factors::Model>, AppError> {
+        Ok(self
+            .repository
+            .find_two_factor(user_id)
+            .await?
+            .filter(|tf| tf.enabled_at.is_some()))
+    }
 
-        // 5. Generate tokens (TANPA .await karena bukan async function)
-        let access_token = jwt::create_token(&access_claims)?;
-        let refresh_token = jwt::create_refresh_token(&refresh_claims)?;
+    /// Kode TOTP valid dan step-nya belum pernah dipakai (mencegah replay)
+    async fn accept_code(
+        &self,
+        two_factor: &user_two_factors::Model,
+        code: &str,
+    ) -> Result<bool, AppError> {
+        match totp::verify(
+            &two_factor.secret,
+            code,
+            Utc::now().timestamp(),
+            two_factor.last_used_step,
+        ) {
+            Some(step) => {
+                self.repository
+                    .use_two_factor_step(two_factor.id, step)
+                    .await
+            }
+            None => Ok(false),
+        }
+    }
 
-        // 6. Return response
-        Ok(AuthResponse {
-            user: UserResponse::from_user_with_roles(&user, &roles),
-            access_token,
-            refresh_token,
+    async fn find_active_user(&self, user_id: i64) -> Result<users::Model, AppError> {
+        self.repository
+            .find_by_id(user_id)
+            .await?
+            .filter(can_sign_in)
+            .ok_or_else(|| AppError::not_found("User not found"))
+    }
+
+    /// Hash bcrypt tidak bisa dicari langsung; cocokkan dengan kode yang belum dipakai satu per satu
+    async fn accept_recovery_code(&self, user_id: i64, code: &str) -> Result<bool, AppError> {
+        let unused = self.repository.find_unused_recovery_codes(user_id).await?;
+        match unused
+            .iter()
+            .find(|record| totp::recovery_code_matches(code, &record.code_hash))
+        {
+            Some(record) => self.repository.use_recovery_code(record.id).await,
+            None => Ok(false),
+        }
+    }
+
+    fn hash_recovery_codes(codes: &[String]) -> Result<Vec<String>, AppError> {
+        codes
+            .iter()
+            .map(|code| totp::hash_recovery_code(code).map_err(AppError::from))
+            .collect()
+    }
+
+    /// Tukar refresh token dengan pasangan baru. Token lama langsung dicabut;
+    /// bila token yang sudah ditukar dipakai lagi, seluruh sesinya dicabut.
+    pub async fn refresh_token(
+        &self,
+        token: String,
+        client: ClientInfo,
+    ) -> Result<RefreshTokenResponse, AppError> {
+        let claims = jwt::verify_refresh_token(&token)?;
+        let now = Utc::now();
+
+        let record = self
+            .repository
+            .find_refresh_token(&claims.jti)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("Invalid refresh token".into()))?;
+
+        match rotation::check(&record, &rotation::hash_token(&token), now) {
+            RefreshCheck::Rotate => {}
+            RefreshCheck::Reused => return Err(self.revoke_reused(&record).await),
+            RefreshCheck::Expired => {
+                return Err(AppError::Unauthorized("Refresh token expired".into()))
+            }
+            RefreshCheck::Revoked | RefreshCheck::Mismatch => {
+                return Err(AppError::Unauthorized("Invalid refresh token".into()))
+            }
+        }
+
+        // Muat ulang akun & permission supaya perubahan role/status langsung berlaku
+        let user = match self.repository.find_by_id(record.user_id).await? {
+            Some(user) if can_sign_in(&user) => user,
+            _ => {
+                self.repository
+                    .revoke_family(&record.family_id, RevokeReason::AccountDisabled)
+                    .await?;
+                return Err(AppError::Unauthorized("Account is inactive".into()));
+            }
+        };
+
+        let (permissions, _) = self.permissions_for(&user).await?;
+
+        let issued = Self::issue_tokens(
+            user.id,
+            user.foundation_id,
+            permissions,
+            &client,
+            record.family_id.clone(),
+            record.session_started_at,
+        )?;
+
+        // Kalah balapan dengan request lain yang memakai token yang sama = reuse
+        if self
+            .repository
+            .rotate_refresh_token(&record, issued.record, now)
+            .await?
+            .is_none()
+        {
+            return Err(self.revoke_reused(&record).await);
+        }
+
+        Ok(RefreshTokenResponse {
+            access_token: issued.access_token,
+            refresh_token: issued.refresh_token,
             token_type: "Bearer".into(),
             expires_in: self.get_token_expiration(),
         })
     }
 
-    pub async fn refresh_token(&self, token: String) -> Result<RefreshTokenResponse, AppError> {
-        // Verify refresh token
+    /// Logout perangkat ini: cabut sesi (family) pemilik refresh token
+    pub async fn logout(&self, token: String) -> Result<(), AppError> {
         let claims = jwt::verify_refresh_token(&token)?;
 
-        // Create new claims dengan permissions yang sama
-        let new_access_claims = jwt::Claims::new(
-            claims.user_id,
-            claims.foundation_id.clone(),
-            "access".into(),
-            claims.permissions.clone(),
-        );
+        if let Some(record) = self.repository.find_refresh_token(&claims.jti).await? {
+            if record.token_hash == rotation::hash_token(&token) {
+                self.repository
+                    .revoke_family(&record.family_id, RevokeReason::Logout)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Logout semua perangkat; access token yang sudah terbit ikut ditolak lewat cutoff
+    pub async fn logout_all(&self, user_id: i64) -> Result<u64, AppError> {
+        self.repository
+            .revoke_user_tokens(user_id, RevokeReason::LogoutAll)
+            .await
+    }
+
+    /// Dipanggil JwtMiddleware tiap request: akun harus masih aktif dan token terbit
+    /// setelah cutoff `tokens_valid_after` (logout semua, reset password, revoke admin)
+    pub async fn authorize_access(&self, claims: &jwt::Claims) -> Result<(), AppError> {
+        let user = self
+            .repository
+            .find_by_id(claims.user_id)
+            .await?
+            .filter(can_sign_in)
+            .ok_or_else(|| AppError::Unauthorized("Account is inactive".into()))?;
+
+        if !issued_after_cutoff(&user, claims.iat) {
+            return Err(AppError::Unauthorized("Token has been revoked".into()));
+        }
+
+        Ok(())
+    }
+
+    pub async fn sessions(&self, user_id: i64) -> Result<Vec<SessionResponse>, AppError> {
+        let tokens = self.repository.find_active_sessions(user_id).await?;
+
+        Ok(tokens.into_iter().map(SessionResponse::from).collect())
+    }
+
+    pub async fn revoke_session(&self, user_id: i64, session_id: &str) -> Result<(), AppError> {
+        let owned = self
+            .repository
+            .find_active_sessions(user_id)
+            .await?
+            .iter()
+            .any(|token| token.family_id == session_id);
+
+        if !owned {
+            return Err(AppError::not_found("Session not found"));
+        }
+
+        self.repository
+            .revoke_family(session_id, RevokeReason::Logout)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Admin: paksa semua sesi pengguna berakhir, mis. setelah role-nya diubah
+    pub async fn revoke_user_tokens(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<u64, AppError> {
+        self.repository
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        self.repository
+            .revoke_user_tokens(user_id, RevokeReason::Admin)
+            .await
+    }
+
+    /// Kirim tautan reset password. Selalu berhasil agar tidak membocorkan email terdaftar.
+    pub async fn forgot_password(&self, email: &str) -> Result<(), AppError> {
+        let Some(user) = self.repository.find_by_email(email).await? else {
+            return Ok(());
+        };
+        if !can_sign_in(&user) {
+            return Ok(());
+        }
+
+        let link = self
+            .action_link(
+                &user,
+                UserTokenPurpose::PasswordReset,
+                emails::PASSWORD_RESET_TTL_SECONDS,
+                "/reset-password",
+            )
+            .await?;
+        self.deliver(emails::password_reset(&user, &link)).await;
+
+        Ok(())
+    }
+
+    /// Ganti password dengan token dari email; semua sesi lama ikut dicabut
+    pub async fn reset_password(&self, body: ResetPasswordRequest) -> Result<(), AppError> {
+        let (token, user) = self
+            .redeem(&body.token, UserTokenPurpose::PasswordReset)
+            .await?;
+
+        let mut active = user.into_active_model();
+        active.password = Set(password::hash(&body.new_password)?);
+        active.updated_at = Set(Utc::now());
+        self.consume(&token, active).await?;
+
+        self.repository
+            .revoke_user_tokens(token.user_id, RevokeReason::PasswordReset)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn verify_email(&self, token: &str) -> Result<(), AppError> {
+        let (token, user) = self
+            .redeem(token, UserTokenPurpose::EmailVerification)
+            .await?;
 
-        let new_refresh_claims = jwt::Claims::new(
-            claims.user_id,
-            claims.foundation_id,
-            "refresh".into(),
-            claims.permissions,
+        let mut active = user.into_active_model();
+        active.is_verified = Set(Some(1));
+        active.updated_at = Set(Utc::now());
+        self.consume(&token, active).await
+    }
+
+    /// Kirim ulang tautan verifikasi; diam saja bila email tidak ada atau sudah terverifikasi
+    pub async fn resend_verification(&self, email: &str) -> Result<(), AppError> {
+        if let Some(user) = self.repository.find_by_email(email).await? {
+            if can_sign_in(&user) && user.is_verified != Some(1) {
+                self.send_verification(&user).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn send_verification(&self, user: &users::Model) {
+        match self
+            .action_link(
+                user,
+                UserTokenPurpose::EmailVerification,
+                emails::EMAIL_VERIFICATION_TTL_SECONDS,
+                "/verify-email",
+            )
+            .await
+        {
+            Ok(link) => self.deliver(emails::email_verification(user, &link)).await,
+            Err(e) => log::error!("Failed to issue verification token: {}", e),
+        }
+    }
+
+    /// Terbitkan token bertanda tangan, simpan jti-nya, lalu bentuk tautan frontend
+    async fn action_link(
+        &self,
+        user: &users::Model,
+        purpose: UserTokenPurpose,
+        ttl_seconds: i64,
+        path: &str,
+    ) -> Result<String, AppError> {
+        let claims = jwt::ActionClaims::new(user.id, &purpose.to_value(), ttl_seconds);
+        let token = jwt::create_action_token(&claims)?;
+
+        self.repository
+            .create_action_token(
+                user.id,
+                purpose,
+                claims.jti,
+                Utc::now() + Duration::seconds(ttl_seconds),
+            )
+            .await?;
+
+        let base_url = env::var("APP_URL").unwrap_or_else(|_| "http://localhost:3000".into());
+        Ok(emails::action_link(&base_url, path, &token))
+    }
+
+    /// Kegagalan kirim email hanya dicatat; alur pengguna tidak ikut gagal
+    async fn deliver(&self, email: Email) {
+        let to = email.to.clone();
+        if let Err(e) = self.mailer.send(email).await {
+            log::error!("Failed to send email to {}: {}", to, e);
+        }
+    }
+
+    /// Token valid bila tanda tangan & tujuan cocok, belum dipakai, dan akunnya masih aktif
+    async fn redeem(
+        &self,
+        token: &str,
+        purpose: UserTokenPurpose,
+    ) -> Result<(user_action_tokens::Model, users::Model), AppError> {
+        let invalid = || AppError::bad_request("Invalid or expired token");
+
+        let claims = jwt::verify_action_token(token, &purpose.to_value()).map_err(|_| invalid())?;
+
+        let record = self
+            .repository
+            .find_action_token(&claims.jti)
+            .await?
+            .filter(|record| {
+                record.user_id == claims.user_id
+                    && record.purpose == purpose
+                    && record.used_at.is_none()
+                    && record.expires_at > Utc::now()
+            })
+            .ok_or_else(invalid)?;
+
+        let user = self
+            .repository
+            .find_by_id(record.user_id)
+            .await?
+            .filter(can_sign_in)
+            .ok_or_else(invalid)?;
+
+        Ok((record, user))
+    }
+
+    async fn consume(
+        &self,
+        token: &user_action_tokens::Model,
+        user: users::ActiveModel,
+    ) -> Result<(), AppError> {
+        if !self.repository.consume_action_token(token, user).await? {
+            return Err(AppError::bad_request("Invalid or expired token"));
+        }
+
+        Ok(())
+    }
+
+    async fn revoke_reused(&self, record: &refresh_tokens::Model) -> AppError {
+        log::warn!(
+            "Refresh token reuse detected - user_id: {}, family: {}",
+            record.user_id,
+            record.family_id
         );
 
-        // Generate new tokens (TANPA .await)
-        let access_token = jwt::create_token(&new_access_claims)?;
-        let refresh_token = jwt::create_refresh_token(&new_refresh_claims)?;
+        if let Err(e) = self
+            .repository
+            .revoke_family(&record.family_id, RevokeReason::ReuseDetected)
+            .await
+        {
+            return e;
+        }
 
-        Ok(RefreshTokenResponse {
+        AppError::Unauthorized("Refresh token reuse detected; session revoked".into())
+    }
+
+    fn issue_tokens(
+        user_id: i64,
+        foundation_id: i64,
+        permissions: Vec<String>,
+        client: &ClientInfo,
+        family_id: String,
+        session_started_at: DateTime<Utc>,
+    ) -> Result<IssuedTokens, AppError> {
+        let access_claims =
+            jwt::Claims::new(user_id, foundation_id, "access".into(), permissions.clone());
+        let refresh_claims =
+            jwt::Claims::new(user_id, foundation_id, "refresh".into(), permissions);
+
+        let access_token = jwt::create_token(&access_claims)?;
+        let refresh_token = jwt::create_refresh_token(&refresh_claims)?;
+
+        let issued_at = DateTime::from_timestamp(refresh_claims.iat as i64, 0).unwrap_or_default();
+        let expires_at = DateTime::from_timestamp(refresh_claims.exp as i64, 0).unwrap_or_default();
+
+        let record = refresh_tokens::ActiveModel {
+            user_id: Set(user_id),
+            foundation_id: Set(foundation_id),
+            jti: Set(refresh_claims.jti),
+            family_id: Set(family_id),
+            token_hash: Set(rotation::hash_token(&refresh_token)),
+            user_agent: Set(client.user_agent.clone()),
+            ip_address: Set(client.ip_address.clone()),
+            session_started_at: Set(session_started_at),
+            issued_at: Set(issued_at),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        };
+
+        Ok(IssuedTokens {
             access_token,
             refresh_token,
-            token_type: "Bearer".into(),
-            expires_in: self.get_token_expiration(),
+            record,
         })
     }
 
@@ -124,3 +833,50 @@ impl AuthService {
             .unwrap_or(900)
     }
 }
+
+/// Akun nonaktif (`is_active = 0`) atau terhapus tidak boleh mendapat token
+fn can_sign_in(user: &users::Model) -> bool {
+    user.deleted_at.is_none() && user.is_active != Some(0)
+}
+
+/// `iat` hanya presisi detik; token yang terbit di detik yang sama dengan cutoff tetap berlaku
+/// agar login ulang tepat setelah reset password tidak ikut tertolak
+fn issued_after_cutoff(user: &users::Model, iat: usize) -> bool {
+    match user.tokens_valid_after {
+        Some(cutoff) => iat as i64 >= cutoff.timestamp(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_before_cutoff_are_rejected() {
+        let cutoff = Utc::now();
+        let mut user = users::Model {
+            id: 1,
+            name: "Budi".to_string(),
+            email: "budi@example.com".to_string(),
+            username: None,
+            password: String::new(),
+            is_verified: Some(1),
+            is_active: Some(1),
+            foundation_id: 1,
+            created_at: cutoff,
+            updated_at: cutoff,
+            deleted_at: None,
+            tokens_valid_after: None,
+        };
+        let before = (cutoff.timestamp() - 60) as usize;
+        assert!(issued_after_cutoff(&user, before));
+
+        user.tokens_valid_after = Some(cutoff);
+        assert!(!issued_after_cutoff(&user, before));
+        assert!(issued_after_cutoff(&user, cutoff.timestamp() as usize));
+
+        user.is_active = Some(0);
+        assert!(!can_sign_in(&user));
+    }
+}
@@ -0,0 +1,253 @@
+// ============================================================================
+// backend/src/modules/auth/totp.rs
+// totp.rs - TOTP (RFC 6238), recovery code & aturan wajib 2FA (pure, tanpa akses database)
+// ============================================================================
+use crate::modules::{payrolls, salary_grades};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+pub const DIGITS: u32 = 6;
+pub const PERIOD_SECONDS: i64 = 30;
+pub const RECOVERY_CODE_COUNT: usize = 10;
+/// Kode cadangan 80-bit: 4 kelompok x 5 karakter hex
+const RECOVERY_CODE_GROUPS: usize = 4;
+/// Cost bcrypt kode cadangan; lebih rendah dari password karena diverifikasi satu per satu
+const RECOVERY_CODE_HASH_COST: u32 = 10;
+
+/// Token tantangan login dua langkah berlaku singkat, sekali pakai,
+/// dan hangus setelah beberapa kali kode salah
+pub const CHALLENGE_TTL_SECONDS: i64 = 5 * 60;
+pub const MAX_CHALLENGE_ATTEMPTS: i32 = 5;
+
+/// Permission yang hanya berlaku bila yayasan mewajibkan 2FA dan pemiliknya sudah mengaktifkannya
+pub const SENSITIVE_PERMISSIONS: [&str; 3] = [
+    payrolls::service::FINANCE_PERMISSION,
+    salary_grades::service::MANAGE_PERMISSION,
+    "users.manage",
+];
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Secret 160-bit baru dalam base32
+pub fn generate_secret() -> String {
+    let mut bytes = Vec::with_capacity(20);
+    bytes.extend_from_slice(uuid::Uuid::new_v4().as_bytes());
+    bytes.extend_from_slice(&uuid::Uuid::new_v4().as_bytes()[..4]);
+    base32_encode(&bytes)
+}
+
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            out.push(BASE32_ALPHABET[((buffer >> (bits - 5)) & 31) as usize] as char);
+            bits -= 5;
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+
+    out
+}
+
+pub fn base32_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in text.chars().filter(|c| *c != '=' && !c.is_whitespace()) {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a as char == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            out.push((buffer >> (bits - 8)) as u8);
+            bits -= 8;
+        }
+    }
+
+    Some(out)
+}
+
+/// Kode HOTP untuk satu time step (RFC 4226 dynamic truncation)
+pub fn code_at(secret: &[u8], step: u64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(&step.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+
+    binary % 10u32.pow(DIGITS)
+}
+
+/// Cocokkan kode dengan toleransi satu step (jam perangkat yang meleset).
+/// Mengembalikan step yang cocok; step yang sudah pernah dipakai ditolak.
+pub fn verify(
+    secret: &str,
+    code: &str,
+    unix_time: i64,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let secret = base32_decode(secret)?;
+    let code = code.trim();
+    if code.len() != DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let code: u32 = code.parse().ok()?;
+
+    let current = unix_time / PERIOD_SECONDS;
+    (current - 1..=current + 1)
+        .filter(|step| *step >= 0)
+        .filter(|step| last_used_step.is_none_or(|last| *step > last))
+        .find(|step| code_at(&secret, *step as u64) == code)
+}
+
+/// URI `otpauth://` untuk dijadikan QR code oleh aplikasi authenticator
+pub fn provisioning_uri(issuer: &str, account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(issuer),
+        percent_encode(account),
+        secret,
+        percent_encode(issuer),
+        DIGITS,
+        PERIOD_SECONDS
+    )
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Kode cadangan berformat `xxxxx-xxxxx-xxxxx-xxxxx`; hanya ditampilkan sekali ke pengguna
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let hex = uuid::Uuid::new_v4().simple().to_string();
+            (0..RECOVERY_CODE_GROUPS)
+                .map(|group| &hex[group * 5..(group + 1) * 5])
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .collect()
+}
+
+/// Bentuk baku sebelum di-hash: huruf kecil, tanpa tanda hubung & spasi
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Hash bcrypt (ber-salt) dari bentuk baku kode cadangan
+pub fn hash_recovery_code(code: &str) -> Result<String, bcrypt::BcryptError> {
+    bcrypt::hash(normalize_recovery_code(code), RECOVERY_CODE_HASH_COST)
+}
+
+pub fn recovery_code_matches(code: &str, code_hash: &str) -> bool {
+    bcrypt::verify(normalize_recovery_code(code), code_hash).unwrap_or(false)
+}
+
+pub fn holds_sensitive(permissions: &[String]) -> bool {
+    permissions
+        .iter()
+        .any(|p| SENSITIVE_PERMISSIONS.contains(&p.as_str()))
+}
+
+/// Permission yang benar-benar masuk ke token. Bila yayasan mewajibkan 2FA dan
+/// pengguna belum mengaktifkannya, permission sensitif ditahan sampai 2FA aktif.
+pub fn effective_permissions(
+    permissions: Vec<String>,
+    require_two_factor: i8,
+    two_factor_enabled: bool,
+) -> Vec<String> {
+    if require_two_factor == 0 || two_factor_enabled {
+        return permissions;
+    }
+
+    permissions
+        .into_iter()
+        .filter(|p| !SENSITIVE_PERMISSIONS.contains(&p.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc6238_vectors_and_replay() {
+        // Secret ASCII "12345678901234567890" dari lampiran B RFC 6238 (SHA-1)
+        let secret = base32_encode(b"12345678901234567890");
+        assert_eq!(secret, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+        assert_eq!(base32_decode(&secret).unwrap(), b"12345678901234567890");
+
+        let raw = b"12345678901234567890";
+        assert_eq!(code_at(raw, 59 / 30), 287_082);
+        assert_eq!(code_at(raw, 1_111_111_109 / 30), 81_804);
+        assert_eq!(code_at(raw, 1_234_567_890 / 30), 5_924);
+
+        assert_eq!(verify(&secret, "287082", 59, None), Some(1));
+        // Toleransi satu step, lalu step yang sudah dipakai ditolak
+        assert_eq!(verify(&secret, "287082", 75, None), Some(1));
+        assert_eq!(verify(&secret, "287082", 59, Some(1)), None);
+        assert_eq!(verify(&secret, "28708", 59, None), None);
+        assert_eq!(
+            verify(&secret, "005924", 1_234_567_890, None),
+            Some(41_152_263)
+        );
+    }
+
+    #[test]
+    fn test_mandate_strips_sensitive_permissions() {
+        let permissions = vec!["payrolls.manage".to_string(), "students.read".to_string()];
+        assert!(holds_sensitive(&permissions));
+
+        assert_eq!(
+            effective_permissions(permissions.clone(), 1, false),
+            vec!["students.read".to_string()]
+        );
+        assert_eq!(
+            effective_permissions(permissions.clone(), 1, true),
+            permissions
+        );
+        assert_eq!(
+            effective_permissions(permissions.clone(), 0, false),
+            permissions
+        );
+
+        assert_eq!(normalize_recovery_code(" AB12C-d3e4F "), "ab12cd3e4f");
+        let codes = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(normalize_recovery_code(&codes[0]).len(), 20);
+        let hash = hash_recovery_code(&codes[0]).unwrap();
+        assert!(recovery_code_matches(&codes[0].to_uppercase(), &hash));
+        assert!(!recovery_code_matches(&codes[1], &hash));
+        assert_eq!(
+            provisioning_uri("Silsilah", "a b@x.id", "ABC"),
+            "otpauth://totp/Silsilah:a%20b%40x.id?secret=ABC&issuer=Silsilah&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
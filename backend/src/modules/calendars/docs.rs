@@ -0,0 +1,47 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{CalendarFeedUrls, CalendarTokenResponse};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_token,
+        handler::regenerate_token,
+        handler::revoke_token,
+        handler::feed_mine,
+        handler::feed_class,
+        handler::feed_teacher,
+        handler::feed_room,
+    ),
+    components(
+        schemas(
+            CalendarTokenResponse,
+            CalendarFeedUrls,
+        )
+    ),
+    tags(
+        (name = "Calendar ", description = "iCalendar timetable feed endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct CalendarsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// backend/src/modules/calendars/dto.rs
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CalendarTokenResponse {
+    pub token: String,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+    pub feeds: CalendarFeedUrls,
+}
+
+/// Path feed `.ics`; `{id}` diganti dengan ID kelas, guru, atau ruangan
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CalendarFeedUrls {
+    /// Jadwal mengajar milik user (hanya jika user terdaftar sebagai guru)
+    pub mine: Option<String>,
+    pub class: String,
+    pub teacher: String,
+    pub room: String,
+}
+
+impl CalendarTokenResponse {
+    pub fn new(model: entity::calendar_tokens::Model, has_teacher_profile: bool) -> Self {
+        let base = format!("/calendar/{}", model.token);
+        Self {
+            feeds: CalendarFeedUrls {
+                mine: has_teacher_profile.then(|| format!("{}/me.ics", base)),
+                class: format!("{}/classes/{{id}}.ics", base),
+                teacher: format!("{}/teachers/{{id}}.ics", base),
+                room: format!("{}/rooms/{{id}}.ics", base),
+            },
+            token: model.token,
+            last_used_at: model.last_used_at.map(|t| t.to_string()),
+            created_at: model.created_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::CalendarTokenResponse;
+use super::service::FeedTarget;
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use actix_web::{web, HttpResponse};
+
+/// Get calendar feed token and subscription URLs
+#[utoipa::path(
+    get,
+    path = "/api/calendars/token",
+    responses(
+        (status = 200, description = "Calendar feed token", body = CalendarTokenResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Calendar "
+)]
+pub async fn get_token(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .calendar_service
+        .get_token(auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Regenerate calendar feed token
+#[utoipa::path(
+    post,
+    path = "/api/calendars/token/regenerate",
+    responses(
+        (status = 200, description = "New calendar feed token", body = CalendarTokenResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Calendar "
+)]
+pub async fn regenerate_token(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .calendar_service
+        .regenerate_token(auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Revoke calendar feed token
+#[utoipa::path(
+    delete,
+    path = "/api/calendars/token",
+    responses(
+        (status = 204, description = "Calendar feed token revoked"),
+        (status = 404, description = "Calendar token not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Calendar "
+)]
+pub async fn revoke_token(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .calendar_service
+        .revoke_token(auth.user_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// iCalendar feed of the token owner's teaching schedule
+#[utoipa::path(
+    get,
+    path = "/calendar/{token}/me.ics",
+    params(
+        ("token" = String, Path, description = "Calendar feed token")
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed", content_type = "text/calendar", body = String),
+        (status = 404, description = "Feed not found")
+    ),
+    tag = "Calendar "
+)]
+pub async fn feed_mine(
+    app_state: web::Data<AppState>,
+    token: web::Path<String>,
+) -> Result<HttpResponse, AppError> {
+    render(app_state, &token.into_inner(), FeedTarget::Mine).await
+}
+
+/// iCalendar feed of a class timetable
+#[utoipa::path(
+    get,
+    path = "/calendar/{token}/classes/{id}.ics",
+    params(
+        ("token" = String, Path, description = "Calendar feed token"),
+        ("id" = i64, Path, description = "Class ID")
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed", content_type = "text/calendar", body = String),
+        (status = 404, description = "Feed not found")
+    ),
+    tag = "Calendar "
+)]
+pub async fn feed_class(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, i64)>,
+) -> Result<HttpResponse, AppError> {
+    let (token, id) = path.into_inner();
+    render(app_state, &token, FeedTarget::Class(id)).await
+}
+
+/// iCalendar feed of a teacher timetable
+#[utoipa::path(
+    get,
+    path = "/calendar/{token}/teachers/{id}.ics",
+    params(
+        ("token" = String, Path, description = "Calendar feed token"),
+        ("id" = i64, Path, description = "Teacher ID")
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed", content_type = "text/calendar", body = String),
+        (status = 404, description = "Feed not found")
+    ),
+    tag = "Calendar "
+)]
+pub async fn feed_teacher(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, i64)>,
+) -> Result<HttpResponse, AppError> {
+    let (token, id) = path.into_inner();
+    render(app_state, &token, FeedTarget::Teacher(id)).await
+}
+
+/// iCalendar feed of a room timetable
+#[utoipa::path(
+    get,
+    path = "/calendar/{token}/rooms/{id}.ics",
+    params(
+        ("token" = String, Path, description = "Calendar feed token"),
+        ("id" = i64, Path, description = "Room ID")
+    ),
+    responses(
+        (status = 200, description = "iCalendar feed", content_type = "text/calendar", body = String),
+        (status = 404, description = "Feed not found")
+    ),
+    tag = "Calendar "
+)]
+pub async fn feed_room(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, i64)>,
+) -> Result<HttpResponse, AppError> {
+    let (token, id) = path.into_inner();
+    render(app_state, &token, FeedTarget::Room(id)).await
+}
+
+async fn render(
+    app_state: web::Data<AppState>,
+    token: &str,
+    target: FeedTarget,
+) -> Result<HttpResponse, AppError> {
+    let body = app_state.calendar_service.feed(token, target).await?;
+    Ok(HttpResponse::Ok()
+        .content_type("text/calendar; charset=utf-8")
+        .body(body))
+}
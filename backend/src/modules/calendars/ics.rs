@@ -0,0 +1,160 @@
+// ============================================================================
+// backend/src/modules/calendars/ics.rs
+// ics.rs - iCalendar (RFC 5545) Writer (pure, tanpa akses database)
+// ============================================================================
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+
+const PRODID: &str = "-//Silsilah//Timetable//ID";
+
+/// Satu pertemuan mingguan dari `class_schedules` + `time_slots`
+#[derive(Debug, Clone)]
+pub struct WeeklyEvent {
+    pub uid: String,
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    /// ISO weekday: 1 = Senin ... 7 = Minggu
+    pub day_of_week: i8,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub semester_start: NaiveDate,
+    pub semester_end: NaiveDate,
+}
+
+/// Tanggal pertama >= `start` yang jatuh pada `day_of_week` (ISO)
+pub fn first_occurrence(start: NaiveDate, day_of_week: i8) -> NaiveDate {
+    let current = start.weekday().number_from_monday() as i64;
+    let target = (day_of_week as i64).clamp(1, 7);
+    start + Duration::days((target - current).rem_euclid(7))
+}
+
+/// Render VCALENDAR berisi event berulang mingguan; event yang tidak pernah
+/// terjadi dalam rentang semester dilewati
+pub fn render(
+    calendar_name: &str,
+    events: &[WeeklyEvent],
+    stamp: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    ];
+
+    let dtstamp = stamp.format("%Y%m%dT%H%M%SZ").to_string();
+    for event in events {
+        let first = first_occurrence(event.semester_start, event.day_of_week);
+        if first > event.semester_end {
+            continue;
+        }
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", dtstamp));
+        lines.push(format!(
+            "DTSTART:{}",
+            first.and_time(event.start_time).format("%Y%m%dT%H%M%S")
+        ));
+        lines.push(format!(
+            "DTEND:{}",
+            first.and_time(event.end_time).format("%Y%m%dT%H%M%S")
+        ));
+        lines.push(format!(
+            "RRULE:FREQ=WEEKLY;UNTIL={}T235959",
+            event.semester_end.format("%Y%m%d")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut output = String::new();
+    for line in lines {
+        output.push_str(&fold_line(&line));
+        output.push_str("\r\n");
+    }
+    output
+}
+
+/// Escape TEXT value: backslash, koma, titik koma, dan newline
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Lipat baris lebih dari 75 octet tanpa memotong karakter UTF-8
+fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let mut folded = String::with_capacity(line.len() + line.len() / LIMIT * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > LIMIT {
+            folded.push_str("\r\n ");
+            // Spasi pembuka baris lanjutan ikut dihitung
+            width = 1;
+        }
+        folded.push(c);
+        width += len;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_first_occurrence_aligns_to_weekday() {
+        // 2026-07-13 adalah hari Senin
+        assert_eq!(first_occurrence(date(2026, 7, 13), 1), date(2026, 7, 13));
+        assert_eq!(first_occurrence(date(2026, 7, 13), 3), date(2026, 7, 15));
+        assert_eq!(first_occurrence(date(2026, 7, 15), 1), date(2026, 7, 20));
+    }
+
+    #[test]
+    fn test_render_recurring_event_with_escaping_and_folding() {
+        let event = WeeklyEvent {
+            uid: "schedule-1@silsilah".to_string(),
+            summary: "Matematika, Kelas 7A; Wajib".to_string(),
+            location: Some("Lab".to_string()),
+            description: Some("x".repeat(100)),
+            day_of_week: 2,
+            start_time: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(7, 45, 0).unwrap(),
+            semester_start: date(2026, 7, 13),
+            semester_end: date(2026, 12, 18),
+        };
+        let stamp = chrono::DateTime::from_timestamp(0, 0).unwrap();
+
+        let ics = render("Jadwal", &[event], stamp);
+
+        assert!(ics.contains("DTSTART:20260714T070000\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;UNTIL=20261218T235959\r\n"));
+        assert!(ics.contains("SUMMARY:Matematika\\, Kelas 7A\\; Wajib\r\n"));
+        assert!(ics.lines().all(|l| l.trim_end_matches('\r').len() <= 75));
+    }
+}
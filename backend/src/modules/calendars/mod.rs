@@ -0,0 +1,22 @@
+// backend/src/modules/calendars/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod ics;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::CalendarRepository;
+pub use service::CalendarService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> CalendarService {
+    let repository = CalendarRepository::new(db);
+    CalendarService::new(repository)
+}
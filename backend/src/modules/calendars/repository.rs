@@ -0,0 +1,286 @@
+// ============================================================================
+// backend/src/modules/calendars/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use crate::config::database::Database;
+use crate::errors::AppError;
+use entity::calendar_tokens::{self, Entity as CalendarToken};
+use entity::class_schedules::{self, Entity as ClassSchedule};
+use entity::sea_orm_active_enums::ScheduleStatus;
+use entity::traits::soft_delete::SoftDelete;
+use entity::{class_subjects, classes, rooms, semesters, subjects, teachers, time_slots, users};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+#[derive(Clone)]
+pub struct CalendarRepository {
+    db: Database,
+}
+
+impl CalendarRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find token of a user
+    pub async fn find_token_by_user(
+        &self,
+        user_id: i64,
+    ) -> Result<Option<calendar_tokens::Model>, AppError> {
+        CalendarToken::find()
+            .filter(calendar_tokens::Column::UserId.eq(user_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find token by its secret value
+    pub async fn find_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<calendar_tokens::Model>, AppError> {
+        CalendarToken::find()
+            .filter(calendar_tokens::Column::Token.eq(token))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Whether the token owner can still sign in (active and not deleted)
+    pub async fn is_user_active(&self, user_id: i64) -> Result<bool, AppError> {
+        users::Entity::find_by_id(user_id)
+            .filter(users::Column::DeletedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(users::Column::IsActive.is_null())
+                    .add(users::Column::IsActive.ne(0)),
+            )
+            .one(self.conn())
+            .await
+            .map(|user| user.is_some())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Create token
+    pub async fn create_token(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+        token: String,
+    ) -> Result<calendar_tokens::Model, AppError> {
+        let now = chrono::Utc::now();
+        calendar_tokens::ActiveModel {
+            user_id: Set(user_id),
+            foundation_id: Set(foundation_id),
+            token: Set(token),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(self.conn())
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Replace the secret of an existing token
+    pub async fn rotate_token(
+        &self,
+        model: calendar_tokens::Model,
+        foundation_id: i64,
+        token: String,
+    ) -> Result<calendar_tokens::Model, AppError> {
+        let mut active: calendar_tokens::ActiveModel = model.into();
+        active.foundation_id = Set(foundation_id);
+        active.token = Set(token);
+        active.last_used_at = Set(None);
+        active.updated_at = Set(chrono::Utc::now());
+        active
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Record feed access
+    pub async fn touch_token(&self, model: calendar_tokens::Model) -> Result<(), AppError> {
+        let mut active: calendar_tokens::ActiveModel = model.into();
+        active.last_used_at = Set(Some(chrono::Utc::now()));
+        active
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Delete token
+    pub async fn delete_token(&self, id: i64) -> Result<(), AppError> {
+        CalendarToken::delete_by_id(id)
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Find teacher profile of a user
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher of a foundation
+    pub async fn find_teacher(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class of a foundation
+    pub async fn find_class(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<classes::Model>, AppError> {
+        classes::Entity::find_by_id(id)
+            .filter(classes::Column::FoundationId.eq(foundation_id))
+            .filter(classes::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find room of a foundation
+    pub async fn find_room(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<rooms::Model>, AppError> {
+        rooms::Entity::find_by_id(id)
+            .filter(rooms::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a foundation, optionally of one class
+    pub async fn find_class_subjects(
+        &self,
+        foundation_id: i64,
+        class_id: Option<i64>,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        let mut query = class_subjects::Entity::find()
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null());
+
+        if let Some(class_id) = class_id {
+            query = query.filter(class_subjects::Column::ClassId.eq(class_id));
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find published schedules of the given class subjects
+    pub async fn find_published(
+        &self,
+        class_subject_ids: Vec<i64>,
+        teacher_id: Option<i64>,
+        room_id: Option<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        let mut query = ClassSchedule::find()
+            .filter(class_schedules::Column::ClassSubjectId.is_in(class_subject_ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published));
+
+        if let Some(teacher_id) = teacher_id {
+            query = query.filter(class_schedules::Column::TeacherId.eq(teacher_id));
+        }
+        if let Some(room_id) = room_id {
+            query = query.filter(class_schedules::Column::RoomId.eq(room_id));
+        }
+
+        query
+            .order_by_asc(class_schedules::Column::TimeSlotId)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots by IDs (not deleted)
+    pub async fn find_time_slots(&self, ids: Vec<i64>) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semesters by IDs (not deleted)
+    pub async fn find_semesters(&self, ids: Vec<i64>) -> Result<Vec<semesters::Model>, AppError> {
+        semesters::Entity::find()
+            .filter(semesters::Column::Id.is_in(ids))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find subjects by IDs
+    pub async fn find_subjects(&self, ids: Vec<i64>) -> Result<Vec<subjects::Model>, AppError> {
+        subjects::Entity::find()
+            .filter(subjects::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find classes by IDs
+    pub async fn find_classes(&self, ids: Vec<i64>) -> Result<Vec<classes::Model>, AppError> {
+        classes::Entity::find()
+            .filter(classes::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find rooms by IDs
+    pub async fn find_rooms(&self, ids: Vec<i64>) -> Result<Vec<rooms::Model>, AppError> {
+        rooms::Entity::find()
+            .filter(rooms::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers by IDs
+    pub async fn find_teachers(&self, ids: Vec<i64>) -> Result<Vec<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
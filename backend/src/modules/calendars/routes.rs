@@ -0,0 +1,27 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{middleware::auth::JwtMiddleware, modules::calendars::handler};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/calendars")
+            .wrap(JwtMiddleware)
+            .route("/token", web::get().to(handler::get_token))
+            .route("/token", web::delete().to(handler::revoke_token))
+            .route(
+                "/token/regenerate",
+                web::post().to(handler::regenerate_token),
+            ),
+    );
+
+    // Feed publik: autentikasi lewat secret token di path (untuk aplikasi kalender)
+    cfg.service(
+        web::scope("/calendar/{token}")
+            .route("/me.ics", web::get().to(handler::feed_mine))
+            .route("/classes/{id}.ics", web::get().to(handler::feed_class))
+            .route("/teachers/{id}.ics", web::get().to(handler::feed_teacher))
+            .route("/rooms/{id}.ics", web::get().to(handler::feed_room)),
+    );
+}
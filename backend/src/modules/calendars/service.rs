@@ -0,0 +1,277 @@
+// ============================================================================
+// backend/src/modules/calendars/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::CalendarTokenResponse;
+use super::ics::{self, WeeklyEvent};
+use super::repository::CalendarRepository;
+use crate::errors::AppError;
+use std::collections::HashMap;
+
+/// Sumber jadwal yang diekspor ke satu feed
+#[derive(Debug, Clone, Copy)]
+pub enum FeedTarget {
+    Mine,
+    Class(i64),
+    Teacher(i64),
+    Room(i64),
+}
+
+#[derive(Clone)]
+pub struct CalendarService {
+    repository: CalendarRepository,
+}
+
+impl CalendarService {
+    pub fn new(repository: CalendarRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Get the user's feed token, creating it on first use
+    pub async fn get_token(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<CalendarTokenResponse, AppError> {
+        let token = match self.repository.find_token_by_user(user_id).await? {
+            Some(token) if token.foundation_id == foundation_id => token,
+            Some(token) => {
+                // Token dari foundation lain tidak boleh membuka feed foundation aktif
+                self.repository
+                    .rotate_token(token, foundation_id, generate_secret())
+                    .await?
+            }
+            None => {
+                self.repository
+                    .create_token(user_id, foundation_id, generate_secret())
+                    .await?
+            }
+        };
+
+        self.to_response(token).await
+    }
+
+    /// Issue a new secret; subscriptions using the old one stop working
+    pub async fn regenerate_token(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<CalendarTokenResponse, AppError> {
+        let token = match self.repository.find_token_by_user(user_id).await? {
+            Some(token) => {
+                self.repository
+                    .rotate_token(token, foundation_id, generate_secret())
+                    .await?
+            }
+            None => {
+                self.repository
+                    .create_token(user_id, foundation_id, generate_secret())
+                    .await?
+            }
+        };
+
+        self.to_response(token).await
+    }
+
+    /// Revoke the user's feed token
+    pub async fn revoke_token(&self, user_id: i64) -> Result<(), AppError> {
+        let token = self
+            .repository
+            .find_token_by_user(user_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Calendar token not found".to_string()))?;
+
+        self.repository.delete_token(token.id).await
+    }
+
+    /// Render the published timetable of a class, teacher or room as iCalendar
+    pub async fn feed(&self, secret: &str, target: FeedTarget) -> Result<String, AppError> {
+        let token = self
+            .repository
+            .find_token(secret)
+            .await?
+            .ok_or_else(|| AppError::not_found("Calendar feed not found".to_string()))?;
+
+        // Feed ikut mati bila pemiliknya dinonaktifkan atau dihapus
+        if !self.repository.is_user_active(token.user_id).await? {
+            return Err(AppError::not_found("Calendar feed not found".to_string()));
+        }
+        let foundation_id = token.foundation_id;
+        let user_id = token.user_id;
+
+        let (name, class_filter, teacher_id, room_id) = match target {
+            FeedTarget::Mine => {
+                let teacher = self
+                    .repository
+                    .find_teacher_by_user(user_id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Teacher profile not found".to_string()))?;
+                (teacher.name, None, Some(teacher.id), None)
+            }
+            FeedTarget::Teacher(id) => {
+                let teacher = self
+                    .repository
+                    .find_teacher(id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Teacher not found".to_string()))?;
+                (teacher.name, None, Some(teacher.id), None)
+            }
+            FeedTarget::Class(id) => {
+                let class = self
+                    .repository
+                    .find_class(id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+                (class.name, Some(class.id), None, None)
+            }
+            FeedTarget::Room(id) => {
+                let room = self
+                    .repository
+                    .find_room(id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Room not found".to_string()))?;
+                (room.name, None, None, Some(room.id))
+            }
+        };
+
+        self.repository.touch_token(token).await?;
+
+        let class_subjects: HashMap<i64, _> = self
+            .repository
+            .find_class_subjects(foundation_id, class_filter)
+            .await?
+            .into_iter()
+            .map(|cs| (cs.id, cs))
+            .collect();
+        if class_subjects.is_empty() {
+            return Ok(ics::render(&name, &[], chrono::Utc::now()));
+        }
+
+        let schedules = self
+            .repository
+            .find_published(
+                class_subjects.keys().copied().collect(),
+                teacher_id,
+                room_id,
+            )
+            .await?;
+
+        // Lookup nama & waktu untuk setiap baris jadwal
+        let ids = |f: &dyn Fn(&entity::class_schedules::Model) -> Option<i64>| -> Vec<i64> {
+            let mut ids: Vec<i64> = schedules.iter().filter_map(f).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            ids
+        };
+        let slot_ids = ids(&|s| Some(s.time_slot_id));
+        let room_ids = ids(&|s| s.room_id);
+        let teacher_ids = ids(&|s| s.teacher_id);
+        let used: Vec<_> = schedules
+            .iter()
+            .filter_map(|s| class_subjects.get(&s.class_subject_id))
+            .collect();
+        let subject_ids: Vec<i64> = used.iter().map(|cs| cs.subject_id).collect();
+        let class_ids: Vec<i64> = used.iter().map(|cs| cs.class_id).collect();
+        let semester_ids: Vec<i64> = used.iter().map(|cs| cs.semester_id).collect();
+
+        let slots: HashMap<i64, _> = self
+            .repository
+            .find_time_slots(slot_ids)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+        let semesters: HashMap<i64, _> = self
+            .repository
+            .find_semesters(semester_ids)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+        let subjects: HashMap<i64, String> = self
+            .repository
+            .find_subjects(subject_ids)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s.name))
+            .collect();
+        let classes: HashMap<i64, String> = self
+            .repository
+            .find_classes(class_ids)
+            .await?
+            .into_iter()
+            .map(|c| (c.id, c.name))
+            .collect();
+        let rooms: HashMap<i64, String> = self
+            .repository
+            .find_rooms(room_ids)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+        let teachers: HashMap<i64, String> = self
+            .repository
+            .find_teachers(teacher_ids)
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+
+        let events: Vec<WeeklyEvent> = schedules
+            .iter()
+            .filter_map(|schedule| {
+                let class_subject = class_subjects.get(&schedule.class_subject_id)?;
+                let slot = slots.get(&schedule.time_slot_id)?;
+                let semester = semesters.get(&class_subject.semester_id)?;
+
+                let subject = subjects
+                    .get(&class_subject.subject_id)
+                    .map(String::as_str)
+                    .unwrap_or("Lesson");
+                let summary = match classes.get(&class_subject.class_id) {
+                    Some(class) => format!("{} - {}", subject, class),
+                    None => subject.to_string(),
+                };
+
+                Some(WeeklyEvent {
+                    uid: format!("class-schedule-{}@silsilah", schedule.id),
+                    summary,
+                    location: schedule.room_id.and_then(|id| rooms.get(&id).cloned()),
+                    description: schedule
+                        .teacher_id
+                        .and_then(|id| teachers.get(&id))
+                        .map(|name| format!("Teacher: {}", name)),
+                    day_of_week: slot.day_of_week,
+                    start_time: slot.start_time,
+                    end_time: slot.end_time,
+                    semester_start: semester.start_date,
+                    semester_end: semester.end_date,
+                })
+            })
+            .collect();
+
+        Ok(ics::render(&name, &events, chrono::Utc::now()))
+    }
+
+    async fn to_response(
+        &self,
+        token: entity::calendar_tokens::Model,
+    ) -> Result<CalendarTokenResponse, AppError> {
+        let has_teacher_profile = self
+            .repository
+            .find_teacher_by_user(token.user_id, token.foundation_id)
+            .await?
+            .is_some();
+
+        Ok(CalendarTokenResponse::new(token, has_teacher_profile))
+    }
+}
+
+/// Secret 64 karakter hex dari dua UUID v4 (244 bit acak)
+fn generate_secret() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
@@ -0,0 +1,68 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    AssessmentCell, AssessmentHistoryItem, BulkAssessmentRequest, BulkAssessmentResponse,
+    CharacterTraitResponse, ClassAssessmentQuery, ClassAssessmentResponse,
+    CreateCharacterTraitRequest, SemesterAssessments, StudentAssessmentEntry, StudentAssessmentRow,
+    StudentCharacterHistoryResponse, TraitGradeEntry, TraitTrend, UpdateCharacterTraitRequest,
+};
+use super::handler;
+use super::history::Trend;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create,
+        handler::get_by_id,
+        handler::get_all,
+        handler::update,
+        handler::delete,
+        handler::class_assessments,
+        handler::save_class_assessments,
+        handler::student_history,
+    ),
+    components(
+        schemas(
+            CharacterTraitResponse,
+            CreateCharacterTraitRequest,
+            UpdateCharacterTraitRequest,
+            ClassAssessmentQuery,
+            AssessmentCell,
+            StudentAssessmentRow,
+            ClassAssessmentResponse,
+            TraitGradeEntry,
+            StudentAssessmentEntry,
+            BulkAssessmentRequest,
+            BulkAssessmentResponse,
+            AssessmentHistoryItem,
+            SemesterAssessments,
+            Trend,
+            TraitTrend,
+            StudentCharacterHistoryResponse,
+        )
+    ),
+    tags(
+        (name = "Character Trait ", description = "Character trait and character assessment endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct CharacterTraitsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// backend/src/modules/character_traits/dto.rs
+use super::history::Trend;
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::Grade;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CharacterTraitResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateCharacterTraitRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateCharacterTraitRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ClassAssessmentQuery {
+    pub semester_id: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentCell {
+    pub character_trait_id: i64,
+    pub grade: Grade,
+    pub notes: Option<String>,
+    pub assessed_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentAssessmentRow {
+    pub student_id: i64,
+    pub student_name: String,
+    pub student_number: String,
+    pub assessments: Vec<AssessmentCell>,
+}
+
+/// Matriks penilaian sikap satu kelas: semua siswa x semua aspek
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassAssessmentResponse {
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub traits: Vec<CharacterTraitResponse>,
+    pub rows: Vec<StudentAssessmentRow>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TraitGradeEntry {
+    #[validate(range(min = 1))]
+    pub character_trait_id: i64,
+    pub grade: Grade,
+    #[validate(length(max = 1000))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct StudentAssessmentEntry {
+    #[validate(range(min = 1))]
+    pub student_id: i64,
+    #[validate(length(min = 1), nested)]
+    pub assessments: Vec<TraitGradeEntry>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkAssessmentRequest {
+    #[validate(range(min = 1))]
+    pub semester_id: i64,
+    #[validate(length(min = 1), nested)]
+    pub entries: Vec<StudentAssessmentEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkAssessmentResponse {
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub saved: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentHistoryItem {
+    pub character_trait_id: i64,
+    pub trait_name: String,
+    pub grade: Grade,
+    pub notes: Option<String>,
+    pub assessed_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SemesterAssessments {
+    pub semester_id: i64,
+    pub semester_name: String,
+    pub start_date: NaiveDate,
+    pub assessments: Vec<AssessmentHistoryItem>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TraitTrend {
+    pub character_trait_id: i64,
+    pub trait_name: String,
+    pub latest: Grade,
+    pub previous: Option<Grade>,
+    /// Empty when the trait was assessed in only one semester
+    pub trend: Option<Trend>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentCharacterHistoryResponse {
+    pub student_id: i64,
+    pub semesters: Vec<SemesterAssessments>,
+    pub trends: Vec<TraitTrend>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::character_traits::Model> for CharacterTraitResponse {
+    fn from(model: entity::character_traits::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            name: model.name,
+            description: model.description,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BulkAssessmentRequest, BulkAssessmentResponse, CharacterTraitResponse, ClassAssessmentQuery,
+    ClassAssessmentResponse, CreateCharacterTraitRequest, StudentCharacterHistoryResponse,
+    UpdateCharacterTraitRequest,
+};
+use super::service::MANAGE_PERMISSION;
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Create new character trait
+#[utoipa::path(
+    post,
+    path = "/api/character_traits",
+    request_body = CreateCharacterTraitRequest,
+    responses(
+        (status = 201, description = "Character trait created", body = CharacterTraitResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Character trait already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateCharacterTraitRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .create(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get character trait by ID
+#[utoipa::path(
+    get,
+    path = "/api/character_traits/{id}",
+    params(
+        ("id" = i64, Path, description = "Character trait ID")
+    ),
+    responses(
+        (status = 200, description = "Character trait found", body = CharacterTraitResponse),
+        (status = 404, description = "Character trait not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get all character traits with pagination
+#[utoipa::path(
+    get,
+    path = "/api/character_traits",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ("search" = Option<String>, Query, description = "Search by name or description"),
+    ),
+    responses(
+        (status = 200, description = "List of character traits", body = PaginatedResponse<CharacterTraitResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .get_all(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update character trait
+#[utoipa::path(
+    put,
+    path = "/api/character_traits/{id}",
+    params(
+        ("id" = i64, Path, description = "Character trait ID")
+    ),
+    request_body = UpdateCharacterTraitRequest,
+    responses(
+        (status = 200, description = "Character trait updated", body = CharacterTraitResponse),
+        (status = 404, description = "Character trait not found"),
+        (status = 409, description = "Character trait already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateCharacterTraitRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .update(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete character trait
+#[utoipa::path(
+    delete,
+    path = "/api/character_traits/{id}",
+    params(
+        ("id" = i64, Path, description = "Character trait ID")
+    ),
+    responses(
+        (status = 204, description = "Character trait deleted"),
+        (status = 404, description = "Character trait not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .character_trait_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Get character assessments of a class
+#[utoipa::path(
+    get,
+    path = "/api/character_assessments/classes/{class_id}",
+    params(
+        ("class_id" = i64, Path, description = "Class ID"),
+        ClassAssessmentQuery,
+    ),
+    responses(
+        (status = 200, description = "Character assessments of the class", body = ClassAssessmentResponse),
+        (status = 404, description = "Class or semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn class_assessments(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    class_id: web::Path<i64>,
+    query: web::Query<ClassAssessmentQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .class_assessments(class_id.into_inner(), query.semester_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Assess every student of a class in one request (homeroom teacher only)
+#[utoipa::path(
+    put,
+    path = "/api/character_assessments/classes/{class_id}",
+    params(
+        ("class_id" = i64, Path, description = "Class ID")
+    ),
+    request_body = BulkAssessmentRequest,
+    responses(
+        (status = 200, description = "Character assessments saved", body = BulkAssessmentResponse),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Not the homeroom teacher of this class"),
+        (status = 404, description = "Class or semester not found"),
+        (status = 409, description = "Report card is finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn save_class_assessments(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    class_id: web::Path<i64>,
+    request: web::Json<BulkAssessmentRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .save_class_assessments(
+            class_id.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+            auth.has_permission(MANAGE_PERMISSION),
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get character assessment history of a student across semesters
+#[utoipa::path(
+    get,
+    path = "/api/character_assessments/students/{student_id}/history",
+    params(
+        ("student_id" = i64, Path, description = "Student ID")
+    ),
+    responses(
+        (status = 200, description = "Character assessment history", body = StudentCharacterHistoryResponse),
+        (status = 404, description = "Student not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Character Trait "
+)]
+pub async fn student_history(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    student_id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .character_trait_service
+        .student_history(student_id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -0,0 +1,61 @@
+// ============================================================================
+// backend/src/modules/character_traits/history.rs
+// history.rs - Tren nilai sikap antar semester (pure, tanpa akses database)
+// ============================================================================
+use entity::sea_orm_active_enums::Grade;
+use serde::Serialize;
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Trend {
+    Improving,
+    Stable,
+    Declining,
+}
+
+/// A paling tinggi, E paling rendah
+fn level(grade: &Grade) -> u8 {
+    match grade {
+        Grade::A => 5,
+        Grade::B => 4,
+        Grade::C => 3,
+        Grade::D => 2,
+        Grade::E => 1,
+    }
+}
+
+/// Bandingkan nilai semester terakhir dengan semester sebelumnya
+pub fn trend(previous: &Grade, latest: &Grade) -> Trend {
+    match level(latest).cmp(&level(previous)) {
+        Ordering::Greater => Trend::Improving,
+        Ordering::Equal => Trend::Stable,
+        Ordering::Less => Trend::Declining,
+    }
+}
+
+/// Nilai terakhir & sebelumnya dari riwayat satu aspek (urut semester dari yang terlama)
+pub fn latest_pair(history: &[Grade]) -> Option<(Grade, Option<Grade>)> {
+    let (latest, rest) = history.split_last()?;
+    Some((latest.clone(), rest.last().cloned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trend_follows_grade_order() {
+        assert_eq!(trend(&Grade::C, &Grade::B), Trend::Improving);
+        assert_eq!(trend(&Grade::B, &Grade::B), Trend::Stable);
+        assert_eq!(trend(&Grade::A, &Grade::D), Trend::Declining);
+
+        assert_eq!(
+            latest_pair(&[Grade::C, Grade::B, Grade::A]),
+            Some((Grade::A, Some(Grade::B)))
+        );
+        assert_eq!(latest_pair(&[Grade::C]), Some((Grade::C, None)));
+        assert_eq!(latest_pair(&[]), None);
+    }
+}
@@ -0,0 +1,22 @@
+// backend/src/modules/character_traits/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod history;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::CharacterTraitRepository;
+pub use service::CharacterTraitService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> CharacterTraitService {
+    let repository = CharacterTraitRepository::new(db);
+    CharacterTraitService::new(repository)
+}
@@ -0,0 +1,354 @@
+// ============================================================================
+// repository.rs - Database Operations Only
+// ============================================================================
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use entity::character_traits::{self, Entity as CharacterTrait};
+use entity::sea_orm_active_enums::{Grade, ReportCardStatus};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{character_assessments, classes, report_cards, semesters, students, teachers};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+use std::collections::HashMap;
+
+/// Satu nilai sikap yang akan disimpan: (student_id, character_trait_id, grade, notes)
+pub type AssessmentInput = (i64, i64, Grade, Option<String>);
+
+#[derive(Clone)]
+pub struct CharacterTraitRepository {
+    db: Database,
+}
+
+impl CharacterTraitRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create new character trait
+    pub async fn create(
+        &self,
+        active_model: character_traits::ActiveModel,
+    ) -> Result<character_traits::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find character trait of a foundation by ID (not deleted)
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<character_traits::Model>, AppError> {
+        CharacterTrait::find_not_deleted()
+            .filter(character_traits::Column::Id.eq(id))
+            .filter(character_traits::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and search
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        foundation_id: i64,
+    ) -> Result<(Vec<character_traits::Model>, u64), AppError> {
+        let mut query = CharacterTrait::find_not_deleted()
+            .filter(character_traits::Column::FoundationId.eq(foundation_id));
+
+        // Apply search filter if provided
+        if let Some(ref search) = params.search {
+            query = query.filter(
+                Condition::any()
+                    .add(character_traits::Column::Name.contains(search))
+                    .add(character_traits::Column::Description.contains(search)),
+            );
+        }
+
+        let paginator = query
+            .order_by_asc(character_traits::Column::Name)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find every active trait of a foundation
+    pub async fn find_all_traits(
+        &self,
+        foundation_id: i64,
+    ) -> Result<Vec<character_traits::Model>, AppError> {
+        CharacterTrait::find_not_deleted()
+            .filter(character_traits::Column::FoundationId.eq(foundation_id))
+            .order_by_asc(character_traits::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find traits by IDs, including deleted ones (untuk riwayat)
+    pub async fn find_traits_with_deleted(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<character_traits::Model>, AppError> {
+        CharacterTrait::find_with_deleted()
+            .filter(character_traits::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find by name within a foundation (not deleted)
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+        foundation_id: i64,
+    ) -> Result<Option<character_traits::Model>, AppError> {
+        CharacterTrait::find_not_deleted()
+            .filter(character_traits::Column::FoundationId.eq(foundation_id))
+            .filter(character_traits::Column::Name.eq(name))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update character trait
+    pub async fn update(
+        &self,
+        active_model: character_traits::ActiveModel,
+    ) -> Result<character_traits::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Soft delete character trait; penilaian yang sudah ada tetap tersimpan
+    pub async fn soft_delete(&self, id: i64) -> Result<(), AppError> {
+        let model = character_traits::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Find class of a foundation (not deleted)
+    pub async fn find_class(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<classes::Model>, AppError> {
+        classes::Entity::find_by_id(id)
+            .filter(classes::Column::FoundationId.eq(foundation_id))
+            .filter(classes::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher profile of a user account
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semester of a foundation (not deleted)
+    pub async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .filter(semesters::Column::FoundationId.eq(foundation_id))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semesters by IDs
+    pub async fn find_semesters(&self, ids: Vec<i64>) -> Result<Vec<semesters::Model>, AppError> {
+        semesters::Entity::find()
+            .filter(semesters::Column::Id.is_in(ids))
+            .order_by_asc(semesters::Column::StartDate)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find student of a foundation (not deleted)
+    pub async fn find_student(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<students::Model>, AppError> {
+        students::Entity::find_by_id(id)
+            .filter(students::Column::FoundationId.eq(foundation_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active students of a class
+    pub async fn find_students_by_class(
+        &self,
+        class_id: i64,
+    ) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::ClassId.eq(class_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find assessments of the given students in a semester (not deleted)
+    pub async fn find_assessments(
+        &self,
+        student_ids: Vec<i64>,
+        semester_id: i64,
+    ) -> Result<Vec<character_assessments::Model>, AppError> {
+        character_assessments::Entity::find_not_deleted()
+            .filter(character_assessments::Column::StudentId.is_in(student_ids))
+            .filter(character_assessments::Column::SemesterId.eq(semester_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find every assessment of a student (not deleted)
+    pub async fn find_assessments_by_student(
+        &self,
+        student_id: i64,
+    ) -> Result<Vec<character_assessments::Model>, AppError> {
+        character_assessments::Entity::find_not_deleted()
+            .filter(character_assessments::Column::StudentId.eq(student_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Students whose report card of the semester is already finalized
+    pub async fn find_finalized_report_card_students(
+        &self,
+        student_ids: Vec<i64>,
+        semester_id: i64,
+    ) -> Result<Vec<i64>, AppError> {
+        let cards = report_cards::Entity::find_not_deleted()
+            .filter(report_cards::Column::StudentId.is_in(student_ids))
+            .filter(report_cards::Column::SemesterId.eq(semester_id))
+            .filter(report_cards::Column::Status.eq(ReportCardStatus::Finalized))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(cards.into_iter().map(|card| card.student_id).collect())
+    }
+
+    /// Insert or update assessments in a single transaction
+    pub async fn upsert_many(
+        &self,
+        foundation_id: i64,
+        semester_id: i64,
+        assessed_by: i64,
+        entries: Vec<AssessmentInput>,
+    ) -> Result<usize, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        // Termasuk baris yang pernah dihapus, agar unique key tidak bentrok
+        let student_ids: Vec<i64> = entries.iter().map(|e| e.0).collect();
+        let existing: HashMap<(i64, i64), i64> = character_assessments::Entity::find()
+            .filter(character_assessments::Column::StudentId.is_in(student_ids))
+            .filter(character_assessments::Column::SemesterId.eq(semester_id))
+            .all(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|a| ((a.student_id, a.character_trait_id), a.id))
+            .collect();
+
+        let now = chrono::Utc::now();
+        let saved = entries.len();
+        for (student_id, character_trait_id, grade, notes) in entries {
+            let result = match existing.get(&(student_id, character_trait_id)) {
+                Some(id) => {
+                    character_assessments::ActiveModel {
+                        id: Set(*id),
+                        grade: Set(grade),
+                        notes: Set(notes),
+                        assessed_by: Set(Some(assessed_by)),
+                        updated_at: Set(now),
+                        deleted_at: Set(None),
+                        ..Default::default()
+                    }
+                    .update(&txn)
+                    .await
+                }
+                None => {
+                    character_assessments::ActiveModel {
+                        foundation_id: Set(foundation_id),
+                        student_id: Set(student_id),
+                        semester_id: Set(semester_id),
+                        character_trait_id: Set(character_trait_id),
+                        grade: Set(grade),
+                        notes: Set(notes),
+                        assessed_by: Set(Some(assessed_by)),
+                        created_at: Set(now),
+                        updated_at: Set(now),
+                        ..Default::default()
+                    }
+                    .insert(&txn)
+                    .await
+                }
+            };
+            result.map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+}
@@ -0,0 +1,41 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::character_traits::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/character_traits")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("", web::post().to(handler::create))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}", web::put().to(handler::update))
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("character_traits.delete")),
+            ),
+    );
+    cfg.service(
+        web::scope("/api/character_assessments")
+            .wrap(JwtMiddleware)
+            .route(
+                "/classes/{class_id}",
+                web::get().to(handler::class_assessments),
+            )
+            .route(
+                "/classes/{class_id}",
+                web::put().to(handler::save_class_assessments),
+            )
+            .route(
+                "/students/{student_id}/history",
+                web::get().to(handler::student_history),
+            ),
+    );
+}
@@ -0,0 +1,450 @@
+// ============================================================================
+// backend/src/modules/character_traits/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    AssessmentCell, AssessmentHistoryItem, BulkAssessmentRequest, BulkAssessmentResponse,
+    CharacterTraitResponse, ClassAssessmentResponse, CreateCharacterTraitRequest,
+    SemesterAssessments, StudentAssessmentRow, StudentCharacterHistoryResponse, TraitTrend,
+    UpdateCharacterTraitRequest,
+};
+use super::history;
+use super::repository::{AssessmentInput, CharacterTraitRepository};
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use entity::sea_orm_active_enums::Grade;
+use entity::{character_traits, classes};
+use sea_orm::Set;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use validator::Validate;
+
+/// Permission untuk mengisi nilai sikap kelas mana pun, bukan hanya kelas perwaliannya
+pub const MANAGE_PERMISSION: &str = "character_assessments.manage";
+
+#[derive(Clone)]
+pub struct CharacterTraitService {
+    repository: CharacterTraitRepository,
+}
+
+impl CharacterTraitService {
+    pub fn new(repository: CharacterTraitRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create new character trait with validation
+    pub async fn create(
+        &self,
+        request: CreateCharacterTraitRequest,
+        foundation_id: i64,
+    ) -> Result<CharacterTraitResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let name = request.name.trim().to_string();
+
+        // Check duplicate name
+        if self
+            .repository
+            .find_by_name(&name, foundation_id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ConflictError(
+                "Character trait with this name already exists".to_string(),
+            ));
+        }
+
+        let active_model = character_traits::ActiveModel {
+            foundation_id: Set(foundation_id),
+            name: Set(name),
+            description: Set(request.description),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let created = self.repository.create(active_model).await?;
+        Ok(CharacterTraitResponse::from(created))
+    }
+
+    /// Get character trait by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<CharacterTraitResponse, AppError> {
+        let character_trait = self.find_trait(id, foundation_id).await?;
+        Ok(CharacterTraitResponse::from(character_trait))
+    }
+
+    /// Get all character traits with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<CharacterTraitResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self.repository.find_all(&params, foundation_id).await?;
+
+        let responses: Vec<CharacterTraitResponse> = items
+            .into_iter()
+            .map(CharacterTraitResponse::from)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Update character trait
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateCharacterTraitRequest,
+        foundation_id: i64,
+    ) -> Result<CharacterTraitResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self.find_trait(id, foundation_id).await?;
+        let mut active_model: character_traits::ActiveModel = existing.clone().into();
+
+        // Business rule: check duplicate name if changing
+        if let Some(name) = request.name.map(|n| n.trim().to_string()) {
+            if name != existing.name
+                && self
+                    .repository
+                    .find_by_name(&name, foundation_id)
+                    .await?
+                    .is_some()
+            {
+                return Err(AppError::ConflictError(
+                    "Character trait with this name already exists".to_string(),
+                ));
+            }
+            active_model.name = Set(name);
+        }
+        if let Some(description) = request.description {
+            active_model.description = Set(Some(description));
+        }
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let updated = self.repository.update(active_model).await?;
+        Ok(CharacterTraitResponse::from(updated))
+    }
+
+    /// Delete character trait (soft delete)
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        self.find_trait(id, foundation_id).await?;
+        self.repository.soft_delete(id).await
+    }
+
+    /// Matriks penilaian sikap satu kelas pada satu semester
+    pub async fn class_assessments(
+        &self,
+        class_id: i64,
+        semester_id: i64,
+        foundation_id: i64,
+    ) -> Result<ClassAssessmentResponse, AppError> {
+        self.find_class_and_semester(class_id, semester_id, foundation_id)
+            .await?;
+
+        let traits = self.repository.find_all_traits(foundation_id).await?;
+        let students = self.repository.find_students_by_class(class_id).await?;
+
+        let mut by_student: HashMap<i64, Vec<AssessmentCell>> = HashMap::new();
+        for assessment in self
+            .repository
+            .find_assessments(students.iter().map(|s| s.id).collect(), semester_id)
+            .await?
+        {
+            by_student
+                .entry(assessment.student_id)
+                .or_default()
+                .push(AssessmentCell {
+                    character_trait_id: assessment.character_trait_id,
+                    grade: assessment.grade,
+                    notes: assessment.notes,
+                    assessed_by: assessment.assessed_by,
+                });
+        }
+
+        let rows = students
+            .into_iter()
+            .map(|student| {
+                let mut assessments = by_student.remove(&student.id).unwrap_or_default();
+                assessments.sort_by_key(|cell| cell.character_trait_id);
+                StudentAssessmentRow {
+                    student_id: student.id,
+                    student_name: student.name,
+                    student_number: student.student_number,
+                    assessments,
+                }
+            })
+            .collect();
+
+        Ok(ClassAssessmentResponse {
+            class_id,
+            semester_id,
+            traits: traits
+                .into_iter()
+                .map(CharacterTraitResponse::from)
+                .collect(),
+            rows,
+        })
+    }
+
+    /// Simpan penilaian sikap banyak siswa sekaligus; penilai diambil dari JWT
+    pub async fn save_class_assessments(
+        &self,
+        class_id: i64,
+        request: BulkAssessmentRequest,
+        foundation_id: i64,
+        user_id: i64,
+        can_manage: bool,
+    ) -> Result<BulkAssessmentResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let class = self
+            .find_class_and_semester(class_id, request.semester_id, foundation_id)
+            .await?;
+        if !can_manage {
+            self.ensure_homeroom_teacher(&class, user_id).await?;
+        }
+
+        let roster: HashSet<i64> = self
+            .repository
+            .find_students_by_class(class_id)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        let traits: HashSet<i64> = self
+            .repository
+            .find_all_traits(foundation_id)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut inputs: Vec<AssessmentInput> = Vec::new();
+        for entry in request.entries {
+            if !roster.contains(&entry.student_id) {
+                return Err(AppError::validation(format!(
+                    "Student {} is not in class {}",
+                    entry.student_id, class_id
+                )));
+            }
+            for assessment in entry.assessments {
+                if !traits.contains(&assessment.character_trait_id) {
+                    return Err(AppError::validation(format!(
+                        "Character trait {} not found",
+                        assessment.character_trait_id
+                    )));
+                }
+                if !seen.insert((entry.student_id, assessment.character_trait_id)) {
+                    return Err(AppError::validation(format!(
+                        "Student {} is assessed more than once for trait {}",
+                        entry.student_id, assessment.character_trait_id
+                    )));
+                }
+                inputs.push((
+                    entry.student_id,
+                    assessment.character_trait_id,
+                    assessment.grade,
+                    assessment.notes,
+                ));
+            }
+        }
+
+        // Business rule: rapor yang sudah final mengunci penilaian sikap
+        let student_ids: Vec<i64> = seen
+            .iter()
+            .map(|(student_id, _)| *student_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let locked = self
+            .repository
+            .find_finalized_report_card_students(student_ids, request.semester_id)
+            .await?;
+        if let Some(student_id) = locked.first() {
+            return Err(AppError::ConflictError(format!(
+                "Report card of student {} is finalized; character assessments are locked",
+                student_id
+            )));
+        }
+
+        let saved = self
+            .repository
+            .upsert_many(foundation_id, request.semester_id, user_id, inputs)
+            .await?;
+
+        Ok(BulkAssessmentResponse {
+            class_id,
+            semester_id: request.semester_id,
+            saved,
+        })
+    }
+
+    /// Riwayat penilaian sikap seorang siswa lintas semester beserta trennya
+    pub async fn student_history(
+        &self,
+        student_id: i64,
+        foundation_id: i64,
+    ) -> Result<StudentCharacterHistoryResponse, AppError> {
+        self.repository
+            .find_student(student_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Student not found".to_string()))?;
+
+        let assessments = self
+            .repository
+            .find_assessments_by_student(student_id)
+            .await?;
+
+        let semesters = self
+            .repository
+            .find_semesters(
+                assessments
+                    .iter()
+                    .map(|a| a.semester_id)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+            )
+            .await?;
+        let trait_names: HashMap<i64, String> = self
+            .repository
+            .find_traits_with_deleted(
+                assessments
+                    .iter()
+                    .map(|a| a.character_trait_id)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect(),
+            )
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+        let trait_name = |id: i64| trait_names.get(&id).cloned().unwrap_or_default();
+
+        let mut by_semester: HashMap<i64, Vec<AssessmentHistoryItem>> = HashMap::new();
+        for assessment in assessments {
+            by_semester
+                .entry(assessment.semester_id)
+                .or_default()
+                .push(AssessmentHistoryItem {
+                    character_trait_id: assessment.character_trait_id,
+                    trait_name: trait_name(assessment.character_trait_id),
+                    grade: assessment.grade,
+                    notes: assessment.notes,
+                    assessed_by: assessment.assessed_by,
+                });
+        }
+
+        // Semester sudah terurut dari yang terlama (start_date asc)
+        let mut per_trait: BTreeMap<i64, Vec<Grade>> = BTreeMap::new();
+        let mut history_rows = Vec::with_capacity(semesters.len());
+        for semester in semesters {
+            let mut items = by_semester.remove(&semester.id).unwrap_or_default();
+            items.sort_by_key(|item| item.character_trait_id);
+            for item in &items {
+                per_trait
+                    .entry(item.character_trait_id)
+                    .or_default()
+                    .push(item.grade.clone());
+            }
+            history_rows.push(SemesterAssessments {
+                semester_id: semester.id,
+                semester_name: semester.name,
+                start_date: semester.start_date,
+                assessments: items,
+            });
+        }
+
+        let trends = per_trait
+            .into_iter()
+            .filter_map(|(character_trait_id, grades)| {
+                let (latest, previous) = history::latest_pair(&grades)?;
+                Some(TraitTrend {
+                    character_trait_id,
+                    trait_name: trait_name(character_trait_id),
+                    trend: previous.as_ref().map(|p| history::trend(p, &latest)),
+                    latest,
+                    previous,
+                })
+            })
+            .collect();
+
+        Ok(StudentCharacterHistoryResponse {
+            student_id,
+            semesters: history_rows,
+            trends,
+        })
+    }
+
+    async fn find_trait(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<character_traits::Model, AppError> {
+        self.repository
+            .find_by_id(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Character trait not found".to_string()))
+    }
+
+    async fn find_class_and_semester(
+        &self,
+        class_id: i64,
+        semester_id: i64,
+        foundation_id: i64,
+    ) -> Result<classes::Model, AppError> {
+        let class = self
+            .repository
+            .find_class(class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+        self.repository
+            .find_semester(semester_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+        Ok(class)
+    }
+
+    async fn ensure_homeroom_teacher(
+        &self,
+        class: &classes::Model,
+        user_id: i64,
+    ) -> Result<(), AppError> {
+        let teacher = self
+            .repository
+            .find_teacher_by_user(user_id, class.foundation_id)
+            .await?;
+        match (teacher, class.homeroom_teacher_id) {
+            (Some(teacher), Some(homeroom_teacher_id)) if teacher.id == homeroom_teacher_id => {
+                Ok(())
+            }
+            _ => Err(AppError::forbidden(
+                "Only the homeroom teacher can assess this class",
+            )),
+        }
+    }
+}
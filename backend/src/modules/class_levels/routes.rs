@@ -1,7 +1,10 @@
 // ============================================================================
 // routes.rs - Route Configuration
 // ============================================================================
-use crate::{middleware::auth::JwtMiddleware, modules::class_levels::handler};
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::class_levels::handler,
+};
 use actix_web::web;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -12,6 +15,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("", web::get().to(handler::get_all))
             .route("/{id}", web::get().to(handler::get_by_id))
             .route("/{id}", web::put().to(handler::update))
-            .route("/{id}", web::delete().to(handler::delete)),
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("class_levels.delete")),
+            ),
     );
 }
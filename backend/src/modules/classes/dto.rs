@@ -8,6 +8,8 @@ pub struct ClassResponse {
     pub id: i64,
     pub foundation_id: i64,
     pub name: String,
+    /// Wali kelas (teacher ID)
+    pub homeroom_teacher_id: Option<i64>,
     pub created_at: String, // ← Tambah ini (good practice)
     pub updated_at: String, // ← Tambah ini
 }
@@ -17,6 +19,8 @@ pub struct CreateClassRequest {
     pub foundation_id: i64,
     #[validate(length(min = 3, max = 100))]
     pub name: String,
+    #[validate(range(min = 1))]
+    pub homeroom_teacher_id: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -24,6 +28,8 @@ pub struct UpdateClassRequest {
     #[validate(length(min = 3, max = 100))]
     pub name: Option<String>,
     pub foundation_id: i64,
+    #[validate(range(min = 1))]
+    pub homeroom_teacher_id: Option<i64>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -41,6 +47,7 @@ impl From<entity::classes::Model> for ClassResponse {
             id: model.id,
             foundation_id: model.foundation_id,
             name: model.name,
+            homeroom_teacher_id: model.homeroom_teacher_id,
             created_at: model.created_at.to_string(),
             updated_at: model.updated_at.to_string(),
         }
@@ -2,7 +2,10 @@
 // ============================================================================
 // routes.rs - Route Configuration
 // ============================================================================
-use crate::{middleware::auth::JwtMiddleware, modules::classes::handler};
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::classes::handler,
+};
 use actix_web::web;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -13,6 +16,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("", web::get().to(handler::get_all))
             .route("/{id}", web::get().to(handler::get_by_id))
             .route("/{id}", web::put().to(handler::update))
-            .route("/{id}", web::delete().to(handler::delete)),
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("classes.delete")),
+            ),
     );
 }
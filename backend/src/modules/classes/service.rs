@@ -43,6 +43,7 @@ impl ClassService {
         let active_model = classes::ActiveModel {
             foundation_id: Set(request.foundation_id),
             name: Set(request.name),
+            homeroom_teacher_id: Set(request.homeroom_teacher_id),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..Default::default()
@@ -131,6 +132,9 @@ impl ClassService {
         if let Some(name) = request.name {
             active_model.name = Set(name);
         }
+        if let Some(homeroom_teacher_id) = request.homeroom_teacher_id {
+            active_model.homeroom_teacher_id = Set(Some(homeroom_teacher_id));
+        }
 
         // Delegate to repository
         let updated = self.repository.update(id, active_model).await?;
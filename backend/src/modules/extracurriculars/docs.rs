@@ -0,0 +1,64 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    BulkGradeRequest, BulkGradeResponse, CreateExtracurricularRequest, EnrollRequest,
+    EnrollmentListResponse, EnrollmentQuery, EnrollmentResponse, ExtracurricularResponse,
+    GradeEntry, ScheduleEntry, ScheduleQuery, UpdateExtracurricularRequest,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create,
+        handler::get_by_id,
+        handler::get_all,
+        handler::update,
+        handler::delete,
+        handler::schedule,
+        handler::enrollments,
+        handler::enroll,
+        handler::withdraw,
+        handler::save_grades,
+    ),
+    components(
+        schemas(
+            ExtracurricularResponse,
+            CreateExtracurricularRequest,
+            UpdateExtracurricularRequest,
+            EnrollRequest,
+            EnrollmentResponse,
+            EnrollmentQuery,
+            EnrollmentListResponse,
+            GradeEntry,
+            BulkGradeRequest,
+            BulkGradeResponse,
+            ScheduleQuery,
+            ScheduleEntry,
+        )
+    ),
+    tags(
+        (name = "Extracurricular ", description = "Extracurricular activity, enrollment and grading endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct ExtracurricularsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,173 @@
+// backend/src/modules/extracurriculars/dto.rs
+use entity::sea_orm_active_enums::Grade;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExtracurricularResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub coach_id: Option<i64>,
+    pub time_slot_id: Option<i64>,
+    pub room_id: Option<i64>,
+    /// Empty means unlimited participants
+    pub max_participants: Option<i32>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateExtracurricularRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: String,
+    pub description: Option<String>,
+    #[validate(range(min = 1))]
+    pub coach_id: Option<i64>,
+    /// Time slot with slot_type `extracurricular`
+    #[validate(range(min = 1))]
+    pub time_slot_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub room_id: Option<i64>,
+    #[validate(range(min = 1, max = 1000))]
+    pub max_participants: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateExtracurricularRequest {
+    #[validate(length(min = 2, max = 100))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[validate(range(min = 1))]
+    pub coach_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub time_slot_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub room_id: Option<i64>,
+    #[validate(range(min = 1, max = 1000))]
+    pub max_participants: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct EnrollRequest {
+    #[validate(range(min = 1))]
+    pub semester_id: i64,
+    /// Omit to enroll the student account of the logged-in user
+    #[validate(range(min = 1))]
+    pub student_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EnrollmentResponse {
+    pub id: i64,
+    pub extracurricular_id: i64,
+    pub semester_id: i64,
+    pub student_id: i64,
+    pub student_name: String,
+    pub student_number: String,
+    pub grade: Option<Grade>,
+    pub notes: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct EnrollmentQuery {
+    pub semester_id: i64,
+}
+
+/// Daftar peserta satu kegiatan pada satu semester
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EnrollmentListResponse {
+    pub extracurricular_id: i64,
+    pub semester_id: i64,
+    pub max_participants: Option<i32>,
+    /// Empty when the activity has no participant limit
+    pub remaining_seats: Option<i64>,
+    pub enrollments: Vec<EnrollmentResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct GradeEntry {
+    #[validate(range(min = 1))]
+    pub student_id: i64,
+    pub grade: Grade,
+    #[validate(length(max = 1000))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkGradeRequest {
+    #[validate(range(min = 1))]
+    pub semester_id: i64,
+    #[validate(length(min = 1), nested)]
+    pub entries: Vec<GradeEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkGradeResponse {
+    pub extracurricular_id: i64,
+    pub semester_id: i64,
+    pub saved: usize,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ScheduleQuery {
+    pub coach_id: Option<i64>,
+    /// Only activities the student is enrolled in (requires semester_id)
+    pub student_id: Option<i64>,
+    pub semester_id: Option<i64>,
+}
+
+/// Satu sesi mingguan kegiatan ekstrakurikuler
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScheduleEntry {
+    pub extracurricular_id: i64,
+    pub name: String,
+    pub time_slot_id: i64,
+    pub day_of_week: i8,
+    pub start_time: String,
+    pub end_time: String,
+    pub coach_id: Option<i64>,
+    pub coach_name: Option<String>,
+    pub room_id: Option<i64>,
+    pub room_name: Option<String>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::extracurricular_activities::Model> for ExtracurricularResponse {
+    fn from(model: entity::extracurricular_activities::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            name: model.name,
+            description: model.description,
+            coach_id: model.coach_id,
+            time_slot_id: model.time_slot_id,
+            room_id: model.room_id,
+            max_participants: model.max_participants,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+
+impl EnrollmentResponse {
+    pub fn new(
+        model: entity::extracurricular_enrollments::Model,
+        student: &entity::students::Model,
+    ) -> Self {
+        Self {
+            id: model.id,
+            extracurricular_id: model.extracurricular_id,
+            semester_id: model.semester_id,
+            student_id: model.student_id,
+            student_name: student.name.clone(),
+            student_number: student.student_number.clone(),
+            grade: model.grade,
+            notes: model.notes,
+            created_at: model.created_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// ============================================================================
+// backend/src/modules/extracurriculars/enrollment.rs
+// enrollment.rs - Aturan kuota & jadwal penilaian (pure, tanpa akses database)
+// ============================================================================
+use chrono::{Duration, NaiveDate};
+
+/// Penilaian pembina dibuka sejak N hari sebelum semester berakhir
+pub const GRADING_WINDOW_DAYS: i64 = 30;
+
+/// Sisa kursi; `None` berarti kegiatan tanpa batas peserta
+pub fn remaining_seats(max_participants: Option<i32>, enrolled: u64) -> Option<i64> {
+    max_participants.map(|max| (i64::from(max) - enrolled as i64).max(0))
+}
+
+pub fn has_seat(max_participants: Option<i32>, enrolled: u64) -> bool {
+    remaining_seats(max_participants, enrolled).is_none_or(|seats| seats > 0)
+}
+
+/// Pendaftaran hanya selama semester belum berakhir
+pub fn enrollment_open(today: NaiveDate, semester_end: NaiveDate) -> bool {
+    today <= semester_end
+}
+
+/// Nilai diberikan di akhir semester: mulai GRADING_WINDOW_DAYS sebelum tanggal akhir
+pub fn grading_open(today: NaiveDate, semester_end: NaiveDate) -> bool {
+    today >= semester_end - Duration::days(GRADING_WINDOW_DAYS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity_limits() {
+        assert_eq!(remaining_seats(Some(20), 18), Some(2));
+        assert_eq!(remaining_seats(Some(20), 25), Some(0));
+        assert_eq!(remaining_seats(None, 500), None);
+
+        assert!(has_seat(Some(20), 19));
+        assert!(!has_seat(Some(20), 20));
+        assert!(has_seat(None, 1000));
+    }
+
+    #[test]
+    fn test_enrollment_and_grading_windows() {
+        let end = NaiveDate::from_ymd_opt(2026, 12, 20).unwrap();
+        let date = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+
+        assert!(enrollment_open(date(12, 20), end));
+        assert!(!enrollment_open(date(12, 21), end));
+
+        assert!(!grading_open(date(11, 19), end));
+        assert!(grading_open(date(11, 20), end));
+        assert!(grading_open(date(12, 28), end));
+    }
+}
@@ -0,0 +1,298 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BulkGradeRequest, BulkGradeResponse, CreateExtracurricularRequest, EnrollRequest,
+    EnrollmentListResponse, EnrollmentQuery, EnrollmentResponse, ExtracurricularResponse,
+    ScheduleEntry, ScheduleQuery, UpdateExtracurricularRequest,
+};
+use super::service::{Actor, MANAGE_PERMISSION};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Create new extracurricular activity
+#[utoipa::path(
+    post,
+    path = "/api/extracurriculars",
+    request_body = CreateExtracurricularRequest,
+    responses(
+        (status = 201, description = "Extracurricular created", body = ExtracurricularResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Name already exists or coach/room is busy in the time slot")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateExtracurricularRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .create(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get extracurricular activity by ID
+#[utoipa::path(
+    get,
+    path = "/api/extracurriculars/{id}",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID")
+    ),
+    responses(
+        (status = 200, description = "Extracurricular found", body = ExtracurricularResponse),
+        (status = 404, description = "Extracurricular not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get all extracurricular activities with pagination
+#[utoipa::path(
+    get,
+    path = "/api/extracurriculars",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ("search" = Option<String>, Query, description = "Search by name or description"),
+    ),
+    responses(
+        (status = 200, description = "List of extracurriculars", body = PaginatedResponse<ExtracurricularResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .get_all(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update extracurricular activity
+#[utoipa::path(
+    put,
+    path = "/api/extracurriculars/{id}",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID")
+    ),
+    request_body = UpdateExtracurricularRequest,
+    responses(
+        (status = 200, description = "Extracurricular updated", body = ExtracurricularResponse),
+        (status = 404, description = "Extracurricular not found"),
+        (status = 409, description = "Name already exists or coach/room is busy in the time slot")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateExtracurricularRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .update(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete extracurricular activity
+#[utoipa::path(
+    delete,
+    path = "/api/extracurriculars/{id}",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID")
+    ),
+    responses(
+        (status = 204, description = "Extracurricular deleted"),
+        (status = 404, description = "Extracurricular not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .extracurricular_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Weekly extracurricular schedule
+#[utoipa::path(
+    get,
+    path = "/api/extracurriculars/schedule",
+    params(ScheduleQuery),
+    responses(
+        (status = 200, description = "Weekly sessions ordered by day and start time", body = Vec<ScheduleEntry>),
+        (status = 400, description = "semester_id missing for student filter")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn schedule(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<ScheduleQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .schedule(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get participants of an extracurricular in a semester
+#[utoipa::path(
+    get,
+    path = "/api/extracurriculars/{id}/enrollments",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID"),
+        EnrollmentQuery
+    ),
+    responses(
+        (status = 200, description = "Participants and remaining seats", body = EnrollmentListResponse),
+        (status = 404, description = "Extracurricular or semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn enrollments(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    query: web::Query<EnrollmentQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .enrollments(id.into_inner(), query.semester_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Enroll a student (self, guardian or staff)
+#[utoipa::path(
+    post,
+    path = "/api/extracurriculars/{id}/enrollments",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID")
+    ),
+    request_body = EnrollRequest,
+    responses(
+        (status = 201, description = "Student enrolled", body = EnrollmentResponse),
+        (status = 400, description = "Semester has ended"),
+        (status = 403, description = "Not the student, its guardian or a manager"),
+        (status = 404, description = "Extracurricular, semester or student not found"),
+        (status = 409, description = "Already enrolled, activity full or time slot taken")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn enroll(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<EnrollRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .enroll(id.into_inner(), request.into_inner(), actor(&auth))
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Withdraw a student from an extracurricular
+#[utoipa::path(
+    delete,
+    path = "/api/extracurriculars/{id}/enrollments/{enrollment_id}",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID"),
+        ("enrollment_id" = i64, Path, description = "Enrollment ID")
+    ),
+    responses(
+        (status = 204, description = "Enrollment withdrawn"),
+        (status = 403, description = "Not the student, its guardian or a manager"),
+        (status = 404, description = "Enrollment not found"),
+        (status = 409, description = "Enrollment already graded")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn withdraw(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<(i64, i64)>,
+) -> Result<HttpResponse, AppError> {
+    let (id, enrollment_id) = path.into_inner();
+    app_state
+        .extracurricular_service
+        .withdraw(id, enrollment_id, actor(&auth))
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Grade participants at the end of the semester (coach only)
+#[utoipa::path(
+    put,
+    path = "/api/extracurriculars/{id}/grades",
+    params(
+        ("id" = i64, Path, description = "Extracurricular ID")
+    ),
+    request_body = BulkGradeRequest,
+    responses(
+        (status = 200, description = "Grades saved", body = BulkGradeResponse),
+        (status = 400, description = "Invalid entries or grading window not open yet"),
+        (status = 403, description = "Not the coach of this extracurricular"),
+        (status = 409, description = "Report card already finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Extracurricular "
+)]
+pub async fn save_grades(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<BulkGradeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .extracurricular_service
+        .save_grades(id.into_inner(), request.into_inner(), actor(&auth))
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+fn actor(auth: &AuthContext) -> Actor {
+    Actor {
+        user_id: auth.user_id,
+        foundation_id: auth.foundation_id,
+        can_manage: auth.has_permission(MANAGE_PERMISSION),
+    }
+}
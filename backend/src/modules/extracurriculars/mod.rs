@@ -0,0 +1,22 @@
+// backend/src/modules/extracurriculars/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod enrollment;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::ExtracurricularRepository;
+pub use service::ExtracurricularService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> ExtracurricularService {
+    let repository = ExtracurricularRepository::new(db);
+    ExtracurricularService::new(repository)
+}
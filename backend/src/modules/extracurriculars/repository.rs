@@ -0,0 +1,498 @@
+// ============================================================================
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::enrollment;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use entity::extracurricular_activities::{self, Entity as Extracurricular};
+use entity::extracurricular_enrollments;
+use entity::sea_orm_active_enums::{Grade, ReportCardStatus};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{report_cards, rooms, semesters, students, teachers, time_slots};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+/// Satu nilai yang akan disimpan: (enrollment_id, grade, notes)
+pub type GradeInput = (i64, Grade, Option<String>);
+
+#[derive(Clone)]
+pub struct ExtracurricularRepository {
+    db: Database,
+}
+
+impl ExtracurricularRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create new extracurricular activity
+    pub async fn create(
+        &self,
+        active_model: extracurricular_activities::ActiveModel,
+    ) -> Result<extracurricular_activities::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find activity of a foundation by ID (not deleted)
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<extracurricular_activities::Model>, AppError> {
+        Extracurricular::find_not_deleted()
+            .filter(extracurricular_activities::Column::Id.eq(id))
+            .filter(extracurricular_activities::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and search
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        foundation_id: i64,
+    ) -> Result<(Vec<extracurricular_activities::Model>, u64), AppError> {
+        let mut query = Extracurricular::find_not_deleted()
+            .filter(extracurricular_activities::Column::FoundationId.eq(foundation_id));
+
+        // Apply search filter if provided
+        if let Some(ref search) = params.search {
+            query = query.filter(
+                Condition::any()
+                    .add(extracurricular_activities::Column::Name.contains(search))
+                    .add(extracurricular_activities::Column::Description.contains(search)),
+            );
+        }
+
+        let paginator = query
+            .order_by_asc(extracurricular_activities::Column::Name)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find by name within a foundation (not deleted)
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+        foundation_id: i64,
+    ) -> Result<Option<extracurricular_activities::Model>, AppError> {
+        Extracurricular::find_not_deleted()
+            .filter(extracurricular_activities::Column::FoundationId.eq(foundation_id))
+            .filter(extracurricular_activities::Column::Name.eq(name))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find scheduled activities of a foundation, optionally of one coach or a set of IDs
+    pub async fn find_scheduled(
+        &self,
+        foundation_id: i64,
+        coach_id: Option<i64>,
+        ids: Option<Vec<i64>>,
+    ) -> Result<Vec<extracurricular_activities::Model>, AppError> {
+        let mut query = Extracurricular::find_not_deleted()
+            .filter(extracurricular_activities::Column::FoundationId.eq(foundation_id))
+            .filter(extracurricular_activities::Column::TimeSlotId.is_not_null());
+
+        if let Some(coach_id) = coach_id {
+            query = query.filter(extracurricular_activities::Column::CoachId.eq(coach_id));
+        }
+        if let Some(ids) = ids {
+            query = query.filter(extracurricular_activities::Column::Id.is_in(ids));
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Other activities held in the same time slot
+    pub async fn find_in_slot(
+        &self,
+        time_slot_id: i64,
+        exclude_id: Option<i64>,
+    ) -> Result<Vec<extracurricular_activities::Model>, AppError> {
+        let mut query = Extracurricular::find_not_deleted()
+            .filter(extracurricular_activities::Column::TimeSlotId.eq(time_slot_id));
+
+        if let Some(id) = exclude_id {
+            query = query.filter(extracurricular_activities::Column::Id.ne(id));
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update extracurricular activity
+    pub async fn update(
+        &self,
+        active_model: extracurricular_activities::ActiveModel,
+    ) -> Result<extracurricular_activities::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Soft delete activity; riwayat peserta & nilai tetap tersimpan
+    pub async fn soft_delete(&self, id: i64) -> Result<(), AppError> {
+        let model = extracurricular_activities::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Find time slot of a foundation (not deleted)
+    pub async fn find_time_slot(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::Id.eq(id))
+            .filter(time_slots::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots by IDs
+    pub async fn find_time_slots(&self, ids: Vec<i64>) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher of a foundation (not deleted)
+    pub async fn find_teacher(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher profile of a user account
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers by IDs
+    pub async fn find_teachers(&self, ids: Vec<i64>) -> Result<Vec<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find room of a foundation
+    pub async fn find_room(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<rooms::Model>, AppError> {
+        rooms::Entity::find_by_id(id)
+            .filter(rooms::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find rooms by IDs
+    pub async fn find_rooms(&self, ids: Vec<i64>) -> Result<Vec<rooms::Model>, AppError> {
+        rooms::Entity::find()
+            .filter(rooms::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semester of a foundation (not deleted)
+    pub async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .filter(semesters::Column::FoundationId.eq(foundation_id))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find student of a foundation (not deleted)
+    pub async fn find_student(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<students::Model>, AppError> {
+        students::Entity::find_by_id(id)
+            .filter(students::Column::FoundationId.eq(foundation_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find student profile of a user account
+    pub async fn find_student_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::UserId.eq(user_id))
+            .filter(students::Column::FoundationId.eq(foundation_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find students by IDs
+    pub async fn find_students(&self, ids: Vec<i64>) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::Id.is_in(ids))
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find enrollment by ID (not deleted)
+    pub async fn find_enrollment(
+        &self,
+        id: i64,
+    ) -> Result<Option<extracurricular_enrollments::Model>, AppError> {
+        extracurricular_enrollments::Entity::find_not_deleted()
+            .filter(extracurricular_enrollments::Column::Id.eq(id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find participants of an activity in a semester (not deleted)
+    pub async fn find_enrollments(
+        &self,
+        extracurricular_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<extracurricular_enrollments::Model>, AppError> {
+        extracurricular_enrollments::Entity::find_not_deleted()
+            .filter(extracurricular_enrollments::Column::ExtracurricularId.eq(extracurricular_id))
+            .filter(extracurricular_enrollments::Column::SemesterId.eq(semester_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find enrollments of a student in a semester (not deleted)
+    pub async fn find_student_enrollments(
+        &self,
+        student_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<extracurricular_enrollments::Model>, AppError> {
+        extracurricular_enrollments::Entity::find_not_deleted()
+            .filter(extracurricular_enrollments::Column::StudentId.eq(student_id))
+            .filter(extracurricular_enrollments::Column::SemesterId.eq(semester_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Daftarkan siswa; kuota dicek di dalam transaksi dengan baris kegiatan dikunci
+    pub async fn enroll(
+        &self,
+        activity_id: i64,
+        foundation_id: i64,
+        student_id: i64,
+        semester_id: i64,
+    ) -> Result<extracurricular_enrollments::Model, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        // SELECT ... FOR UPDATE: pendaftaran bersamaan ke kegiatan yang sama diantrekan
+        let activity = Extracurricular::find_by_id(activity_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| AppError::not_found("Extracurricular not found".to_string()))?;
+
+        let rows = extracurricular_enrollments::Entity::find()
+            .filter(extracurricular_enrollments::Column::ExtracurricularId.eq(activity_id))
+            .filter(extracurricular_enrollments::Column::SemesterId.eq(semester_id))
+            .all(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let existing = rows.iter().find(|row| row.student_id == student_id);
+        if existing.is_some_and(|row| row.deleted_at.is_none()) {
+            return Err(AppError::ConflictError(
+                "Student is already enrolled in this extracurricular".to_string(),
+            ));
+        }
+
+        let enrolled = rows.iter().filter(|row| row.deleted_at.is_none()).count() as u64;
+        if !enrollment::has_seat(activity.max_participants, enrolled) {
+            return Err(AppError::ConflictError(
+                "Extracurricular has reached its participant limit".to_string(),
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        let result = match existing {
+            // Pernah mengundurkan diri: aktifkan lagi baris lama agar unique key tidak bentrok
+            Some(row) => {
+                extracurricular_enrollments::ActiveModel {
+                    id: Set(row.id),
+                    grade: Set(None),
+                    notes: Set(None),
+                    updated_at: Set(now),
+                    deleted_at: Set(None),
+                    ..Default::default()
+                }
+                .update(&txn)
+                .await
+            }
+            None => {
+                extracurricular_enrollments::ActiveModel {
+                    foundation_id: Set(foundation_id),
+                    student_id: Set(student_id),
+                    extracurricular_id: Set(activity_id),
+                    semester_id: Set(semester_id),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await
+            }
+        }
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result)
+    }
+
+    /// Withdraw from an activity (soft delete)
+    pub async fn withdraw(&self, enrollment_id: i64) -> Result<(), AppError> {
+        extracurricular_enrollments::ActiveModel {
+            id: Set(enrollment_id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+        .update(self.conn())
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Students whose report card of the semester is already finalized
+    pub async fn find_finalized_report_card_students(
+        &self,
+        student_ids: Vec<i64>,
+        semester_id: i64,
+    ) -> Result<Vec<i64>, AppError> {
+        let cards = report_cards::Entity::find_not_deleted()
+            .filter(report_cards::Column::StudentId.is_in(student_ids))
+            .filter(report_cards::Column::SemesterId.eq(semester_id))
+            .filter(report_cards::Column::Status.eq(ReportCardStatus::Finalized))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(cards.into_iter().map(|card| card.student_id).collect())
+    }
+
+    /// Store grades of enrollments in a single transaction
+    pub async fn save_grades(&self, entries: Vec<GradeInput>) -> Result<usize, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let now = chrono::Utc::now();
+        let saved = entries.len();
+        for (id, grade, notes) in entries {
+            extracurricular_enrollments::ActiveModel {
+                id: Set(id),
+                grade: Set(Some(grade)),
+                notes: Set(notes),
+                updated_at: Set(now),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+}
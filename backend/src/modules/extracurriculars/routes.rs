@@ -0,0 +1,33 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::extracurriculars::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/extracurriculars")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("", web::post().to(handler::create))
+            .route("/schedule", web::get().to(handler::schedule))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}", web::put().to(handler::update))
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("extracurriculars.delete")),
+            )
+            .route("/{id}/enrollments", web::get().to(handler::enrollments))
+            .route("/{id}/enrollments", web::post().to(handler::enroll))
+            .route(
+                "/{id}/enrollments/{enrollment_id}",
+                web::delete().to(handler::withdraw),
+            )
+            .route("/{id}/grades", web::put().to(handler::save_grades)),
+    );
+}
@@ -0,0 +1,582 @@
+// ============================================================================
+// backend/src/modules/extracurriculars/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    BulkGradeRequest, BulkGradeResponse, CreateExtracurricularRequest, EnrollRequest,
+    EnrollmentListResponse, EnrollmentResponse, ExtracurricularResponse, ScheduleEntry,
+    ScheduleQuery, UpdateExtracurricularRequest,
+};
+use super::enrollment;
+use super::repository::{ExtracurricularRepository, GradeInput};
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use entity::sea_orm_active_enums::SlotType;
+use entity::{extracurricular_activities, semesters, students};
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Kode permission staf yang boleh mengelola peserta & nilai kegiatan mana pun
+pub const MANAGE_PERMISSION: &str = "extracurriculars.manage";
+
+/// Pengguna yang sedang login
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub user_id: i64,
+    pub foundation_id: i64,
+    pub can_manage: bool,
+}
+
+#[derive(Clone)]
+pub struct ExtracurricularService {
+    repository: ExtracurricularRepository,
+}
+
+impl ExtracurricularService {
+    pub fn new(repository: ExtracurricularRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create new extracurricular activity with validation
+    pub async fn create(
+        &self,
+        request: CreateExtracurricularRequest,
+        foundation_id: i64,
+    ) -> Result<ExtracurricularResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let name = request.name.trim().to_string();
+
+        // Check duplicate name
+        if self
+            .repository
+            .find_by_name(&name, foundation_id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ConflictError(
+                "Extracurricular with this name already exists".to_string(),
+            ));
+        }
+
+        self.check_placement(
+            None,
+            request.coach_id,
+            request.time_slot_id,
+            request.room_id,
+            foundation_id,
+        )
+        .await?;
+
+        let active_model = extracurricular_activities::ActiveModel {
+            foundation_id: Set(foundation_id),
+            name: Set(name),
+            description: Set(request.description),
+            coach_id: Set(request.coach_id),
+            time_slot_id: Set(request.time_slot_id),
+            room_id: Set(request.room_id),
+            max_participants: Set(request.max_participants),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let created = self.repository.create(active_model).await?;
+        Ok(ExtracurricularResponse::from(created))
+    }
+
+    /// Get extracurricular activity by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<ExtracurricularResponse, AppError> {
+        let activity = self.find_activity(id, foundation_id).await?;
+        Ok(ExtracurricularResponse::from(activity))
+    }
+
+    /// Get all extracurricular activities with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<ExtracurricularResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self.repository.find_all(&params, foundation_id).await?;
+
+        let responses: Vec<ExtracurricularResponse> = items
+            .into_iter()
+            .map(ExtracurricularResponse::from)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Update extracurricular activity
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateExtracurricularRequest,
+        foundation_id: i64,
+    ) -> Result<ExtracurricularResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self.find_activity(id, foundation_id).await?;
+        let mut active_model: extracurricular_activities::ActiveModel = existing.clone().into();
+
+        // Business rule: check duplicate name if changing
+        if let Some(name) = request.name.map(|n| n.trim().to_string()) {
+            if name != existing.name
+                && self
+                    .repository
+                    .find_by_name(&name, foundation_id)
+                    .await?
+                    .is_some()
+            {
+                return Err(AppError::ConflictError(
+                    "Extracurricular with this name already exists".to_string(),
+                ));
+            }
+            active_model.name = Set(name);
+        }
+
+        let coach_id = request.coach_id.or(existing.coach_id);
+        let time_slot_id = request.time_slot_id.or(existing.time_slot_id);
+        let room_id = request.room_id.or(existing.room_id);
+        self.check_placement(Some(id), coach_id, time_slot_id, room_id, foundation_id)
+            .await?;
+
+        if let Some(description) = request.description {
+            active_model.description = Set(Some(description));
+        }
+        if let Some(max_participants) = request.max_participants {
+            active_model.max_participants = Set(Some(max_participants));
+        }
+        active_model.coach_id = Set(coach_id);
+        active_model.time_slot_id = Set(time_slot_id);
+        active_model.room_id = Set(room_id);
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let updated = self.repository.update(active_model).await?;
+        Ok(ExtracurricularResponse::from(updated))
+    }
+
+    /// Delete extracurricular activity (soft delete)
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        self.find_activity(id, foundation_id).await?;
+        self.repository.soft_delete(id).await
+    }
+
+    /// Peserta kegiatan pada satu semester beserta sisa kuota
+    pub async fn enrollments(
+        &self,
+        id: i64,
+        semester_id: i64,
+        foundation_id: i64,
+    ) -> Result<EnrollmentListResponse, AppError> {
+        let activity = self.find_activity(id, foundation_id).await?;
+        self.find_semester(semester_id, foundation_id).await?;
+
+        let rows = self.repository.find_enrollments(id, semester_id).await?;
+        let students = self
+            .repository
+            .find_students(rows.iter().map(|r| r.student_id).collect())
+            .await?;
+        let mut by_student: HashMap<i64, _> = rows.into_iter().map(|r| (r.student_id, r)).collect();
+
+        // Urut nama siswa (find_students sudah terurut)
+        let enrollments: Vec<EnrollmentResponse> = students
+            .iter()
+            .filter_map(|student| {
+                let row = by_student.remove(&student.id)?;
+                Some(EnrollmentResponse::new(row, student))
+            })
+            .collect();
+
+        Ok(EnrollmentListResponse {
+            extracurricular_id: id,
+            semester_id,
+            max_participants: activity.max_participants,
+            remaining_seats: enrollment::remaining_seats(
+                activity.max_participants,
+                enrollments.len() as u64,
+            ),
+            enrollments,
+        })
+    }
+
+    /// Daftarkan siswa: oleh siswa sendiri, wali yang terhubung, atau staf pengelola
+    pub async fn enroll(
+        &self,
+        id: i64,
+        request: EnrollRequest,
+        actor: Actor,
+    ) -> Result<EnrollmentResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let activity = self.find_activity(id, actor.foundation_id).await?;
+        let semester = self
+            .find_semester(request.semester_id, actor.foundation_id)
+            .await?;
+
+        let student = match request.student_id {
+            Some(student_id) => self
+                .repository
+                .find_student(student_id, actor.foundation_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Student not found".to_string()))?,
+            None => self
+                .repository
+                .find_student_by_user(actor.user_id, actor.foundation_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Student profile not found".to_string()))?,
+        };
+        ensure_can_act_for(&student, actor)?;
+
+        if !enrollment::enrollment_open(chrono::Utc::now().date_naive(), semester.end_date) {
+            return Err(AppError::validation(
+                "Enrollment is closed; the semester has ended".to_string(),
+            ));
+        }
+
+        // Business rule: satu siswa tidak boleh ikut dua kegiatan pada slot yang sama
+        if let Some(slot_id) = activity.time_slot_id {
+            let others: Vec<i64> = self
+                .repository
+                .find_student_enrollments(student.id, semester.id)
+                .await?
+                .into_iter()
+                .map(|e| e.extracurricular_id)
+                .filter(|other| *other != id)
+                .collect();
+            if !others.is_empty()
+                && self
+                    .repository
+                    .find_scheduled(actor.foundation_id, None, Some(others))
+                    .await?
+                    .iter()
+                    .any(|other| other.time_slot_id == Some(slot_id))
+            {
+                return Err(AppError::ConflictError(
+                    "Student already joins another extracurricular in this time slot".to_string(),
+                ));
+            }
+        }
+
+        let created = self
+            .repository
+            .enroll(id, actor.foundation_id, student.id, semester.id)
+            .await?;
+
+        Ok(EnrollmentResponse::new(created, &student))
+    }
+
+    /// Undur diri dari kegiatan; tidak bisa setelah dinilai
+    pub async fn withdraw(
+        &self,
+        id: i64,
+        enrollment_id: i64,
+        actor: Actor,
+    ) -> Result<(), AppError> {
+        self.find_activity(id, actor.foundation_id).await?;
+
+        let row = self
+            .repository
+            .find_enrollment(enrollment_id)
+            .await?
+            .filter(|row| row.extracurricular_id == id)
+            .ok_or_else(|| AppError::not_found("Enrollment not found".to_string()))?;
+        let student = self
+            .repository
+            .find_student(row.student_id, actor.foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Student not found".to_string()))?;
+        ensure_can_act_for(&student, actor)?;
+
+        if row.grade.is_some() {
+            return Err(AppError::ConflictError(
+                "Enrollment has already been graded".to_string(),
+            ));
+        }
+
+        self.repository.withdraw(row.id).await
+    }
+
+    /// Penilaian akhir semester oleh pembina kegiatan
+    pub async fn save_grades(
+        &self,
+        id: i64,
+        request: BulkGradeRequest,
+        actor: Actor,
+    ) -> Result<BulkGradeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let activity = self.find_activity(id, actor.foundation_id).await?;
+        let semester = self
+            .find_semester(request.semester_id, actor.foundation_id)
+            .await?;
+
+        if !actor.can_manage {
+            let teacher_id = self
+                .repository
+                .find_teacher_by_user(actor.user_id, actor.foundation_id)
+                .await?
+                .map(|teacher| teacher.id);
+            if teacher_id.is_none() || teacher_id != activity.coach_id {
+                return Err(AppError::forbidden(
+                    "Only the coach of this extracurricular can grade its participants",
+                ));
+            }
+        }
+
+        if !enrollment::grading_open(chrono::Utc::now().date_naive(), semester.end_date) {
+            return Err(AppError::validation(format!(
+                "Grading opens {} days before the semester ends ({})",
+                enrollment::GRADING_WINDOW_DAYS,
+                semester.end_date
+            )));
+        }
+
+        let enrolled: HashMap<i64, i64> = self
+            .repository
+            .find_enrollments(id, semester.id)
+            .await?
+            .into_iter()
+            .map(|row| (row.student_id, row.id))
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut inputs: Vec<GradeInput> = Vec::with_capacity(request.entries.len());
+        for entry in request.entries {
+            let enrollment_id = enrolled.get(&entry.student_id).ok_or_else(|| {
+                AppError::validation(format!(
+                    "Student {} is not enrolled in this extracurricular",
+                    entry.student_id
+                ))
+            })?;
+            if !seen.insert(entry.student_id) {
+                return Err(AppError::validation(format!(
+                    "Student {} is graded more than once",
+                    entry.student_id
+                )));
+            }
+            inputs.push((*enrollment_id, entry.grade, entry.notes));
+        }
+
+        // Business rule: rapor yang sudah final mengunci nilai ekstrakurikuler
+        let locked = self
+            .repository
+            .find_finalized_report_card_students(seen.into_iter().collect(), semester.id)
+            .await?;
+        if let Some(student_id) = locked.first() {
+            return Err(AppError::ConflictError(format!(
+                "Report card of student {} is finalized; extracurricular grades are locked",
+                student_id
+            )));
+        }
+
+        let saved = self.repository.save_grades(inputs).await?;
+
+        Ok(BulkGradeResponse {
+            extracurricular_id: id,
+            semester_id: semester.id,
+            saved,
+        })
+    }
+
+    /// Jadwal mingguan kegiatan, untuk digabung dengan jadwal pelajaran
+    pub async fn schedule(
+        &self,
+        query: ScheduleQuery,
+        foundation_id: i64,
+    ) -> Result<Vec<ScheduleEntry>, AppError> {
+        let ids = match query.student_id {
+            Some(student_id) => {
+                let semester_id = query.semester_id.ok_or_else(|| {
+                    AppError::validation("semester_id is required with student_id".to_string())
+                })?;
+                self.repository
+                    .find_student(student_id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Student not found".to_string()))?;
+                let ids: Vec<i64> = self
+                    .repository
+                    .find_student_enrollments(student_id, semester_id)
+                    .await?
+                    .into_iter()
+                    .map(|e| e.extracurricular_id)
+                    .collect();
+                if ids.is_empty() {
+                    return Ok(Vec::new());
+                }
+                Some(ids)
+            }
+            None => None,
+        };
+
+        let activities = self
+            .repository
+            .find_scheduled(foundation_id, query.coach_id, ids)
+            .await?;
+
+        let slots: HashMap<i64, _> = self
+            .repository
+            .find_time_slots(activities.iter().filter_map(|a| a.time_slot_id).collect())
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+        let coaches: HashMap<i64, String> = self
+            .repository
+            .find_teachers(activities.iter().filter_map(|a| a.coach_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+        let rooms: HashMap<i64, String> = self
+            .repository
+            .find_rooms(activities.iter().filter_map(|a| a.room_id).collect())
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+
+        let mut entries: Vec<(i8, chrono::NaiveTime, ScheduleEntry)> = activities
+            .into_iter()
+            .filter_map(|activity| {
+                let slot = slots.get(&activity.time_slot_id?)?;
+                Some((
+                    slot.day_of_week,
+                    slot.start_time,
+                    ScheduleEntry {
+                        extracurricular_id: activity.id,
+                        name: activity.name,
+                        time_slot_id: slot.id,
+                        day_of_week: slot.day_of_week,
+                        start_time: slot.start_time.format("%H:%M").to_string(),
+                        end_time: slot.end_time.format("%H:%M").to_string(),
+                        coach_name: activity.coach_id.and_then(|id| coaches.get(&id).cloned()),
+                        coach_id: activity.coach_id,
+                        room_name: activity.room_id.and_then(|id| rooms.get(&id).cloned()),
+                        room_id: activity.room_id,
+                    },
+                ))
+            })
+            .collect();
+        entries.sort_by(|a, b| (a.0, a.1, &a.2.name).cmp(&(b.0, b.1, &b.2.name)));
+
+        Ok(entries.into_iter().map(|(_, _, entry)| entry).collect())
+    }
+
+    async fn find_activity(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<extracurricular_activities::Model, AppError> {
+        self.repository
+            .find_by_id(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Extracurricular not found".to_string()))
+    }
+
+    async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<semesters::Model, AppError> {
+        self.repository
+            .find_semester(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))
+    }
+
+    /// Validasi pembina, slot & ruangan; tolak bila bentrok dengan kegiatan lain
+    async fn check_placement(
+        &self,
+        id: Option<i64>,
+        coach_id: Option<i64>,
+        time_slot_id: Option<i64>,
+        room_id: Option<i64>,
+        foundation_id: i64,
+    ) -> Result<(), AppError> {
+        if let Some(coach_id) = coach_id {
+            self.repository
+                .find_teacher(coach_id, foundation_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Coach not found".to_string()))?;
+        }
+        if let Some(room_id) = room_id {
+            self.repository
+                .find_room(room_id, foundation_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Room not found".to_string()))?;
+        }
+
+        let Some(time_slot_id) = time_slot_id else {
+            return Ok(());
+        };
+        let slot = self
+            .repository
+            .find_time_slot(time_slot_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Time slot not found".to_string()))?;
+        if slot.slot_type != Some(SlotType::Extracurricular) {
+            return Err(AppError::validation(
+                "Time slot must be of type extracurricular".to_string(),
+            ));
+        }
+
+        for other in self.repository.find_in_slot(time_slot_id, id).await? {
+            if coach_id.is_some() && other.coach_id == coach_id {
+                return Err(AppError::ConflictError(format!(
+                    "Coach already leads '{}' in this time slot",
+                    other.name
+                )));
+            }
+            if room_id.is_some() && other.room_id == room_id {
+                return Err(AppError::ConflictError(format!(
+                    "Room is already used by '{}' in this time slot",
+                    other.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Siswa sendiri, wali yang terhubung, atau staf dengan permission pengelola
+fn ensure_can_act_for(student: &students::Model, actor: Actor) -> Result<(), AppError> {
+    if actor.can_manage
+        || student.user_id == actor.user_id
+        || student.guardian_user_id == Some(actor.user_id)
+    {
+        Ok(())
+    } else {
+        Err(AppError::forbidden(
+            "You can only manage enrollments of yourself or your own children",
+        ))
+    }
+}
@@ -7,6 +7,10 @@ use validator::Validate;
 pub struct FoundationResponse {
     pub id: i64,
     pub name: String,
+    /// Login ditolak bila email pengguna belum diverifikasi
+    pub require_email_verification: bool,
+    /// Permission sensitif (payroll, golongan gaji, kelola user) butuh 2FA aktif
+    pub require_two_factor: bool,
     pub created_at: String, // ← Tambah ini (good practice)
     pub updated_at: String, // ← Tambah ini
 }
@@ -15,12 +19,16 @@ pub struct FoundationResponse {
 pub struct CreateFoundationRequest {
     #[validate(length(min = 3, max = 100))]
     pub name: String,
+    pub require_email_verification: Option<bool>,
+    pub require_two_factor: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateFoundationRequest {
     #[validate(length(min = 3, max = 100))]
     pub name: Option<String>,
+    pub require_email_verification: Option<bool>,
+    pub require_two_factor: Option<bool>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -37,6 +45,8 @@ impl From<entity::foundations::Model> for FoundationResponse {
         Self {
             id: model.id,
             name: model.name,
+            require_email_verification: model.require_email_verification != 0,
+            require_two_factor: model.require_two_factor != 0,
             created_at: model.created_at.to_string(),
             updated_at: model.updated_at.to_string(),
         }
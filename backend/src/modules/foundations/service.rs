@@ -41,6 +41,10 @@ impl FoundationService {
         // Build entity with parsed dates
         let active_model = foundations::ActiveModel {
             name: Set(request.name),
+            require_email_verification: Set(
+                request.require_email_verification.unwrap_or(false) as i8
+            ),
+            require_two_factor: Set(request.require_two_factor.unwrap_or(false) as i8),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..Default::default()
@@ -125,6 +129,12 @@ impl FoundationService {
         if let Some(name) = request.name {
             active_model.name = Set(name);
         }
+        if let Some(require) = request.require_email_verification {
+            active_model.require_email_verification = Set(require as i8);
+        }
+        if let Some(require) = request.require_two_factor {
+            active_model.require_two_factor = Set(require as i8);
+        }
 
         // Delegate to repository
         let updated = self.repository.update(id, active_model).await?;
@@ -0,0 +1,164 @@
+// ============================================================================
+// backend/src/modules/grades/calculator.rs
+// calculator.rs - Final Grade Calculation (pure, tanpa akses database)
+// ============================================================================
+use entity::sea_orm_active_enums::Predicate;
+use sea_orm::prelude::Decimal;
+
+/// Bobot komponen nilai dalam persen (total 100)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights {
+    pub attendance: Decimal,
+    pub assignment: Decimal,
+    pub quiz: Decimal,
+    pub midterm: Decimal,
+    pub final_exam: Decimal,
+}
+
+impl Default for Weights {
+    /// Bobot bawaan jika mapel belum dikonfigurasi: 10/20/10/30/30
+    fn default() -> Self {
+        Self {
+            attendance: Decimal::from(10),
+            assignment: Decimal::from(20),
+            quiz: Decimal::from(10),
+            midterm: Decimal::from(30),
+            final_exam: Decimal::from(30),
+        }
+    }
+}
+
+impl Weights {
+    pub fn total(&self) -> Decimal {
+        self.attendance + self.assignment + self.quiz + self.midterm + self.final_exam
+    }
+}
+
+/// Nilai komponen satu siswa (skala 0-100)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComponentScores {
+    pub attendance: Option<Decimal>,
+    pub assignment: Option<Decimal>,
+    pub quiz: Option<Decimal>,
+    pub midterm: Option<Decimal>,
+    pub final_exam: Option<Decimal>,
+}
+
+/// Satu baris skala penilaian: nilai >= `min_score` mendapat huruf & predikat ini
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleBand {
+    pub min_score: Decimal,
+    pub letter: String,
+    pub predicate: Predicate,
+}
+
+/// Skala bawaan jika foundation belum mengatur `grading_scales`
+pub fn default_scale() -> Vec<ScaleBand> {
+    [
+        (90, "A", Predicate::SangatBaik),
+        (80, "B", Predicate::Baik),
+        (70, "C", Predicate::Cukup),
+        (60, "D", Predicate::Kurang),
+        (0, "E", Predicate::Kurang),
+    ]
+    .into_iter()
+    .map(|(min, letter, predicate)| ScaleBand {
+        min_score: Decimal::from(min),
+        letter: letter.to_string(),
+        predicate,
+    })
+    .collect()
+}
+
+/// Rata-rata berbobot dari komponen yang sudah diisi, dibulatkan 2 desimal.
+/// Komponen kosong tidak ikut dihitung sehingga nilai sementara tetap bermakna
+pub fn final_grade(scores: &ComponentScores, weights: &Weights) -> Decimal {
+    let pairs = [
+        (scores.attendance, weights.attendance),
+        (scores.assignment, weights.assignment),
+        (scores.quiz, weights.quiz),
+        (scores.midterm, weights.midterm),
+        (scores.final_exam, weights.final_exam),
+    ];
+
+    let (weighted, total_weight) = pairs
+        .iter()
+        .filter_map(|(score, weight)| score.map(|s| (s * weight, *weight)))
+        .fold(
+            (Decimal::ZERO, Decimal::ZERO),
+            |(sum, total), (value, weight)| (sum + value, total + weight),
+        );
+
+    if total_weight.is_zero() {
+        return Decimal::ZERO;
+    }
+    (weighted / total_weight).round_dp(2)
+}
+
+/// Huruf & predikat dari band tertinggi yang `min_score`-nya terlampaui
+pub fn classify(grade: Decimal, scale: &[ScaleBand]) -> Option<(String, Predicate)> {
+    scale
+        .iter()
+        .filter(|band| grade >= band.min_score)
+        .max_by(|a, b| a.min_score.cmp(&b.min_score))
+        .map(|band| (band.letter.clone(), band.predicate.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(value: i64) -> Decimal {
+        Decimal::from(value)
+    }
+
+    #[test]
+    fn test_final_grade_uses_weights() {
+        let scores = ComponentScores {
+            attendance: Some(d(100)),
+            assignment: Some(d(80)),
+            quiz: Some(d(70)),
+            midterm: Some(d(75)),
+            final_exam: Some(d(90)),
+        };
+
+        // 10 + 16 + 7 + 22.5 + 27 = 82.5
+        assert_eq!(
+            final_grade(&scores, &Weights::default()),
+            Decimal::new(8250, 2)
+        );
+    }
+
+    #[test]
+    fn test_final_grade_ignores_missing_components() {
+        let scores = ComponentScores {
+            midterm: Some(d(80)),
+            final_exam: Some(d(70)),
+            ..Default::default()
+        };
+
+        assert_eq!(final_grade(&scores, &Weights::default()), d(75));
+        assert_eq!(
+            final_grade(&ComponentScores::default(), &Weights::default()),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_classify_picks_highest_matching_band() {
+        let scale = default_scale();
+
+        assert_eq!(
+            classify(Decimal::new(8999, 2), &scale),
+            Some(("B".to_string(), Predicate::Baik))
+        );
+        assert_eq!(
+            classify(d(90), &scale),
+            Some(("A".to_string(), Predicate::SangatBaik))
+        );
+        assert_eq!(
+            classify(d(10), &scale).map(|(l, _)| l),
+            Some("E".to_string())
+        );
+    }
+}
@@ -0,0 +1,91 @@
+// ============================================================================
+// backend/src/modules/grades/changes.rs
+// changes.rs - Alur pengajuan perubahan nilai (pure, tanpa akses database)
+// ============================================================================
+use super::calculator::ComponentScores;
+use entity::sea_orm_active_enums::GradeChangeStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewAction {
+    Approve,
+    Reject,
+    Cancel,
+}
+
+/// Status berikutnya; hanya pengajuan `pending` yang bisa diproses
+pub fn next_status(
+    current: &GradeChangeStatus,
+    action: ReviewAction,
+) -> Result<GradeChangeStatus, String> {
+    if *current != GradeChangeStatus::Pending {
+        return Err(format!(
+            "Grade change request is already {}",
+            sea_orm::ActiveEnum::to_value(current)
+        ));
+    }
+
+    Ok(match action {
+        ReviewAction::Approve => GradeChangeStatus::Approved,
+        ReviewAction::Reject => GradeChangeStatus::Rejected,
+        ReviewAction::Cancel => GradeChangeStatus::Cancelled,
+    })
+}
+
+/// Komponen yang diusulkan menggantikan nilai lama; yang tidak diusulkan tetap
+pub fn apply(current: &ComponentScores, proposed: &ComponentScores) -> ComponentScores {
+    ComponentScores {
+        attendance: proposed.attendance.or(current.attendance),
+        assignment: proposed.assignment.or(current.assignment),
+        quiz: proposed.quiz.or(current.quiz),
+        midterm: proposed.midterm.or(current.midterm),
+        final_exam: proposed.final_exam.or(current.final_exam),
+    }
+}
+
+/// Pengajuan tanpa satu pun komponen yang berbeda dari nilai sekarang tidak ada gunanya
+pub fn changes_anything(current: &ComponentScores, proposed: &ComponentScores) -> bool {
+    let pairs = [
+        (proposed.attendance, current.attendance),
+        (proposed.assignment, current.assignment),
+        (proposed.quiz, current.quiz),
+        (proposed.midterm, current.midterm),
+        (proposed.final_exam, current.final_exam),
+    ];
+    pairs.iter().any(|(new, old)| new.is_some() && new != old)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::prelude::Decimal;
+
+    #[test]
+    fn test_only_pending_requests_move_and_proposal_overrides() {
+        assert_eq!(
+            next_status(&GradeChangeStatus::Pending, ReviewAction::Approve),
+            Ok(GradeChangeStatus::Approved)
+        );
+        assert_eq!(
+            next_status(&GradeChangeStatus::Pending, ReviewAction::Cancel),
+            Ok(GradeChangeStatus::Cancelled)
+        );
+        assert!(next_status(&GradeChangeStatus::Rejected, ReviewAction::Approve).is_err());
+
+        let current = ComponentScores {
+            quiz: Some(Decimal::from(70)),
+            final_exam: Some(Decimal::from(60)),
+            ..Default::default()
+        };
+        let proposed = ComponentScores {
+            final_exam: Some(Decimal::from(80)),
+            ..Default::default()
+        };
+        let merged = apply(&current, &proposed);
+        assert_eq!(merged.quiz, Some(Decimal::from(70)));
+        assert_eq!(merged.final_exam, Some(Decimal::from(80)));
+
+        assert!(changes_anything(&current, &proposed));
+        assert!(!changes_anything(&current, &current));
+        assert!(!changes_anything(&current, &ComponentScores::default()));
+    }
+}
@@ -0,0 +1,77 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    BulkGradeRequest, CreateGradeChangeRequest, FinalizeGradesRequest, FinalizeGradesResponse,
+    GradeChangeFilters, GradeChangeResponse, GradeEntry, GradeHistoryResponse, GradeResponse,
+    GradeWeightsQuery, GradeWeightsResponse, GradebookResponse, GradebookRow, GradingScaleBand,
+    GradingScaleResponse, ReviewGradeChangeRequest, SetGradeWeightsRequest, SetGradingScaleRequest,
+};
+use super::handler;
+use entity::sea_orm_active_enums::GradeChangeStatus;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::gradebook,
+        handler::save_bulk,
+        handler::finalize,
+        handler::get_weights,
+        handler::set_weights,
+        handler::get_scale,
+        handler::set_scale,
+        handler::request_change,
+        handler::get_change_requests,
+        handler::get_change_request,
+        handler::approve_change,
+        handler::reject_change,
+        handler::cancel_change,
+        handler::grade_history,
+    ),
+    components(
+        schemas(
+            GradeResponse,
+            GradebookRow,
+            GradebookResponse,
+            GradeEntry,
+            BulkGradeRequest,
+            FinalizeGradesRequest,
+            FinalizeGradesResponse,
+            GradeWeightsQuery,
+            GradeWeightsResponse,
+            SetGradeWeightsRequest,
+            GradingScaleBand,
+            SetGradingScaleRequest,
+            GradingScaleResponse,
+            GradeChangeStatus,
+            CreateGradeChangeRequest,
+            ReviewGradeChangeRequest,
+            GradeChangeFilters,
+            GradeChangeResponse,
+            GradeHistoryResponse,
+        )
+    ),
+    tags(
+        (name = "Grade ", description = "Gradebook, grade weights, grading scale and grade change request endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct GradesApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
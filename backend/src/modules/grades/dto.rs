@@ -0,0 +1,281 @@
+// backend/src/modules/grades/dto.rs
+use entity::sea_orm_active_enums::{GradeChangeStatus, Predicate};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradeResponse {
+    pub id: i64,
+    pub student_id: i64,
+    pub class_subject_id: i64,
+    pub semester_id: i64,
+    pub attendance_score: Option<f64>,
+    pub assignment_score: Option<f64>,
+    pub quiz_score: Option<f64>,
+    pub midterm_score: Option<f64>,
+    pub final_score: Option<f64>,
+    pub final_grade: f64,
+    pub grade_letter: Option<String>,
+    pub predicate: Option<Predicate>,
+    pub teacher_notes: Option<String>,
+    pub is_finalized: bool,
+    pub finalized_at: Option<String>,
+    pub finalized_by: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradebookRow {
+    pub student_id: i64,
+    pub student_name: String,
+    pub student_number: String,
+    /// `null` jika nilai siswa belum pernah diisi
+    pub grade: Option<GradeResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradebookResponse {
+    pub class_subject_id: i64,
+    pub class_id: i64,
+    pub subject_id: i64,
+    pub semester_id: i64,
+    pub weights: GradeWeightsResponse,
+    pub rows: Vec<GradebookRow>,
+}
+
+/// Nilai komponen satu siswa; field yang tidak dikirim tidak diubah
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct GradeEntry {
+    #[validate(range(min = 1))]
+    pub student_id: i64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub attendance_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub assignment_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub quiz_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub midterm_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub final_score: Option<f64>,
+    pub teacher_notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkGradeRequest {
+    #[validate(length(min = 1), nested)]
+    pub entries: Vec<GradeEntry>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct FinalizeGradesRequest {
+    /// Siswa yang difinalisasi; kosong = semua nilai pada class subject ini
+    pub student_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FinalizeGradesResponse {
+    pub class_subject_id: i64,
+    pub finalized: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradeWeightsResponse {
+    pub subject_id: i64,
+    pub attendance_weight: f64,
+    pub assignment_weight: f64,
+    pub quiz_weight: f64,
+    pub midterm_weight: f64,
+    pub final_weight: f64,
+    /// `true` jika mapel belum dikonfigurasi dan bobot bawaan yang dipakai
+    pub is_default: bool,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetGradeWeightsRequest {
+    #[validate(range(min = 1))]
+    pub subject_id: i64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub attendance_weight: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub assignment_weight: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub quiz_weight: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub midterm_weight: f64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub final_weight: f64,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct GradeWeightsQuery {
+    pub subject_id: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct GradingScaleBand {
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub min_score: f64,
+    #[validate(length(min = 1, max = 5))]
+    pub grade_letter: String,
+    pub predicate: Predicate,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetGradingScaleRequest {
+    #[validate(length(min = 1), nested)]
+    pub bands: Vec<GradingScaleBand>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradingScaleResponse {
+    pub bands: Vec<GradingScaleBand>,
+    /// `true` jika foundation belum mengatur skala dan skala bawaan yang dipakai
+    pub is_default: bool,
+}
+
+/// Usulan perubahan nilai yang sudah difinalisasi; komponen yang tidak dikirim tidak diubah
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateGradeChangeRequest {
+    #[validate(range(min = 1))]
+    pub grade_id: i64,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub attendance_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub assignment_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub quiz_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub midterm_score: Option<f64>,
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub final_score: Option<f64>,
+    #[validate(length(min = 10, max = 2000))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReviewGradeChangeRequest {
+    /// Required when rejecting
+    #[validate(length(max = 2000))]
+    pub review_notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct GradeChangeFilters {
+    pub status: Option<GradeChangeStatus>,
+    pub class_subject_id: Option<i64>,
+    pub grade_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradeChangeResponse {
+    pub id: i64,
+    pub grade_id: i64,
+    pub proposed_attendance_score: Option<f64>,
+    pub proposed_assignment_score: Option<f64>,
+    pub proposed_quiz_score: Option<f64>,
+    pub proposed_midterm_score: Option<f64>,
+    pub proposed_final_score: Option<f64>,
+    pub reason: String,
+    pub status: GradeChangeStatus,
+    pub requested_by: i64,
+    pub reviewed_by: Option<i64>,
+    pub reviewed_at: Option<String>,
+    pub review_notes: Option<String>,
+    /// Nilai saat ini (setelah disetujui: nilai yang sudah diperbarui)
+    pub grade: Option<GradeResponse>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Salinan nilai sebelum satu perubahan disetujui
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GradeHistoryResponse {
+    pub id: i64,
+    pub grade_id: i64,
+    pub change_request_id: Option<i64>,
+    pub attendance_score: Option<f64>,
+    pub assignment_score: Option<f64>,
+    pub quiz_score: Option<f64>,
+    pub midterm_score: Option<f64>,
+    pub final_score: Option<f64>,
+    pub final_grade: f64,
+    pub grade_letter: Option<String>,
+    pub predicate: Option<Predicate>,
+    pub changed_by: i64,
+    pub created_at: String,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::grades::Model> for GradeResponse {
+    fn from(model: entity::grades::Model) -> Self {
+        Self {
+            id: model.id,
+            student_id: model.student_id,
+            class_subject_id: model.class_subject_id,
+            semester_id: model.semester_id,
+            attendance_score: model.attendance_score.and_then(|v| v.try_into().ok()),
+            assignment_score: model.assignment_score.and_then(|v| v.try_into().ok()),
+            quiz_score: model.quiz_score.and_then(|v| v.try_into().ok()),
+            midterm_score: model.midterm_score.and_then(|v| v.try_into().ok()),
+            final_score: model.final_score.and_then(|v| v.try_into().ok()),
+            final_grade: model.final_grade.try_into().unwrap_or_default(),
+            grade_letter: model.grade_letter,
+            predicate: model.predicate,
+            teacher_notes: model.teacher_notes,
+            is_finalized: model.is_finalized.unwrap_or(0) != 0,
+            finalized_at: model.finalized_at.map(|t| t.to_string()),
+            finalized_by: model.finalized_by,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+
+impl GradeChangeResponse {
+    pub fn new(
+        model: entity::grade_change_requests::Model,
+        grade: Option<entity::grades::Model>,
+    ) -> Self {
+        Self {
+            id: model.id,
+            grade_id: model.grade_id,
+            proposed_attendance_score: model.attendance_score.and_then(|v| v.try_into().ok()),
+            proposed_assignment_score: model.assignment_score.and_then(|v| v.try_into().ok()),
+            proposed_quiz_score: model.quiz_score.and_then(|v| v.try_into().ok()),
+            proposed_midterm_score: model.midterm_score.and_then(|v| v.try_into().ok()),
+            proposed_final_score: model.final_score.and_then(|v| v.try_into().ok()),
+            reason: model.reason,
+            status: model.status,
+            requested_by: model.requested_by,
+            reviewed_by: model.reviewed_by,
+            reviewed_at: model.reviewed_at.map(|t| t.to_string()),
+            review_notes: model.review_notes,
+            grade: grade.map(GradeResponse::from),
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+
+impl From<entity::grade_histories::Model> for GradeHistoryResponse {
+    fn from(model: entity::grade_histories::Model) -> Self {
+        Self {
+            id: model.id,
+            grade_id: model.grade_id,
+            change_request_id: model.change_request_id,
+            attendance_score: model.attendance_score.and_then(|v| v.try_into().ok()),
+            assignment_score: model.assignment_score.and_then(|v| v.try_into().ok()),
+            quiz_score: model.quiz_score.and_then(|v| v.try_into().ok()),
+            midterm_score: model.midterm_score.and_then(|v| v.try_into().ok()),
+            final_score: model.final_score.and_then(|v| v.try_into().ok()),
+            final_grade: model.final_grade.try_into().unwrap_or_default(),
+            grade_letter: model.grade_letter,
+            predicate: model.predicate,
+            changed_by: model.changed_by,
+            created_at: model.created_at.to_string(),
+        }
+    }
+}
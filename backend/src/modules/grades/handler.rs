@@ -0,0 +1,413 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BulkGradeRequest, CreateGradeChangeRequest, FinalizeGradesRequest, FinalizeGradesResponse,
+    GradeChangeFilters, GradeChangeResponse, GradeHistoryResponse, GradeResponse,
+    GradeWeightsQuery, GradeWeightsResponse, GradebookResponse, GradingScaleResponse,
+    ReviewGradeChangeRequest, SetGradeWeightsRequest, SetGradingScaleRequest,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Get gradebook of a class subject
+#[utoipa::path(
+    get,
+    path = "/api/grades/class_subjects/{id}",
+    params(
+        ("id" = i64, Path, description = "Class subject ID")
+    ),
+    responses(
+        (status = 200, description = "Gradebook of the class subject", body = GradebookResponse),
+        (status = 404, description = "Class subject not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn gradebook(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .gradebook(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Enter component scores for many students at once
+#[utoipa::path(
+    put,
+    path = "/api/grades/class_subjects/{id}",
+    params(
+        ("id" = i64, Path, description = "Class subject ID")
+    ),
+    request_body = BulkGradeRequest,
+    responses(
+        (status = 200, description = "Grades saved", body = Vec<GradeResponse>),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Not the teacher of the class subject"),
+        (status = 404, description = "Class subject not found"),
+        (status = 409, description = "Some grades are already finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn save_bulk(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<BulkGradeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .save_bulk(
+            id.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Finalize grades of a class subject
+#[utoipa::path(
+    post,
+    path = "/api/grades/class_subjects/{id}/finalize",
+    params(
+        ("id" = i64, Path, description = "Class subject ID")
+    ),
+    request_body = FinalizeGradesRequest,
+    responses(
+        (status = 200, description = "Grades finalized", body = FinalizeGradesResponse),
+        (status = 400, description = "No open grades to finalize"),
+        (status = 403, description = "Not the teacher of the class subject"),
+        (status = 404, description = "Class subject not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn finalize(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<FinalizeGradesRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .finalize(
+            id.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get component weights of a subject
+#[utoipa::path(
+    get,
+    path = "/api/grades/weights",
+    params(GradeWeightsQuery),
+    responses(
+        (status = 200, description = "Component weights", body = GradeWeightsResponse),
+        (status = 404, description = "Subject not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn get_weights(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<GradeWeightsQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .get_weights(query.subject_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Set component weights of a subject
+#[utoipa::path(
+    put,
+    path = "/api/grades/weights",
+    request_body = SetGradeWeightsRequest,
+    responses(
+        (status = 200, description = "Component weights saved", body = GradeWeightsResponse),
+        (status = 400, description = "Weights do not add up to 100"),
+        (status = 403, description = "Missing grades.manage permission"),
+        (status = 404, description = "Subject not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn set_weights(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<SetGradeWeightsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .set_weights(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get grading scale
+#[utoipa::path(
+    get,
+    path = "/api/grades/scale",
+    responses(
+        (status = 200, description = "Grading scale", body = GradingScaleResponse)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn get_scale(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .get_scale(auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Replace grading scale
+#[utoipa::path(
+    put,
+    path = "/api/grades/scale",
+    request_body = SetGradingScaleRequest,
+    responses(
+        (status = 200, description = "Grading scale saved", body = GradingScaleResponse),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Missing grades.manage permission")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn set_scale(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<SetGradingScaleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .set_scale(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Request a change to a finalized grade
+#[utoipa::path(
+    post,
+    path = "/api/grades/change_requests",
+    request_body = CreateGradeChangeRequest,
+    responses(
+        (status = 201, description = "Grade change requested", body = GradeChangeResponse),
+        (status = 400, description = "Grade not finalized or nothing changes"),
+        (status = 403, description = "Not the teacher of the class subject"),
+        (status = 404, description = "Grade not found"),
+        (status = 409, description = "A pending request already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn request_change(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateGradeChangeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .request_change(request.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get grade change requests with pagination
+#[utoipa::path(
+    get,
+    path = "/api/grades/change_requests",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        GradeChangeFilters,
+    ),
+    responses(
+        (status = 200, description = "List of grade change requests", body = PaginatedResponse<GradeChangeResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn get_change_requests(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<GradeChangeFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .get_change_requests(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get grade change request by ID
+#[utoipa::path(
+    get,
+    path = "/api/grades/change_requests/{id}",
+    params(
+        ("id" = i64, Path, description = "Grade change request ID")
+    ),
+    responses(
+        (status = 200, description = "Grade change request found", body = GradeChangeResponse),
+        (status = 404, description = "Grade change request not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn get_change_request(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .get_change_request(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Approve a grade change request (head of unit)
+#[utoipa::path(
+    post,
+    path = "/api/grades/change_requests/{id}/approve",
+    params(
+        ("id" = i64, Path, description = "Grade change request ID")
+    ),
+    request_body = ReviewGradeChangeRequest,
+    responses(
+        (status = 200, description = "Grade updated and previous values kept in history", body = GradeChangeResponse),
+        (status = 403, description = "Not the head of unit"),
+        (status = 404, description = "Grade change request not found"),
+        (status = 409, description = "Request no longer pending or report card finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn approve_change(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<ReviewGradeChangeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .approve_change(
+            id.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Reject a grade change request (head of unit)
+#[utoipa::path(
+    post,
+    path = "/api/grades/change_requests/{id}/reject",
+    params(
+        ("id" = i64, Path, description = "Grade change request ID")
+    ),
+    request_body = ReviewGradeChangeRequest,
+    responses(
+        (status = 200, description = "Grade change request rejected", body = GradeChangeResponse),
+        (status = 400, description = "review_notes missing"),
+        (status = 403, description = "Not the head of unit"),
+        (status = 404, description = "Grade change request not found"),
+        (status = 409, description = "Request no longer pending")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn reject_change(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<ReviewGradeChangeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .reject_change(
+            id.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Cancel own pending grade change request
+#[utoipa::path(
+    post,
+    path = "/api/grades/change_requests/{id}/cancel",
+    params(
+        ("id" = i64, Path, description = "Grade change request ID")
+    ),
+    responses(
+        (status = 200, description = "Grade change request cancelled", body = GradeChangeResponse),
+        (status = 403, description = "Not the requester"),
+        (status = 404, description = "Grade change request not found"),
+        (status = 409, description = "Request no longer pending")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn cancel_change(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .cancel_change(id.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get change history of a grade
+#[utoipa::path(
+    get,
+    path = "/api/grades/{id}/history",
+    params(
+        ("id" = i64, Path, description = "Grade ID")
+    ),
+    responses(
+        (status = 200, description = "Previous values, newest first", body = Vec<GradeHistoryResponse>),
+        (status = 404, description = "Grade not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Grade "
+)]
+pub async fn grade_history(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .grade_service
+        .grade_history(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -0,0 +1,23 @@
+// backend/src/modules/grades/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod calculator;
+pub mod changes;
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::GradeRepository;
+pub use service::GradeService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> GradeService {
+    let repository = GradeRepository::new(db);
+    GradeService::new(repository)
+}
@@ -0,0 +1,706 @@
+// ============================================================================
+// backend/src/modules/grades/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::calculator::{ScaleBand, Weights};
+use super::dto::GradeChangeFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use entity::grades::{self, Entity as Grade};
+use entity::sea_orm_active_enums::{GradeChangeStatus, Predicate, ReportCardStatus};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{
+    class_subjects, employees, grade_change_requests, grade_histories, grade_weights,
+    grading_scales, report_cards, semesters, students, subjects, teachers, units,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct GradeRepository {
+    db: Database,
+}
+
+impl GradeRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find class subject of a foundation (not deleted)
+    pub async fn find_class_subject(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find_by_id(id)
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a foundation, optionally of one subject
+    pub async fn find_class_subjects(
+        &self,
+        foundation_id: i64,
+        subject_id: Option<i64>,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        let mut query = class_subjects::Entity::find()
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null());
+
+        if let Some(subject_id) = subject_id {
+            query = query.filter(class_subjects::Column::SubjectId.eq(subject_id));
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find subject of a foundation (not deleted)
+    pub async fn find_subject(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<subjects::Model>, AppError> {
+        subjects::Entity::find_by_id(id)
+            .filter(subjects::Column::FoundationId.eq(foundation_id))
+            .filter(subjects::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active students of a class
+    pub async fn find_students_by_class(
+        &self,
+        class_id: i64,
+    ) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::ClassId.eq(class_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find grades of the given class subjects
+    pub async fn find_by_class_subjects(
+        &self,
+        class_subject_ids: Vec<i64>,
+        only_open: bool,
+    ) -> Result<Vec<grades::Model>, AppError> {
+        let mut query =
+            Grade::find().filter(grades::Column::ClassSubjectId.is_in(class_subject_ids));
+
+        if only_open {
+            query = query.filter(
+                Condition::any()
+                    .add(grades::Column::IsFinalized.is_null())
+                    .add(grades::Column::IsFinalized.eq(0)),
+            );
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert new and update existing grade rows in a single transaction
+    pub async fn save_many(
+        &self,
+        models: Vec<grades::ActiveModel>,
+    ) -> Result<Vec<grades::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(models.len());
+        for model in models {
+            let row = if !model.id.is_not_set() {
+                model.update(&txn).await
+            } else {
+                model.insert(&txn).await
+            }
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Store recomputed final grade, letter and predicate
+    pub async fn update_computed(
+        &self,
+        rows: Vec<(i64, Decimal, Option<String>, Option<Predicate>)>,
+    ) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        for (id, final_grade, letter, predicate) in rows {
+            grades::ActiveModel {
+                id: Set(id),
+                final_grade: Set(final_grade),
+                grade_letter: Set(letter),
+                predicate: Set(predicate),
+                updated_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Lock grade rows
+    pub async fn finalize(&self, ids: Vec<i64>, user_id: i64) -> Result<u64, AppError> {
+        let now = chrono::Utc::now();
+        let result = Grade::update_many()
+            .col_expr(grades::Column::IsFinalized, Expr::value(1))
+            .col_expr(grades::Column::FinalizedBy, Expr::value(user_id))
+            .col_expr(grades::Column::FinalizedAt, Expr::value(now))
+            .col_expr(grades::Column::UpdatedAt, Expr::value(now))
+            .filter(grades::Column::Id.is_in(ids))
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Find configured weights of the given subjects
+    pub async fn find_weights(
+        &self,
+        foundation_id: i64,
+        subject_ids: Vec<i64>,
+    ) -> Result<Vec<grade_weights::Model>, AppError> {
+        grade_weights::Entity::find()
+            .filter(grade_weights::Column::FoundationId.eq(foundation_id))
+            .filter(grade_weights::Column::SubjectId.is_in(subject_ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Create or update weights of a subject
+    pub async fn upsert_weights(
+        &self,
+        foundation_id: i64,
+        subject_id: i64,
+        weights: Weights,
+    ) -> Result<grade_weights::Model, AppError> {
+        let now = chrono::Utc::now();
+        let existing = self
+            .find_weights(foundation_id, vec![subject_id])
+            .await?
+            .pop();
+
+        let mut active: grade_weights::ActiveModel = match existing {
+            Some(model) => model.into(),
+            None => grade_weights::ActiveModel {
+                foundation_id: Set(foundation_id),
+                subject_id: Set(subject_id),
+                created_at: Set(now),
+                ..Default::default()
+            },
+        };
+        active.attendance_weight = Set(weights.attendance);
+        active.assignment_weight = Set(weights.assignment);
+        active.quiz_weight = Set(weights.quiz);
+        active.midterm_weight = Set(weights.midterm);
+        active.final_weight = Set(weights.final_exam);
+        active.updated_at = Set(now);
+
+        if !active.id.is_not_set() {
+            active.update(self.conn()).await
+        } else {
+            active.insert(self.conn()).await
+        }
+        .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find grading scale of a foundation, highest band first
+    pub async fn find_scale(
+        &self,
+        foundation_id: i64,
+    ) -> Result<Vec<grading_scales::Model>, AppError> {
+        grading_scales::Entity::find()
+            .filter(grading_scales::Column::FoundationId.eq(foundation_id))
+            .order_by_desc(grading_scales::Column::MinScore)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Replace the grading scale of a foundation in a single transaction
+    pub async fn replace_scale(
+        &self,
+        foundation_id: i64,
+        bands: Vec<ScaleBand>,
+    ) -> Result<Vec<grading_scales::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        grading_scales::Entity::delete_many()
+            .filter(grading_scales::Column::FoundationId.eq(foundation_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let now = chrono::Utc::now();
+        let mut saved = Vec::with_capacity(bands.len());
+        for band in bands {
+            let row = grading_scales::ActiveModel {
+                foundation_id: Set(foundation_id),
+                min_score: Set(band.min_score),
+                grade_letter: Set(band.letter),
+                predicate: Set(band.predicate),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Find grade by ID
+    pub async fn find_grade(&self, id: i64) -> Result<Option<grades::Model>, AppError> {
+        Grade::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find grades by IDs
+    pub async fn find_grades(&self, ids: Vec<i64>) -> Result<Vec<grades::Model>, AppError> {
+        Grade::find()
+            .filter(grades::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher profile of a user account
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find the head of the unit a semester belongs to
+    pub async fn find_unit_head(
+        &self,
+        semester_id: i64,
+    ) -> Result<Option<employees::Model>, AppError> {
+        let semester = semesters::Entity::find_by_id(semester_id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let Some(semester) = semester else {
+            return Ok(None);
+        };
+
+        let unit = units::Entity::find_not_deleted()
+            .filter(units::Column::Id.eq(semester.unit_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let Some(head_employee_id) = unit.and_then(|u| u.head_employee_id) else {
+            return Ok(None);
+        };
+
+        employees::Entity::find_by_id(head_employee_id)
+            .filter(employees::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Whether the student's report card of the semester is already finalized
+    pub async fn is_report_card_finalized(
+        &self,
+        student_id: i64,
+        semester_id: i64,
+    ) -> Result<bool, AppError> {
+        let count = report_cards::Entity::find_not_deleted()
+            .filter(report_cards::Column::StudentId.eq(student_id))
+            .filter(report_cards::Column::SemesterId.eq(semester_id))
+            .filter(report_cards::Column::Status.eq(ReportCardStatus::Finalized))
+            .count(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(count > 0)
+    }
+
+    /// Create new grade change request
+    pub async fn create_change_request(
+        &self,
+        active_model: grade_change_requests::ActiveModel,
+    ) -> Result<grade_change_requests::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find change request of a foundation by ID
+    pub async fn find_change_request(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<grade_change_requests::Model>, AppError> {
+        grade_change_requests::Entity::find_by_id(id)
+            .filter(grade_change_requests::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find the pending change request of a grade, if any
+    pub async fn find_pending_change_request(
+        &self,
+        grade_id: i64,
+    ) -> Result<Option<grade_change_requests::Model>, AppError> {
+        grade_change_requests::Entity::find()
+            .filter(grade_change_requests::Column::GradeId.eq(grade_id))
+            .filter(grade_change_requests::Column::Status.eq(GradeChangeStatus::Pending))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find change requests with pagination and filters, newest first
+    pub async fn find_change_requests(
+        &self,
+        params: &PaginationParams,
+        filters: &GradeChangeFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<grade_change_requests::Model>, u64), AppError> {
+        let mut query = grade_change_requests::Entity::find()
+            .filter(grade_change_requests::Column::FoundationId.eq(foundation_id));
+
+        if let Some(ref status) = filters.status {
+            query = query.filter(grade_change_requests::Column::Status.eq(status.clone()));
+        }
+        if let Some(grade_id) = filters.grade_id {
+            query = query.filter(grade_change_requests::Column::GradeId.eq(grade_id));
+        }
+        if let Some(class_subject_id) = filters.class_subject_id {
+            query = query.filter(
+                grade_change_requests::Column::GradeId.in_subquery(
+                    Query::select()
+                        .column(grades::Column::Id)
+                        .from(grades::Entity)
+                        .and_where(grades::Column::ClassSubjectId.eq(class_subject_id))
+                        .to_owned(),
+                ),
+            );
+        }
+
+        let paginator = query
+            .order_by_desc(grade_change_requests::Column::CreatedAt)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Update change request (reject / cancel)
+    pub async fn update_change_request(
+        &self,
+        active_model: grade_change_requests::ActiveModel,
+    ) -> Result<grade_change_requests::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Tandai pengajuan disetujui, simpan nilai lama ke riwayat, lalu perbarui nilai.
+    /// Pengajuan hanya bisa diklaim selama masih `pending`, sehingga dua persetujuan
+    /// bersamaan tidak menerapkan perubahan dua kali.
+    pub async fn apply_change_request(
+        &self,
+        previous: &grades::Model,
+        grade: grades::ActiveModel,
+        mut review: grade_change_requests::ActiveModel,
+        changed_by: i64,
+    ) -> Result<(grades::Model, grade_change_requests::Model), AppError> {
+        let request_id = review
+            .id
+            .take()
+            .ok_or_else(|| AppError::internal("Change request id is required".to_string()))?;
+
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let claimed = grade_change_requests::Entity::update_many()
+            .set(review)
+            .filter(grade_change_requests::Column::Id.eq(request_id))
+            .filter(grade_change_requests::Column::Status.eq(GradeChangeStatus::Pending))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        if claimed.rows_affected == 0 {
+            return Err(AppError::conflict(
+                "Change request has already been reviewed".to_string(),
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        grade_histories::ActiveModel {
+            grade_id: Set(previous.id),
+            change_request_id: Set(Some(request_id)),
+            attendance_score: Set(previous.attendance_score),
+            assignment_score: Set(previous.assignment_score),
+            quiz_score: Set(previous.quiz_score),
+            midterm_score: Set(previous.midterm_score),
+            final_score: Set(previous.final_score),
+            final_grade: Set(previous.final_grade),
+            grade_letter: Set(previous.grade_letter.clone()),
+            predicate: Set(previous.predicate.clone()),
+            changed_by: Set(changed_by),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let grade = grade
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let request = grade_change_requests::Entity::find_by_id(request_id)
+            .one(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| AppError::not_found("Change request not found".to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((grade, request))
+    }
+
+    /// Find history of a grade, newest first
+    pub async fn find_histories(
+        &self,
+        grade_id: i64,
+    ) -> Result<Vec<grade_histories::Model>, AppError> {
+        grade_histories::Entity::find()
+            .filter(grade_histories::Column::GradeId.eq(grade_id))
+            .order_by_desc(grade_histories::Column::CreatedAt)
+            .order_by_desc(grade_histories::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use std::sync::Arc;
+
+    fn weights_row(id: i64, midterm: i64) -> grade_weights::Model {
+        let now = chrono::Utc::now();
+        grade_weights::Model {
+            id,
+            foundation_id: 1,
+            subject_id: 7,
+            attendance_weight: Decimal::from(10),
+            assignment_weight: Decimal::from(20),
+            quiz_weight: Decimal::from(10),
+            midterm_weight: Decimal::from(midterm),
+            final_weight: Decimal::from(60 - midterm),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert_weights_updates_existing_row() {
+        let first = weights_row(5, 30);
+        let second = weights_row(5, 20);
+        let connection = MockDatabase::new(DatabaseBackend::MySql)
+            .append_query_results([
+                // Simpan pertama: belum ada bobot, insert lalu baca ulang
+                vec![],
+                vec![first.clone()],
+                // Simpan kedua: baris yang sama ditemukan, update lalu baca ulang
+                vec![first],
+                vec![second.clone()],
+            ])
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 5,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+        let repository = GradeRepository::new(Database {
+            connection: Arc::new(connection),
+        });
+
+        let weights = |midterm: i64| Weights {
+            attendance: Decimal::from(10),
+            assignment: Decimal::from(20),
+            quiz: Decimal::from(10),
+            midterm: Decimal::from(midterm),
+            final_exam: Decimal::from(60 - midterm),
+        };
+        repository.upsert_weights(1, 7, weights(30)).await.unwrap();
+        let saved = repository.upsert_weights(1, 7, weights(20)).await.unwrap();
+        assert_eq!(saved, second);
+
+        let statements = repository
+            .conn()
+            .clone()
+            .into_transaction_log()
+            .into_iter()
+            .map(|log| log.statements()[0].sql.clone())
+            .collect::<Vec<_>>();
+        assert!(statements[1].starts_with("INSERT INTO `grade_weights`"));
+        assert!(statements[4].starts_with("UPDATE `grade_weights`"));
+        assert_eq!(
+            statements
+                .iter()
+                .filter(|sql| sql.starts_with("INSERT"))
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_change_request_rejects_already_reviewed() {
+        let now = chrono::Utc::now();
+        let previous = grades::Model {
+            id: 3,
+            student_id: 1,
+            class_subject_id: 2,
+            semester_id: 1,
+            attendance_score: None,
+            assignment_score: None,
+            quiz_score: None,
+            midterm_score: Some(Decimal::from(70)),
+            final_score: None,
+            final_grade: Decimal::from(21),
+            grade_letter: None,
+            predicate: None,
+            teacher_notes: None,
+            is_finalized: Some(0),
+            finalized_at: None,
+            finalized_by: None,
+            created_at: now,
+            updated_at: now,
+        };
+        // Persetujuan lain sudah lebih dulu mengubah status pengajuan
+        let connection = MockDatabase::new(DatabaseBackend::MySql)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+        let repository = GradeRepository::new(Database {
+            connection: Arc::new(connection),
+        });
+
+        let mut grade: grades::ActiveModel = previous.clone().into();
+        grade.midterm_score = Set(Some(Decimal::from(85)));
+        let review = grade_change_requests::ActiveModel {
+            id: Set(11),
+            status: Set(GradeChangeStatus::Approved),
+            reviewed_by: Set(Some(9)),
+            ..Default::default()
+        };
+
+        let result = repository
+            .apply_change_request(&previous, grade, review, 9)
+            .await;
+        assert!(matches!(result, Err(AppError::ConflictError(_))));
+
+        let statements = repository
+            .conn()
+            .clone()
+            .into_transaction_log()
+            .into_iter()
+            .flat_map(|log| log.statements().to_vec())
+            .map(|statement| statement.sql)
+            .collect::<Vec<_>>();
+        assert!(statements
+            .iter()
+            .any(|sql| sql.starts_with("UPDATE `grade_change_requests`")
+                && sql.contains("`status` = ?")));
+        assert!(!statements
+            .iter()
+            .any(|sql| sql.contains("grade_histories") || sql.starts_with("UPDATE `grades`")));
+    }
+}
@@ -0,0 +1,58 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use super::service::MANAGE_PERMISSION;
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::grades::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/grades")
+            .wrap(JwtMiddleware)
+            .route("/weights", web::get().to(handler::get_weights))
+            .route(
+                "/weights",
+                web::put()
+                    .to(handler::set_weights)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route("/scale", web::get().to(handler::get_scale))
+            .route(
+                "/scale",
+                web::put()
+                    .to(handler::set_scale)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route("/class_subjects/{id}", web::get().to(handler::gradebook))
+            .route("/class_subjects/{id}", web::put().to(handler::save_bulk))
+            .route(
+                "/class_subjects/{id}/finalize",
+                web::post().to(handler::finalize),
+            )
+            .route(
+                "/change_requests",
+                web::get().to(handler::get_change_requests),
+            )
+            .route("/change_requests", web::post().to(handler::request_change))
+            .route(
+                "/change_requests/{id}",
+                web::get().to(handler::get_change_request),
+            )
+            .route(
+                "/change_requests/{id}/approve",
+                web::post().to(handler::approve_change),
+            )
+            .route(
+                "/change_requests/{id}/reject",
+                web::post().to(handler::reject_change),
+            )
+            .route(
+                "/change_requests/{id}/cancel",
+                web::post().to(handler::cancel_change),
+            )
+            .route("/{id}/history", web::get().to(handler::grade_history)),
+    );
+}
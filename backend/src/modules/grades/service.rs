@@ -0,0 +1,886 @@
+// ============================================================================
+// backend/src/modules/grades/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::calculator::{self, ComponentScores, ScaleBand, Weights};
+use super::changes::{self, ReviewAction};
+use super::dto::{
+    BulkGradeRequest, CreateGradeChangeRequest, FinalizeGradesRequest, FinalizeGradesResponse,
+    GradeChangeFilters, GradeChangeResponse, GradeHistoryResponse, GradeResponse,
+    GradeWeightsResponse, GradebookResponse, GradebookRow, GradingScaleBand, GradingScaleResponse,
+    ReviewGradeChangeRequest, SetGradeWeightsRequest, SetGradingScaleRequest,
+};
+use super::repository::GradeRepository;
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use entity::sea_orm_active_enums::GradeChangeStatus;
+use entity::{class_subjects, grade_change_requests, grades};
+use sea_orm::prelude::Decimal;
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Permission untuk mengatur bobot komponen & skala nilai tingkat yayasan
+pub const MANAGE_PERMISSION: &str = "grades.manage";
+
+#[derive(Clone)]
+pub struct GradeService {
+    repository: GradeRepository,
+}
+
+impl GradeService {
+    pub fn new(repository: GradeRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Gradebook of one class subject: every student of the class with their grade
+    pub async fn gradebook(
+        &self,
+        class_subject_id: i64,
+        foundation_id: i64,
+    ) -> Result<GradebookResponse, AppError> {
+        let class_subject = self
+            .find_class_subject(class_subject_id, foundation_id)
+            .await?;
+        let weights = self
+            .weights_for(foundation_id, vec![class_subject.subject_id])
+            .await?;
+        let weights = weights
+            .get(&class_subject.subject_id)
+            .copied()
+            .unwrap_or_default();
+        let is_default = !self
+            .has_weights(foundation_id, class_subject.subject_id)
+            .await?;
+
+        let mut grades: HashMap<i64, grades::Model> = self
+            .repository
+            .find_by_class_subjects(vec![class_subject.id], false)
+            .await?
+            .into_iter()
+            .map(|g| (g.student_id, g))
+            .collect();
+
+        let rows = self
+            .repository
+            .find_students_by_class(class_subject.class_id)
+            .await?
+            .into_iter()
+            .map(|student| GradebookRow {
+                grade: grades.remove(&student.id).map(GradeResponse::from),
+                student_id: student.id,
+                student_name: student.name,
+                student_number: student.student_number,
+            })
+            .collect();
+
+        Ok(GradebookResponse {
+            class_subject_id: class_subject.id,
+            class_id: class_subject.class_id,
+            subject_id: class_subject.subject_id,
+            semester_id: class_subject.semester_id,
+            weights: weights_response(class_subject.subject_id, &weights, is_default),
+            rows,
+        })
+    }
+
+    /// Bulk entry of component scores; final grade, letter and predicate are computed
+    pub async fn save_bulk(
+        &self,
+        class_subject_id: i64,
+        request: BulkGradeRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<GradeResponse>, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let class_subject = self
+            .find_class_subject(class_subject_id, foundation_id)
+            .await?;
+        self.ensure_subject_teacher(&class_subject, user_id).await?;
+
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = request.entries.iter().find(|e| !seen.insert(e.student_id)) {
+            return Err(AppError::validation(format!(
+                "Student {} is listed more than once",
+                duplicate.student_id
+            )));
+        }
+
+        // Business rule: siswa harus terdaftar di kelas tersebut
+        let class_students: HashSet<i64> = self
+            .repository
+            .find_students_by_class(class_subject.class_id)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        if let Some(entry) = request
+            .entries
+            .iter()
+            .find(|e| !class_students.contains(&e.student_id))
+        {
+            return Err(AppError::validation(format!(
+                "Student {} is not in class {}",
+                entry.student_id, class_subject.class_id
+            )));
+        }
+
+        let mut existing: HashMap<i64, grades::Model> = self
+            .repository
+            .find_by_class_subjects(vec![class_subject.id], false)
+            .await?
+            .into_iter()
+            .map(|g| (g.student_id, g))
+            .collect();
+
+        // Business rule: nilai yang sudah difinalisasi terkunci
+        if let Some(locked) = request
+            .entries
+            .iter()
+            .filter_map(|e| existing.get(&e.student_id))
+            .find(|g| g.is_finalized.unwrap_or(0) != 0)
+        {
+            return Err(AppError::ConflictError(format!(
+                "Grade of student {} is finalized and can no longer be changed",
+                locked.student_id
+            )));
+        }
+
+        let weights = self
+            .weights_for(foundation_id, vec![class_subject.subject_id])
+            .await?
+            .get(&class_subject.subject_id)
+            .copied()
+            .unwrap_or_default();
+        let scale = self.scale_for(foundation_id).await?;
+
+        let now = chrono::Utc::now();
+        let models = request
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let current = existing.remove(&entry.student_id);
+                // Komponen yang tidak dikirim tetap memakai nilai sebelumnya
+                let keep = |value: Option<f64>, pick: fn(&grades::Model) -> Option<Decimal>| {
+                    value
+                        .map(to_decimal)
+                        .or_else(|| current.as_ref().and_then(pick))
+                };
+                let scores = ComponentScores {
+                    attendance: keep(entry.attendance_score, |g| g.attendance_score),
+                    assignment: keep(entry.assignment_score, |g| g.assignment_score),
+                    quiz: keep(entry.quiz_score, |g| g.quiz_score),
+                    midterm: keep(entry.midterm_score, |g| g.midterm_score),
+                    final_exam: keep(entry.final_score, |g| g.final_score),
+                };
+
+                let mut active: grades::ActiveModel = match current {
+                    Some(model) => model.into(),
+                    None => grades::ActiveModel {
+                        student_id: Set(entry.student_id),
+                        class_subject_id: Set(class_subject.id),
+                        semester_id: Set(class_subject.semester_id),
+                        is_finalized: Set(Some(0)),
+                        created_at: Set(now),
+                        ..Default::default()
+                    },
+                };
+
+                let final_grade = calculator::final_grade(&scores, &weights);
+                let classified = calculator::classify(final_grade, &scale);
+
+                active.attendance_score = Set(scores.attendance);
+                active.assignment_score = Set(scores.assignment);
+                active.quiz_score = Set(scores.quiz);
+                active.midterm_score = Set(scores.midterm);
+                active.final_score = Set(scores.final_exam);
+                active.final_grade = Set(final_grade);
+                active.grade_letter = Set(classified.as_ref().map(|(letter, _)| letter.clone()));
+                active.predicate = Set(classified.map(|(_, predicate)| predicate));
+                if let Some(notes) = entry.teacher_notes {
+                    active.teacher_notes = Set(Some(notes));
+                }
+                active.updated_at = Set(now);
+                active
+            })
+            .collect();
+
+        let saved = self.repository.save_many(models).await?;
+        Ok(saved.into_iter().map(GradeResponse::from).collect())
+    }
+
+    /// Lock grades of a class subject
+    pub async fn finalize(
+        &self,
+        class_subject_id: i64,
+        request: FinalizeGradesRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<FinalizeGradesResponse, AppError> {
+        let class_subject = self
+            .find_class_subject(class_subject_id, foundation_id)
+            .await?;
+        self.ensure_subject_teacher(&class_subject, user_id).await?;
+        let only: Option<HashSet<i64>> = request.student_ids.map(|ids| ids.into_iter().collect());
+
+        let ids: Vec<i64> = self
+            .repository
+            .find_by_class_subjects(vec![class_subject.id], true)
+            .await?
+            .into_iter()
+            .filter(|g| only.as_ref().is_none_or(|set| set.contains(&g.student_id)))
+            .map(|g| g.id)
+            .collect();
+        if ids.is_empty() {
+            return Err(AppError::validation(
+                "There are no open grades to finalize".to_string(),
+            ));
+        }
+
+        let finalized = self.repository.finalize(ids, user_id).await?;
+        Ok(FinalizeGradesResponse {
+            class_subject_id: class_subject.id,
+            finalized: finalized as usize,
+        })
+    }
+
+    /// Get component weights of a subject
+    pub async fn get_weights(
+        &self,
+        subject_id: i64,
+        foundation_id: i64,
+    ) -> Result<GradeWeightsResponse, AppError> {
+        self.find_subject(subject_id, foundation_id).await?;
+
+        let weights = self
+            .weights_for(foundation_id, vec![subject_id])
+            .await?
+            .get(&subject_id)
+            .copied();
+
+        Ok(weights_response(
+            subject_id,
+            &weights.unwrap_or_default(),
+            weights.is_none(),
+        ))
+    }
+
+    /// Set component weights of a subject and recompute its open grades
+    pub async fn set_weights(
+        &self,
+        request: SetGradeWeightsRequest,
+        foundation_id: i64,
+    ) -> Result<GradeWeightsResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        self.find_subject(request.subject_id, foundation_id).await?;
+
+        let weights = Weights {
+            attendance: to_decimal(request.attendance_weight),
+            assignment: to_decimal(request.assignment_weight),
+            quiz: to_decimal(request.quiz_weight),
+            midterm: to_decimal(request.midterm_weight),
+            final_exam: to_decimal(request.final_weight),
+        };
+        if weights.total() != Decimal::from(100) {
+            return Err(AppError::validation(format!(
+                "Weights must add up to 100 (got {})",
+                weights.total()
+            )));
+        }
+
+        self.repository
+            .upsert_weights(foundation_id, request.subject_id, weights)
+            .await?;
+        self.recalculate(foundation_id, Some(request.subject_id))
+            .await?;
+
+        Ok(weights_response(request.subject_id, &weights, false))
+    }
+
+    /// Get grading scale of the foundation
+    pub async fn get_scale(&self, foundation_id: i64) -> Result<GradingScaleResponse, AppError> {
+        let configured = self.repository.find_scale(foundation_id).await?;
+        let is_default = configured.is_empty();
+
+        Ok(GradingScaleResponse {
+            bands: self
+                .scale_for(foundation_id)
+                .await?
+                .into_iter()
+                .map(|band| GradingScaleBand {
+                    min_score: band.min_score.try_into().unwrap_or_default(),
+                    grade_letter: band.letter,
+                    predicate: band.predicate,
+                })
+                .collect(),
+            is_default,
+        })
+    }
+
+    /// Replace grading scale of the foundation and recompute open grades
+    pub async fn set_scale(
+        &self,
+        request: SetGradingScaleRequest,
+        foundation_id: i64,
+    ) -> Result<GradingScaleResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let bands: Vec<ScaleBand> = request
+            .bands
+            .into_iter()
+            .map(|band| ScaleBand {
+                min_score: to_decimal(band.min_score),
+                letter: band.grade_letter.trim().to_string(),
+                predicate: band.predicate,
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = bands.iter().find(|b| !seen.insert(b.min_score)) {
+            return Err(AppError::validation(format!(
+                "More than one band starts at {}",
+                duplicate.min_score
+            )));
+        }
+        // Business rule: setiap nilai 0-100 harus mendapat huruf
+        if !bands.iter().any(|b| b.min_score.is_zero()) {
+            return Err(AppError::validation(
+                "The lowest band must start at 0".to_string(),
+            ));
+        }
+
+        self.repository.replace_scale(foundation_id, bands).await?;
+        self.recalculate(foundation_id, None).await?;
+
+        self.get_scale(foundation_id).await
+    }
+
+    /// Guru pengampu mengusulkan perubahan nilai yang sudah difinalisasi
+    pub async fn request_change(
+        &self,
+        request: CreateGradeChangeRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<GradeChangeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (grade, class_subject) = self.find_grade(request.grade_id, foundation_id).await?;
+        if grade.is_finalized.unwrap_or(0) == 0 {
+            return Err(AppError::validation(
+                "Grade is not finalized yet; edit it through the gradebook".to_string(),
+            ));
+        }
+
+        // Business rule: hanya guru pengampu mapel yang boleh mengajukan
+        self.ensure_subject_teacher(&class_subject, user_id).await?;
+
+        let proposed = ComponentScores {
+            attendance: request.attendance_score.map(to_decimal),
+            assignment: request.assignment_score.map(to_decimal),
+            quiz: request.quiz_score.map(to_decimal),
+            midterm: request.midterm_score.map(to_decimal),
+            final_exam: request.final_score.map(to_decimal),
+        };
+        if !changes::changes_anything(&component_scores(&grade), &proposed) {
+            return Err(AppError::validation(
+                "The request does not change any score".to_string(),
+            ));
+        }
+
+        if self
+            .repository
+            .find_pending_change_request(grade.id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ConflictError(
+                "This grade already has a pending change request".to_string(),
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        let created = self
+            .repository
+            .create_change_request(grade_change_requests::ActiveModel {
+                foundation_id: Set(foundation_id),
+                grade_id: Set(grade.id),
+                attendance_score: Set(proposed.attendance),
+                assignment_score: Set(proposed.assignment),
+                quiz_score: Set(proposed.quiz),
+                midterm_score: Set(proposed.midterm),
+                final_score: Set(proposed.final_exam),
+                reason: Set(request.reason.trim().to_string()),
+                status: Set(GradeChangeStatus::Pending),
+                requested_by: Set(user_id),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(GradeChangeResponse::new(created, Some(grade)))
+    }
+
+    /// Get grade change requests with pagination
+    pub async fn get_change_requests(
+        &self,
+        params: PaginationParams,
+        filters: GradeChangeFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<GradeChangeResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_change_requests(&params, &filters, foundation_id)
+            .await?;
+
+        let grades: HashMap<i64, grades::Model> = self
+            .repository
+            .find_grades(items.iter().map(|r| r.grade_id).collect())
+            .await?
+            .into_iter()
+            .map(|g| (g.id, g))
+            .collect();
+        let responses = items
+            .into_iter()
+            .map(|item| {
+                let grade = grades.get(&item.grade_id).cloned();
+                GradeChangeResponse::new(item, grade)
+            })
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Get grade change request by ID
+    pub async fn get_change_request(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<GradeChangeResponse, AppError> {
+        let change = self.find_change_request(id, foundation_id).await?;
+        let grade = self.repository.find_grade(change.grade_id).await?;
+        Ok(GradeChangeResponse::new(change, grade))
+    }
+
+    /// Kepala unit menyetujui: nilai lama disimpan ke riwayat lalu nilai diperbarui
+    pub async fn approve_change(
+        &self,
+        id: i64,
+        request: ReviewGradeChangeRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<GradeChangeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let change = self.find_change_request(id, foundation_id).await?;
+        let status = changes::next_status(&change.status, ReviewAction::Approve)
+            .map_err(AppError::ConflictError)?;
+        let (grade, class_subject) = self.find_grade(change.grade_id, foundation_id).await?;
+        self.ensure_unit_head(&grade, &change, user_id).await?;
+
+        // Business rule: rapor final harus dibuka ulang dulu agar rata-rata & peringkat ikut terhitung
+        if self
+            .repository
+            .is_report_card_finalized(grade.student_id, grade.semester_id)
+            .await?
+        {
+            return Err(AppError::ConflictError(
+                "Report card of this student is finalized; reopen it before changing grades"
+                    .to_string(),
+            ));
+        }
+
+        let proposed = ComponentScores {
+            attendance: change.attendance_score,
+            assignment: change.assignment_score,
+            quiz: change.quiz_score,
+            midterm: change.midterm_score,
+            final_exam: change.final_score,
+        };
+        let scores = changes::apply(&component_scores(&grade), &proposed);
+        let weights = self
+            .weights_for(foundation_id, vec![class_subject.subject_id])
+            .await?
+            .get(&class_subject.subject_id)
+            .copied()
+            .unwrap_or_default();
+        let scale = self.scale_for(foundation_id).await?;
+        let final_grade = calculator::final_grade(&scores, &weights);
+        let classified = calculator::classify(final_grade, &scale);
+
+        let now = chrono::Utc::now();
+        let mut updated: grades::ActiveModel = grade.clone().into();
+        updated.attendance_score = Set(scores.attendance);
+        updated.assignment_score = Set(scores.assignment);
+        updated.quiz_score = Set(scores.quiz);
+        updated.midterm_score = Set(scores.midterm);
+        updated.final_score = Set(scores.final_exam);
+        updated.final_grade = Set(final_grade);
+        updated.grade_letter = Set(classified.as_ref().map(|(letter, _)| letter.clone()));
+        updated.predicate = Set(classified.map(|(_, predicate)| predicate));
+        updated.updated_at = Set(now);
+
+        let reviewed = grade_change_requests::ActiveModel {
+            id: Set(change.id),
+            status: Set(status),
+            reviewed_by: Set(Some(user_id)),
+            reviewed_at: Set(Some(now)),
+            review_notes: Set(request.review_notes),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let (grade, change) = self
+            .repository
+            .apply_change_request(&grade, updated, reviewed, user_id)
+            .await?;
+
+        Ok(GradeChangeResponse::new(change, Some(grade)))
+    }
+
+    /// Kepala unit menolak pengajuan; alasan penolakan wajib diisi
+    pub async fn reject_change(
+        &self,
+        id: i64,
+        request: ReviewGradeChangeRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<GradeChangeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+        let notes = request
+            .review_notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .ok_or_else(|| {
+                AppError::validation("review_notes is required when rejecting".to_string())
+            })?;
+
+        let change = self.find_change_request(id, foundation_id).await?;
+        let status = changes::next_status(&change.status, ReviewAction::Reject)
+            .map_err(AppError::ConflictError)?;
+        let (grade, _) = self.find_grade(change.grade_id, foundation_id).await?;
+        self.ensure_unit_head(&grade, &change, user_id).await?;
+
+        let now = chrono::Utc::now();
+        let updated = self
+            .repository
+            .update_change_request(grade_change_requests::ActiveModel {
+                id: Set(change.id),
+                status: Set(status),
+                reviewed_by: Set(Some(user_id)),
+                reviewed_at: Set(Some(now)),
+                review_notes: Set(Some(notes)),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(GradeChangeResponse::new(updated, Some(grade)))
+    }
+
+    /// Pengaju membatalkan pengajuan yang belum diproses
+    pub async fn cancel_change(
+        &self,
+        id: i64,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<GradeChangeResponse, AppError> {
+        let change = self.find_change_request(id, foundation_id).await?;
+        if change.requested_by != user_id {
+            return Err(AppError::forbidden(
+                "Only the requester can cancel a grade change request",
+            ));
+        }
+        let status = changes::next_status(&change.status, ReviewAction::Cancel)
+            .map_err(AppError::ConflictError)?;
+
+        let updated = self
+            .repository
+            .update_change_request(grade_change_requests::ActiveModel {
+                id: Set(change.id),
+                status: Set(status),
+                updated_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            })
+            .await?;
+        let grade = self.repository.find_grade(updated.grade_id).await?;
+
+        Ok(GradeChangeResponse::new(updated, grade))
+    }
+
+    /// Riwayat nilai sebelum setiap perubahan yang disetujui
+    pub async fn grade_history(
+        &self,
+        grade_id: i64,
+        foundation_id: i64,
+    ) -> Result<Vec<GradeHistoryResponse>, AppError> {
+        self.find_grade(grade_id, foundation_id).await?;
+
+        Ok(self
+            .repository
+            .find_histories(grade_id)
+            .await?
+            .into_iter()
+            .map(GradeHistoryResponse::from)
+            .collect())
+    }
+
+    /// Hitung ulang nilai akhir yang belum difinalisasi setelah bobot/skala berubah
+    async fn recalculate(
+        &self,
+        foundation_id: i64,
+        subject_id: Option<i64>,
+    ) -> Result<(), AppError> {
+        let class_subjects: HashMap<i64, i64> = self
+            .repository
+            .find_class_subjects(foundation_id, subject_id)
+            .await?
+            .into_iter()
+            .map(|cs| (cs.id, cs.subject_id))
+            .collect();
+        if class_subjects.is_empty() {
+            return Ok(());
+        }
+
+        let open = self
+            .repository
+            .find_by_class_subjects(class_subjects.keys().copied().collect(), true)
+            .await?;
+        let subject_ids: Vec<i64> = class_subjects
+            .values()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let weights = self.weights_for(foundation_id, subject_ids).await?;
+        let scale = self.scale_for(foundation_id).await?;
+
+        let changed: Vec<_> = open
+            .into_iter()
+            .filter_map(|grade| {
+                let weights = class_subjects
+                    .get(&grade.class_subject_id)
+                    .and_then(|subject_id| weights.get(subject_id))
+                    .copied()
+                    .unwrap_or_default();
+                let scores = ComponentScores {
+                    attendance: grade.attendance_score,
+                    assignment: grade.assignment_score,
+                    quiz: grade.quiz_score,
+                    midterm: grade.midterm_score,
+                    final_exam: grade.final_score,
+                };
+                let final_grade = calculator::final_grade(&scores, &weights);
+                let (letter, predicate) = match calculator::classify(final_grade, &scale) {
+                    Some((letter, predicate)) => (Some(letter), Some(predicate)),
+                    None => (None, None),
+                };
+
+                let unchanged = grade.final_grade == final_grade
+                    && grade.grade_letter == letter
+                    && grade.predicate == predicate;
+                (!unchanged).then_some((grade.id, final_grade, letter, predicate))
+            })
+            .collect();
+
+        if changed.is_empty() {
+            return Ok(());
+        }
+        self.repository.update_computed(changed).await
+    }
+
+    async fn find_class_subject(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<class_subjects::Model, AppError> {
+        self.repository
+            .find_class_subject(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class subject not found".to_string()))
+    }
+
+    /// Find grade together with its class subject (scoped to foundation)
+    async fn find_grade(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<(grades::Model, class_subjects::Model), AppError> {
+        let grade = self
+            .repository
+            .find_grade(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Grade not found".to_string()))?;
+        let class_subject = self
+            .repository
+            .find_class_subject(grade.class_subject_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Grade not found".to_string()))?;
+        Ok((grade, class_subject))
+    }
+
+    async fn find_change_request(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<grade_change_requests::Model, AppError> {
+        self.repository
+            .find_change_request(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Grade change request not found".to_string()))
+    }
+
+    /// Hanya kepala unit (dari semester nilai) yang memutuskan, dan bukan pengajunya sendiri
+    /// Nilai hanya boleh diisi, difinalisasi & diajukan perubahannya oleh guru pengampu mapel
+    async fn ensure_subject_teacher(
+        &self,
+        class_subject: &class_subjects::Model,
+        user_id: i64,
+    ) -> Result<(), AppError> {
+        let teacher_id = self
+            .repository
+            .find_teacher_by_user(user_id, class_subject.foundation_id)
+            .await?
+            .map(|teacher| teacher.id);
+        if teacher_id.is_none() || teacher_id != class_subject.teacher_id {
+            return Err(AppError::forbidden(
+                "Only the teacher of this class subject can manage its grades",
+            ));
+        }
+        Ok(())
+    }
+
+    async fn ensure_unit_head(
+        &self,
+        grade: &grades::Model,
+        change: &grade_change_requests::Model,
+        user_id: i64,
+    ) -> Result<(), AppError> {
+        let head = self.repository.find_unit_head(grade.semester_id).await?;
+        if head.is_none_or(|employee| employee.user_id != user_id) {
+            return Err(AppError::forbidden(
+                "Only the head of unit can review grade change requests",
+            ));
+        }
+        if change.requested_by == user_id {
+            return Err(AppError::forbidden(
+                "You cannot review your own grade change request",
+            ));
+        }
+        Ok(())
+    }
+
+    async fn find_subject(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        self.repository
+            .find_subject(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Subject not found".to_string()))?;
+        Ok(())
+    }
+
+    async fn has_weights(&self, foundation_id: i64, subject_id: i64) -> Result<bool, AppError> {
+        Ok(!self
+            .repository
+            .find_weights(foundation_id, vec![subject_id])
+            .await?
+            .is_empty())
+    }
+
+    async fn weights_for(
+        &self,
+        foundation_id: i64,
+        subject_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, Weights>, AppError> {
+        Ok(self
+            .repository
+            .find_weights(foundation_id, subject_ids)
+            .await?
+            .into_iter()
+            .map(|w| {
+                (
+                    w.subject_id,
+                    Weights {
+                        attendance: w.attendance_weight,
+                        assignment: w.assignment_weight,
+                        quiz: w.quiz_weight,
+                        midterm: w.midterm_weight,
+                        final_exam: w.final_weight,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    async fn scale_for(&self, foundation_id: i64) -> Result<Vec<ScaleBand>, AppError> {
+        let configured = self.repository.find_scale(foundation_id).await?;
+        if configured.is_empty() {
+            return Ok(calculator::default_scale());
+        }
+
+        Ok(configured
+            .into_iter()
+            .map(|band| ScaleBand {
+                min_score: band.min_score,
+                letter: band.grade_letter,
+                predicate: band.predicate,
+            })
+            .collect())
+    }
+}
+
+fn component_scores(grade: &grades::Model) -> ComponentScores {
+    ComponentScores {
+        attendance: grade.attendance_score,
+        assignment: grade.assignment_score,
+        quiz: grade.quiz_score,
+        midterm: grade.midterm_score,
+        final_exam: grade.final_score,
+    }
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::try_from(value).unwrap_or_default().round_dp(2)
+}
+
+fn weights_response(subject_id: i64, weights: &Weights, is_default: bool) -> GradeWeightsResponse {
+    let to_f64 = |value: Decimal| -> f64 { value.try_into().unwrap_or_default() };
+    GradeWeightsResponse {
+        subject_id,
+        attendance_weight: to_f64(weights.attendance),
+        assignment_weight: to_f64(weights.assignment),
+        quiz_weight: to_f64(weights.quiz),
+        midterm_weight: to_f64(weights.midterm),
+        final_weight: to_f64(weights.final_exam),
+        is_default,
+    }
+}
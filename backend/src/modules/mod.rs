@@ -2,20 +2,35 @@ pub mod academic_years;
 pub mod applicants;
 pub mod attendances;
 pub mod auth;
+pub mod calendars;
+pub mod character_traits;
 pub mod class_levels;
 pub mod classes;
 pub mod departments;
 pub mod employees;
+pub mod extracurriculars;
 pub mod foundations;
+pub mod grades;
+pub mod payrolls;
 pub mod permissions;
 pub mod positions;
+pub mod report_cards;
 pub mod roles;
+pub mod room_bookings;
 pub mod rooms;
+pub mod salary_grades;
+pub mod schedule_generation_logs;
+pub mod schedules;
 pub mod semesters;
 pub mod settings;
+pub mod staff_attendances;
 pub mod students;
+pub mod subject_preferences;
 pub mod subjects;
+pub mod substitutions;
+pub mod teacher_availability;
 pub mod teachers;
+pub mod time_slots;
 pub mod unit_types;
 pub mod units;
 pub mod user_profiles;
@@ -0,0 +1,166 @@
+// ============================================================================
+// backend/src/modules/payrolls/bank_export.rs
+// bank_export.rs - File transfer massal gaji (pure, tanpa akses database)
+// ============================================================================
+use chrono::NaiveDate;
+use sea_orm::prelude::Decimal;
+
+/// Panjang setiap record format fixed-width
+pub const RECORD_WIDTH: usize = 100;
+
+/// Satu baris transfer gaji bersih ke rekening penerima
+#[derive(Debug, Clone)]
+pub struct TransferLine {
+    pub bank_code: String,
+    pub account_number: String,
+    pub account_name: String,
+    pub amount: Decimal,
+    pub reference: String,
+}
+
+pub fn total(lines: &[TransferLine]) -> Decimal {
+    lines.iter().map(|l| l.amount).sum()
+}
+
+/// CSV dengan header; field yang mengandung koma/kutip/baris baru diapit kutip
+pub fn to_csv(lines: &[TransferLine]) -> String {
+    let mut out = String::from("no,bank_code,account_number,account_name,amount,reference\r\n");
+    for (index, line) in lines.iter().enumerate() {
+        let fields = [
+            (index + 1).to_string(),
+            line.bank_code.clone(),
+            line.account_number.clone(),
+            line.account_name.clone(),
+            format!("{:.2}", line.amount.round_dp(2)),
+            line.reference.clone(),
+        ];
+        out.push_str(
+            &fields
+                .iter()
+                .map(|f| csv_field(f))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// Format bulk transfer fixed-width, setiap record RECORD_WIDTH karakter:
+///
+/// - header  `0` rekening sumber(20) tanggal YYYYMMDD(8) jumlah record(6) total sen(18)
+/// - detail  `1` kode bank(10) no. rekening(20) nama(35) nominal sen(15) referensi(19)
+/// - trailer `9` jumlah record(6) total sen(18)
+///
+/// Teks alfanumerik rata kiri diisi spasi (huruf besar, ASCII), angka rata kanan diisi nol.
+pub fn to_fixed_width(
+    lines: &[TransferLine],
+    source_account: &str,
+    transfer_date: NaiveDate,
+) -> String {
+    let count = lines.len().to_string();
+    let total = cents(total(lines));
+
+    let mut records = vec![format!(
+        "0{}{}{}{}",
+        alpha(source_account, 20),
+        transfer_date.format("%Y%m%d"),
+        numeric(&count, 6),
+        numeric(&total, 18)
+    )];
+    for line in lines {
+        records.push(format!(
+            "1{}{}{}{}{}",
+            alpha(&line.bank_code, 10),
+            alpha(&line.account_number, 20),
+            alpha(&line.account_name, 35),
+            numeric(&cents(line.amount), 15),
+            alpha(&line.reference, 19)
+        ));
+    }
+    records.push(format!("9{}{}", numeric(&count, 6), numeric(&total, 18)));
+
+    records
+        .into_iter()
+        .map(|record| format!("{:<width$}\r\n", record, width = RECORD_WIDTH))
+        .collect()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Nominal dalam sen tanpa pemisah
+fn cents(amount: Decimal) -> String {
+    (amount.round_dp(2) * Decimal::from(100))
+        .trunc()
+        .to_string()
+}
+
+fn alpha(value: &str, width: usize) -> String {
+    let cleaned: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() || c == ' ' {
+                c.to_ascii_uppercase()
+            } else {
+                ' '
+            }
+        })
+        .take(width)
+        .collect();
+    format!("{:<width$}", cleaned, width = width)
+}
+
+fn numeric(value: &str, width: usize) -> String {
+    let digits = &value[value.len().saturating_sub(width)..];
+    format!("{:0>width$}", digits, width = width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_and_fixed_width_cover_all_net_salaries() {
+        let lines = vec![
+            TransferLine {
+                bank_code: "002".to_string(),
+                account_number: "1234567890".to_string(),
+                account_name: "Siti Aminah, S.Pd".to_string(),
+                amount: Decimal::new(405_000_050, 2),
+                reference: "GAJI 202610".to_string(),
+            },
+            TransferLine {
+                bank_code: "008".to_string(),
+                account_number: "9876543210".to_string(),
+                account_name: "Budi Santoso".to_string(),
+                amount: Decimal::from(3_500_000),
+                reference: "GAJI 202610".to_string(),
+            },
+        ];
+
+        let csv = to_csv(&lines);
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.contains("1,002,1234567890,\"Siti Aminah, S.Pd\",4050000.50,GAJI 202610"));
+        assert!(csv.contains("2,008,9876543210,Budi Santoso,3500000.00,GAJI 202610"));
+
+        let file = to_fixed_width(
+            &lines,
+            "0011223344",
+            NaiveDate::from_ymd_opt(2026, 10, 28).unwrap(),
+        );
+        let records: Vec<&str> = file.split_terminator("\r\n").collect();
+        assert_eq!(records.len(), 4);
+        assert!(records.iter().all(|r| r.len() == RECORD_WIDTH));
+        assert!(records[0].starts_with("00011223344          20261028000002000000000755000050"));
+        assert_eq!(&records[1][..31], "1002       1234567890          ");
+        assert_eq!(&records[1][31..66], format!("{:<35}", "SITI AMINAH, S.PD"));
+        assert_eq!(&records[1][66..81], "000000405000050");
+        assert!(records[3].starts_with("9000002000000000755000050"));
+    }
+}
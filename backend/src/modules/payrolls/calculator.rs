@@ -0,0 +1,200 @@
+// ============================================================================
+// backend/src/modules/payrolls/calculator.rs
+// calculator.rs - Perhitungan gaji & status payroll (pure, tanpa akses database)
+// ============================================================================
+use crate::modules::staff_attendances::presence::MonthlyCounts;
+use entity::sea_orm_active_enums::PayrollStatus;
+use entity::teacher_salaries;
+use sea_orm::prelude::Decimal;
+
+/// Aturan kehadiran yang berlaku untuk satu run
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AttendanceRules {
+    pub late_penalty_per_day: Decimal,
+    /// `None` = proporsional: gaji pokok / hari kerja
+    pub absent_penalty_per_day: Option<Decimal>,
+    pub perfect_attendance_bonus: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Payslip {
+    pub base_salary: Decimal,
+    pub total_allowances: Decimal,
+    pub total_deductions: Decimal,
+    pub bonus: Decimal,
+    pub penalty: Decimal,
+    pub gross_salary: Decimal,
+    pub net_salary: Decimal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayrollAction {
+    Approve,
+    Pay,
+}
+
+pub fn total_allowances(salary: &teacher_salaries::Model) -> Decimal {
+    [
+        salary.position_allowance,
+        salary.teaching_allowance,
+        salary.certification_allowance,
+        salary.performance_allowance,
+        salary.transport_allowance,
+        salary.meal_allowance,
+        salary.other_allowances,
+    ]
+    .iter()
+    .flatten()
+    .sum()
+}
+
+pub fn total_deductions(salary: &teacher_salaries::Model) -> Decimal {
+    [
+        salary.tax_deduction,
+        salary.insurance_deduction,
+        salary.loan_deduction,
+        salary.other_deductions,
+    ]
+    .iter()
+    .flatten()
+    .sum()
+}
+
+/// Gaji kotor = pokok + tunjangan + bonus; bersih = kotor - potongan - denda (minimal 0)
+pub fn compute(
+    salary: &teacher_salaries::Model,
+    counts: &MonthlyCounts,
+    rules: &AttendanceRules,
+) -> Payslip {
+    let base_salary = salary.base_salary;
+    let total_allowances = total_allowances(salary);
+    let total_deductions = total_deductions(salary);
+
+    let absent_rate = rules.absent_penalty_per_day.unwrap_or_else(|| {
+        if counts.working_days > 0 {
+            (base_salary / Decimal::from(counts.working_days)).round_dp(2)
+        } else {
+            Decimal::ZERO
+        }
+    });
+    let penalty = rules.late_penalty_per_day * Decimal::from(counts.late_days)
+        + absent_rate * Decimal::from(counts.absent_days);
+
+    let perfect = counts.present_days > 0 && counts.late_days == 0 && counts.absent_days == 0;
+    let bonus = if perfect {
+        rules.perfect_attendance_bonus
+    } else {
+        Decimal::ZERO
+    };
+
+    let gross_salary = base_salary + total_allowances + bonus;
+    let net_salary = (gross_salary - total_deductions - penalty).max(Decimal::ZERO);
+
+    Payslip {
+        base_salary,
+        total_allowances,
+        total_deductions,
+        bonus,
+        penalty,
+        gross_salary,
+        net_salary,
+    }
+}
+
+/// draft -> approved -> paid
+pub fn next_status(
+    current: &PayrollStatus,
+    action: PayrollAction,
+) -> Result<PayrollStatus, String> {
+    match (current, action) {
+        (PayrollStatus::Draft, PayrollAction::Approve) => Ok(PayrollStatus::Approved),
+        (PayrollStatus::Approved, PayrollAction::Pay) => Ok(PayrollStatus::Paid),
+        (PayrollStatus::Draft, PayrollAction::Pay) => {
+            Err("Payroll must be approved before it is paid".to_string())
+        }
+        (status, _) => Err(format!(
+            "Payroll is already {}",
+            sea_orm::ActiveEnum::to_value(status)
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn salary() -> teacher_salaries::Model {
+        teacher_salaries::Model {
+            id: 1,
+            teacher_assignment_id: 1,
+            salary_grade_id: 1,
+            base_salary: Decimal::from(4_400_000),
+            position_allowance: Some(Decimal::from(500_000)),
+            teaching_allowance: None,
+            certification_allowance: None,
+            performance_allowance: None,
+            transport_allowance: Some(Decimal::from(300_000)),
+            meal_allowance: None,
+            other_allowances: None,
+            tax_deduction: Some(Decimal::from(200_000)),
+            insurance_deduction: Some(Decimal::from(100_000)),
+            loan_deduction: None,
+            other_deductions: None,
+            gross_salary: None,
+            net_salary: None,
+            effective_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            notes: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_with_attendance_penalties_and_bonus() {
+        let rules = AttendanceRules {
+            late_penalty_per_day: Decimal::from(25_000),
+            absent_penalty_per_day: None,
+            perfect_attendance_bonus: Decimal::from(250_000),
+        };
+
+        let counts = MonthlyCounts {
+            working_days: 22,
+            present_days: 20,
+            late_days: 2,
+            absent_days: 2,
+            ..Default::default()
+        };
+        let slip = compute(&salary(), &counts, &rules);
+        assert_eq!(slip.total_allowances, Decimal::from(800_000));
+        assert_eq!(slip.total_deductions, Decimal::from(300_000));
+        assert_eq!(slip.bonus, Decimal::ZERO);
+        // 2 x 25.000 + 2 x (4.400.000 / 22)
+        assert_eq!(slip.penalty, Decimal::from(450_000));
+        assert_eq!(slip.gross_salary, Decimal::from(5_200_000));
+        assert_eq!(slip.net_salary, Decimal::from(4_450_000));
+
+        let perfect = MonthlyCounts {
+            working_days: 22,
+            present_days: 22,
+            ..Default::default()
+        };
+        let slip = compute(&salary(), &perfect, &rules);
+        assert_eq!(slip.bonus, Decimal::from(250_000));
+        assert_eq!(slip.net_salary, Decimal::from(5_150_000));
+    }
+
+    #[test]
+    fn test_status_flow() {
+        assert_eq!(
+            next_status(&PayrollStatus::Draft, PayrollAction::Approve),
+            Ok(PayrollStatus::Approved)
+        );
+        assert_eq!(
+            next_status(&PayrollStatus::Approved, PayrollAction::Pay),
+            Ok(PayrollStatus::Paid)
+        );
+        assert!(next_status(&PayrollStatus::Draft, PayrollAction::Pay).is_err());
+        assert!(next_status(&PayrollStatus::Paid, PayrollAction::Approve).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    BankExportFormat, BankExportQuery, CreatePayrollRunRequest, PayPayrollRunRequest,
+    PayrollRunDetailResponse, PayrollRunFilters, PayrollRunResponse, SalaryPaymentResponse,
+    SkippedAssignment,
+};
+use super::handler;
+use crate::utils::pagination::PaginatedResponse;
+use entity::sea_orm_active_enums::PayrollStatus;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create,
+        handler::get_all,
+        handler::get_by_id,
+        handler::recalculate,
+        handler::approve,
+        handler::pay,
+        handler::delete,
+        handler::my_payments,
+        handler::payslip,
+        handler::run_payslips,
+        handler::bank_export,
+    ),
+    components(
+        schemas(
+            PayrollStatus,
+            PayrollRunResponse,
+            SalaryPaymentResponse,
+            SkippedAssignment,
+            PayrollRunDetailResponse,
+            CreatePayrollRunRequest,
+            PayPayrollRunRequest,
+            PayrollRunFilters,
+            BankExportFormat,
+            BankExportQuery,
+            PaginatedResponse<PayrollRunResponse>,
+        )
+    ),
+    tags(
+        (name = "Payroll ", description = "Monthly payroll runs: draft, approval, payment, payslips and bank transfer files")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct PayrollsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
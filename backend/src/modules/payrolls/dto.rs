@@ -0,0 +1,202 @@
+// backend/src/modules/payrolls/dto.rs
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::PayrollStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PayrollRunResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub period_month: i32,
+    pub period_year: i32,
+    pub status: PayrollStatus,
+    pub late_penalty_per_day: Option<f64>,
+    /// NULL = potongan proporsional gaji pokok / hari kerja
+    pub absent_penalty_per_day: Option<f64>,
+    pub perfect_attendance_bonus: Option<f64>,
+    pub total_gross: Option<f64>,
+    pub total_net: Option<f64>,
+    pub created_by: i64,
+    pub approved_by: Option<i64>,
+    pub approved_at: Option<String>,
+    pub paid_by: Option<i64>,
+    pub paid_at: Option<String>,
+    pub payment_date: Option<NaiveDate>,
+    pub payment_method: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SalaryPaymentResponse {
+    pub id: i64,
+    pub payroll_run_id: Option<i64>,
+    pub teacher_salary_id: i64,
+    pub teacher_id: Option<i64>,
+    pub teacher_name: Option<String>,
+    pub payment_period_month: i32,
+    pub payment_period_year: i32,
+    pub base_salary: f64,
+    pub total_allowances: Option<f64>,
+    pub total_deductions: Option<f64>,
+    pub bonus: Option<f64>,
+    pub penalty: Option<f64>,
+    pub gross_salary: f64,
+    pub net_salary: f64,
+    pub working_days: Option<i32>,
+    pub present_days: Option<i32>,
+    pub absent_days: Option<i32>,
+    pub late_days: Option<i32>,
+    pub payment_status: Option<String>,
+    pub payment_date: Option<NaiveDate>,
+    pub payment_method: Option<String>,
+    pub approved_by: Option<i64>,
+    pub approved_at: Option<String>,
+    pub paid_by: Option<i64>,
+    pub paid_at: Option<String>,
+}
+
+/// Penugasan aktif yang tidak ikut dihitung
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SkippedAssignment {
+    pub teacher_assignment_id: i64,
+    pub teacher_id: i64,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PayrollRunDetailResponse {
+    pub run: PayrollRunResponse,
+    pub payments: Vec<SalaryPaymentResponse>,
+    /// Hanya diisi saat run dibuat atau dihitung ulang
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedAssignment>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreatePayrollRunRequest {
+    #[validate(range(min = 2000, max = 2100))]
+    pub year: i32,
+    #[validate(range(min = 1, max = 12))]
+    pub month: u32,
+    /// Potongan per hari terlambat
+    #[validate(range(min = 0.0))]
+    pub late_penalty_per_day: Option<f64>,
+    /// Potongan per hari alpa; kosong = gaji pokok / hari kerja
+    #[validate(range(min = 0.0))]
+    pub absent_penalty_per_day: Option<f64>,
+    /// Bonus bila hadir penuh tanpa terlambat
+    #[validate(range(min = 0.0))]
+    pub perfect_attendance_bonus: Option<f64>,
+    #[validate(length(max = 1000))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PayPayrollRunRequest {
+    pub payment_date: NaiveDate,
+    #[validate(length(min = 1, max = 50))]
+    pub payment_method: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PayrollRunFilters {
+    pub year: Option<i32>,
+    pub status: Option<PayrollStatus>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankExportFormat {
+    #[default]
+    Csv,
+    /// Bulk transfer fixed-width, 100 karakter per record
+    FixedWidth,
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams, ToSchema)]
+pub struct BankExportQuery {
+    #[serde(default)]
+    pub format: BankExportFormat,
+    /// Rekening sumber (debet); wajib untuk fixed_width
+    #[validate(length(min = 1, max = 20))]
+    pub source_account: Option<String>,
+    /// Default: tanggal bayar run, atau hari ini
+    pub transfer_date: Option<NaiveDate>,
+}
+
+/// File unduhan (slip gaji PDF atau file transfer bank)
+#[derive(Debug)]
+pub struct PayrollFile {
+    pub filename: String,
+    pub content_type: &'static str,
+    pub content: Vec<u8>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::payroll_runs::Model> for PayrollRunResponse {
+    fn from(model: entity::payroll_runs::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            period_month: model.period_month,
+            period_year: model.period_year,
+            status: model.status,
+            late_penalty_per_day: model.late_penalty_per_day.and_then(|v| v.try_into().ok()),
+            absent_penalty_per_day: model.absent_penalty_per_day.and_then(|v| v.try_into().ok()),
+            perfect_attendance_bonus: model
+                .perfect_attendance_bonus
+                .and_then(|v| v.try_into().ok()),
+            total_gross: model.total_gross.and_then(|v| v.try_into().ok()),
+            total_net: model.total_net.and_then(|v| v.try_into().ok()),
+            created_by: model.created_by,
+            approved_by: model.approved_by,
+            approved_at: model.approved_at.map(|t| t.to_string()),
+            paid_by: model.paid_by,
+            paid_at: model.paid_at.map(|t| t.to_string()),
+            payment_date: model.payment_date,
+            payment_method: model.payment_method,
+            notes: model.notes,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+
+impl SalaryPaymentResponse {
+    pub fn new(
+        model: entity::salary_payments::Model,
+        teacher: Option<&entity::teachers::Model>,
+    ) -> Self {
+        Self {
+            id: model.id,
+            payroll_run_id: model.payroll_run_id,
+            teacher_salary_id: model.teacher_salary_id,
+            teacher_id: teacher.map(|t| t.id),
+            teacher_name: teacher.map(|t| t.name.clone()),
+            payment_period_month: model.payment_period_month,
+            payment_period_year: model.payment_period_year,
+            base_salary: model.base_salary.try_into().unwrap_or_default(),
+            total_allowances: model.total_allowances.and_then(|v| v.try_into().ok()),
+            total_deductions: model.total_deductions.and_then(|v| v.try_into().ok()),
+            bonus: model.bonus.and_then(|v| v.try_into().ok()),
+            penalty: model.penalty.and_then(|v| v.try_into().ok()),
+            gross_salary: model.gross_salary.try_into().unwrap_or_default(),
+            net_salary: model.net_salary.try_into().unwrap_or_default(),
+            working_days: model.working_days,
+            present_days: model.present_days,
+            absent_days: model.absent_days,
+            late_days: model.late_days,
+            payment_status: model.payment_status,
+            payment_date: model.payment_date,
+            payment_method: model.payment_method,
+            approved_by: model.approved_by,
+            approved_at: model.approved_at.map(|t| t.to_string()),
+            paid_by: model.paid_by,
+            paid_at: model.paid_at.map(|t| t.to_string()),
+        }
+    }
+}
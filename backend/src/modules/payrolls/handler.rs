@@ -0,0 +1,332 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BankExportQuery, CreatePayrollRunRequest, PayPayrollRunRequest, PayrollFile,
+    PayrollRunDetailResponse, PayrollRunFilters, PayrollRunResponse, SalaryPaymentResponse,
+};
+use super::service::{Actor, FINANCE_PERMISSION};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{web, HttpResponse};
+
+/// Create a draft payroll run for a month
+#[utoipa::path(
+    post,
+    path = "/api/payrolls",
+    request_body = CreatePayrollRunRequest,
+    responses(
+        (status = 201, description = "Draft payroll created with one payment per active assignment", body = PayrollRunDetailResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Payroll for the period already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreatePayrollRunRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .create(request.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get all payroll runs with pagination
+#[utoipa::path(
+    get,
+    path = "/api/payrolls",
+    params(PaginationParams, PayrollRunFilters),
+    responses(
+        (status = 200, description = "List of payroll runs", body = PaginatedResponse<PayrollRunResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    params: web::Query<PaginationParams>,
+    filters: web::Query<PayrollRunFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .get_all(
+            params.into_inner(),
+            filters.into_inner(),
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get payroll run with its payments
+#[utoipa::path(
+    get,
+    path = "/api/payrolls/{id}",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    responses(
+        (status = 200, description = "Payroll run found", body = PayrollRunDetailResponse),
+        (status = 404, description = "Payroll run not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .get_by_id(path.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Recalculate a draft payroll run
+#[utoipa::path(
+    post,
+    path = "/api/payrolls/{id}/recalculate",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    responses(
+        (status = 200, description = "Payments recalculated", body = PayrollRunDetailResponse),
+        (status = 409, description = "Payroll is not a draft"),
+        (status = 404, description = "Payroll run not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn recalculate(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .recalculate(path.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Approve a draft payroll run
+#[utoipa::path(
+    post,
+    path = "/api/payrolls/{id}/approve",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    responses(
+        (status = 200, description = "Payroll approved", body = PayrollRunResponse),
+        (status = 409, description = "Payroll is not a draft"),
+        (status = 404, description = "Payroll run not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn approve(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .approve(path.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Mark an approved payroll run as paid
+#[utoipa::path(
+    post,
+    path = "/api/payrolls/{id}/pay",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    request_body = PayPayrollRunRequest,
+    responses(
+        (status = 200, description = "Payroll paid", body = PayrollRunResponse),
+        (status = 409, description = "Payroll is not approved"),
+        (status = 404, description = "Payroll run not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn pay(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+    request: web::Json<PayPayrollRunRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .pay(
+            path.into_inner(),
+            request.into_inner(),
+            auth.user_id,
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete a draft payroll run
+#[utoipa::path(
+    delete,
+    path = "/api/payrolls/{id}",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    responses(
+        (status = 204, description = "Payroll deleted"),
+        (status = 409, description = "Payroll is not a draft"),
+        (status = 404, description = "Payroll run not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .payroll_service
+        .delete(path.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Payslip PDF of one payment (the employee themself or finance)
+#[utoipa::path(
+    get,
+    path = "/api/payrolls/payments/{payment_id}/payslip",
+    params(
+        ("payment_id" = i64, Path, description = "Salary payment ID")
+    ),
+    responses(
+        (status = 200, description = "Payslip PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 403, description = "Not your payslip and no finance permission"),
+        (status = 404, description = "Payment not found"),
+        (status = 409, description = "Payroll is not paid")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn payslip(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .payslip(path.into_inner(), actor(&auth))
+        .await?;
+    Ok(file_response(result))
+}
+
+/// Paid salary payments of the logged-in teacher
+#[utoipa::path(
+    get,
+    path = "/api/payrolls/payments/mine",
+    responses(
+        (status = 200, description = "Own paid salary payments", body = Vec<SalaryPaymentResponse>),
+        (status = 404, description = "Not a teacher of the foundation")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn my_payments(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state.payroll_service.my_payments(actor(&auth)).await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// All payslips of a paid payroll run as one PDF (finance)
+#[utoipa::path(
+    get,
+    path = "/api/payrolls/{id}/payslips",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID")
+    ),
+    responses(
+        (status = 200, description = "Merged payslip PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 403, description = "Finance permission required"),
+        (status = 404, description = "Payroll run not found"),
+        (status = 409, description = "Payroll is not paid")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn run_payslips(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .run_payslips(path.into_inner(), actor(&auth))
+        .await?;
+    Ok(file_response(result))
+}
+
+/// Bank transfer batch file of all net salaries (finance)
+#[utoipa::path(
+    get,
+    path = "/api/payrolls/{id}/bank_export",
+    params(
+        ("id" = i64, Path, description = "Payroll run ID"),
+        BankExportQuery
+    ),
+    responses(
+        (status = 200, description = "CSV or fixed-width bulk transfer file", content_type = "text/plain", body = String),
+        (status = 400, description = "Bank account incomplete or source account missing"),
+        (status = 403, description = "Finance permission required"),
+        (status = 404, description = "Payroll run not found"),
+        (status = 409, description = "Payroll is not approved")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Payroll "
+)]
+pub async fn bank_export(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+    query: web::Query<BankExportQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .payroll_service
+        .bank_export(path.into_inner(), query.into_inner(), actor(&auth))
+        .await?;
+    Ok(file_response(result))
+}
+
+fn actor(auth: &AuthContext) -> Actor {
+    Actor {
+        user_id: auth.user_id,
+        foundation_id: auth.foundation_id,
+        can_manage: auth.has_permission(FINANCE_PERMISSION),
+    }
+}
+
+fn file_response(file: PayrollFile) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(file.content_type)
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file.filename)],
+        })
+        .body(file.content)
+}
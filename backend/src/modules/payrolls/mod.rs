@@ -0,0 +1,24 @@
+// backend/src/modules/payrolls/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod bank_export;
+pub mod calculator;
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod payslip;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::PayrollRepository;
+pub use service::PayrollService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> PayrollService {
+    let repository = PayrollRepository::new(db);
+    PayrollService::new(repository)
+}
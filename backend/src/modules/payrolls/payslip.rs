@@ -0,0 +1,325 @@
+// ============================================================================
+// backend/src/modules/payrolls/payslip.rs
+// payslip.rs - Tata letak slip gaji ke PDF (pure, tanpa akses database)
+// ============================================================================
+use crate::utils::pdf::{Font, PdfDocument, PdfPage, A4_HEIGHT, A4_WIDTH};
+use chrono::NaiveDate;
+use entity::teacher_salaries;
+use sea_orm::prelude::Decimal;
+
+const MARGIN: f32 = 50.0;
+const LINE: f32 = 15.0;
+const BODY: f32 = 10.0;
+
+#[derive(Debug, Clone)]
+pub struct PayslipLine {
+    pub label: String,
+    pub amount: Decimal,
+}
+
+/// Semua data yang dicetak pada satu slip gaji
+#[derive(Debug, Clone)]
+pub struct PayslipSheet {
+    pub foundation_name: String,
+    pub employee_name: String,
+    pub employee_number: String,
+    pub position: String,
+    pub period: String,
+    pub base_salary: Decimal,
+    pub allowances: Vec<PayslipLine>,
+    pub bonus: Decimal,
+    pub deductions: Vec<PayslipLine>,
+    pub penalty: Decimal,
+    pub gross_salary: Decimal,
+    pub net_salary: Decimal,
+    pub working_days: i32,
+    pub present_days: i32,
+    pub late_days: i32,
+    pub absent_days: i32,
+    pub payment_date: Option<NaiveDate>,
+    pub payment_method: Option<String>,
+    pub bank_account: Option<String>,
+}
+
+/// Rincian tunjangan yang nilainya tidak nol
+pub fn allowance_lines(salary: &teacher_salaries::Model) -> Vec<PayslipLine> {
+    lines(&[
+        ("Tunjangan Jabatan", salary.position_allowance),
+        ("Tunjangan Mengajar", salary.teaching_allowance),
+        ("Tunjangan Sertifikasi", salary.certification_allowance),
+        ("Tunjangan Kinerja", salary.performance_allowance),
+        ("Tunjangan Transport", salary.transport_allowance),
+        ("Tunjangan Makan", salary.meal_allowance),
+        ("Tunjangan Lainnya", salary.other_allowances),
+    ])
+}
+
+/// Rincian potongan yang nilainya tidak nol
+pub fn deduction_lines(salary: &teacher_salaries::Model) -> Vec<PayslipLine> {
+    lines(&[
+        ("Pajak (PPh 21)", salary.tax_deduction),
+        ("BPJS / Asuransi", salary.insurance_deduction),
+        ("Angsuran Pinjaman", salary.loan_deduction),
+        ("Potongan Lainnya", salary.other_deductions),
+    ])
+}
+
+fn lines(items: &[(&str, Option<Decimal>)]) -> Vec<PayslipLine> {
+    items
+        .iter()
+        .filter_map(|(label, amount)| {
+            amount.filter(|a| !a.is_zero()).map(|amount| PayslipLine {
+                label: label.to_string(),
+                amount,
+            })
+        })
+        .collect()
+}
+
+pub fn month_name(month: i32) -> &'static str {
+    const NAMES: [&str; 12] = [
+        "Januari",
+        "Februari",
+        "Maret",
+        "April",
+        "Mei",
+        "Juni",
+        "Juli",
+        "Agustus",
+        "September",
+        "Oktober",
+        "November",
+        "Desember",
+    ];
+    usize::try_from(month - 1)
+        .ok()
+        .and_then(|i| NAMES.get(i))
+        .copied()
+        .unwrap_or("-")
+}
+
+/// Format rupiah: 4400000.5 -> "Rp 4.400.000,50"
+pub fn rupiah(amount: Decimal) -> String {
+    let rounded = amount.round_dp(2).abs();
+    let text = format!("{:.2}", rounded);
+    let (whole, cents) = text.split_once('.').unwrap_or((&text, "00"));
+
+    let mut grouped = String::new();
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index) % 3 == 0 {
+            grouped.push('.');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if amount.is_sign_negative() && !rounded.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    format!("{}Rp {},{}", sign, grouped, cents)
+}
+
+/// Render satu slip gaji menjadi dokumen PDF satu halaman
+pub fn render(sheet: &PayslipSheet) -> PdfDocument {
+    let mut page = PdfPage::new();
+    let right = A4_WIDTH - MARGIN;
+    let center = A4_WIDTH / 2.0;
+    let mut y = A4_HEIGHT - MARGIN;
+
+    page.text_centered(center, y, 14.0, Font::Bold, "SLIP GAJI");
+    y -= LINE + 2.0;
+    page.text_centered(center, y, 12.0, Font::Bold, &sheet.foundation_name);
+    y -= LINE;
+    page.text_centered(
+        center,
+        y,
+        BODY,
+        Font::Regular,
+        &format!("Periode {}", sheet.period),
+    );
+    y -= LINE * 2.0;
+
+    let identity = [
+        ("Nama", sheet.employee_name.as_str()),
+        ("NIP", sheet.employee_number.as_str()),
+        ("Jabatan", sheet.position.as_str()),
+    ];
+    for (label, value) in identity {
+        page.text(MARGIN, y, BODY, Font::Regular, label);
+        page.text(
+            MARGIN + 85.0,
+            y,
+            BODY,
+            Font::Regular,
+            &format!(": {}", value),
+        );
+        y -= LINE;
+    }
+
+    let section = |page: &mut PdfPage, y: &mut f32, title: &str| {
+        *y -= LINE * 0.5;
+        page.text(MARGIN, *y, 11.0, Font::Bold, title);
+        *y -= 4.0;
+        page.line(MARGIN, *y, right, *y, 0.75);
+        *y -= LINE;
+    };
+    let amount_row = |page: &mut PdfPage, y: &mut f32, label: &str, amount: Decimal, font: Font| {
+        page.text(MARGIN + 10.0, *y, BODY, font, label);
+        page.text_right(right, *y, BODY, font, &rupiah(amount));
+        *y -= LINE;
+    };
+
+    // A. Penerimaan
+    section(&mut page, &mut y, "A. Penerimaan");
+    amount_row(
+        &mut page,
+        &mut y,
+        "Gaji Pokok",
+        sheet.base_salary,
+        Font::Regular,
+    );
+    for line in &sheet.allowances {
+        amount_row(&mut page, &mut y, &line.label, line.amount, Font::Regular);
+    }
+    if !sheet.bonus.is_zero() {
+        amount_row(
+            &mut page,
+            &mut y,
+            "Bonus Kehadiran",
+            sheet.bonus,
+            Font::Regular,
+        );
+    }
+    amount_row(
+        &mut page,
+        &mut y,
+        "Total Penerimaan",
+        sheet.gross_salary,
+        Font::Bold,
+    );
+
+    // B. Potongan
+    section(&mut page, &mut y, "B. Potongan");
+    for line in &sheet.deductions {
+        amount_row(&mut page, &mut y, &line.label, line.amount, Font::Regular);
+    }
+    if !sheet.penalty.is_zero() {
+        amount_row(
+            &mut page,
+            &mut y,
+            "Potongan Kehadiran",
+            sheet.penalty,
+            Font::Regular,
+        );
+    }
+    let total_deductions =
+        sheet.deductions.iter().map(|l| l.amount).sum::<Decimal>() + sheet.penalty;
+    amount_row(
+        &mut page,
+        &mut y,
+        "Total Potongan",
+        total_deductions,
+        Font::Bold,
+    );
+
+    // C. Kehadiran
+    section(&mut page, &mut y, "C. Kehadiran");
+    page.text(
+        MARGIN + 10.0,
+        y,
+        BODY,
+        Font::Regular,
+        &format!(
+            "Hari kerja {}   Hadir {}   Terlambat {}   Alpa {}",
+            sheet.working_days, sheet.present_days, sheet.late_days, sheet.absent_days
+        ),
+    );
+    y -= LINE * 1.5;
+
+    page.line(MARGIN, y + LINE - 4.0, right, y + LINE - 4.0, 0.75);
+    page.text(MARGIN, y, 12.0, Font::Bold, "GAJI BERSIH");
+    page.text_right(right, y, 12.0, Font::Bold, &rupiah(sheet.net_salary));
+    y -= LINE * 2.0;
+
+    let payment = [
+        (
+            "Tanggal Bayar",
+            sheet.payment_date.map(|d| d.format("%d-%m-%Y").to_string()),
+        ),
+        ("Metode", sheet.payment_method.clone()),
+        ("Rekening", sheet.bank_account.clone()),
+    ];
+    for (label, value) in payment {
+        page.text(MARGIN, y, BODY, Font::Regular, label);
+        page.text(
+            MARGIN + 85.0,
+            y,
+            BODY,
+            Font::Regular,
+            &format!(": {}", value.as_deref().unwrap_or("-")),
+        );
+        y -= LINE;
+    }
+
+    let mut doc = PdfDocument::new().with_title(&format!(
+        "Slip Gaji {} {}",
+        sheet.employee_name, sheet.period
+    ));
+    doc.add_page(page);
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rupiah_and_payslip_lists_every_component() {
+        assert_eq!(rupiah(Decimal::new(440_000_050, 2)), "Rp 4.400.000,50");
+        assert_eq!(rupiah(Decimal::from(950)), "Rp 950,00");
+        assert_eq!(rupiah(Decimal::from(-1_000)), "-Rp 1.000,00");
+
+        let sheet = PayslipSheet {
+            foundation_name: "Yayasan Contoh".to_string(),
+            employee_name: "Siti".to_string(),
+            employee_number: "G-001".to_string(),
+            position: "Guru Kelas".to_string(),
+            period: format!("{} 2026", month_name(10)),
+            base_salary: Decimal::from(4_000_000),
+            allowances: vec![PayslipLine {
+                label: "Tunjangan Transport".to_string(),
+                amount: Decimal::from(300_000),
+            }],
+            bonus: Decimal::ZERO,
+            deductions: vec![PayslipLine {
+                label: "Pajak (PPh 21)".to_string(),
+                amount: Decimal::from(200_000),
+            }],
+            penalty: Decimal::from(50_000),
+            gross_salary: Decimal::from(4_300_000),
+            net_salary: Decimal::from(4_050_000),
+            working_days: 22,
+            present_days: 22,
+            late_days: 2,
+            absent_days: 0,
+            payment_date: NaiveDate::from_ymd_opt(2026, 10, 28),
+            payment_method: Some("transfer".to_string()),
+            bank_account: None,
+        };
+
+        let bytes = render(&sheet).to_bytes();
+        let text = String::from_utf8_lossy(&bytes);
+        assert_eq!(text.matches("/Type /Page /Parent").count(), 1);
+        for expected in [
+            "Periode Oktober 2026",
+            "Tunjangan Transport",
+            "Potongan Kehadiran",
+            "(Rp 250.000,00) Tj",
+            "(Rp 4.050.000,00) Tj",
+        ] {
+            assert!(text.contains(expected), "missing {}", expected);
+        }
+        assert!(!text.contains("Bonus Kehadiran"));
+    }
+}
@@ -0,0 +1,408 @@
+// ============================================================================
+// backend/src/modules/payrolls/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::PayrollRunFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::payroll_runs::{self, Entity as PayrollRun};
+use entity::{
+    foundations, salary_payments, staff_attendances, teacher_assignments, teacher_salaries,
+    teachers, units,
+};
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct PayrollRepository {
+    db: Database,
+}
+
+impl PayrollRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find payroll run of a foundation
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<payroll_runs::Model>, AppError> {
+        PayrollRun::find_by_id(id)
+            .filter(payroll_runs::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find payroll run of a foundation for a period
+    pub async fn find_by_period(
+        &self,
+        foundation_id: i64,
+        year: i32,
+        month: i32,
+    ) -> Result<Option<payroll_runs::Model>, AppError> {
+        PayrollRun::find()
+            .filter(payroll_runs::Column::FoundationId.eq(foundation_id))
+            .filter(payroll_runs::Column::PeriodYear.eq(year))
+            .filter(payroll_runs::Column::PeriodMonth.eq(month))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &PayrollRunFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<payroll_runs::Model>, u64), AppError> {
+        let mut query =
+            PayrollRun::find().filter(payroll_runs::Column::FoundationId.eq(foundation_id));
+
+        if let Some(year) = filters.year {
+            query = query.filter(payroll_runs::Column::PeriodYear.eq(year));
+        }
+        if let Some(ref status) = filters.status {
+            query = query.filter(payroll_runs::Column::Status.eq(status.clone()));
+        }
+
+        let paginator = query
+            .order_by_desc(payroll_runs::Column::PeriodYear)
+            .order_by_desc(payroll_runs::Column::PeriodMonth)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Save a run and replace its payments in one transaction
+    pub async fn save_with_payments(
+        &self,
+        run: payroll_runs::ActiveModel,
+        payments: Vec<salary_payments::ActiveModel>,
+    ) -> Result<(payroll_runs::Model, Vec<salary_payments::Model>), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let run = if !run.id.is_not_set() {
+            run.update(&txn).await
+        } else {
+            run.insert(&txn).await
+        }
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        salary_payments::Entity::delete_many()
+            .filter(salary_payments::Column::PayrollRunId.eq(run.id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(payments.len());
+        for mut payment in payments {
+            payment.payroll_run_id = sea_orm::Set(Some(run.id));
+            let row = payment
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((run, saved))
+    }
+
+    /// Update run status and copy it (with approval/payment info) to its payments
+    pub async fn update_status(
+        &self,
+        run: payroll_runs::ActiveModel,
+    ) -> Result<payroll_runs::Model, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let run = run
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        salary_payments::Entity::update_many()
+            .col_expr(
+                salary_payments::Column::PaymentStatus,
+                Expr::value(sea_orm::ActiveEnum::to_value(&run.status)),
+            )
+            .col_expr(
+                salary_payments::Column::ApprovedBy,
+                Expr::value(run.approved_by),
+            )
+            .col_expr(
+                salary_payments::Column::ApprovedAt,
+                Expr::value(run.approved_at),
+            )
+            .col_expr(salary_payments::Column::PaidBy, Expr::value(run.paid_by))
+            .col_expr(salary_payments::Column::PaidAt, Expr::value(run.paid_at))
+            .col_expr(
+                salary_payments::Column::PaymentDate,
+                Expr::value(run.payment_date),
+            )
+            .col_expr(
+                salary_payments::Column::PaymentMethod,
+                Expr::value(run.payment_method.clone()),
+            )
+            .col_expr(
+                salary_payments::Column::UpdatedAt,
+                Expr::value(run.updated_at),
+            )
+            .filter(salary_payments::Column::PayrollRunId.eq(run.id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(run)
+    }
+
+    /// Delete a run together with its payments
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        salary_payments::Entity::delete_many()
+            .filter(salary_payments::Column::PayrollRunId.eq(id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        PayrollRun::delete_by_id(id)
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find payments of a run
+    pub async fn find_payments(
+        &self,
+        payroll_run_id: i64,
+    ) -> Result<Vec<salary_payments::Model>, AppError> {
+        salary_payments::Entity::find()
+            .filter(salary_payments::Column::PayrollRunId.eq(payroll_run_id))
+            .order_by_asc(salary_payments::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find assignments of the foundation's teachers active at some point in the period
+    pub async fn find_active_assignments(
+        &self,
+        foundation_id: i64,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Vec<teacher_assignments::Model>, AppError> {
+        teacher_assignments::Entity::find()
+            .filter(teacher_assignments::Column::IsActive.eq(1))
+            .filter(teacher_assignments::Column::AssignmentStartDate.lte(last))
+            .filter(
+                Condition::any()
+                    .add(teacher_assignments::Column::AssignmentEndDate.is_null())
+                    .add(teacher_assignments::Column::AssignmentEndDate.gte(first)),
+            )
+            .filter(
+                teacher_assignments::Column::TeacherId.in_subquery(
+                    Query::select()
+                        .column(teachers::Column::Id)
+                        .from(teachers::Entity)
+                        .and_where(teachers::Column::FoundationId.eq(foundation_id))
+                        .and_where(teachers::Column::DeletedAt.is_null())
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(teacher_assignments::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find assignments by ids
+    pub async fn find_assignments_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teacher_assignments::Model>, AppError> {
+        teacher_assignments::Entity::find()
+            .filter(teacher_assignments::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salaries of assignments effective on or before a date, latest first
+    pub async fn find_salaries_effective(
+        &self,
+        assignment_ids: Vec<i64>,
+        on_or_before: NaiveDate,
+    ) -> Result<Vec<teacher_salaries::Model>, AppError> {
+        teacher_salaries::Entity::find()
+            .filter(teacher_salaries::Column::TeacherAssignmentId.is_in(assignment_ids))
+            .filter(teacher_salaries::Column::EffectiveDate.lte(on_or_before))
+            .order_by_desc(teacher_salaries::Column::EffectiveDate)
+            .order_by_desc(teacher_salaries::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salaries by ids
+    pub async fn find_salaries_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teacher_salaries::Model>, AppError> {
+        teacher_salaries::Entity::find()
+            .filter(teacher_salaries::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers by ids
+    pub async fn find_teachers_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find units of a foundation (not deleted)
+    pub async fn find_units(&self, foundation_id: i64) -> Result<Vec<units::Model>, AppError> {
+        units::Entity::find()
+            .filter(units::Column::FoundationId.eq(foundation_id))
+            .filter(units::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find staff attendance of users between two dates
+    pub async fn find_staff_attendances(
+        &self,
+        foundation_id: i64,
+        user_ids: Vec<i64>,
+        date_from: NaiveDate,
+        date_to: NaiveDate,
+    ) -> Result<Vec<staff_attendances::Model>, AppError> {
+        staff_attendances::Entity::find()
+            .filter(staff_attendances::Column::FoundationId.eq(foundation_id))
+            .filter(staff_attendances::Column::UserId.is_in(user_ids))
+            .filter(staff_attendances::Column::Date.between(date_from, date_to))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salary payment by id
+    pub async fn find_payment(&self, id: i64) -> Result<Option<salary_payments::Model>, AppError> {
+        salary_payments::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find payments of a teacher (through salary -> assignment) with a status, latest first
+    pub async fn find_payments_by_teacher(
+        &self,
+        teacher_id: i64,
+        payment_status: &str,
+    ) -> Result<Vec<salary_payments::Model>, AppError> {
+        salary_payments::Entity::find()
+            .filter(salary_payments::Column::PaymentStatus.eq(payment_status))
+            .filter(
+                salary_payments::Column::TeacherSalaryId.in_subquery(
+                    Query::select()
+                        .column(teacher_salaries::Column::Id)
+                        .from(teacher_salaries::Entity)
+                        .and_where(
+                            teacher_salaries::Column::TeacherAssignmentId.in_subquery(
+                                Query::select()
+                                    .column(teacher_assignments::Column::Id)
+                                    .from(teacher_assignments::Entity)
+                                    .and_where(
+                                        teacher_assignments::Column::TeacherId.eq(teacher_id),
+                                    )
+                                    .to_owned(),
+                            ),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .order_by_desc(salary_payments::Column::PaymentPeriodYear)
+            .order_by_desc(salary_payments::Column::PaymentPeriodMonth)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher of a foundation by user account (not deleted)
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find foundation by id
+    pub async fn find_foundation(&self, id: i64) -> Result<Option<foundations::Model>, AppError> {
+        foundations::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
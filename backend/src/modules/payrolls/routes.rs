@@ -0,0 +1,75 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use super::service::FINANCE_PERMISSION;
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::payrolls::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/payrolls")
+            .wrap(JwtMiddleware)
+            .route(
+                "",
+                web::get()
+                    .to(handler::get_all)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "",
+                web::post()
+                    .to(handler::create)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route("/payments/mine", web::get().to(handler::my_payments))
+            .route(
+                "/payments/{payment_id}/payslip",
+                web::get().to(handler::payslip),
+            )
+            .route(
+                "/{id}",
+                web::get()
+                    .to(handler::get_by_id)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}/recalculate",
+                web::post()
+                    .to(handler::recalculate)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}/approve",
+                web::post()
+                    .to(handler::approve)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}/pay",
+                web::post()
+                    .to(handler::pay)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}/payslips",
+                web::get()
+                    .to(handler::run_payslips)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            )
+            .route(
+                "/{id}/bank_export",
+                web::get()
+                    .to(handler::bank_export)
+                    .wrap(require_permission(FINANCE_PERMISSION)),
+            ),
+    );
+}
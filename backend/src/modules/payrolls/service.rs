@@ -0,0 +1,763 @@
+// ============================================================================
+// backend/src/modules/payrolls/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::bank_export::{self, TransferLine};
+use super::calculator::{self, AttendanceRules, PayrollAction};
+use super::dto::{
+    BankExportFormat, BankExportQuery, CreatePayrollRunRequest, PayPayrollRunRequest, PayrollFile,
+    PayrollRunDetailResponse, PayrollRunFilters, PayrollRunResponse, SalaryPaymentResponse,
+    SkippedAssignment,
+};
+use super::payslip::{self, PayslipSheet};
+use super::repository::PayrollRepository;
+use crate::errors::AppError;
+use crate::modules::staff_attendances::presence;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use crate::utils::pdf::PdfDocument;
+use chrono::{Duration, NaiveDate, Utc};
+use entity::sea_orm_active_enums::{PayrollStatus, StaffAttendanceStatus};
+use entity::{
+    payroll_runs, salary_payments, teacher_assignments, teacher_salaries, teachers, units,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::Set;
+use std::collections::HashMap;
+use validator::Validate;
+
+const DEFAULT_WORK_DAYS_PER_WEEK: i8 = 5;
+
+/// Kode permission bagian keuangan: slip gaji siapa pun & file transfer bank
+pub const FINANCE_PERMISSION: &str = "payrolls.manage";
+
+/// Pengguna yang sedang login
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub user_id: i64,
+    pub foundation_id: i64,
+    pub can_manage: bool,
+}
+
+/// Gaji, penugasan & guru di balik satu pembayaran
+struct Payee {
+    salary: teacher_salaries::Model,
+    assignment: Option<teacher_assignments::Model>,
+    teacher: Option<teachers::Model>,
+}
+
+/// Hasil perhitungan satu periode sebelum disimpan
+struct Calculation {
+    payments: Vec<salary_payments::ActiveModel>,
+    skipped: Vec<SkippedAssignment>,
+    total_gross: Decimal,
+    total_net: Decimal,
+}
+
+#[derive(Clone)]
+pub struct PayrollService {
+    repository: PayrollRepository,
+}
+
+impl PayrollService {
+    pub fn new(repository: PayrollRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create a draft payroll run for a month
+    pub async fn create(
+        &self,
+        request: CreatePayrollRunRequest,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<PayrollRunDetailResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let month = request.month as i32;
+        if self
+            .repository
+            .find_by_period(foundation_id, request.year, month)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ConflictError(format!(
+                "Payroll for {}-{:02} already exists",
+                request.year, request.month
+            )));
+        }
+
+        let rules = AttendanceRules {
+            late_penalty_per_day: to_decimal(request.late_penalty_per_day),
+            absent_penalty_per_day: request
+                .absent_penalty_per_day
+                .and_then(|v| Decimal::try_from(v).ok()),
+            perfect_attendance_bonus: to_decimal(request.perfect_attendance_bonus),
+        };
+        let calculation = self
+            .calculate(foundation_id, request.year, request.month, &rules)
+            .await?;
+
+        let now = Utc::now();
+        let run = payroll_runs::ActiveModel {
+            foundation_id: Set(foundation_id),
+            period_month: Set(month),
+            period_year: Set(request.year),
+            status: Set(PayrollStatus::Draft),
+            late_penalty_per_day: Set(Some(rules.late_penalty_per_day)),
+            absent_penalty_per_day: Set(rules.absent_penalty_per_day),
+            perfect_attendance_bonus: Set(Some(rules.perfect_attendance_bonus)),
+            total_gross: Set(Some(calculation.total_gross)),
+            total_net: Set(Some(calculation.total_net)),
+            created_by: Set(user_id),
+            notes: Set(request.notes),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let (run, payments) = self
+            .repository
+            .save_with_payments(run, calculation.payments)
+            .await?;
+
+        self.detail(run, payments, calculation.skipped).await
+    }
+
+    /// Recalculate a draft run from current salaries and attendance
+    pub async fn recalculate(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<PayrollRunDetailResponse, AppError> {
+        let run = self.find_run(id, foundation_id).await?;
+        if run.status != PayrollStatus::Draft {
+            return Err(AppError::ConflictError(
+                "Only draft payrolls can be recalculated".to_string(),
+            ));
+        }
+
+        let rules = AttendanceRules {
+            late_penalty_per_day: run.late_penalty_per_day.unwrap_or_default(),
+            absent_penalty_per_day: run.absent_penalty_per_day,
+            perfect_attendance_bonus: run.perfect_attendance_bonus.unwrap_or_default(),
+        };
+        let calculation = self
+            .calculate(
+                foundation_id,
+                run.period_year,
+                run.period_month as u32,
+                &rules,
+            )
+            .await?;
+
+        let mut active_model: payroll_runs::ActiveModel = run.into();
+        active_model.total_gross = Set(Some(calculation.total_gross));
+        active_model.total_net = Set(Some(calculation.total_net));
+        active_model.updated_at = Set(Utc::now());
+
+        let (run, payments) = self
+            .repository
+            .save_with_payments(active_model, calculation.payments)
+            .await?;
+
+        self.detail(run, payments, calculation.skipped).await
+    }
+
+    /// Approve a draft run
+    pub async fn approve(
+        &self,
+        id: i64,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<PayrollRunResponse, AppError> {
+        let run = self.find_run(id, foundation_id).await?;
+        let status = calculator::next_status(&run.status, PayrollAction::Approve)
+            .map_err(AppError::ConflictError)?;
+
+        let now = Utc::now();
+        let mut active_model: payroll_runs::ActiveModel = run.into();
+        active_model.status = Set(status);
+        active_model.approved_by = Set(Some(user_id));
+        active_model.approved_at = Set(Some(now));
+        active_model.updated_at = Set(now);
+
+        let updated = self.repository.update_status(active_model).await?;
+
+        Ok(PayrollRunResponse::from(updated))
+    }
+
+    /// Mark an approved run as paid
+    pub async fn pay(
+        &self,
+        id: i64,
+        request: PayPayrollRunRequest,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<PayrollRunResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let run = self.find_run(id, foundation_id).await?;
+        let status = calculator::next_status(&run.status, PayrollAction::Pay)
+            .map_err(AppError::ConflictError)?;
+
+        let now = Utc::now();
+        let mut active_model: payroll_runs::ActiveModel = run.into();
+        active_model.status = Set(status);
+        active_model.paid_by = Set(Some(user_id));
+        active_model.paid_at = Set(Some(now));
+        active_model.payment_date = Set(Some(request.payment_date));
+        active_model.payment_method = Set(Some(request.payment_method));
+        active_model.updated_at = Set(now);
+
+        let updated = self.repository.update_status(active_model).await?;
+
+        Ok(PayrollRunResponse::from(updated))
+    }
+
+    /// Get run with its payments
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<PayrollRunDetailResponse, AppError> {
+        let run = self.find_run(id, foundation_id).await?;
+        let payments = self.repository.find_payments(run.id).await?;
+
+        self.detail(run, payments, Vec::new()).await
+    }
+
+    /// Get all runs with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: PayrollRunFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<PayrollRunResponse>, AppError> {
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let data: Vec<PayrollRunResponse> =
+            items.into_iter().map(PayrollRunResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            data,
+            params.page(),
+            params.per_page(),
+            total,
+        ))
+    }
+
+    /// Delete a draft run
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        let run = self.find_run(id, foundation_id).await?;
+        if run.status != PayrollStatus::Draft {
+            return Err(AppError::ConflictError(
+                "Only draft payrolls can be deleted".to_string(),
+            ));
+        }
+
+        self.repository.delete(run.id).await
+    }
+
+    /// Payslip PDF of one payment; only the employee themself or finance
+    pub async fn payslip(&self, payment_id: i64, actor: Actor) -> Result<PayrollFile, AppError> {
+        let not_found = || AppError::not_found(format!("Payment with id {} not found", payment_id));
+        let payment = self
+            .repository
+            .find_payment(payment_id)
+            .await?
+            .ok_or_else(not_found)?;
+        let run = match payment.payroll_run_id {
+            Some(run_id) => {
+                self.repository
+                    .find_by_id(run_id, actor.foundation_id)
+                    .await?
+            }
+            None => None,
+        }
+        .ok_or_else(not_found)?;
+
+        let mut payees = self.payees(std::slice::from_ref(&payment)).await?;
+        let payee = payees
+            .remove(&payment.teacher_salary_id)
+            .ok_or_else(not_found)?;
+        let own = payee
+            .teacher
+            .as_ref()
+            .is_some_and(|t| t.user_id == actor.user_id);
+        if !actor.can_manage && !own {
+            return Err(AppError::forbidden(
+                "You can only download your own payslip".to_string(),
+            ));
+        }
+        ensure_paid(&run)?;
+
+        let foundation_name = self.foundation_name(actor.foundation_id).await?;
+        let sheet = payslip_sheet(&foundation_name, &payment, &payee);
+        let employee_number = payee
+            .teacher
+            .as_ref()
+            .map_or_else(|| payment.id.to_string(), |t| t.employee_number.clone());
+
+        Ok(PayrollFile {
+            filename: format!(
+                "slip_gaji_{}_{}{:02}.pdf",
+                file_safe(&employee_number),
+                run.period_year,
+                run.period_month
+            ),
+            content_type: "application/pdf",
+            content: payslip::render(&sheet).to_bytes(),
+        })
+    }
+
+    /// All payslips of a paid run as one merged PDF (finance)
+    pub async fn run_payslips(&self, id: i64, actor: Actor) -> Result<PayrollFile, AppError> {
+        ensure_finance(&actor)?;
+        let run = self.find_run(id, actor.foundation_id).await?;
+        ensure_paid(&run)?;
+
+        let payments = self.repository.find_payments(run.id).await?;
+        let payees = self.payees(&payments).await?;
+        let foundation_name = self.foundation_name(actor.foundation_id).await?;
+
+        let mut doc = PdfDocument::new().with_title(&format!(
+            "Slip Gaji {} {}",
+            payslip::month_name(run.period_month),
+            run.period_year
+        ));
+        for payment in &payments {
+            if let Some(payee) = payees.get(&payment.teacher_salary_id) {
+                doc.append(payslip::render(&payslip_sheet(
+                    &foundation_name,
+                    payment,
+                    payee,
+                )));
+            }
+        }
+
+        Ok(PayrollFile {
+            filename: format!("slip_gaji_{}{:02}.pdf", run.period_year, run.period_month),
+            content_type: "application/pdf",
+            content: doc.to_bytes(),
+        })
+    }
+
+    /// Bank transfer batch of all net salaries of an approved or paid run (finance)
+    pub async fn bank_export(
+        &self,
+        id: i64,
+        query: BankExportQuery,
+        actor: Actor,
+    ) -> Result<PayrollFile, AppError> {
+        query
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+        ensure_finance(&actor)?;
+
+        let run = self.find_run(id, actor.foundation_id).await?;
+        if run.status == PayrollStatus::Draft {
+            return Err(AppError::ConflictError(
+                "Payroll must be approved before it is exported".to_string(),
+            ));
+        }
+
+        let payments = self.repository.find_payments(run.id).await?;
+        let payees = self.payees(&payments).await?;
+        let reference = format!("GAJI {}{:02}", run.period_year, run.period_month);
+        let fixed_width = matches!(query.format, BankExportFormat::FixedWidth);
+
+        // Business rule: semua gaji bersih harus ikut; rekening yang belum lengkap digagalkan
+        let mut lines = Vec::new();
+        let mut incomplete = Vec::new();
+        for payment in payments.iter().filter(|p| p.net_salary > Decimal::ZERO) {
+            let teacher = payees
+                .get(&payment.teacher_salary_id)
+                .and_then(|p| p.teacher.as_ref());
+            let account = teacher.and_then(|t| {
+                let number = t.bank_account_number.clone().filter(|n| !n.is_empty())?;
+                let code = t.bank_code.clone().unwrap_or_default();
+                (!fixed_width || !code.is_empty()).then_some((t, code, number))
+            });
+            match account {
+                Some((teacher, bank_code, account_number)) => lines.push(TransferLine {
+                    bank_code,
+                    account_number,
+                    account_name: teacher
+                        .bank_account_name
+                        .clone()
+                        .unwrap_or_else(|| teacher.name.clone()),
+                    amount: payment.net_salary,
+                    reference: reference.clone(),
+                }),
+                None => incomplete.push(
+                    teacher.map_or_else(|| format!("payment {}", payment.id), |t| t.name.clone()),
+                ),
+            }
+        }
+        if !incomplete.is_empty() {
+            return Err(AppError::validation(format!(
+                "Bank account is incomplete for: {}",
+                incomplete.join(", ")
+            )));
+        }
+
+        let period = format!("{}{:02}", run.period_year, run.period_month);
+        Ok(match query.format {
+            BankExportFormat::Csv => PayrollFile {
+                filename: format!("transfer_gaji_{}.csv", period),
+                content_type: "text/csv",
+                content: bank_export::to_csv(&lines).into_bytes(),
+            },
+            BankExportFormat::FixedWidth => {
+                let source_account = query.source_account.ok_or_else(|| {
+                    AppError::validation(
+                        "source_account is required for fixed_width export".to_string(),
+                    )
+                })?;
+                let transfer_date = query
+                    .transfer_date
+                    .or(run.payment_date)
+                    .unwrap_or_else(|| Utc::now().date_naive());
+                PayrollFile {
+                    filename: format!("transfer_gaji_{}.txt", period),
+                    content_type: "text/plain",
+                    content: bank_export::to_fixed_width(&lines, &source_account, transfer_date)
+                        .into_bytes(),
+                }
+            }
+        })
+    }
+
+    /// Paid payments of the logged-in teacher, latest first
+    pub async fn my_payments(&self, actor: Actor) -> Result<Vec<SalaryPaymentResponse>, AppError> {
+        let teacher = self
+            .repository
+            .find_teacher_by_user(actor.user_id, actor.foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Teacher not found".to_string()))?;
+
+        let payments = self
+            .repository
+            .find_payments_by_teacher(
+                teacher.id,
+                &sea_orm::ActiveEnum::to_value(&PayrollStatus::Paid),
+            )
+            .await?;
+
+        Ok(payments
+            .into_iter()
+            .map(|payment| SalaryPaymentResponse::new(payment, Some(&teacher)))
+            .collect())
+    }
+
+    async fn foundation_name(&self, foundation_id: i64) -> Result<String, AppError> {
+        Ok(self
+            .repository
+            .find_foundation(foundation_id)
+            .await?
+            .map(|f| f.name)
+            .unwrap_or_default())
+    }
+
+    async fn find_run(&self, id: i64, foundation_id: i64) -> Result<payroll_runs::Model, AppError> {
+        self.repository
+            .find_by_id(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("Payroll with id {} not found", id)))
+    }
+
+    /// Satu slip per penugasan aktif, memakai gaji yang berlaku di akhir bulan
+    async fn calculate(
+        &self,
+        foundation_id: i64,
+        year: i32,
+        month: u32,
+        rules: &AttendanceRules,
+    ) -> Result<Calculation, AppError> {
+        let (first, last) = presence::month_bounds(year, month)
+            .ok_or_else(|| AppError::validation("Invalid year or month".to_string()))?;
+
+        let assignments = self
+            .repository
+            .find_active_assignments(foundation_id, first, last)
+            .await?;
+
+        // Gaji terbaru per penugasan (hasil query sudah urut terbaru dulu)
+        let mut salaries: HashMap<i64, teacher_salaries::Model> = HashMap::new();
+        for salary in self
+            .repository
+            .find_salaries_effective(assignments.iter().map(|a| a.id).collect(), last)
+            .await?
+        {
+            salaries
+                .entry(salary.teacher_assignment_id)
+                .or_insert(salary);
+        }
+
+        let teachers: HashMap<i64, teachers::Model> = self
+            .repository
+            .find_teachers_by_ids(assignments.iter().map(|a| a.teacher_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+
+        let units: HashMap<i64, units::Model> = self
+            .repository
+            .find_units(foundation_id)
+            .await?
+            .into_iter()
+            .map(|u| (u.id, u))
+            .collect();
+
+        let mut records: HashMap<i64, Vec<(NaiveDate, StaffAttendanceStatus)>> = HashMap::new();
+        for row in self
+            .repository
+            .find_staff_attendances(
+                foundation_id,
+                teachers.values().map(|t| t.user_id).collect(),
+                first,
+                last,
+            )
+            .await?
+        {
+            records
+                .entry(row.user_id)
+                .or_default()
+                .push((row.date, row.status));
+        }
+
+        let now = Utc::now();
+        let mut calculation = Calculation {
+            payments: Vec::new(),
+            skipped: Vec::new(),
+            total_gross: Decimal::ZERO,
+            total_net: Decimal::ZERO,
+        };
+
+        for assignment in assignments {
+            let skip = |reason: &str| SkippedAssignment {
+                teacher_assignment_id: assignment.id,
+                teacher_id: assignment.teacher_id,
+                reason: reason.to_string(),
+            };
+            let Some(teacher) = teachers.get(&assignment.teacher_id) else {
+                calculation.skipped.push(skip("Teacher not found"));
+                continue;
+            };
+            let Some(salary) = salaries.get(&assignment.id) else {
+                calculation
+                    .skipped
+                    .push(skip("No salary effective in this period"));
+                continue;
+            };
+
+            // Hari kerja yang belum lewat (waktu lokal unit) tidak dihitung alpa
+            let unit = units.get(&assignment.unit_id);
+            let today = unit.map_or_else(
+                || now.date_naive(),
+                |u| (now.naive_utc() + Duration::minutes(i64::from(u.utc_offset_minutes))).date(),
+            );
+            let counts = presence::monthly_counts(
+                first,
+                last,
+                today,
+                unit.map_or(DEFAULT_WORK_DAYS_PER_WEEK, |u| u.work_days_per_week),
+                records
+                    .get(&teacher.user_id)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            );
+            let slip = calculator::compute(salary, &counts, rules);
+
+            calculation.total_gross += slip.gross_salary;
+            calculation.total_net += slip.net_salary;
+            calculation.payments.push(salary_payments::ActiveModel {
+                teacher_salary_id: Set(salary.id),
+                payment_period_month: Set(month as i32),
+                payment_period_year: Set(year),
+                base_salary: Set(slip.base_salary),
+                total_allowances: Set(Some(slip.total_allowances)),
+                total_deductions: Set(Some(slip.total_deductions)),
+                gross_salary: Set(slip.gross_salary),
+                net_salary: Set(slip.net_salary),
+                working_days: Set(Some(counts.working_days)),
+                present_days: Set(Some(counts.present_days)),
+                absent_days: Set(Some(counts.absent_days)),
+                late_days: Set(Some(counts.late_days)),
+                bonus: Set(Some(slip.bonus)),
+                penalty: Set(Some(slip.penalty)),
+                payment_status: Set(Some(sea_orm::ActiveEnum::to_value(&PayrollStatus::Draft))),
+                created_at: Set(Some(now)),
+                updated_at: Set(Some(now)),
+                ..Default::default()
+            });
+        }
+
+        Ok(calculation)
+    }
+
+    async fn detail(
+        &self,
+        run: payroll_runs::Model,
+        payments: Vec<salary_payments::Model>,
+        skipped: Vec<SkippedAssignment>,
+    ) -> Result<PayrollRunDetailResponse, AppError> {
+        let payees = self.payees(&payments).await?;
+        let payments = payments
+            .into_iter()
+            .map(|payment| {
+                let teacher = payees
+                    .get(&payment.teacher_salary_id)
+                    .and_then(|p| p.teacher.as_ref());
+                SalaryPaymentResponse::new(payment, teacher)
+            })
+            .collect();
+
+        Ok(PayrollRunDetailResponse {
+            run: PayrollRunResponse::from(run),
+            payments,
+            skipped,
+        })
+    }
+
+    /// salary -> assignment -> teacher, dikunci per teacher_salary_id
+    async fn payees(
+        &self,
+        payments: &[salary_payments::Model],
+    ) -> Result<HashMap<i64, Payee>, AppError> {
+        let salaries = self
+            .repository
+            .find_salaries_by_ids(payments.iter().map(|p| p.teacher_salary_id).collect())
+            .await?;
+        let assignments: HashMap<i64, teacher_assignments::Model> = self
+            .repository
+            .find_assignments_by_ids(salaries.iter().map(|s| s.teacher_assignment_id).collect())
+            .await?
+            .into_iter()
+            .map(|a| (a.id, a))
+            .collect();
+        let teachers: HashMap<i64, teachers::Model> = self
+            .repository
+            .find_teachers_by_ids(assignments.values().map(|a| a.teacher_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+
+        Ok(salaries
+            .into_iter()
+            .map(|salary| {
+                let assignment = assignments.get(&salary.teacher_assignment_id).cloned();
+                let teacher = assignment
+                    .as_ref()
+                    .and_then(|a| teachers.get(&a.teacher_id))
+                    .cloned();
+                (
+                    salary.id,
+                    Payee {
+                        salary,
+                        assignment,
+                        teacher,
+                    },
+                )
+            })
+            .collect())
+    }
+}
+
+fn to_decimal(value: Option<f64>) -> Decimal {
+    value
+        .and_then(|v| Decimal::try_from(v).ok())
+        .unwrap_or_default()
+}
+
+fn ensure_finance(actor: &Actor) -> Result<(), AppError> {
+    if actor.can_manage {
+        Ok(())
+    } else {
+        Err(AppError::forbidden(format!(
+            "Permission {} is required",
+            FINANCE_PERMISSION
+        )))
+    }
+}
+
+fn ensure_paid(run: &payroll_runs::Model) -> Result<(), AppError> {
+    if run.status == PayrollStatus::Paid {
+        Ok(())
+    } else {
+        Err(AppError::ConflictError(
+            "Payslips are only available for paid payrolls".to_string(),
+        ))
+    }
+}
+
+fn payslip_sheet(
+    foundation_name: &str,
+    payment: &salary_payments::Model,
+    payee: &Payee,
+) -> PayslipSheet {
+    let bank_account = payee.teacher.as_ref().and_then(|t| {
+        t.bank_account_number.as_ref().map(|number| {
+            [t.bank_name.as_deref(), Some(number.as_str())]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    });
+
+    PayslipSheet {
+        foundation_name: foundation_name.to_string(),
+        employee_name: payee
+            .teacher
+            .as_ref()
+            .map(|t| t.name.clone())
+            .unwrap_or_default(),
+        employee_number: payee
+            .teacher
+            .as_ref()
+            .map(|t| t.employee_number.clone())
+            .unwrap_or_default(),
+        position: payee
+            .assignment
+            .as_ref()
+            .map(|a| a.position.clone())
+            .unwrap_or_default(),
+        period: format!(
+            "{} {}",
+            payslip::month_name(payment.payment_period_month),
+            payment.payment_period_year
+        ),
+        base_salary: payment.base_salary,
+        allowances: payslip::allowance_lines(&payee.salary),
+        bonus: payment.bonus.unwrap_or_default(),
+        deductions: payslip::deduction_lines(&payee.salary),
+        penalty: payment.penalty.unwrap_or_default(),
+        gross_salary: payment.gross_salary,
+        net_salary: payment.net_salary,
+        working_days: payment.working_days.unwrap_or_default(),
+        present_days: payment.present_days.unwrap_or_default(),
+        late_days: payment.late_days.unwrap_or_default(),
+        absent_days: payment.absent_days.unwrap_or_default(),
+        payment_date: payment.payment_date,
+        payment_method: payment.payment_method.clone(),
+        bank_account,
+    }
+}
+
+fn file_safe(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
@@ -0,0 +1,60 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    ClassReportCardsQuery, FinalizeReportCardsResponse, GenerateReportCardsResponse,
+    ReportCardBatchRequest, ReportCardFilters, ReportCardResponse, UpdateReportCardNotesRequest,
+};
+use super::handler;
+use entity::sea_orm_active_enums::ReportCardStatus;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::generate,
+        handler::finalize_class,
+        handler::get_by_id,
+        handler::get_all,
+        handler::update_notes,
+        handler::review,
+        handler::reopen,
+        handler::finalize,
+        handler::pdf,
+        handler::class_pdf,
+    ),
+    components(
+        schemas(
+            ReportCardResponse,
+            ReportCardStatus,
+            ReportCardBatchRequest,
+            GenerateReportCardsResponse,
+            ReportCardFilters,
+            UpdateReportCardNotesRequest,
+            FinalizeReportCardsResponse,
+            ClassReportCardsQuery,
+        )
+    ),
+    tags(
+        (name = "Report Card ", description = "Report card generation, review workflow and printing endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct ReportCardsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
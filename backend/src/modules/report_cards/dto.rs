@@ -0,0 +1,112 @@
+// backend/src/modules/report_cards/dto.rs
+use entity::sea_orm_active_enums::ReportCardStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReportCardResponse {
+    pub id: i64,
+    pub student_id: i64,
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub academic_year_id: i64,
+    pub class_rank: Option<i32>,
+    pub total_students: Option<i32>,
+    pub average_score: Option<f64>,
+    pub total_absences: Option<i32>,
+    pub homeroom_teacher_notes: Option<String>,
+    pub principal_notes: Option<String>,
+    pub status: Option<ReportCardStatus>,
+    pub finalized_at: Option<String>,
+    pub finalized_by: Option<i64>,
+    pub printed_count: Option<i32>,
+    pub last_printed_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Satu kelas pada satu semester
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReportCardBatchRequest {
+    #[validate(range(min = 1))]
+    pub class_id: i64,
+    #[validate(range(min = 1))]
+    pub semester_id: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenerateReportCardsResponse {
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub academic_year_id: i64,
+    pub created: usize,
+    pub refreshed: usize,
+    /// Finalized report cards are left untouched
+    pub skipped_finalized: usize,
+    /// Class subjects where some students still have no finalized grade
+    pub incomplete_class_subject_ids: Vec<i64>,
+    pub report_cards: Vec<ReportCardResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ReportCardFilters {
+    pub class_id: Option<i64>,
+    pub semester_id: Option<i64>,
+    pub student_id: Option<i64>,
+    pub status: Option<ReportCardStatus>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateReportCardNotesRequest {
+    #[validate(length(max = 2000))]
+    pub homeroom_teacher_notes: Option<String>,
+    #[validate(length(max = 2000))]
+    pub principal_notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FinalizeReportCardsResponse {
+    pub class_id: i64,
+    pub semester_id: i64,
+    pub finalized: u64,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::report_cards::Model> for ReportCardResponse {
+    fn from(model: entity::report_cards::Model) -> Self {
+        Self {
+            id: model.id,
+            student_id: model.student_id,
+            class_id: model.class_id,
+            semester_id: model.semester_id,
+            academic_year_id: model.academic_year_id,
+            class_rank: model.class_rank,
+            total_students: model.total_students,
+            average_score: model.average_score.and_then(|v| v.try_into().ok()),
+            total_absences: model.total_absences,
+            homeroom_teacher_notes: model.homeroom_teacher_notes,
+            principal_notes: model.principal_notes,
+            status: model.status,
+            finalized_at: model.finalized_at.map(|v| v.to_string()),
+            finalized_by: model.finalized_by,
+            printed_count: model.printed_count,
+            last_printed_at: model.last_printed_at.map(|v| v.to_string()),
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ClassReportCardsQuery {
+    pub class_id: i64,
+    pub semester_id: i64,
+}
+
+/// Hasil render PDF rapor
+#[derive(Debug)]
+pub struct ReportCardPdf {
+    pub filename: String,
+    pub content: Vec<u8>,
+}
@@ -0,0 +1,290 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    ClassReportCardsQuery, FinalizeReportCardsResponse, GenerateReportCardsResponse,
+    ReportCardBatchRequest, ReportCardFilters, ReportCardPdf, ReportCardResponse,
+    UpdateReportCardNotesRequest,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::{web, HttpResponse};
+
+/// Generate or refresh draft report cards of a class
+#[utoipa::path(
+    post,
+    path = "/api/report_cards/generate",
+    request_body = ReportCardBatchRequest,
+    responses(
+        (status = 200, description = "Report cards generated", body = GenerateReportCardsResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Class or semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn generate(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<ReportCardBatchRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .generate(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Finalize every reviewed report card of a class
+#[utoipa::path(
+    post,
+    path = "/api/report_cards/finalize",
+    request_body = ReportCardBatchRequest,
+    responses(
+        (status = 200, description = "Report cards finalized", body = FinalizeReportCardsResponse),
+        (status = 400, description = "No reviewed report cards"),
+        (status = 404, description = "Class not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn finalize_class(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<ReportCardBatchRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .finalize_class(request.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get report card by ID
+#[utoipa::path(
+    get,
+    path = "/api/report_cards/{id}",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    responses(
+        (status = 200, description = "Report card found", body = ReportCardResponse),
+        (status = 404, description = "Report card not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get all report cards with pagination
+#[utoipa::path(
+    get,
+    path = "/api/report_cards",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ReportCardFilters,
+    ),
+    responses(
+        (status = 200, description = "List of report cards", body = PaginatedResponse<ReportCardResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<ReportCardFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update homeroom teacher and principal notes
+#[utoipa::path(
+    put,
+    path = "/api/report_cards/{id}/notes",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    request_body = UpdateReportCardNotesRequest,
+    responses(
+        (status = 200, description = "Notes updated", body = ReportCardResponse),
+        (status = 404, description = "Report card not found"),
+        (status = 409, description = "Report card is finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn update_notes(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateReportCardNotesRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .update_notes(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Mark a draft report card as reviewed
+#[utoipa::path(
+    post,
+    path = "/api/report_cards/{id}/review",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    responses(
+        (status = 200, description = "Report card reviewed", body = ReportCardResponse),
+        (status = 404, description = "Report card not found"),
+        (status = 409, description = "Report card is not a draft")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn review(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .review(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Send a reviewed report card back to draft
+#[utoipa::path(
+    post,
+    path = "/api/report_cards/{id}/reopen",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    responses(
+        (status = 200, description = "Report card reopened", body = ReportCardResponse),
+        (status = 404, description = "Report card not found"),
+        (status = 409, description = "Report card is not reviewed")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn reopen(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .reopen(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Finalize a reviewed report card
+#[utoipa::path(
+    post,
+    path = "/api/report_cards/{id}/finalize",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    responses(
+        (status = 200, description = "Report card finalized", body = ReportCardResponse),
+        (status = 404, description = "Report card not found"),
+        (status = 409, description = "Report card is not reviewed")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn finalize(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .finalize(id.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Print a finalized report card as PDF
+#[utoipa::path(
+    get,
+    path = "/api/report_cards/{id}/pdf",
+    params(
+        ("id" = i64, Path, description = "Report card ID")
+    ),
+    responses(
+        (status = 200, description = "Report card PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 404, description = "Report card not found"),
+        (status = 409, description = "Report card is not finalized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn pdf(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .render_pdf(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(pdf_response(result))
+}
+
+/// Print every finalized report card of a class as one merged PDF
+#[utoipa::path(
+    get,
+    path = "/api/report_cards/pdf",
+    params(ClassReportCardsQuery),
+    responses(
+        (status = 200, description = "Merged report card PDF", content_type = "application/pdf", body = Vec<u8>),
+        (status = 400, description = "No finalized report cards"),
+        (status = 404, description = "Class not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Report Card "
+)]
+pub async fn class_pdf(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<ClassReportCardsQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .report_card_service
+        .render_class_pdf(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(pdf_response(result))
+}
+
+fn pdf_response(pdf: ReportCardPdf) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("application/pdf")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(pdf.filename)],
+        })
+        .body(pdf.content)
+}
@@ -0,0 +1,23 @@
+// backend/src/modules/report_cards/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod printout;
+pub mod ranking;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::ReportCardRepository;
+pub use service::ReportCardService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> ReportCardService {
+    let repository = ReportCardRepository::new(db);
+    ReportCardService::new(repository)
+}
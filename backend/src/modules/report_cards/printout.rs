@@ -0,0 +1,331 @@
+// ============================================================================
+// backend/src/modules/report_cards/printout.rs
+// printout.rs - Tata letak rapor ke PDF (pure, tanpa akses database)
+// ============================================================================
+use crate::utils::pdf::{self, Font, PdfDocument, PdfPage, A4_HEIGHT, A4_WIDTH};
+use chrono::NaiveDate;
+use sea_orm::prelude::Decimal;
+
+const MARGIN: f32 = 50.0;
+const BOTTOM: f32 = 60.0;
+const LINE: f32 = 15.0;
+const BODY: f32 = 10.0;
+
+#[derive(Debug, Clone)]
+pub struct SubjectLine {
+    pub name: String,
+    pub final_grade: Decimal,
+    pub letter: Option<String>,
+    pub predicate: Option<String>,
+}
+
+/// Baris penilaian sikap atau ekstrakurikuler
+#[derive(Debug, Clone)]
+pub struct AssessmentLine {
+    pub name: String,
+    pub grade: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Semua data yang dicetak pada satu rapor
+#[derive(Debug, Clone)]
+pub struct ReportCardSheet {
+    pub school_name: String,
+    pub student_name: String,
+    pub student_number: String,
+    pub class_name: String,
+    pub semester_name: String,
+    pub academic_year: String,
+    pub subjects: Vec<SubjectLine>,
+    pub average_score: Option<Decimal>,
+    pub class_rank: Option<i32>,
+    pub total_students: Option<i32>,
+    pub characters: Vec<AssessmentLine>,
+    pub extracurriculars: Vec<AssessmentLine>,
+    pub total_absences: i32,
+    pub homeroom_teacher_notes: Option<String>,
+    pub principal_notes: Option<String>,
+    pub printed_on: NaiveDate,
+}
+
+/// Penulis halaman dengan kursor vertikal; otomatis pindah halaman bila penuh
+struct Flow {
+    doc: PdfDocument,
+    page: PdfPage,
+    y: f32,
+}
+
+impl Flow {
+    fn new(title: &str) -> Self {
+        Self {
+            doc: PdfDocument::new().with_title(title),
+            page: PdfPage::new(),
+            y: A4_HEIGHT - MARGIN,
+        }
+    }
+
+    fn ensure(&mut self, height: f32) {
+        if self.y - height < BOTTOM {
+            let page = std::mem::take(&mut self.page);
+            self.doc.add_page(page);
+            self.y = A4_HEIGHT - MARGIN;
+        }
+    }
+
+    fn heading(&mut self, text: &str) {
+        self.ensure(LINE * 3.0);
+        self.y -= LINE * 0.5;
+        self.page.text(MARGIN, self.y, 11.0, Font::Bold, text);
+        self.y -= 4.0;
+        self.page
+            .line(MARGIN, self.y, A4_WIDTH - MARGIN, self.y, 0.75);
+        self.y -= LINE;
+    }
+
+    /// Baris tabel: (posisi x, teks); kolom terakhir boleh terbungkus
+    fn row(&mut self, cells: &[(f32, String)], font: Font) {
+        let (last_x, last_text) = cells.last().cloned().unwrap_or_default();
+        let wrapped = pdf::wrap(&last_text, A4_WIDTH - MARGIN - last_x, BODY, font);
+        self.ensure(LINE * wrapped.len() as f32);
+
+        for (x, text) in &cells[..cells.len().saturating_sub(1)] {
+            self.page.text(*x, self.y, BODY, font, text);
+        }
+        for line in wrapped {
+            self.page.text(last_x, self.y, BODY, font, &line);
+            self.y -= LINE;
+        }
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        for line in pdf::wrap(text, A4_WIDTH - MARGIN * 2.0, BODY, Font::Regular) {
+            self.ensure(LINE);
+            self.page.text(MARGIN, self.y, BODY, Font::Regular, &line);
+            self.y -= LINE;
+        }
+    }
+
+    fn finish(mut self) -> PdfDocument {
+        self.doc.add_page(self.page);
+        self.doc
+    }
+}
+
+/// Render satu rapor menjadi dokumen PDF (satu atau beberapa halaman)
+pub fn render(sheet: &ReportCardSheet) -> PdfDocument {
+    let mut flow = Flow::new(&format!("Rapor {}", sheet.student_name));
+    let center = A4_WIDTH / 2.0;
+
+    flow.page
+        .text_centered(center, flow.y, 14.0, Font::Bold, "LAPORAN HASIL BELAJAR");
+    flow.y -= LINE + 2.0;
+    flow.page
+        .text_centered(center, flow.y, 12.0, Font::Bold, &sheet.school_name);
+    flow.y -= LINE * 2.0;
+
+    let identity = [
+        (
+            "Nama Siswa",
+            sheet.student_name.as_str(),
+            "Kelas",
+            sheet.class_name.as_str(),
+        ),
+        (
+            "Nomor Induk",
+            sheet.student_number.as_str(),
+            "Semester",
+            sheet.semester_name.as_str(),
+        ),
+        ("", "", "Tahun Ajaran", sheet.academic_year.as_str()),
+    ];
+    for (left_label, left, right_label, right) in identity {
+        flow.page
+            .text(MARGIN, flow.y, BODY, Font::Regular, left_label);
+        flow.page
+            .text(MARGIN + 85.0, flow.y, BODY, Font::Regular, &colon(left));
+        flow.page
+            .text(330.0, flow.y, BODY, Font::Regular, right_label);
+        flow.page
+            .text(400.0, flow.y, BODY, Font::Regular, &colon(right));
+        flow.y -= LINE;
+    }
+
+    // A. Nilai akademik
+    flow.heading("A. Nilai Akademik");
+    let columns = [MARGIN, MARGIN + 25.0, 330.0, 390.0, 430.0];
+    flow.row(
+        &header(
+            &columns,
+            &["No", "Mata Pelajaran", "Nilai", "Huruf", "Predikat"],
+        ),
+        Font::Bold,
+    );
+    for (index, subject) in sheet.subjects.iter().enumerate() {
+        flow.row(
+            &header(
+                &columns,
+                &[
+                    &(index + 1).to_string(),
+                    &subject.name,
+                    &subject.final_grade.round_dp(2).to_string(),
+                    subject.letter.as_deref().unwrap_or("-"),
+                    subject.predicate.as_deref().unwrap_or("-"),
+                ],
+            ),
+            Font::Regular,
+        );
+    }
+    flow.y -= 4.0;
+    let summary = format!(
+        "Rata-rata: {}    Peringkat: {}",
+        sheet
+            .average_score
+            .map(|v| v.round_dp(2).to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        match (sheet.class_rank, sheet.total_students) {
+            (Some(rank), Some(total)) => format!("{} dari {} siswa", rank, total),
+            (Some(rank), None) => rank.to_string(),
+            _ => "-".to_string(),
+        }
+    );
+    flow.row(&[(MARGIN, summary)], Font::Bold);
+
+    // B. Sikap
+    flow.heading("B. Sikap dan Karakter");
+    assessments(&mut flow, &sheet.characters, "Aspek");
+
+    // C. Ekstrakurikuler
+    flow.heading("C. Ekstrakurikuler");
+    assessments(&mut flow, &sheet.extracurriculars, "Kegiatan");
+
+    // D. Ketidakhadiran
+    flow.heading("D. Ketidakhadiran");
+    flow.row(
+        &[
+            (MARGIN, "Jumlah hari tidak hadir".to_string()),
+            (MARGIN + 150.0, format!(": {} hari", sheet.total_absences)),
+        ],
+        Font::Regular,
+    );
+
+    // E. Catatan
+    flow.heading("E. Catatan Wali Kelas");
+    flow.paragraph(sheet.homeroom_teacher_notes.as_deref().unwrap_or("-"));
+    flow.heading("F. Catatan Kepala Sekolah");
+    flow.paragraph(sheet.principal_notes.as_deref().unwrap_or("-"));
+
+    // Tanda tangan
+    flow.ensure(LINE * 7.0);
+    flow.y -= LINE;
+    flow.page.text_right(
+        A4_WIDTH - MARGIN,
+        flow.y,
+        BODY,
+        Font::Regular,
+        &format!("Dicetak {}", sheet.printed_on.format("%d-%m-%Y")),
+    );
+    flow.y -= LINE * 1.5;
+    let signers = ["Orang Tua/Wali", "Wali Kelas", "Kepala Sekolah"];
+    let width = (A4_WIDTH - MARGIN * 2.0) / signers.len() as f32;
+    for (index, signer) in signers.iter().enumerate() {
+        let x = MARGIN + width * index as f32;
+        flow.page
+            .text_centered(x + width / 2.0, flow.y, BODY, Font::Regular, signer);
+        flow.page.line(
+            x + 15.0,
+            flow.y - 55.0,
+            x + width - 15.0,
+            flow.y - 55.0,
+            0.5,
+        );
+    }
+    flow.y -= LINE * 5.0;
+
+    flow.finish()
+}
+
+fn assessments(flow: &mut Flow, lines: &[AssessmentLine], label: &str) {
+    let columns = [MARGIN, MARGIN + 25.0, 250.0, 300.0];
+    if lines.is_empty() {
+        flow.row(&[(MARGIN, "-".to_string())], Font::Regular);
+        return;
+    }
+    flow.row(
+        &header(&columns, &["No", label, "Nilai", "Keterangan"]),
+        Font::Bold,
+    );
+    for (index, line) in lines.iter().enumerate() {
+        flow.row(
+            &header(
+                &columns,
+                &[
+                    &(index + 1).to_string(),
+                    &line.name,
+                    line.grade.as_deref().unwrap_or("-"),
+                    line.notes.as_deref().unwrap_or("-"),
+                ],
+            ),
+            Font::Regular,
+        );
+    }
+}
+
+fn header(columns: &[f32], texts: &[&str]) -> Vec<(f32, String)> {
+    columns
+        .iter()
+        .zip(texts)
+        .map(|(x, text)| (*x, text.to_string()))
+        .collect()
+}
+
+fn colon(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_report_card_flows_onto_next_page() {
+        let subject = SubjectLine {
+            name: "Matematika".to_string(),
+            final_grade: Decimal::new(8750, 2),
+            letter: Some("B".to_string()),
+            predicate: Some("Baik".to_string()),
+        };
+        let mut sheet = ReportCardSheet {
+            school_name: "SD Contoh".to_string(),
+            student_name: "Budi".to_string(),
+            student_number: "2026001".to_string(),
+            class_name: "4A".to_string(),
+            semester_name: "Ganjil".to_string(),
+            academic_year: "2026/2027".to_string(),
+            subjects: vec![subject.clone(); 5],
+            average_score: Some(Decimal::new(8750, 2)),
+            class_rank: Some(2),
+            total_students: Some(30),
+            characters: Vec::new(),
+            extracurriculars: Vec::new(),
+            total_absences: 3,
+            homeroom_teacher_notes: None,
+            principal_notes: None,
+            printed_on: NaiveDate::from_ymd_opt(2026, 12, 18).unwrap(),
+        };
+
+        let pages = |sheet: &ReportCardSheet| {
+            String::from_utf8_lossy(&render(sheet).to_bytes())
+                .matches("/Type /Page /Parent")
+                .count()
+        };
+
+        assert_eq!(pages(&sheet), 1);
+
+        sheet.subjects = vec![subject; 60];
+        assert_eq!(pages(&sheet), 2);
+    }
+}
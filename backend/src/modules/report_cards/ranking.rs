@@ -0,0 +1,112 @@
+// ============================================================================
+// backend/src/modules/report_cards/ranking.rs
+// ranking.rs - Rata-rata, peringkat kelas & rekap absensi (pure, tanpa database)
+// ============================================================================
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::AttendanceStatus;
+use sea_orm::prelude::Decimal;
+use std::collections::{HashMap, HashSet};
+
+/// Rata-rata nilai akhir, dibulatkan 2 desimal; `None` bila belum ada nilai
+pub fn average(scores: &[Decimal]) -> Option<Decimal> {
+    if scores.is_empty() {
+        return None;
+    }
+    let total: Decimal = scores.iter().copied().sum();
+    Some((total / Decimal::from(scores.len() as i64)).round_dp(2))
+}
+
+/// Peringkat kelas dengan standard competition ranking (1, 2, 2, 4):
+/// siswa dengan rata-rata sama berbagi peringkat, siswa tanpa nilai tidak diberi peringkat
+pub fn rank(averages: &[(i64, Option<Decimal>)]) -> HashMap<i64, i32> {
+    let mut ranked: Vec<(i64, Decimal)> = averages
+        .iter()
+        .filter_map(|(student_id, avg)| avg.map(|avg| (*student_id, avg)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let mut ranks = HashMap::with_capacity(ranked.len());
+    let mut previous: Option<(Decimal, i32)> = None;
+    for (position, (student_id, avg)) in ranked.into_iter().enumerate() {
+        let rank = match previous {
+            Some((prev_avg, prev_rank)) if prev_avg == avg => prev_rank,
+            _ => position as i32 + 1,
+        };
+        previous = Some((avg, rank));
+        ranks.insert(student_id, rank);
+    }
+    ranks
+}
+
+/// Status yang dihitung sebagai ketidakhadiran (sakit, izin, alpa)
+pub fn is_absence(status: &AttendanceStatus) -> bool {
+    matches!(
+        status,
+        AttendanceStatus::Sick | AttendanceStatus::Permission | AttendanceStatus::Absent
+    )
+}
+
+/// Jumlah hari tidak hadir per siswa; beberapa jam pelajaran di hari yang sama dihitung satu hari
+pub fn absent_days(records: &[(i64, NaiveDate, AttendanceStatus)]) -> HashMap<i64, i32> {
+    let days: HashSet<(i64, NaiveDate)> = records
+        .iter()
+        .filter(|(_, _, status)| is_absence(status))
+        .map(|(student_id, date, _)| (*student_id, *date))
+        .collect();
+
+    let mut totals = HashMap::new();
+    for (student_id, _) in days {
+        *totals.entry(student_id).or_insert(0) += 1;
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_shares_position_on_ties() {
+        let averages = vec![
+            (1, Some(Decimal::new(8550, 2))),
+            (2, Some(Decimal::new(9000, 2))),
+            (3, Some(Decimal::new(8550, 2))),
+            (4, Some(Decimal::new(7000, 2))),
+            (5, None),
+        ];
+
+        let ranks = rank(&averages);
+
+        assert_eq!(ranks[&2], 1);
+        assert_eq!(ranks[&1], 2);
+        assert_eq!(ranks[&3], 2);
+        assert_eq!(ranks[&4], 4);
+        assert!(!ranks.contains_key(&5));
+    }
+
+    #[test]
+    fn test_absent_days_counts_each_date_once() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 10, 13).unwrap();
+        let records = vec![
+            (1, monday, AttendanceStatus::Sick),
+            (1, monday, AttendanceStatus::Sick),
+            (1, tuesday, AttendanceStatus::Late),
+            (2, tuesday, AttendanceStatus::Absent),
+            (2, monday, AttendanceStatus::Present),
+        ];
+
+        let totals = absent_days(&records);
+
+        assert_eq!(totals[&1], 1);
+        assert_eq!(totals[&2], 1);
+        assert_eq!(
+            average(&[
+                Decimal::new(80, 0),
+                Decimal::new(85, 0),
+                Decimal::new(90, 0)
+            ]),
+            Some(Decimal::new(8500, 2))
+        );
+    }
+}
@@ -0,0 +1,392 @@
+// ============================================================================
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::ReportCardFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::report_cards::{self, Entity as ReportCard};
+use entity::sea_orm_active_enums::{AttendanceStatus, ReportCardStatus};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{
+    academic_years, attendances, character_assessments, character_traits, class_subjects, classes,
+    extracurricular_activities, extracurricular_enrollments, foundations, grades, semesters,
+    students, subjects,
+};
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct ReportCardRepository {
+    db: Database,
+}
+
+impl ReportCardRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find class of a foundation (not deleted)
+    pub async fn find_class(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<classes::Model>, AppError> {
+        classes::Entity::find_by_id(id)
+            .filter(classes::Column::FoundationId.eq(foundation_id))
+            .filter(classes::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semester of a foundation (not deleted)
+    pub async fn find_semester(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .filter(semesters::Column::FoundationId.eq(foundation_id))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find the academic year that contains a date
+    pub async fn find_academic_year_at(
+        &self,
+        foundation_id: i64,
+        date: NaiveDate,
+    ) -> Result<Option<academic_years::Model>, AppError> {
+        academic_years::Entity::find()
+            .filter(academic_years::Column::FoundationId.eq(foundation_id))
+            .filter(academic_years::Column::DeletedAt.is_null())
+            .filter(academic_years::Column::StartDate.lte(date))
+            .filter(academic_years::Column::EndDate.gte(date))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active students of a class
+    pub async fn find_students_by_class(
+        &self,
+        class_id: i64,
+    ) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::ClassId.eq(class_id))
+            .filter(students::Column::DeletedAt.is_null())
+            .order_by_asc(students::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a class in a semester
+    pub async fn find_class_subjects(
+        &self,
+        class_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find()
+            .filter(class_subjects::Column::ClassId.eq(class_id))
+            .filter(class_subjects::Column::SemesterId.eq(semester_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find finalized grades of the given class subjects
+    pub async fn find_finalized_grades(
+        &self,
+        class_subject_ids: Vec<i64>,
+    ) -> Result<Vec<grades::Model>, AppError> {
+        grades::Entity::find()
+            .filter(grades::Column::ClassSubjectId.is_in(class_subject_ids))
+            .filter(grades::Column::IsFinalized.eq(1))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find attendance records of the given class subjects within a date range
+    pub async fn find_attendances(
+        &self,
+        class_subject_ids: Vec<i64>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<(i64, NaiveDate, AttendanceStatus)>, AppError> {
+        let rows = attendances::Entity::find()
+            .filter(attendances::Column::ClassSubjectId.is_in(class_subject_ids))
+            .filter(attendances::Column::Date.between(from, to))
+            .filter(attendances::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.student_id, row.date, row.status))
+            .collect())
+    }
+
+    /// Find report cards of a class in a semester (not deleted)
+    pub async fn find_by_class_semester(
+        &self,
+        class_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<report_cards::Model>, AppError> {
+        ReportCard::find_not_deleted()
+            .filter(report_cards::Column::ClassId.eq(class_id))
+            .filter(report_cards::Column::SemesterId.eq(semester_id))
+            .order_by_asc(report_cards::Column::ClassRank)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find report card by ID (not deleted)
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<report_cards::Model>, AppError> {
+        ReportCard::find_not_deleted()
+            .filter(report_cards::Column::Id.eq(id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all report cards of a foundation with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &ReportCardFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<report_cards::Model>, u64), AppError> {
+        // report_cards tidak punya foundation_id, scope lewat kelas
+        let mut query = ReportCard::find_not_deleted().filter(
+            report_cards::Column::ClassId.in_subquery(
+                Query::select()
+                    .column(classes::Column::Id)
+                    .from(classes::Entity)
+                    .and_where(classes::Column::FoundationId.eq(foundation_id))
+                    .to_owned(),
+            ),
+        );
+
+        if let Some(class_id) = filters.class_id {
+            query = query.filter(report_cards::Column::ClassId.eq(class_id));
+        }
+        if let Some(semester_id) = filters.semester_id {
+            query = query.filter(report_cards::Column::SemesterId.eq(semester_id));
+        }
+        if let Some(student_id) = filters.student_id {
+            query = query.filter(report_cards::Column::StudentId.eq(student_id));
+        }
+        if let Some(ref status) = filters.status {
+            query = query.filter(report_cards::Column::Status.eq(status.clone()));
+        }
+
+        let paginator = query
+            .order_by_desc(report_cards::Column::SemesterId)
+            .order_by_asc(report_cards::Column::ClassId)
+            .order_by_asc(report_cards::Column::ClassRank)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Insert new and update existing report cards in a single transaction
+    pub async fn save_many(
+        &self,
+        models: Vec<report_cards::ActiveModel>,
+    ) -> Result<Vec<report_cards::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(models.len());
+        for model in models {
+            let row = if !model.id.is_not_set() {
+                model.update(&txn).await
+            } else {
+                model.insert(&txn).await
+            }
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Update report card
+    pub async fn update(
+        &self,
+        active_model: report_cards::ActiveModel,
+    ) -> Result<report_cards::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Finalize every reviewed report card of a class in a semester
+    pub async fn finalize_reviewed(
+        &self,
+        class_id: i64,
+        semester_id: i64,
+        user_id: i64,
+    ) -> Result<u64, AppError> {
+        let now = chrono::Utc::now();
+        let result = ReportCard::update_many()
+            .col_expr(
+                report_cards::Column::Status,
+                Expr::value(ReportCardStatus::Finalized),
+            )
+            .col_expr(report_cards::Column::FinalizedBy, Expr::value(user_id))
+            .col_expr(report_cards::Column::FinalizedAt, Expr::value(now))
+            .col_expr(report_cards::Column::UpdatedAt, Expr::value(now))
+            .filter(report_cards::Column::ClassId.eq(class_id))
+            .filter(report_cards::Column::SemesterId.eq(semester_id))
+            .filter(report_cards::Column::Status.eq(ReportCardStatus::Reviewed))
+            .filter(report_cards::Column::DeletedAt.is_null())
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Find student by ID
+    pub async fn find_student(&self, id: i64) -> Result<Option<students::Model>, AppError> {
+        students::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find academic year by ID
+    pub async fn find_academic_year(
+        &self,
+        id: i64,
+    ) -> Result<Option<academic_years::Model>, AppError> {
+        academic_years::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find foundation by ID
+    pub async fn find_foundation(&self, id: i64) -> Result<Option<foundations::Model>, AppError> {
+        foundations::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find subjects by IDs
+    pub async fn find_subjects(&self, ids: Vec<i64>) -> Result<Vec<subjects::Model>, AppError> {
+        subjects::Entity::find()
+            .filter(subjects::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find character assessments of a student in a semester
+    pub async fn find_character_assessments(
+        &self,
+        student_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<character_assessments::Model>, AppError> {
+        character_assessments::Entity::find()
+            .filter(character_assessments::Column::StudentId.eq(student_id))
+            .filter(character_assessments::Column::SemesterId.eq(semester_id))
+            .filter(character_assessments::Column::DeletedAt.is_null())
+            .order_by_asc(character_assessments::Column::CharacterTraitId)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find character traits by IDs
+    pub async fn find_character_traits(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<character_traits::Model>, AppError> {
+        character_traits::Entity::find()
+            .filter(character_traits::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find extracurricular enrollments of a student in a semester
+    pub async fn find_extracurricular_enrollments(
+        &self,
+        student_id: i64,
+        semester_id: i64,
+    ) -> Result<Vec<extracurricular_enrollments::Model>, AppError> {
+        extracurricular_enrollments::Entity::find()
+            .filter(extracurricular_enrollments::Column::StudentId.eq(student_id))
+            .filter(extracurricular_enrollments::Column::SemesterId.eq(semester_id))
+            .filter(extracurricular_enrollments::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find extracurricular activities by IDs
+    pub async fn find_extracurricular_activities(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<extracurricular_activities::Model>, AppError> {
+        extracurricular_activities::Entity::find()
+            .filter(extracurricular_activities::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Increment print counter of the given report cards
+    pub async fn mark_printed(&self, ids: Vec<i64>) -> Result<(), AppError> {
+        let now = chrono::Utc::now();
+        ReportCard::update_many()
+            .col_expr(
+                report_cards::Column::PrintedCount,
+                Expr::cust("COALESCE(printed_count, 0) + 1"),
+            )
+            .col_expr(report_cards::Column::LastPrintedAt, Expr::value(now))
+            .filter(report_cards::Column::Id.is_in(ids))
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+}
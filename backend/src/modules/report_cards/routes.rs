@@ -0,0 +1,22 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{middleware::auth::JwtMiddleware, modules::report_cards::handler};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/report_cards")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("/generate", web::post().to(handler::generate))
+            .route("/finalize", web::post().to(handler::finalize_class))
+            .route("/pdf", web::get().to(handler::class_pdf))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}/pdf", web::get().to(handler::pdf))
+            .route("/{id}/notes", web::put().to(handler::update_notes))
+            .route("/{id}/review", web::post().to(handler::review))
+            .route("/{id}/reopen", web::post().to(handler::reopen))
+            .route("/{id}/finalize", web::post().to(handler::finalize)),
+    );
+}
@@ -0,0 +1,625 @@
+// ============================================================================
+// backend/src/modules/report_cards/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    ClassReportCardsQuery, FinalizeReportCardsResponse, GenerateReportCardsResponse,
+    ReportCardBatchRequest, ReportCardFilters, ReportCardPdf, ReportCardResponse,
+    UpdateReportCardNotesRequest,
+};
+use super::printout::{self, AssessmentLine, ReportCardSheet, SubjectLine};
+use super::ranking;
+use super::repository::ReportCardRepository;
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use crate::utils::pdf::PdfDocument;
+use entity::report_cards;
+use entity::sea_orm_active_enums::ReportCardStatus;
+use sea_orm::prelude::Decimal;
+use sea_orm::{ActiveEnum, Set};
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct ReportCardService {
+    repository: ReportCardRepository,
+}
+
+impl ReportCardService {
+    pub fn new(repository: ReportCardRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Buat atau perbarui rapor draft satu kelas untuk satu semester
+    pub async fn generate(
+        &self,
+        request: ReportCardBatchRequest,
+        foundation_id: i64,
+    ) -> Result<GenerateReportCardsResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        self.repository
+            .find_class(request.class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+        let semester = self
+            .repository
+            .find_semester(request.semester_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+        let academic_year = self
+            .repository
+            .find_academic_year_at(foundation_id, semester.start_date)
+            .await?
+            .ok_or_else(|| {
+                AppError::validation(format!(
+                    "No academic year covers the start of semester {}",
+                    semester.id
+                ))
+            })?;
+
+        let students = self
+            .repository
+            .find_students_by_class(request.class_id)
+            .await?;
+        if students.is_empty() {
+            return Err(AppError::validation(format!(
+                "Class {} has no students",
+                request.class_id
+            )));
+        }
+        let roster: HashSet<i64> = students.iter().map(|s| s.id).collect();
+
+        let class_subject_ids: Vec<i64> = self
+            .repository
+            .find_class_subjects(request.class_id, semester.id)
+            .await?
+            .into_iter()
+            .map(|cs| cs.id)
+            .collect();
+
+        // Hanya nilai yang sudah difinalisasi yang masuk rapor
+        let mut scores: HashMap<i64, Vec<Decimal>> = HashMap::new();
+        let mut graded: HashMap<i64, HashSet<i64>> = HashMap::new();
+        for grade in self
+            .repository
+            .find_finalized_grades(class_subject_ids.clone())
+            .await?
+            .into_iter()
+            .filter(|g| roster.contains(&g.student_id))
+        {
+            scores
+                .entry(grade.student_id)
+                .or_default()
+                .push(grade.final_grade);
+            graded
+                .entry(grade.class_subject_id)
+                .or_default()
+                .insert(grade.student_id);
+        }
+        let incomplete_class_subject_ids: Vec<i64> = class_subject_ids
+            .iter()
+            .copied()
+            .filter(|id| graded.get(id).map_or(0, |s| s.len()) < roster.len())
+            .collect();
+
+        let absences = ranking::absent_days(
+            &self
+                .repository
+                .find_attendances(class_subject_ids, semester.start_date, semester.end_date)
+                .await?,
+        );
+
+        let averages: Vec<(i64, Option<Decimal>)> = students
+            .iter()
+            .map(|s| {
+                let avg = scores.get(&s.id).and_then(|v| ranking::average(v));
+                (s.id, avg)
+            })
+            .collect();
+        let ranks = ranking::rank(&averages);
+        let total_students = students.len() as i32;
+
+        let mut existing: HashMap<i64, report_cards::Model> = self
+            .repository
+            .find_by_class_semester(request.class_id, semester.id)
+            .await?
+            .into_iter()
+            .map(|card| (card.student_id, card))
+            .collect();
+
+        let now = chrono::Utc::now();
+        let (mut created, mut refreshed, mut skipped_finalized) = (0, 0, 0);
+        let mut models = Vec::with_capacity(averages.len());
+        for (student_id, average_score) in averages {
+            let class_rank = ranks.get(&student_id).copied();
+            let total_absences = absences.get(&student_id).copied().unwrap_or(0);
+
+            let mut model = match existing.remove(&student_id) {
+                // Business rule: rapor final tidak diubah lagi
+                Some(card) if card.status == Some(ReportCardStatus::Finalized) => {
+                    skipped_finalized += 1;
+                    continue;
+                }
+                Some(card) => {
+                    let changed = card.class_rank != class_rank
+                        || card.total_students != Some(total_students)
+                        || card.average_score != average_score
+                        || card.total_absences != Some(total_absences)
+                        || card.academic_year_id != academic_year.id;
+                    if !changed {
+                        continue;
+                    }
+                    refreshed += 1;
+                    // Angka berubah, rapor yang sudah direview harus direview ulang
+                    let mut active: report_cards::ActiveModel = card.into();
+                    active.status = Set(Some(ReportCardStatus::Draft));
+                    active
+                }
+                None => {
+                    created += 1;
+                    report_cards::ActiveModel {
+                        student_id: Set(student_id),
+                        class_id: Set(request.class_id),
+                        semester_id: Set(semester.id),
+                        status: Set(Some(ReportCardStatus::Draft)),
+                        printed_count: Set(Some(0)),
+                        created_at: Set(now),
+                        ..Default::default()
+                    }
+                }
+            };
+
+            model.academic_year_id = Set(academic_year.id);
+            model.class_rank = Set(class_rank);
+            model.total_students = Set(Some(total_students));
+            model.average_score = Set(average_score);
+            model.total_absences = Set(Some(total_absences));
+            model.updated_at = Set(now);
+            models.push(model);
+        }
+
+        self.repository.save_many(models).await?;
+
+        let report_cards = self
+            .repository
+            .find_by_class_semester(request.class_id, semester.id)
+            .await?
+            .into_iter()
+            .map(ReportCardResponse::from)
+            .collect();
+
+        Ok(GenerateReportCardsResponse {
+            class_id: request.class_id,
+            semester_id: semester.id,
+            academic_year_id: academic_year.id,
+            created,
+            refreshed,
+            skipped_finalized,
+            incomplete_class_subject_ids,
+            report_cards,
+        })
+    }
+
+    /// Get report card by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<ReportCardResponse, AppError> {
+        let card = self.find_card(id, foundation_id).await?;
+        Ok(ReportCardResponse::from(card))
+    }
+
+    /// Get all report cards with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: ReportCardFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<ReportCardResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let responses: Vec<ReportCardResponse> =
+            items.into_iter().map(ReportCardResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Update homeroom teacher & principal notes
+    pub async fn update_notes(
+        &self,
+        id: i64,
+        request: UpdateReportCardNotesRequest,
+        foundation_id: i64,
+    ) -> Result<ReportCardResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let card = self.find_card(id, foundation_id).await?;
+        if card.status == Some(ReportCardStatus::Finalized) {
+            return Err(AppError::ConflictError(
+                "Report card is finalized and can no longer be changed".to_string(),
+            ));
+        }
+
+        let mut active: report_cards::ActiveModel = card.into();
+        if let Some(notes) = request.homeroom_teacher_notes {
+            active.homeroom_teacher_notes = Set(Some(notes));
+        }
+        if let Some(notes) = request.principal_notes {
+            active.principal_notes = Set(Some(notes));
+        }
+        active.updated_at = Set(chrono::Utc::now());
+
+        let updated = self.repository.update(active).await?;
+        Ok(ReportCardResponse::from(updated))
+    }
+
+    /// Draft -> Reviewed
+    pub async fn review(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<ReportCardResponse, AppError> {
+        self.transition(
+            id,
+            foundation_id,
+            ReportCardStatus::Draft,
+            ReportCardStatus::Reviewed,
+        )
+        .await
+    }
+
+    /// Reviewed -> Draft, misalnya bila wali kelas perlu memperbaiki catatan
+    pub async fn reopen(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<ReportCardResponse, AppError> {
+        self.transition(
+            id,
+            foundation_id,
+            ReportCardStatus::Reviewed,
+            ReportCardStatus::Draft,
+        )
+        .await
+    }
+
+    /// Reviewed -> Finalized
+    pub async fn finalize(
+        &self,
+        id: i64,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<ReportCardResponse, AppError> {
+        let card = self.find_card(id, foundation_id).await?;
+        if card.status != Some(ReportCardStatus::Reviewed) {
+            return Err(AppError::ConflictError(
+                "Only reviewed report cards can be finalized".to_string(),
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        let mut active: report_cards::ActiveModel = card.into();
+        active.status = Set(Some(ReportCardStatus::Finalized));
+        active.finalized_by = Set(Some(user_id));
+        active.finalized_at = Set(Some(now));
+        active.updated_at = Set(now);
+
+        let updated = self.repository.update(active).await?;
+        Ok(ReportCardResponse::from(updated))
+    }
+
+    /// Finalize every reviewed report card of a class
+    pub async fn finalize_class(
+        &self,
+        request: ReportCardBatchRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<FinalizeReportCardsResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        self.repository
+            .find_class(request.class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+
+        let finalized = self
+            .repository
+            .finalize_reviewed(request.class_id, request.semester_id, user_id)
+            .await?;
+        if finalized == 0 {
+            return Err(AppError::validation(
+                "There are no reviewed report cards to finalize".to_string(),
+            ));
+        }
+
+        Ok(FinalizeReportCardsResponse {
+            class_id: request.class_id,
+            semester_id: request.semester_id,
+            finalized,
+        })
+    }
+
+    /// Render rapor final menjadi PDF dan catat jumlah cetak
+    pub async fn render_pdf(&self, id: i64, foundation_id: i64) -> Result<ReportCardPdf, AppError> {
+        let card = self.find_card(id, foundation_id).await?;
+        if card.status != Some(ReportCardStatus::Finalized) {
+            return Err(AppError::ConflictError(
+                "Only finalized report cards can be printed".to_string(),
+            ));
+        }
+
+        let sheet = self.build_sheet(&card, foundation_id).await?;
+        let content = printout::render(&sheet).to_bytes();
+        self.repository.mark_printed(vec![card.id]).await?;
+
+        Ok(ReportCardPdf {
+            filename: format!(
+                "rapor_{}_{}.pdf",
+                file_safe(&sheet.student_number),
+                file_safe(&sheet.semester_name)
+            ),
+            content,
+        })
+    }
+
+    /// Satu PDF gabungan berisi semua rapor final satu kelas
+    pub async fn render_class_pdf(
+        &self,
+        query: ClassReportCardsQuery,
+        foundation_id: i64,
+    ) -> Result<ReportCardPdf, AppError> {
+        let class = self
+            .repository
+            .find_class(query.class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+
+        let cards: Vec<report_cards::Model> = self
+            .repository
+            .find_by_class_semester(class.id, query.semester_id)
+            .await?
+            .into_iter()
+            .filter(|card| card.status == Some(ReportCardStatus::Finalized))
+            .collect();
+        if cards.is_empty() {
+            return Err(AppError::validation(
+                "There are no finalized report cards to print".to_string(),
+            ));
+        }
+
+        let mut sheets = Vec::with_capacity(cards.len());
+        for card in &cards {
+            sheets.push(self.build_sheet(card, foundation_id).await?);
+        }
+        sheets.sort_by(|a, b| a.student_name.cmp(&b.student_name));
+
+        let mut document = PdfDocument::new().with_title(&format!("Rapor {}", class.name));
+        for sheet in &sheets {
+            document.append(printout::render(sheet));
+        }
+        self.repository
+            .mark_printed(cards.iter().map(|card| card.id).collect())
+            .await?;
+
+        Ok(ReportCardPdf {
+            filename: format!(
+                "rapor_{}_{}.pdf",
+                file_safe(&class.name),
+                file_safe(&sheets[0].semester_name)
+            ),
+            content: document.to_bytes(),
+        })
+    }
+
+    /// Kumpulkan semua data yang dicetak pada satu rapor
+    async fn build_sheet(
+        &self,
+        card: &report_cards::Model,
+        foundation_id: i64,
+    ) -> Result<ReportCardSheet, AppError> {
+        let student = self
+            .repository
+            .find_student(card.student_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Student not found".to_string()))?;
+        let class = self
+            .repository
+            .find_class(card.class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Class not found".to_string()))?;
+        let semester = self
+            .repository
+            .find_semester(card.semester_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+        let academic_year = self
+            .repository
+            .find_academic_year(card.academic_year_id)
+            .await?
+            .map(|year| year.name)
+            .unwrap_or_default();
+        let school_name = self
+            .repository
+            .find_foundation(foundation_id)
+            .await?
+            .map(|foundation| foundation.name)
+            .unwrap_or_default();
+
+        // Nilai akademik: hanya nilai final, urut nama mapel
+        let class_subjects = self
+            .repository
+            .find_class_subjects(card.class_id, card.semester_id)
+            .await?;
+        let subject_of: HashMap<i64, i64> = class_subjects
+            .iter()
+            .map(|cs| (cs.id, cs.subject_id))
+            .collect();
+        let subject_names: HashMap<i64, String> = self
+            .repository
+            .find_subjects(class_subjects.iter().map(|cs| cs.subject_id).collect())
+            .await?
+            .into_iter()
+            .map(|subject| (subject.id, subject.name))
+            .collect();
+        let mut subjects: Vec<SubjectLine> = self
+            .repository
+            .find_finalized_grades(subject_of.keys().copied().collect())
+            .await?
+            .into_iter()
+            .filter(|grade| grade.student_id == card.student_id)
+            .map(|grade| SubjectLine {
+                name: subject_of
+                    .get(&grade.class_subject_id)
+                    .and_then(|id| subject_names.get(id))
+                    .cloned()
+                    .unwrap_or_default(),
+                final_grade: grade.final_grade,
+                letter: grade.grade_letter,
+                predicate: grade.predicate.map(|p| p.to_value()),
+            })
+            .collect();
+        subjects.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let assessments = self
+            .repository
+            .find_character_assessments(card.student_id, card.semester_id)
+            .await?;
+        let trait_names: HashMap<i64, String> = self
+            .repository
+            .find_character_traits(assessments.iter().map(|a| a.character_trait_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+        let characters = assessments
+            .into_iter()
+            .map(|a| AssessmentLine {
+                name: trait_names
+                    .get(&a.character_trait_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                grade: Some(a.grade.to_value()),
+                notes: a.notes,
+            })
+            .collect();
+
+        let enrollments = self
+            .repository
+            .find_extracurricular_enrollments(card.student_id, card.semester_id)
+            .await?;
+        let activity_names: HashMap<i64, String> = self
+            .repository
+            .find_extracurricular_activities(
+                enrollments.iter().map(|e| e.extracurricular_id).collect(),
+            )
+            .await?
+            .into_iter()
+            .map(|a| (a.id, a.name))
+            .collect();
+        let extracurriculars = enrollments
+            .into_iter()
+            .map(|e| AssessmentLine {
+                name: activity_names
+                    .get(&e.extracurricular_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                grade: e.grade.map(|g| g.to_value()),
+                notes: e.notes,
+            })
+            .collect();
+
+        Ok(ReportCardSheet {
+            school_name,
+            student_name: student.name,
+            student_number: student.student_number,
+            class_name: class.name,
+            semester_name: semester.name,
+            academic_year,
+            subjects,
+            average_score: card.average_score,
+            class_rank: card.class_rank,
+            total_students: card.total_students,
+            characters,
+            extracurriculars,
+            total_absences: card.total_absences.unwrap_or(0),
+            homeroom_teacher_notes: card.homeroom_teacher_notes.clone(),
+            principal_notes: card.principal_notes.clone(),
+            printed_on: chrono::Utc::now().date_naive(),
+        })
+    }
+
+    async fn transition(
+        &self,
+        id: i64,
+        foundation_id: i64,
+        from: ReportCardStatus,
+        to: ReportCardStatus,
+    ) -> Result<ReportCardResponse, AppError> {
+        let card = self.find_card(id, foundation_id).await?;
+        if card.status.as_ref().unwrap_or(&ReportCardStatus::Draft) != &from {
+            return Err(AppError::ConflictError(format!(
+                "Report card must be {:?} to become {:?}",
+                from, to
+            )));
+        }
+
+        let mut active: report_cards::ActiveModel = card.into();
+        active.status = Set(Some(to));
+        active.updated_at = Set(chrono::Utc::now());
+
+        let updated = self.repository.update(active).await?;
+        Ok(ReportCardResponse::from(updated))
+    }
+
+    /// Rapor hanya terlihat oleh foundation pemilik kelasnya
+    async fn find_card(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<report_cards::Model, AppError> {
+        let card = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Report card not found".to_string()))?;
+
+        self.repository
+            .find_class(card.class_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Report card not found".to_string()))?;
+
+        Ok(card)
+    }
+}
+
+/// Nama file aman untuk header Content-Disposition
+fn file_safe(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
@@ -0,0 +1,101 @@
+// ============================================================================
+// backend/src/modules/room_bookings/availability.rs
+// availability.rs - Room Search Rules (pure, tanpa akses database)
+// ============================================================================
+use crate::modules::schedules::conflicts::FacilityRef;
+use std::collections::HashMap;
+
+/// Facility yang diminta pencarian beserta jumlah minimalnya
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FacilityRequirement {
+    pub facility: FacilityRef,
+    pub qty: i32,
+}
+
+/// Parse query `facilities`: daftar dipisah koma berisi ID atau nama facility,
+/// opsional dengan jumlah minimal `nama:qty`, contoh `projector,computer:30,4`
+pub fn parse_requirements(raw: &str) -> Result<Vec<FacilityRequirement>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let (name, qty) = match item.rsplit_once(':') {
+                Some((name, qty)) => {
+                    let qty = qty
+                        .trim()
+                        .parse::<i32>()
+                        .ok()
+                        .filter(|q| *q > 0)
+                        .ok_or_else(|| format!("Invalid facility quantity in '{}'", item))?;
+                    (name.trim(), qty)
+                }
+                None => (item, 1),
+            };
+
+            let facility = match name.parse::<i64>() {
+                Ok(id) => FacilityRef::Id(id),
+                Err(_) => FacilityRef::Name(name.to_lowercase()),
+            };
+            Ok(FacilityRequirement { facility, qty })
+        })
+        .collect()
+}
+
+/// Ruangan memiliki semua facility yang diminta dalam jumlah cukup
+pub fn satisfies(
+    installed: &HashMap<FacilityRef, i32>,
+    requirements: &[FacilityRequirement],
+) -> bool {
+    requirements.iter().all(|requirement| {
+        installed
+            .get(&requirement.facility)
+            .is_some_and(|qty| *qty >= requirement.qty)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_requirements() {
+        let parsed = parse_requirements(" Projector , 3:2,").unwrap();
+
+        assert_eq!(
+            parsed,
+            vec![
+                FacilityRequirement {
+                    facility: FacilityRef::Name("projector".to_string()),
+                    qty: 1,
+                },
+                FacilityRequirement {
+                    facility: FacilityRef::Id(3),
+                    qty: 2,
+                },
+            ]
+        );
+        assert!(parse_requirements("computer:0").is_err());
+    }
+
+    #[test]
+    fn test_satisfies_checks_quantity() {
+        let installed = HashMap::from([
+            (FacilityRef::Id(3), 30),
+            (FacilityRef::Name("computer".to_string()), 30),
+            (FacilityRef::Name("projector".to_string()), 1),
+        ]);
+
+        assert!(satisfies(
+            &installed,
+            &parse_requirements("projector,computer:30").unwrap()
+        ));
+        assert!(!satisfies(
+            &installed,
+            &parse_requirements("computer:31").unwrap()
+        ));
+        assert!(!satisfies(
+            &installed,
+            &parse_requirements("whiteboard").unwrap()
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    CreateRoomBookingRequest, OccupancyDay, OccupancyEntry, OccupancyKind, OccupancyQuery,
+    RoomBookingFilters, RoomBookingResponse, RoomFacilityResponse, RoomOccupancyResponse,
+    RoomSearchQuery, RoomSearchResult, UpdateRoomBookingRequest,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create,
+        handler::get_by_id,
+        handler::get_all,
+        handler::update,
+        handler::delete,
+        handler::search,
+        handler::occupancy,
+    ),
+    components(
+        schemas(
+            RoomBookingResponse,
+            CreateRoomBookingRequest,
+            UpdateRoomBookingRequest,
+            RoomBookingFilters,
+            RoomSearchQuery,
+            RoomSearchResult,
+            RoomFacilityResponse,
+            OccupancyQuery,
+            OccupancyKind,
+            OccupancyEntry,
+            OccupancyDay,
+            RoomOccupancyResponse,
+        )
+    ),
+    tags(
+        (name = "Room Booking ", description = "Room booking and availability endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct RoomBookingsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// backend/src/modules/room_bookings/dto.rs
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::BookingPurpose;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoomBookingResponse {
+    pub id: i64,
+    pub room_id: i64,
+    pub title: String,
+    pub purpose: BookingPurpose,
+    pub date: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub attendees: Option<i32>,
+    pub notes: Option<String>,
+    pub booked_by: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateRoomBookingRequest {
+    #[validate(range(min = 1))]
+    pub room_id: i64,
+    #[validate(length(min = 1, max = 150))]
+    pub title: String,
+    pub purpose: Option<BookingPurpose>,
+    pub date: NaiveDate,
+    /// Booking satu jam pelajaran; jika diisi, `start_time`/`end_time` diambil dari slot
+    #[validate(range(min = 1))]
+    pub time_slot_id: Option<i64>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    #[validate(range(min = 1))]
+    pub attendees: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateRoomBookingRequest {
+    #[validate(range(min = 1))]
+    pub room_id: Option<i64>,
+    #[validate(length(min = 1, max = 150))]
+    pub title: Option<String>,
+    pub purpose: Option<BookingPurpose>,
+    pub date: Option<NaiveDate>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    #[validate(range(min = 1))]
+    pub attendees: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct RoomBookingFilters {
+    pub room_id: Option<i64>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+    pub purpose: Option<BookingPurpose>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct RoomSearchQuery {
+    /// Tanggal spesifik (cek jadwal pelajaran & booking lain)
+    pub date: Option<NaiveDate>,
+    /// Hari berulang 1 = Senin ... 7 = Minggu (cek jadwal pelajaran saja)
+    pub day_of_week: Option<i8>,
+    /// Slot pelajaran; menentukan jam dan hari jika belum diisi
+    pub time_slot_id: Option<i64>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub min_capacity: Option<i32>,
+    pub room_type_id: Option<i64>,
+    pub unit_id: Option<i64>,
+    /// Facility yang dibutuhkan, contoh `projector,computer:30`
+    pub facilities: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoomFacilityResponse {
+    pub facility_id: i64,
+    pub name: Option<String>,
+    pub qty: i32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoomSearchResult {
+    pub room_id: i64,
+    pub name: String,
+    pub unit_id: i64,
+    pub room_type_id: i64,
+    pub capacity: i32,
+    pub facilities: Vec<RoomFacilityResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct OccupancyQuery {
+    /// Tanggal mana pun dalam minggu yang diminta (dinormalisasi ke Senin)
+    pub week_of: NaiveDate,
+    pub room_id: Option<i64>,
+    pub unit_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OccupancyKind {
+    Lesson,
+    Booking,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OccupancyEntry {
+    pub kind: OccupancyKind,
+    pub start_time: String,
+    pub end_time: String,
+    pub title: String,
+    pub class_schedule_id: Option<i64>,
+    pub time_slot_id: Option<i64>,
+    pub booking_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OccupancyDay {
+    pub date: String,
+    pub day_of_week: i8,
+    pub entries: Vec<OccupancyEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RoomOccupancyResponse {
+    pub room_id: i64,
+    pub name: String,
+    pub is_available: bool,
+    pub days: Vec<OccupancyDay>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::room_bookings::Model> for RoomBookingResponse {
+    fn from(model: entity::room_bookings::Model) -> Self {
+        Self {
+            id: model.id,
+            room_id: model.room_id,
+            title: model.title,
+            purpose: model.purpose,
+            date: model.date.to_string(),
+            start_time: model.start_time.to_string(),
+            end_time: model.end_time.to_string(),
+            attendees: model.attendees,
+            notes: model.notes,
+            booked_by: model.booked_by,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    CreateRoomBookingRequest, OccupancyQuery, RoomBookingFilters, RoomBookingResponse,
+    RoomOccupancyResponse, RoomSearchQuery, RoomSearchResult, UpdateRoomBookingRequest,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Book a room
+#[utoipa::path(
+    post,
+    path = "/api/room_bookings",
+    request_body = CreateRoomBookingRequest,
+    responses(
+        (status = 201, description = "Room booked", body = RoomBookingResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Room or time slot not found"),
+        (status = 409, description = "Room is unavailable or already in use")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateRoomBookingRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .create(request.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get room booking by ID
+#[utoipa::path(
+    get,
+    path = "/api/room_bookings/{id}",
+    params(
+        ("id" = i64, Path, description = "Room booking ID")
+    ),
+    responses(
+        (status = 200, description = "Room booking found", body = RoomBookingResponse),
+        (status = 404, description = "Room booking not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get all room bookings with pagination
+#[utoipa::path(
+    get,
+    path = "/api/room_bookings",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ("search" = Option<String>, Query, description = "Search by title"),
+        RoomBookingFilters,
+    ),
+    responses(
+        (status = 200, description = "List of room bookings", body = PaginatedResponse<RoomBookingResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<RoomBookingFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update room booking
+#[utoipa::path(
+    put,
+    path = "/api/room_bookings/{id}",
+    params(
+        ("id" = i64, Path, description = "Room booking ID")
+    ),
+    request_body = UpdateRoomBookingRequest,
+    responses(
+        (status = 200, description = "Room booking updated", body = RoomBookingResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Room booking not found"),
+        (status = 409, description = "Room is unavailable or already in use")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateRoomBookingRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .update(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Cancel room booking
+#[utoipa::path(
+    delete,
+    path = "/api/room_bookings/{id}",
+    params(
+        ("id" = i64, Path, description = "Room booking ID")
+    ),
+    responses(
+        (status = 204, description = "Room booking cancelled"),
+        (status = 404, description = "Room booking not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .room_booking_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Search free rooms by capacity, type, facilities and time
+#[utoipa::path(
+    get,
+    path = "/api/room_bookings/search",
+    params(RoomSearchQuery),
+    responses(
+        (status = 200, description = "Matching free rooms", body = Vec<RoomSearchResult>),
+        (status = 400, description = "Bad request")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn search(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<RoomSearchQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .search(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Weekly room occupancy (lessons and bookings)
+#[utoipa::path(
+    get,
+    path = "/api/room_bookings/occupancy",
+    params(OccupancyQuery),
+    responses(
+        (status = 200, description = "Room occupancy per day", body = Vec<RoomOccupancyResponse>),
+        (status = 404, description = "Room not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Room Booking "
+)]
+pub async fn occupancy(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<OccupancyQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .room_booking_service
+        .occupancy(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
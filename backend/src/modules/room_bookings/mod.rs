@@ -0,0 +1,22 @@
+// backend/src/modules/room_bookings/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod availability;
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::RoomBookingRepository;
+pub use service::RoomBookingService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> RoomBookingService {
+    let repository = RoomBookingRepository::new(db);
+    RoomBookingService::new(repository)
+}
@@ -0,0 +1,282 @@
+// ============================================================================
+// backend/src/modules/room_bookings/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::RoomBookingFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::room_bookings::{self, Entity as RoomBooking};
+use entity::sea_orm_active_enums::ScheduleStatus;
+use entity::traits::soft_delete::SoftDelete;
+use entity::{
+    class_schedules, class_subjects, classes, facilities, room_facilities, rooms, semesters,
+    subjects, time_slots,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+
+#[derive(Clone)]
+pub struct RoomBookingRepository {
+    db: Database,
+}
+
+impl RoomBookingRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create booking
+    pub async fn create(
+        &self,
+        active_model: room_bookings::ActiveModel,
+    ) -> Result<room_bookings::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find booking by ID (not deleted)
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<room_bookings::Model>, AppError> {
+        RoomBooking::find_not_deleted()
+            .filter(room_bookings::Column::Id.eq(id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all bookings with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &RoomBookingFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<room_bookings::Model>, u64), AppError> {
+        let mut query = RoomBooking::find_not_deleted()
+            .filter(room_bookings::Column::FoundationId.eq(foundation_id));
+
+        if let Some(room_id) = filters.room_id {
+            query = query.filter(room_bookings::Column::RoomId.eq(room_id));
+        }
+        if let Some(date_from) = filters.date_from {
+            query = query.filter(room_bookings::Column::Date.gte(date_from));
+        }
+        if let Some(date_to) = filters.date_to {
+            query = query.filter(room_bookings::Column::Date.lte(date_to));
+        }
+        if let Some(ref purpose) = filters.purpose {
+            query = query.filter(room_bookings::Column::Purpose.eq(purpose.clone()));
+        }
+
+        // Apply search filter if provided
+        if let Some(ref search) = params.search {
+            query = query.filter(room_bookings::Column::Title.contains(search));
+        }
+
+        let paginator = query
+            .order_by_asc(room_bookings::Column::Date)
+            .order_by_asc(room_bookings::Column::StartTime)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find bookings (not deleted) of the given rooms within a date range
+    pub async fn find_bookings(
+        &self,
+        room_ids: Vec<i64>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<room_bookings::Model>, AppError> {
+        RoomBooking::find_not_deleted()
+            .filter(room_bookings::Column::RoomId.is_in(room_ids))
+            .filter(room_bookings::Column::Date.between(from, to))
+            .order_by_asc(room_bookings::Column::StartTime)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update booking
+    pub async fn update(
+        &self,
+        active_model: room_bookings::ActiveModel,
+    ) -> Result<room_bookings::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Soft delete booking
+    pub async fn soft_delete(&self, id: i64) -> Result<(), AppError> {
+        let model = room_bookings::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Find room of a foundation
+    pub async fn find_room(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<rooms::Model>, AppError> {
+        rooms::Entity::find_by_id(id)
+            .filter(rooms::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find rooms of a foundation with optional static filters
+    pub async fn find_rooms(
+        &self,
+        foundation_id: i64,
+        room_id: Option<i64>,
+        unit_id: Option<i64>,
+        room_type_id: Option<i64>,
+        min_capacity: Option<i32>,
+    ) -> Result<Vec<rooms::Model>, AppError> {
+        let mut query = rooms::Entity::find().filter(rooms::Column::FoundationId.eq(foundation_id));
+
+        if let Some(room_id) = room_id {
+            query = query.filter(rooms::Column::Id.eq(room_id));
+        }
+        if let Some(unit_id) = unit_id {
+            query = query.filter(rooms::Column::UnitId.eq(unit_id));
+        }
+        if let Some(room_type_id) = room_type_id {
+            query = query.filter(rooms::Column::RoomTypeId.eq(room_type_id));
+        }
+        if let Some(min_capacity) = min_capacity {
+            query = query.filter(rooms::Column::Capacity.gte(min_capacity));
+        }
+
+        query
+            .order_by_asc(rooms::Column::Capacity)
+            .order_by_asc(rooms::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find facilities installed in the given rooms
+    pub async fn find_room_facilities(
+        &self,
+        room_ids: Vec<i64>,
+    ) -> Result<Vec<(room_facilities::Model, Option<facilities::Model>)>, AppError> {
+        room_facilities::Entity::find()
+            .filter(room_facilities::Column::RoomId.is_in(room_ids))
+            .filter(room_facilities::Column::DeletedAt.is_null())
+            .find_also_related(facilities::Entity)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slot of a foundation (not deleted)
+    pub async fn find_time_slot(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::Id.eq(id))
+            .filter(time_slots::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots by IDs
+    pub async fn find_time_slots(&self, ids: Vec<i64>) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find published schedules held in the given rooms
+    pub async fn find_published_in_rooms(
+        &self,
+        room_ids: Vec<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        class_schedules::Entity::find()
+            .filter(class_schedules::Column::RoomId.is_in(room_ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a foundation by IDs (not deleted)
+    pub async fn find_class_subjects(
+        &self,
+        ids: Vec<i64>,
+        foundation_id: i64,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find()
+            .filter(class_subjects::Column::Id.is_in(ids))
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semesters by IDs (not deleted)
+    pub async fn find_semesters(&self, ids: Vec<i64>) -> Result<Vec<semesters::Model>, AppError> {
+        semesters::Entity::find()
+            .filter(semesters::Column::Id.is_in(ids))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find subjects by IDs
+    pub async fn find_subjects(&self, ids: Vec<i64>) -> Result<Vec<subjects::Model>, AppError> {
+        subjects::Entity::find()
+            .filter(subjects::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find classes by IDs
+    pub async fn find_classes(&self, ids: Vec<i64>) -> Result<Vec<classes::Model>, AppError> {
+        classes::Entity::find()
+            .filter(classes::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,27 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::room_bookings::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/room_bookings")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("", web::post().to(handler::create))
+            .route("/search", web::get().to(handler::search))
+            .route("/occupancy", web::get().to(handler::occupancy))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}", web::put().to(handler::update))
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("room_bookings.delete")),
+            ),
+    );
+}
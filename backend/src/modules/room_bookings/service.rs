@@ -0,0 +1,663 @@
+// ============================================================================
+// backend/src/modules/room_bookings/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::availability::{self, FacilityRequirement};
+use super::dto::{
+    CreateRoomBookingRequest, OccupancyDay, OccupancyEntry, OccupancyKind, OccupancyQuery,
+    RoomBookingFilters, RoomBookingResponse, RoomFacilityResponse, RoomOccupancyResponse,
+    RoomSearchQuery, RoomSearchResult, UpdateRoomBookingRequest,
+};
+use super::repository::RoomBookingRepository;
+use crate::errors::AppError;
+use crate::modules::schedules::conflicts::FacilityRef;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use crate::utils::time::overlaps;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use entity::room_bookings;
+use entity::rooms;
+use entity::sea_orm_active_enums::BookingPurpose;
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Jadwal pelajaran published yang memakai ruangan setiap minggu
+struct LessonOccurrence {
+    room_id: i64,
+    class_schedule_id: i64,
+    time_slot_id: i64,
+    day_of_week: i8,
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+    semester_start: NaiveDate,
+    semester_end: NaiveDate,
+    title: String,
+}
+
+impl LessonOccurrence {
+    fn happens_on(&self, date: NaiveDate) -> bool {
+        self.day_of_week == weekday(date)
+            && self.semester_start <= date
+            && date <= self.semester_end
+    }
+}
+
+#[derive(Clone)]
+pub struct RoomBookingService {
+    repository: RoomBookingRepository,
+}
+
+impl RoomBookingService {
+    pub fn new(repository: RoomBookingRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create room booking
+    pub async fn create(
+        &self,
+        request: CreateRoomBookingRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<RoomBookingResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let room = self
+            .find_bookable_room(request.room_id, foundation_id)
+            .await?;
+
+        let (start_time, end_time) = match request.time_slot_id {
+            Some(time_slot_id) => {
+                let slot = self
+                    .repository
+                    .find_time_slot(time_slot_id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Time slot not found".to_string()))?;
+                if slot.day_of_week != weekday(request.date) {
+                    return Err(AppError::validation(format!(
+                        "Time slot {} is not on the same weekday as {}",
+                        slot.id, request.date
+                    )));
+                }
+                (slot.start_time, slot.end_time)
+            }
+            None => match (request.start_time.as_deref(), request.end_time.as_deref()) {
+                (Some(start), Some(end)) => (
+                    parse_time(start, "start_time")?,
+                    parse_time(end, "end_time")?,
+                ),
+                _ => {
+                    return Err(AppError::validation(
+                        "Either time_slot_id or start_time and end_time are required".to_string(),
+                    ))
+                }
+            },
+        };
+        ensure_range(start_time, end_time)?;
+        ensure_capacity(&room, request.attendees)?;
+
+        self.ensure_free(&room, request.date, (start_time, end_time), None)
+            .await?;
+
+        let now = chrono::Utc::now();
+        let model = room_bookings::ActiveModel {
+            foundation_id: Set(foundation_id),
+            room_id: Set(room.id),
+            title: Set(request.title),
+            purpose: Set(request.purpose.unwrap_or(BookingPurpose::Event)),
+            date: Set(request.date),
+            start_time: Set(start_time),
+            end_time: Set(end_time),
+            attendees: Set(request.attendees),
+            notes: Set(request.notes),
+            booked_by: Set(Some(user_id)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let booking = self.repository.create(model).await?;
+        Ok(RoomBookingResponse::from(booking))
+    }
+
+    /// Get booking by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<RoomBookingResponse, AppError> {
+        let booking = self.find_scoped(id, foundation_id).await?;
+        Ok(RoomBookingResponse::from(booking))
+    }
+
+    /// Get all bookings with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: RoomBookingFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<RoomBookingResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let responses: Vec<RoomBookingResponse> =
+            items.into_iter().map(RoomBookingResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Update booking
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateRoomBookingRequest,
+        foundation_id: i64,
+    ) -> Result<RoomBookingResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self.find_scoped(id, foundation_id).await?;
+
+        let room = self
+            .find_bookable_room(request.room_id.unwrap_or(existing.room_id), foundation_id)
+            .await?;
+        let date = request.date.unwrap_or(existing.date);
+        let start_time = match request.start_time.as_deref() {
+            Some(value) => parse_time(value, "start_time")?,
+            None => existing.start_time,
+        };
+        let end_time = match request.end_time.as_deref() {
+            Some(value) => parse_time(value, "end_time")?,
+            None => existing.end_time,
+        };
+        ensure_range(start_time, end_time)?;
+        ensure_capacity(&room, request.attendees.or(existing.attendees))?;
+
+        self.ensure_free(&room, date, (start_time, end_time), Some(existing.id))
+            .await?;
+
+        let mut active_model: room_bookings::ActiveModel = existing.into();
+        active_model.room_id = Set(room.id);
+        active_model.date = Set(date);
+        active_model.start_time = Set(start_time);
+        active_model.end_time = Set(end_time);
+        if let Some(title) = request.title {
+            active_model.title = Set(title);
+        }
+        if let Some(purpose) = request.purpose {
+            active_model.purpose = Set(purpose);
+        }
+        if let Some(attendees) = request.attendees {
+            active_model.attendees = Set(Some(attendees));
+        }
+        if let Some(notes) = request.notes {
+            active_model.notes = Set(Some(notes));
+        }
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let booking = self.repository.update(active_model).await?;
+        Ok(RoomBookingResponse::from(booking))
+    }
+
+    /// Cancel booking (soft delete)
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        let booking = self.find_scoped(id, foundation_id).await?;
+        self.repository.soft_delete(booking.id).await
+    }
+
+    /// Search free rooms by capacity, type, facilities and time
+    pub async fn search(
+        &self,
+        query: RoomSearchQuery,
+        foundation_id: i64,
+    ) -> Result<Vec<RoomSearchResult>, AppError> {
+        let requirements: Vec<FacilityRequirement> = match query.facilities.as_deref() {
+            Some(raw) => availability::parse_requirements(raw).map_err(AppError::validation)?,
+            None => Vec::new(),
+        };
+
+        let slot = match query.time_slot_id {
+            Some(id) => Some(
+                self.repository
+                    .find_time_slot(id, foundation_id)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("Time slot not found".to_string()))?,
+            ),
+            None => None,
+        };
+
+        let window = match (
+            query.start_time.as_deref(),
+            query.end_time.as_deref(),
+            &slot,
+        ) {
+            (Some(start), Some(end), _) => {
+                let window = (
+                    parse_time(start, "start_time")?,
+                    parse_time(end, "end_time")?,
+                );
+                ensure_range(window.0, window.1)?;
+                Some(window)
+            }
+            (None, None, Some(slot)) => Some((slot.start_time, slot.end_time)),
+            (None, None, None) => None,
+            _ => {
+                return Err(AppError::validation(
+                    "start_time and end_time must be given together".to_string(),
+                ))
+            }
+        };
+
+        let day = query
+            .date
+            .map(weekday)
+            .or(query.day_of_week)
+            .or(slot.as_ref().map(|s| s.day_of_week));
+        if let (Some(slot), Some(day)) = (&slot, day) {
+            if slot.day_of_week != day {
+                return Err(AppError::validation(format!(
+                    "Time slot {} is not on day {}",
+                    slot.id, day
+                )));
+            }
+        }
+        if window.is_some() && day.is_none() {
+            return Err(AppError::validation(
+                "date or day_of_week is required for a time-based search".to_string(),
+            ));
+        }
+
+        // Filter statis: unit, tipe, kapasitas & ruangan yang bisa dipakai
+        let rooms: Vec<rooms::Model> = self
+            .repository
+            .find_rooms(
+                foundation_id,
+                None,
+                query.unit_id,
+                query.room_type_id,
+                query.min_capacity,
+            )
+            .await?
+            .into_iter()
+            .filter(|r| r.is_available.unwrap_or(1) != 0)
+            .collect();
+
+        let facilities = self
+            .room_facilities(rooms.iter().map(|r| r.id).collect())
+            .await?;
+        let mut rooms: Vec<rooms::Model> = rooms
+            .into_iter()
+            .filter(|r| {
+                requirements.is_empty()
+                    || facilities.get(&r.id).is_some_and(|(installed, _)| {
+                        availability::satisfies(installed, &requirements)
+                    })
+            })
+            .collect();
+
+        // Filter waktu: jadwal pelajaran published & booking lain
+        if let (Some(window), Some(day)) = (window, day) {
+            let room_ids: Vec<i64> = rooms.iter().map(|r| r.id).collect();
+            let today = chrono::Utc::now().date_naive();
+            let mut busy: HashSet<i64> = self
+                .lessons(room_ids.clone(), foundation_id, false)
+                .await?
+                .into_iter()
+                .filter(|lesson| match query.date {
+                    Some(date) => lesson.happens_on(date),
+                    // Tanpa tanggal: semester yang sedang berjalan atau akan datang
+                    None => lesson.day_of_week == day && lesson.semester_end >= today,
+                })
+                .filter(|lesson| overlaps(lesson.start_time, lesson.end_time, window.0, window.1))
+                .map(|lesson| lesson.room_id)
+                .collect();
+
+            if let Some(date) = query.date {
+                busy.extend(
+                    self.repository
+                        .find_bookings(room_ids, date, date)
+                        .await?
+                        .into_iter()
+                        .filter(|b| overlaps(b.start_time, b.end_time, window.0, window.1))
+                        .map(|b| b.room_id),
+                );
+            }
+
+            rooms.retain(|r| !busy.contains(&r.id));
+        }
+
+        let mut facilities = facilities;
+        Ok(rooms
+            .into_iter()
+            .map(|room| RoomSearchResult {
+                facilities: facilities
+                    .remove(&room.id)
+                    .map(|(_, list)| list)
+                    .unwrap_or_default(),
+                room_id: room.id,
+                name: room.name,
+                unit_id: room.unit_id,
+                room_type_id: room.room_type_id,
+                capacity: room.capacity,
+            })
+            .collect())
+    }
+
+    /// Weekly occupancy (lessons + bookings) per room
+    pub async fn occupancy(
+        &self,
+        query: OccupancyQuery,
+        foundation_id: i64,
+    ) -> Result<Vec<RoomOccupancyResponse>, AppError> {
+        let monday = query.week_of - Duration::days(weekday(query.week_of) as i64 - 1);
+        let sunday = monday + Duration::days(6);
+
+        let rooms = self
+            .repository
+            .find_rooms(foundation_id, query.room_id, query.unit_id, None, None)
+            .await?;
+        if query.room_id.is_some() && rooms.is_empty() {
+            return Err(AppError::not_found("Room not found".to_string()));
+        }
+        let room_ids: Vec<i64> = rooms.iter().map(|r| r.id).collect();
+
+        let lessons = self.lessons(room_ids.clone(), foundation_id, true).await?;
+        let bookings = self
+            .repository
+            .find_bookings(room_ids, monday, sunday)
+            .await?;
+
+        Ok(rooms
+            .into_iter()
+            .map(|room| {
+                let days = (0..7)
+                    .map(|offset| {
+                        let date = monday + Duration::days(offset);
+                        let mut entries: Vec<OccupancyEntry> = lessons
+                            .iter()
+                            .filter(|l| l.room_id == room.id && l.happens_on(date))
+                            .map(|l| OccupancyEntry {
+                                kind: OccupancyKind::Lesson,
+                                start_time: l.start_time.to_string(),
+                                end_time: l.end_time.to_string(),
+                                title: l.title.clone(),
+                                class_schedule_id: Some(l.class_schedule_id),
+                                time_slot_id: Some(l.time_slot_id),
+                                booking_id: None,
+                            })
+                            .chain(
+                                bookings
+                                    .iter()
+                                    .filter(|b| b.room_id == room.id && b.date == date)
+                                    .map(|b| OccupancyEntry {
+                                        kind: OccupancyKind::Booking,
+                                        start_time: b.start_time.to_string(),
+                                        end_time: b.end_time.to_string(),
+                                        title: b.title.clone(),
+                                        class_schedule_id: None,
+                                        time_slot_id: None,
+                                        booking_id: Some(b.id),
+                                    }),
+                            )
+                            .collect();
+                        // Format HH:MM:SS bisa diurutkan secara leksikal
+                        entries.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+
+                        OccupancyDay {
+                            date: date.to_string(),
+                            day_of_week: weekday(date),
+                            entries,
+                        }
+                    })
+                    .collect();
+
+                RoomOccupancyResponse {
+                    room_id: room.id,
+                    name: room.name,
+                    is_available: room.is_available.unwrap_or(1) != 0,
+                    days,
+                }
+            })
+            .collect())
+    }
+
+    async fn find_scoped(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<room_bookings::Model, AppError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .filter(|b| b.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Room booking not found".to_string()))
+    }
+
+    async fn find_bookable_room(
+        &self,
+        room_id: i64,
+        foundation_id: i64,
+    ) -> Result<rooms::Model, AppError> {
+        let room = self
+            .repository
+            .find_room(room_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Room not found".to_string()))?;
+
+        // Business rule: ruangan yang ditandai tidak tersedia tidak bisa dibooking
+        if room.is_available == Some(0) {
+            return Err(AppError::ConflictError(format!(
+                "Room '{}' is not available for booking",
+                room.name
+            )));
+        }
+        Ok(room)
+    }
+
+    /// Tolak booking yang beririsan dengan jadwal pelajaran atau booking lain
+    async fn ensure_free(
+        &self,
+        room: &rooms::Model,
+        date: NaiveDate,
+        window: (NaiveTime, NaiveTime),
+        exclude_booking_id: Option<i64>,
+    ) -> Result<(), AppError> {
+        let mut clashes: Vec<String> = self
+            .lessons(vec![room.id], room.foundation_id, true)
+            .await?
+            .into_iter()
+            .filter(|l| l.happens_on(date))
+            .filter(|l| overlaps(l.start_time, l.end_time, window.0, window.1))
+            .map(|l| format!("lesson {} ({}-{})", l.title, l.start_time, l.end_time))
+            .collect();
+
+        clashes.extend(
+            self.repository
+                .find_bookings(vec![room.id], date, date)
+                .await?
+                .into_iter()
+                .filter(|b| Some(b.id) != exclude_booking_id)
+                .filter(|b| overlaps(b.start_time, b.end_time, window.0, window.1))
+                .map(|b| format!("booking '{}' ({}-{})", b.title, b.start_time, b.end_time)),
+        );
+
+        if clashes.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ConflictError(format!(
+                "Room '{}' is already in use on {}: {}",
+                room.name,
+                date,
+                clashes.join("; ")
+            )))
+        }
+    }
+
+    /// Facility per ruangan: map untuk pencocokan (ID & nama) dan daftar untuk response
+    async fn room_facilities(
+        &self,
+        room_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, (HashMap<FacilityRef, i32>, Vec<RoomFacilityResponse>)>, AppError>
+    {
+        let mut result: HashMap<i64, (HashMap<FacilityRef, i32>, Vec<RoomFacilityResponse>)> =
+            HashMap::new();
+
+        for (room_facility, facility) in self.repository.find_room_facilities(room_ids).await? {
+            let qty = room_facility.qty.unwrap_or(1);
+            let (installed, list) = result.entry(room_facility.room_id).or_default();
+
+            *installed
+                .entry(FacilityRef::Id(room_facility.facility_id))
+                .or_insert(0) += qty;
+            if let Some(facility) = &facility {
+                *installed
+                    .entry(FacilityRef::Name(facility.name.trim().to_lowercase()))
+                    .or_insert(0) += qty;
+            }
+            list.push(RoomFacilityResponse {
+                facility_id: room_facility.facility_id,
+                name: facility.map(|f| f.name),
+                qty,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Jadwal pelajaran published di ruangan-ruangan tersebut beserta rentang semesternya
+    async fn lessons(
+        &self,
+        room_ids: Vec<i64>,
+        foundation_id: i64,
+        with_titles: bool,
+    ) -> Result<Vec<LessonOccurrence>, AppError> {
+        let schedules = self.repository.find_published_in_rooms(room_ids).await?;
+        if schedules.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let class_subjects: HashMap<i64, entity::class_subjects::Model> = self
+            .repository
+            .find_class_subjects(
+                unique(schedules.iter().map(|s| s.class_subject_id)),
+                foundation_id,
+            )
+            .await?
+            .into_iter()
+            .map(|cs| (cs.id, cs))
+            .collect();
+        let semesters: HashMap<i64, (NaiveDate, NaiveDate)> = self
+            .repository
+            .find_semesters(unique(class_subjects.values().map(|cs| cs.semester_id)))
+            .await?
+            .into_iter()
+            .map(|s| (s.id, (s.start_date, s.end_date)))
+            .collect();
+        let slots: HashMap<i64, entity::time_slots::Model> = self
+            .repository
+            .find_time_slots(unique(schedules.iter().map(|s| s.time_slot_id)))
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+        let (subjects, classes): (HashMap<i64, String>, HashMap<i64, String>) = if with_titles {
+            (
+                self.repository
+                    .find_subjects(unique(class_subjects.values().map(|cs| cs.subject_id)))
+                    .await?
+                    .into_iter()
+                    .map(|s| (s.id, s.name))
+                    .collect(),
+                self.repository
+                    .find_classes(unique(class_subjects.values().map(|cs| cs.class_id)))
+                    .await?
+                    .into_iter()
+                    .map(|c| (c.id, c.name))
+                    .collect(),
+            )
+        } else {
+            (HashMap::new(), HashMap::new())
+        };
+
+        Ok(schedules
+            .into_iter()
+            .filter_map(|schedule| {
+                let class_subject = class_subjects.get(&schedule.class_subject_id)?;
+                let (semester_start, semester_end) = *semesters.get(&class_subject.semester_id)?;
+                let slot = slots.get(&schedule.time_slot_id)?;
+
+                let subject = subjects
+                    .get(&class_subject.subject_id)
+                    .map(String::as_str)
+                    .unwrap_or("Lesson");
+                let title = match classes.get(&class_subject.class_id) {
+                    Some(class) => format!("{} - {}", subject, class),
+                    None => subject.to_string(),
+                };
+
+                Some(LessonOccurrence {
+                    room_id: schedule.room_id?,
+                    class_schedule_id: schedule.id,
+                    time_slot_id: slot.id,
+                    day_of_week: slot.day_of_week,
+                    start_time: slot.start_time,
+                    end_time: slot.end_time,
+                    semester_start,
+                    semester_end,
+                    title,
+                })
+            })
+            .collect())
+    }
+}
+
+fn weekday(date: NaiveDate) -> i8 {
+    date.weekday().number_from_monday() as i8
+}
+
+fn unique(ids: impl Iterator<Item = i64>) -> Vec<i64> {
+    let set: HashSet<i64> = ids.collect();
+    set.into_iter().collect()
+}
+
+fn parse_time(value: &str, field: &str) -> Result<NaiveTime, AppError> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map_err(|_| AppError::ValidationError(format!("Invalid {} format. Use HH:MM", field)))
+}
+
+fn ensure_range(start_time: NaiveTime, end_time: NaiveTime) -> Result<(), AppError> {
+    if end_time <= start_time {
+        return Err(AppError::ValidationError(
+            "End time must be after start time".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn ensure_capacity(room: &rooms::Model, attendees: Option<i32>) -> Result<(), AppError> {
+    match attendees {
+        Some(attendees) if attendees > room.capacity => Err(AppError::ValidationError(format!(
+            "Room '{}' holds {} people but {} are expected",
+            room.name, room.capacity, attendees
+        ))),
+        _ => Ok(()),
+    }
+}
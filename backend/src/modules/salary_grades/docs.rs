@@ -0,0 +1,66 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    CreateSalaryGradeRequest, EvaluatePromotionsRequest, EvaluatePromotionsResponse,
+    PromotionFilters, PromotionResponse, ReviewPromotionRequest, SalaryGradeFilters,
+    SalaryGradeResponse, SkippedTeacher, UpdateSalaryGradeRequest,
+};
+use super::handler;
+use crate::utils::pagination::PaginatedResponse;
+use entity::sea_orm_active_enums::PromotionStatus;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::create,
+        handler::get_all,
+        handler::get_by_id,
+        handler::update,
+        handler::delete,
+        handler::evaluate_promotions,
+        handler::get_promotions,
+        handler::approve_promotion,
+        handler::reject_promotion,
+    ),
+    components(
+        schemas(
+            PromotionStatus,
+            SalaryGradeResponse,
+            CreateSalaryGradeRequest,
+            UpdateSalaryGradeRequest,
+            SalaryGradeFilters,
+            PromotionResponse,
+            EvaluatePromotionsRequest,
+            EvaluatePromotionsResponse,
+            SkippedTeacher,
+            PromotionFilters,
+            ReviewPromotionRequest,
+            PaginatedResponse<SalaryGradeResponse>,
+            PaginatedResponse<PromotionResponse>,
+        )
+    ),
+    tags(
+        (name = "Salary Grade ", description = "Salary grades and grade promotions by service years and qualification")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct SalaryGradesApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// backend/src/modules/salary_grades/dto.rs
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::PromotionStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SalaryGradeResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub name: String,
+    pub code: String,
+    /// Jenjang minimal, mis. "S1"; kosong = semua jenjang
+    pub education_level: Option<String>,
+    pub min_years_experience: Option<i32>,
+    pub base_salary: f64,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSalaryGradeRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(min = 1, max = 20))]
+    pub code: String,
+    #[validate(length(max = 20))]
+    pub education_level: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub min_years_experience: Option<i32>,
+    #[validate(range(min = 0.0))]
+    pub base_salary: f64,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateSalaryGradeRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(min = 1, max = 20))]
+    pub code: Option<String>,
+    #[validate(length(max = 20))]
+    pub education_level: Option<String>,
+    #[validate(range(min = 0, max = 60))]
+    pub min_years_experience: Option<i32>,
+    #[validate(range(min = 0.0))]
+    pub base_salary: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SalaryGradeFilters {
+    pub education_level: Option<String>,
+    /// Cari di nama atau kode
+    pub search: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PromotionResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub teacher_id: i64,
+    pub teacher_name: Option<String>,
+    pub teacher_assignment_id: i64,
+    pub from_teacher_salary_id: Option<i64>,
+    pub from_salary_grade_id: Option<i64>,
+    pub from_grade_name: Option<String>,
+    pub to_salary_grade_id: i64,
+    pub to_grade_name: Option<String>,
+    /// Gaji pokok golongan tujuan
+    pub new_base_salary: Option<f64>,
+    pub years_of_service: i32,
+    pub qualification: Option<String>,
+    pub status: PromotionStatus,
+    pub effective_date: NaiveDate,
+    /// Baris teacher_salaries yang dibuat saat disetujui
+    pub new_teacher_salary_id: Option<i64>,
+    pub reviewed_by: Option<i64>,
+    pub reviewed_at: Option<String>,
+    pub review_notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EvaluatePromotionsRequest {
+    /// Tanggal acuan masa kerja; default hari ini
+    pub as_of: Option<NaiveDate>,
+    /// Tanggal berlaku usulan; default awal bulan berikutnya
+    pub effective_date: Option<NaiveDate>,
+}
+
+/// Penugasan yang tidak bisa dievaluasi
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SkippedTeacher {
+    pub teacher_id: i64,
+    pub teacher_assignment_id: i64,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EvaluatePromotionsResponse {
+    pub evaluated: usize,
+    pub proposed: Vec<PromotionResponse>,
+    pub skipped: Vec<SkippedTeacher>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PromotionFilters {
+    pub status: Option<PromotionStatus>,
+    pub teacher_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReviewPromotionRequest {
+    /// Hanya untuk persetujuan; menggantikan tanggal berlaku usulan
+    pub effective_date: Option<NaiveDate>,
+    #[validate(length(max = 1000))]
+    pub review_notes: Option<String>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::salary_grades::Model> for SalaryGradeResponse {
+    fn from(model: entity::salary_grades::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            name: model.name,
+            code: model.code,
+            education_level: model.education_level,
+            min_years_experience: model.min_years_experience,
+            base_salary: model.base_salary.try_into().unwrap_or_default(),
+            created_at: model.created_at.map(|t| t.to_string()),
+            updated_at: model.updated_at.map(|t| t.to_string()),
+        }
+    }
+}
+
+impl PromotionResponse {
+    pub fn new(
+        model: entity::salary_grade_promotions::Model,
+        teacher_name: Option<String>,
+        from_grade: Option<&entity::salary_grades::Model>,
+        to_grade: Option<&entity::salary_grades::Model>,
+    ) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            teacher_id: model.teacher_id,
+            teacher_name,
+            teacher_assignment_id: model.teacher_assignment_id,
+            from_teacher_salary_id: model.from_teacher_salary_id,
+            from_salary_grade_id: model.from_salary_grade_id,
+            from_grade_name: from_grade.map(|g| g.name.clone()),
+            to_salary_grade_id: model.to_salary_grade_id,
+            to_grade_name: to_grade.map(|g| g.name.clone()),
+            new_base_salary: to_grade.and_then(|g| g.base_salary.try_into().ok()),
+            years_of_service: model.years_of_service,
+            qualification: model.qualification,
+            status: model.status,
+            effective_date: model.effective_date,
+            new_teacher_salary_id: model.new_teacher_salary_id,
+            reviewed_by: model.reviewed_by,
+            reviewed_at: model.reviewed_at.map(|t| t.to_string()),
+            review_notes: model.review_notes,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
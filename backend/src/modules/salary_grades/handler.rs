@@ -0,0 +1,270 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    CreateSalaryGradeRequest, EvaluatePromotionsRequest, EvaluatePromotionsResponse,
+    PromotionFilters, PromotionResponse, ReviewPromotionRequest, SalaryGradeFilters,
+    SalaryGradeResponse, UpdateSalaryGradeRequest,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Create new salary grade
+#[utoipa::path(
+    post,
+    path = "/api/salary_grades",
+    request_body = CreateSalaryGradeRequest,
+    responses(
+        (status = 201, description = "Salary grade created", body = SalaryGradeResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Code already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateSalaryGradeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .create(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get all salary grades with pagination
+#[utoipa::path(
+    get,
+    path = "/api/salary_grades",
+    params(PaginationParams, SalaryGradeFilters),
+    responses(
+        (status = 200, description = "List of salary grades", body = PaginatedResponse<SalaryGradeResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    params: web::Query<PaginationParams>,
+    filters: web::Query<SalaryGradeFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .get_all(
+            params.into_inner(),
+            filters.into_inner(),
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get salary grade by ID
+#[utoipa::path(
+    get,
+    path = "/api/salary_grades/{id}",
+    params(
+        ("id" = i64, Path, description = "Salary grade ID")
+    ),
+    responses(
+        (status = 200, description = "Salary grade found", body = SalaryGradeResponse),
+        (status = 404, description = "Salary grade not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .get_by_id(path.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update salary grade
+#[utoipa::path(
+    put,
+    path = "/api/salary_grades/{id}",
+    params(
+        ("id" = i64, Path, description = "Salary grade ID")
+    ),
+    request_body = UpdateSalaryGradeRequest,
+    responses(
+        (status = 200, description = "Salary grade updated", body = SalaryGradeResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Salary grade not found"),
+        (status = 409, description = "Code already exists")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+    request: web::Json<UpdateSalaryGradeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .update(path.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete salary grade
+#[utoipa::path(
+    delete,
+    path = "/api/salary_grades/{id}",
+    params(
+        ("id" = i64, Path, description = "Salary grade ID")
+    ),
+    responses(
+        (status = 204, description = "Salary grade deleted"),
+        (status = 404, description = "Salary grade not found"),
+        (status = 409, description = "Salary grade is in use")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .salary_grade_service
+        .delete(path.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Evaluate teachers' service years & qualification and propose grade promotions
+#[utoipa::path(
+    post,
+    path = "/api/salary_grades/promotions/evaluate",
+    request_body = EvaluatePromotionsRequest,
+    responses(
+        (status = 200, description = "New pending promotions and skipped assignments", body = EvaluatePromotionsResponse),
+        (status = 400, description = "No salary grades defined")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn evaluate_promotions(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<EvaluatePromotionsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .evaluate_promotions(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get grade promotions with pagination
+#[utoipa::path(
+    get,
+    path = "/api/salary_grades/promotions",
+    params(PaginationParams, PromotionFilters),
+    responses(
+        (status = 200, description = "List of promotions", body = PaginatedResponse<PromotionResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn get_promotions(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    params: web::Query<PaginationParams>,
+    filters: web::Query<PromotionFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .get_promotions(
+            params.into_inner(),
+            filters.into_inner(),
+            auth.foundation_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Approve a promotion; creates a new teacher salary row from the effective date
+#[utoipa::path(
+    post,
+    path = "/api/salary_grades/promotions/{id}/approve",
+    params(
+        ("id" = i64, Path, description = "Promotion ID")
+    ),
+    request_body = ReviewPromotionRequest,
+    responses(
+        (status = 200, description = "Promotion approved", body = PromotionResponse),
+        (status = 400, description = "Effective date not after the current salary"),
+        (status = 404, description = "Promotion not found"),
+        (status = 409, description = "Promotion is not pending")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn approve_promotion(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+    request: web::Json<ReviewPromotionRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .approve_promotion(
+            path.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Reject a promotion
+#[utoipa::path(
+    post,
+    path = "/api/salary_grades/promotions/{id}/reject",
+    params(
+        ("id" = i64, Path, description = "Promotion ID")
+    ),
+    request_body = ReviewPromotionRequest,
+    responses(
+        (status = 200, description = "Promotion rejected", body = PromotionResponse),
+        (status = 404, description = "Promotion not found"),
+        (status = 409, description = "Promotion is not pending")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Salary Grade "
+)]
+pub async fn reject_promotion(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+    request: web::Json<ReviewPromotionRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .salary_grade_service
+        .reject_promotion(
+            path.into_inner(),
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
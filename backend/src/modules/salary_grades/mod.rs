@@ -0,0 +1,22 @@
+// backend/src/modules/salary_grades/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod progression;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::SalaryGradeRepository;
+pub use service::SalaryGradeService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> SalaryGradeService {
+    let repository = SalaryGradeRepository::new(db);
+    SalaryGradeService::new(repository)
+}
@@ -0,0 +1,168 @@
+// ============================================================================
+// backend/src/modules/salary_grades/progression.rs
+// progression.rs - Aturan kenaikan golongan gaji (pure, tanpa akses database)
+// ============================================================================
+use chrono::{Datelike, NaiveDate};
+use entity::salary_grades;
+
+/// Peringkat jenjang pendidikan; dikenali dari token pertama yang cocok,
+/// mis. "S1 Pendidikan Matematika" -> S1
+pub fn education_rank(value: &str) -> Option<u8> {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .find_map(|token| match token.to_ascii_uppercase().as_str() {
+            "SMA" | "SMK" | "SLTA" | "MA" | "MAK" => Some(1),
+            "D1" | "D2" => Some(2),
+            "D3" => Some(3),
+            "D4" | "S1" => Some(4),
+            "S2" => Some(5),
+            "S3" => Some(6),
+            _ => None,
+        })
+}
+
+/// Masa kerja dalam tahun penuh per tanggal `on`
+pub fn years_of_service(hire_date: NaiveDate, on: NaiveDate) -> i32 {
+    if on < hire_date {
+        return 0;
+    }
+    let mut years = on.year() - hire_date.year();
+    if (on.month(), on.day()) < (hire_date.month(), hire_date.day()) {
+        years -= 1;
+    }
+    years
+}
+
+/// Guru memenuhi syarat golongan bila masa kerja cukup dan pendidikannya setara
+/// atau lebih tinggi; golongan yang mensyaratkan pendidikan tidak berlaku bagi
+/// guru yang kualifikasinya tidak dikenali.
+pub fn qualifies(grade: &salary_grades::Model, years: i32, qualification: Option<&str>) -> bool {
+    if years < grade.min_years_experience.unwrap_or(0) {
+        return false;
+    }
+    match grade.education_level.as_deref().and_then(education_rank) {
+        None => true,
+        Some(required) => qualification
+            .and_then(education_rank)
+            .is_some_and(|rank| rank >= required),
+    }
+}
+
+/// Golongan tertinggi (gaji pokok terbesar) yang memenuhi syarat, hanya bila
+/// lebih tinggi dari gaji pokok saat ini — tidak ada penurunan golongan
+pub fn propose<'a>(
+    grades: &'a [salary_grades::Model],
+    current_grade_id: Option<i64>,
+    current_base_salary: Option<sea_orm::prelude::Decimal>,
+    years: i32,
+    qualification: Option<&str>,
+) -> Option<&'a salary_grades::Model> {
+    let best = grades
+        .iter()
+        .filter(|grade| qualifies(grade, years, qualification))
+        .max_by(|a, b| {
+            a.base_salary
+                .cmp(&b.base_salary)
+                .then(a.min_years_experience.cmp(&b.min_years_experience))
+        })?;
+
+    let is_promotion = Some(best.id) != current_grade_id
+        && current_base_salary.is_none_or(|current| best.base_salary > current);
+    is_promotion.then_some(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::prelude::Decimal;
+
+    fn grade(id: i64, education: Option<&str>, years: i32, base: i64) -> salary_grades::Model {
+        salary_grades::Model {
+            id,
+            foundation_id: 1,
+            name: format!("Golongan {}", id),
+            code: format!("G{}", id),
+            education_level: education.map(str::to_string),
+            min_years_experience: Some(years),
+            base_salary: Decimal::from(base),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_education_and_years_of_service() {
+        assert_eq!(education_rank("S1 Pendidikan Matematika"), Some(4));
+        assert_eq!(education_rank("s2"), Some(5));
+        assert_eq!(education_rank("D-III"), None);
+        assert_eq!(education_rank("SMK Negeri"), Some(1));
+
+        let hired = NaiveDate::from_ymd_opt(2020, 7, 15).unwrap();
+        assert_eq!(
+            years_of_service(hired, NaiveDate::from_ymd_opt(2026, 7, 14).unwrap()),
+            5
+        );
+        assert_eq!(
+            years_of_service(hired, NaiveDate::from_ymd_opt(2026, 7, 15).unwrap()),
+            6
+        );
+        assert_eq!(
+            years_of_service(hired, NaiveDate::from_ymd_opt(2019, 1, 1).unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_propose_highest_eligible_grade_without_demotion() {
+        let grades = vec![
+            grade(1, Some("S1"), 0, 3_000_000),
+            grade(2, Some("S1"), 4, 3_600_000),
+            grade(3, Some("S2"), 4, 4_200_000),
+            grade(4, None, 10, 4_000_000),
+        ];
+
+        // S1, 5 tahun: golongan 3 butuh S2, golongan 4 butuh 10 tahun
+        let proposed = propose(
+            &grades,
+            Some(1),
+            Some(Decimal::from(3_000_000)),
+            5,
+            Some("S1"),
+        );
+        assert_eq!(proposed.map(|g| g.id), Some(2));
+
+        // Sudah di golongan terbaik
+        assert!(propose(
+            &grades,
+            Some(2),
+            Some(Decimal::from(3_600_000)),
+            5,
+            Some("S1")
+        )
+        .is_none());
+
+        // S2, 12 tahun -> golongan 3 (gaji pokok tertinggi)
+        let proposed = propose(
+            &grades,
+            Some(2),
+            Some(Decimal::from(3_600_000)),
+            12,
+            Some("S2"),
+        );
+        assert_eq!(proposed.map(|g| g.id), Some(3));
+
+        // Kualifikasi tidak dikenali hanya memenuhi golongan tanpa syarat pendidikan
+        let proposed = propose(&grades, None, None, 12, None);
+        assert_eq!(proposed.map(|g| g.id), Some(4));
+
+        // Gaji sekarang sudah lebih tinggi: bukan kenaikan
+        assert!(propose(
+            &grades,
+            None,
+            Some(Decimal::from(5_000_000)),
+            12,
+            Some("S2")
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,352 @@
+// ============================================================================
+// backend/src/modules/salary_grades/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::{PromotionFilters, SalaryGradeFilters};
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::salary_grades::{self, Entity as SalaryGrade};
+use entity::sea_orm_active_enums::PromotionStatus;
+use entity::{salary_grade_promotions, teacher_assignments, teacher_salaries, teachers};
+use sea_orm::sea_query::Query;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct SalaryGradeRepository {
+    db: Database,
+}
+
+impl SalaryGradeRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create new salary grade
+    pub async fn create(
+        &self,
+        active_model: salary_grades::ActiveModel,
+    ) -> Result<salary_grades::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update salary grade
+    pub async fn update(
+        &self,
+        active_model: salary_grades::ActiveModel,
+    ) -> Result<salary_grades::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Delete salary grade
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        SalaryGrade::delete_by_id(id)
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Find salary grade of a foundation
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<salary_grades::Model>, AppError> {
+        SalaryGrade::find_by_id(id)
+            .filter(salary_grades::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salary grade by code (code is unique across foundations)
+    pub async fn find_by_code(&self, code: &str) -> Result<Option<salary_grades::Model>, AppError> {
+        SalaryGrade::find()
+            .filter(salary_grades::Column::Code.eq(code))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &SalaryGradeFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<salary_grades::Model>, u64), AppError> {
+        let mut query =
+            SalaryGrade::find().filter(salary_grades::Column::FoundationId.eq(foundation_id));
+
+        if let Some(ref education_level) = filters.education_level {
+            query = query.filter(salary_grades::Column::EducationLevel.eq(education_level));
+        }
+        if let Some(ref search) = filters.search {
+            query = query.filter(
+                Condition::any()
+                    .add(salary_grades::Column::Name.contains(search))
+                    .add(salary_grades::Column::Code.contains(search)),
+            );
+        }
+
+        let paginator = query
+            .order_by_asc(salary_grades::Column::BaseSalary)
+            .order_by_asc(salary_grades::Column::Code)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find all salary grades of a foundation
+    pub async fn find_by_foundation(
+        &self,
+        foundation_id: i64,
+    ) -> Result<Vec<salary_grades::Model>, AppError> {
+        SalaryGrade::find()
+            .filter(salary_grades::Column::FoundationId.eq(foundation_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Whether a grade is used by a salary or a promotion
+    pub async fn is_in_use(&self, id: i64) -> Result<bool, AppError> {
+        let salaries = teacher_salaries::Entity::find()
+            .filter(teacher_salaries::Column::SalaryGradeId.eq(id))
+            .count(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let promotions = salary_grade_promotions::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(salary_grade_promotions::Column::ToSalaryGradeId.eq(id))
+                    .add(salary_grade_promotions::Column::FromSalaryGradeId.eq(id)),
+            )
+            .count(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(salaries + promotions > 0)
+    }
+
+    /// Find assignments of the foundation's teachers active on a date
+    pub async fn find_active_assignments(
+        &self,
+        foundation_id: i64,
+        on: NaiveDate,
+    ) -> Result<Vec<teacher_assignments::Model>, AppError> {
+        teacher_assignments::Entity::find()
+            .filter(teacher_assignments::Column::IsActive.eq(1))
+            .filter(teacher_assignments::Column::AssignmentStartDate.lte(on))
+            .filter(
+                Condition::any()
+                    .add(teacher_assignments::Column::AssignmentEndDate.is_null())
+                    .add(teacher_assignments::Column::AssignmentEndDate.gte(on)),
+            )
+            .filter(
+                teacher_assignments::Column::TeacherId.in_subquery(
+                    Query::select()
+                        .column(teachers::Column::Id)
+                        .from(teachers::Entity)
+                        .and_where(teachers::Column::FoundationId.eq(foundation_id))
+                        .and_where(teachers::Column::DeletedAt.is_null())
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(teacher_assignments::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers by ids
+    pub async fn find_teachers_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salaries of assignments, latest effective date first
+    pub async fn find_salaries_by_assignments(
+        &self,
+        assignment_ids: Vec<i64>,
+    ) -> Result<Vec<teacher_salaries::Model>, AppError> {
+        teacher_salaries::Entity::find()
+            .filter(teacher_salaries::Column::TeacherAssignmentId.is_in(assignment_ids))
+            .order_by_desc(teacher_salaries::Column::EffectiveDate)
+            .order_by_desc(teacher_salaries::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find salary by id
+    pub async fn find_salary(&self, id: i64) -> Result<Option<teacher_salaries::Model>, AppError> {
+        teacher_salaries::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Assignment ids of a foundation with a pending promotion
+    pub async fn find_pending_assignment_ids(
+        &self,
+        foundation_id: i64,
+    ) -> Result<Vec<i64>, AppError> {
+        salary_grade_promotions::Entity::find()
+            .select_only()
+            .column(salary_grade_promotions::Column::TeacherAssignmentId)
+            .filter(salary_grade_promotions::Column::FoundationId.eq(foundation_id))
+            .filter(salary_grade_promotions::Column::Status.eq(PromotionStatus::Pending))
+            .into_tuple()
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert proposed promotions in one transaction
+    pub async fn create_promotions(
+        &self,
+        models: Vec<salary_grade_promotions::ActiveModel>,
+    ) -> Result<Vec<salary_grade_promotions::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(models.len());
+        for model in models {
+            let row = model
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Find promotion of a foundation
+    pub async fn find_promotion(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<salary_grade_promotions::Model>, AppError> {
+        salary_grade_promotions::Entity::find_by_id(id)
+            .filter(salary_grade_promotions::Column::FoundationId.eq(foundation_id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find promotions with pagination and filters
+    pub async fn find_promotions(
+        &self,
+        params: &PaginationParams,
+        filters: &PromotionFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<salary_grade_promotions::Model>, u64), AppError> {
+        let mut query = salary_grade_promotions::Entity::find()
+            .filter(salary_grade_promotions::Column::FoundationId.eq(foundation_id));
+
+        if let Some(ref status) = filters.status {
+            query = query.filter(salary_grade_promotions::Column::Status.eq(status.clone()));
+        }
+        if let Some(teacher_id) = filters.teacher_id {
+            query = query.filter(salary_grade_promotions::Column::TeacherId.eq(teacher_id));
+        }
+
+        let paginator = query
+            .order_by_desc(salary_grade_promotions::Column::CreatedAt)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Update promotion
+    pub async fn update_promotion(
+        &self,
+        active_model: salary_grade_promotions::ActiveModel,
+    ) -> Result<salary_grade_promotions::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert the new salary row and mark the promotion approved in one transaction
+    pub async fn approve_promotion(
+        &self,
+        mut promotion: salary_grade_promotions::ActiveModel,
+        salary: teacher_salaries::ActiveModel,
+    ) -> Result<salary_grade_promotions::Model, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let salary = salary
+            .insert(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        promotion.new_teacher_salary_id = Set(Some(salary.id));
+        let promotion = promotion
+            .update(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(promotion)
+    }
+}
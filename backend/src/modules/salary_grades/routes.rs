@@ -0,0 +1,63 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use super::service::MANAGE_PERMISSION;
+use crate::modules::payrolls::service::FINANCE_PERMISSION;
+use crate::{
+    middleware::{
+        auth::JwtMiddleware,
+        permission::{require_all_permissions, require_permission},
+    },
+    modules::salary_grades::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/salary_grades")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route(
+                "",
+                web::post()
+                    .to(handler::create)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route("/promotions", web::get().to(handler::get_promotions))
+            .route(
+                "/promotions/evaluate",
+                web::post()
+                    .to(handler::evaluate_promotions)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route(
+                "/promotions/{id}/approve",
+                web::post()
+                    .to(handler::approve_promotion)
+                    // Menyetujui kenaikan golongan mengubah gaji yang dibayar payroll
+                    .wrap(require_all_permissions(&[
+                        MANAGE_PERMISSION,
+                        FINANCE_PERMISSION,
+                    ])),
+            )
+            .route(
+                "/promotions/{id}/reject",
+                web::post()
+                    .to(handler::reject_promotion)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route(
+                "/{id}",
+                web::put()
+                    .to(handler::update)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            )
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission(MANAGE_PERMISSION)),
+            ),
+    );
+}
@@ -0,0 +1,485 @@
+// ============================================================================
+// backend/src/modules/salary_grades/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    CreateSalaryGradeRequest, EvaluatePromotionsRequest, EvaluatePromotionsResponse,
+    PromotionFilters, PromotionResponse, ReviewPromotionRequest, SalaryGradeFilters,
+    SalaryGradeResponse, SkippedTeacher, UpdateSalaryGradeRequest,
+};
+use super::progression;
+use super::repository::SalaryGradeRepository;
+use crate::errors::AppError;
+use crate::modules::payrolls::calculator::{total_allowances, total_deductions};
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use chrono::{Datelike, NaiveDate, Utc};
+use entity::sea_orm_active_enums::PromotionStatus;
+use entity::{salary_grade_promotions, salary_grades, teacher_salaries, teachers};
+use sea_orm::prelude::Decimal;
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Kode permission untuk mengelola golongan gaji & menyetujui kenaikan golongan
+pub const MANAGE_PERMISSION: &str = "salary_grades.manage";
+
+#[derive(Clone)]
+pub struct SalaryGradeService {
+    repository: SalaryGradeRepository,
+}
+
+impl SalaryGradeService {
+    pub fn new(repository: SalaryGradeRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create new salary grade
+    pub async fn create(
+        &self,
+        request: CreateSalaryGradeRequest,
+        foundation_id: i64,
+    ) -> Result<SalaryGradeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        self.ensure_code_available(&request.code, None).await?;
+
+        let now = Utc::now();
+        let active_model = salary_grades::ActiveModel {
+            foundation_id: Set(foundation_id),
+            name: Set(request.name),
+            code: Set(request.code),
+            education_level: Set(request.education_level),
+            min_years_experience: Set(request.min_years_experience),
+            base_salary: Set(to_decimal(request.base_salary)?),
+            created_at: Set(Some(now)),
+            updated_at: Set(Some(now)),
+            ..Default::default()
+        };
+
+        let created = self.repository.create(active_model).await?;
+
+        Ok(SalaryGradeResponse::from(created))
+    }
+
+    /// Get salary grade by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<SalaryGradeResponse, AppError> {
+        let grade = self.find_grade(id, foundation_id).await?;
+
+        Ok(SalaryGradeResponse::from(grade))
+    }
+
+    /// Get all salary grades with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: SalaryGradeFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<SalaryGradeResponse>, AppError> {
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let data: Vec<SalaryGradeResponse> =
+            items.into_iter().map(SalaryGradeResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            data,
+            params.page(),
+            params.per_page(),
+            total,
+        ))
+    }
+
+    /// Update salary grade; existing teacher_salaries rows keep their amounts
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateSalaryGradeRequest,
+        foundation_id: i64,
+    ) -> Result<SalaryGradeResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self.find_grade(id, foundation_id).await?;
+        if let Some(ref code) = request.code {
+            self.ensure_code_available(code, Some(existing.id)).await?;
+        }
+
+        let mut active_model: salary_grades::ActiveModel = existing.into();
+        if let Some(name) = request.name {
+            active_model.name = Set(name);
+        }
+        if let Some(code) = request.code {
+            active_model.code = Set(code);
+        }
+        if request.education_level.is_some() {
+            active_model.education_level = Set(request.education_level);
+        }
+        if request.min_years_experience.is_some() {
+            active_model.min_years_experience = Set(request.min_years_experience);
+        }
+        if let Some(base_salary) = request.base_salary {
+            active_model.base_salary = Set(to_decimal(base_salary)?);
+        }
+        active_model.updated_at = Set(Some(Utc::now()));
+
+        let updated = self.repository.update(active_model).await?;
+
+        Ok(SalaryGradeResponse::from(updated))
+    }
+
+    /// Delete salary grade that no salary or promotion refers to
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        let grade = self.find_grade(id, foundation_id).await?;
+        if self.repository.is_in_use(grade.id).await? {
+            return Err(AppError::ConflictError(
+                "Salary grade is used by teacher salaries or promotions".to_string(),
+            ));
+        }
+
+        self.repository.delete(grade.id).await
+    }
+
+    /// Evaluate active teachers and propose promotions for those crossing a grade threshold
+    pub async fn evaluate_promotions(
+        &self,
+        request: EvaluatePromotionsRequest,
+        foundation_id: i64,
+    ) -> Result<EvaluatePromotionsResponse, AppError> {
+        let as_of = request.as_of.unwrap_or_else(|| Utc::now().date_naive());
+        let effective_date = match request.effective_date {
+            Some(date) => date,
+            None => first_of_next_month(as_of)
+                .ok_or_else(|| AppError::validation("Invalid as_of date".to_string()))?,
+        };
+
+        let grades = self.repository.find_by_foundation(foundation_id).await?;
+        if grades.is_empty() {
+            return Err(AppError::validation(
+                "No salary grades defined for this foundation".to_string(),
+            ));
+        }
+
+        let assignments = self
+            .repository
+            .find_active_assignments(foundation_id, as_of)
+            .await?;
+        let teachers: HashMap<i64, teachers::Model> = self
+            .repository
+            .find_teachers_by_ids(assignments.iter().map(|a| a.teacher_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+
+        // Gaji terbaru per penugasan (hasil query sudah urut terbaru dulu)
+        let mut current: HashMap<i64, teacher_salaries::Model> = HashMap::new();
+        for salary in self
+            .repository
+            .find_salaries_by_assignments(assignments.iter().map(|a| a.id).collect())
+            .await?
+        {
+            current
+                .entry(salary.teacher_assignment_id)
+                .or_insert(salary);
+        }
+
+        let pending: HashSet<i64> = self
+            .repository
+            .find_pending_assignment_ids(foundation_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        let now = Utc::now();
+        let mut proposals = Vec::new();
+        let mut skipped = Vec::new();
+        for assignment in &assignments {
+            let skip = |reason: &str| SkippedTeacher {
+                teacher_id: assignment.teacher_id,
+                teacher_assignment_id: assignment.id,
+                reason: reason.to_string(),
+            };
+            let Some(teacher) = teachers.get(&assignment.teacher_id) else {
+                skipped.push(skip("Teacher not found"));
+                continue;
+            };
+            if pending.contains(&assignment.id) {
+                skipped.push(skip("A promotion is already pending"));
+                continue;
+            }
+            let Some(hire_date) = teacher.hire_date else {
+                skipped.push(skip("Hire date is not set"));
+                continue;
+            };
+
+            let salary = current.get(&assignment.id);
+            let years = progression::years_of_service(hire_date, as_of);
+            let Some(grade) = progression::propose(
+                &grades,
+                salary.map(|s| s.salary_grade_id),
+                salary.map(|s| s.base_salary),
+                years,
+                teacher.qualification.as_deref(),
+            ) else {
+                continue;
+            };
+
+            proposals.push(salary_grade_promotions::ActiveModel {
+                foundation_id: Set(foundation_id),
+                teacher_id: Set(teacher.id),
+                teacher_assignment_id: Set(assignment.id),
+                from_teacher_salary_id: Set(salary.map(|s| s.id)),
+                from_salary_grade_id: Set(salary.map(|s| s.salary_grade_id)),
+                to_salary_grade_id: Set(grade.id),
+                years_of_service: Set(years),
+                qualification: Set(teacher.qualification.clone()),
+                status: Set(PromotionStatus::Pending),
+                effective_date: Set(effective_date),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            });
+        }
+
+        let created = self.repository.create_promotions(proposals).await?;
+
+        Ok(EvaluatePromotionsResponse {
+            evaluated: assignments.len(),
+            proposed: self.to_responses(created, foundation_id).await?,
+            skipped,
+        })
+    }
+
+    /// Get promotions with pagination
+    pub async fn get_promotions(
+        &self,
+        params: PaginationParams,
+        filters: PromotionFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<PromotionResponse>, AppError> {
+        let (items, total) = self
+            .repository
+            .find_promotions(&params, &filters, foundation_id)
+            .await?;
+
+        let data = self.to_responses(items, foundation_id).await?;
+
+        Ok(PaginatedResponse::new(
+            data,
+            params.page(),
+            params.per_page(),
+            total,
+        ))
+    }
+
+    /// Approve a promotion: a new teacher_salaries row takes effect, history is kept
+    pub async fn approve_promotion(
+        &self,
+        id: i64,
+        request: ReviewPromotionRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<PromotionResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let promotion = self.find_pending(id, foundation_id).await?;
+        let grade = self
+            .find_grade(promotion.to_salary_grade_id, foundation_id)
+            .await?;
+        let effective_date = request.effective_date.unwrap_or(promotion.effective_date);
+
+        let current = match promotion.from_teacher_salary_id {
+            Some(salary_id) => self.repository.find_salary(salary_id).await?,
+            None => None,
+        };
+        if let Some(ref current) = current {
+            if effective_date <= current.effective_date {
+                return Err(AppError::validation(format!(
+                    "effective_date must be after the current salary's effective date ({})",
+                    current.effective_date
+                )));
+            }
+        }
+
+        // Tunjangan & potongan ikut dari gaji sebelumnya, hanya gaji pokok yang naik
+        let allowances = current.as_ref().map(total_allowances).unwrap_or_default();
+        let deductions = current.as_ref().map(total_deductions).unwrap_or_default();
+        let gross_salary = grade.base_salary + allowances;
+        let now = Utc::now();
+        let salary = teacher_salaries::ActiveModel {
+            teacher_assignment_id: Set(promotion.teacher_assignment_id),
+            salary_grade_id: Set(grade.id),
+            base_salary: Set(grade.base_salary),
+            position_allowance: Set(current.as_ref().and_then(|s| s.position_allowance)),
+            teaching_allowance: Set(current.as_ref().and_then(|s| s.teaching_allowance)),
+            certification_allowance: Set(current.as_ref().and_then(|s| s.certification_allowance)),
+            performance_allowance: Set(current.as_ref().and_then(|s| s.performance_allowance)),
+            transport_allowance: Set(current.as_ref().and_then(|s| s.transport_allowance)),
+            meal_allowance: Set(current.as_ref().and_then(|s| s.meal_allowance)),
+            other_allowances: Set(current.as_ref().and_then(|s| s.other_allowances)),
+            tax_deduction: Set(current.as_ref().and_then(|s| s.tax_deduction)),
+            insurance_deduction: Set(current.as_ref().and_then(|s| s.insurance_deduction)),
+            loan_deduction: Set(current.as_ref().and_then(|s| s.loan_deduction)),
+            other_deductions: Set(current.as_ref().and_then(|s| s.other_deductions)),
+            gross_salary: Set(Some(gross_salary)),
+            net_salary: Set(Some((gross_salary - deductions).max(Decimal::ZERO))),
+            effective_date: Set(effective_date),
+            notes: Set(Some(format!("Kenaikan golongan ke {}", grade.name))),
+            created_at: Set(Some(now)),
+            updated_at: Set(Some(now)),
+            ..Default::default()
+        };
+
+        let mut reviewed: salary_grade_promotions::ActiveModel = promotion.into();
+        reviewed.status = Set(PromotionStatus::Approved);
+        reviewed.effective_date = Set(effective_date);
+        reviewed.reviewed_by = Set(Some(user_id));
+        reviewed.reviewed_at = Set(Some(now));
+        reviewed.review_notes = Set(request.review_notes);
+        reviewed.updated_at = Set(now);
+
+        let approved = self.repository.approve_promotion(reviewed, salary).await?;
+
+        self.to_response(approved, foundation_id).await
+    }
+
+    /// Reject a pending promotion
+    pub async fn reject_promotion(
+        &self,
+        id: i64,
+        request: ReviewPromotionRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<PromotionResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let promotion = self.find_pending(id, foundation_id).await?;
+
+        let now = Utc::now();
+        let mut reviewed: salary_grade_promotions::ActiveModel = promotion.into();
+        reviewed.status = Set(PromotionStatus::Rejected);
+        reviewed.reviewed_by = Set(Some(user_id));
+        reviewed.reviewed_at = Set(Some(now));
+        reviewed.review_notes = Set(request.review_notes);
+        reviewed.updated_at = Set(now);
+
+        let rejected = self.repository.update_promotion(reviewed).await?;
+
+        self.to_response(rejected, foundation_id).await
+    }
+
+    async fn find_grade(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<salary_grades::Model, AppError> {
+        self.repository
+            .find_by_id(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("Salary grade with id {} not found", id)))
+    }
+
+    async fn find_pending(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<salary_grade_promotions::Model, AppError> {
+        let promotion = self
+            .repository
+            .find_promotion(id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("Promotion with id {} not found", id)))?;
+        if promotion.status != PromotionStatus::Pending {
+            return Err(AppError::ConflictError(format!(
+                "Promotion is already {}",
+                sea_orm::ActiveEnum::to_value(&promotion.status)
+            )));
+        }
+        Ok(promotion)
+    }
+
+    async fn ensure_code_available(&self, code: &str, except: Option<i64>) -> Result<(), AppError> {
+        match self.repository.find_by_code(code).await? {
+            Some(grade) if Some(grade.id) != except => Err(AppError::ConflictError(format!(
+                "Salary grade with code {} already exists",
+                code
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    async fn to_response(
+        &self,
+        promotion: salary_grade_promotions::Model,
+        foundation_id: i64,
+    ) -> Result<PromotionResponse, AppError> {
+        self.to_responses(vec![promotion], foundation_id)
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::internal("Promotion response is empty".to_string()))
+    }
+
+    async fn to_responses(
+        &self,
+        promotions: Vec<salary_grade_promotions::Model>,
+        foundation_id: i64,
+    ) -> Result<Vec<PromotionResponse>, AppError> {
+        let grades: HashMap<i64, salary_grades::Model> = self
+            .repository
+            .find_by_foundation(foundation_id)
+            .await?
+            .into_iter()
+            .map(|g| (g.id, g))
+            .collect();
+        let names: HashMap<i64, String> = self
+            .repository
+            .find_teachers_by_ids(promotions.iter().map(|p| p.teacher_id).collect())
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+
+        Ok(promotions
+            .into_iter()
+            .map(|promotion| {
+                let teacher_name = names.get(&promotion.teacher_id).cloned();
+                let from_grade = promotion
+                    .from_salary_grade_id
+                    .and_then(|id| grades.get(&id));
+                let to_grade = grades.get(&promotion.to_salary_grade_id);
+                PromotionResponse::new(promotion, teacher_name, from_grade, to_grade)
+            })
+            .collect())
+    }
+}
+
+fn to_decimal(value: f64) -> Result<Decimal, AppError> {
+    Decimal::try_from(value)
+        .map(|v| v.round_dp(2))
+        .map_err(|_| AppError::validation("Invalid base_salary".to_string()))
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    }
+}
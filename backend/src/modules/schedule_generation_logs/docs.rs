@@ -0,0 +1,51 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    BatchActionResponse, GenerationConflictsResponse, GenerationLogFilters, GenerationLogResponse,
+};
+use super::handler;
+use crate::utils::pagination::PaginatedResponse;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_all,
+        handler::get_by_id,
+        handler::get_conflicts,
+        handler::publish,
+        handler::rollback,
+    ),
+    components(
+        schemas(
+            GenerationLogResponse,
+            GenerationConflictsResponse,
+            GenerationLogFilters,
+            BatchActionResponse,
+            PaginatedResponse<GenerationLogResponse>,
+        )
+    ),
+    tags(
+        (name = "Schedule Generation Log ", description = "Schedule generation run history, publish and rollback endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct ScheduleGenerationLogsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// backend/src/modules/schedule_generation_logs/dto.rs
+use entity::sea_orm_active_enums::GenerationStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenerationLogResponse {
+    pub id: i64,
+    pub batch_id: String,
+    pub semester_id: i64,
+    pub unit_id: i64,
+    pub algorithm: Option<String>,
+    pub total_slots_needed: Option<i32>,
+    pub total_slots_filled: Option<i32>,
+    pub success_rate: Option<f64>,
+    pub execution_time_ms: Option<i32>,
+    pub status: Option<GenerationStatus>,
+    pub error_message: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenerationConflictsResponse {
+    pub log_id: i64,
+    pub batch_id: String,
+    #[schema(value_type = Object)]
+    pub conflicts: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchActionResponse {
+    pub log_id: i64,
+    pub batch_id: String,
+    pub affected_rows: u64,
+    pub status: GenerationStatus,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct GenerationLogFilters {
+    pub semester_id: Option<i64>,
+    pub unit_id: Option<i64>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::schedule_generation_logs::Model> for GenerationLogResponse {
+    fn from(model: entity::schedule_generation_logs::Model) -> Self {
+        Self {
+            id: model.id,
+            batch_id: model.batch_id,
+            semester_id: model.semester_id,
+            unit_id: model.unit_id,
+            algorithm: model.algorithm,
+            total_slots_needed: model.total_slots_needed,
+            total_slots_filled: model.total_slots_filled,
+            success_rate: model.success_rate.and_then(|rate| rate.try_into().ok()),
+            execution_time_ms: model.execution_time_ms,
+            status: model.status,
+            error_message: model.error_message,
+            created_by: model.created_by,
+            created_at: model.created_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BatchActionResponse, GenerationConflictsResponse, GenerationLogFilters, GenerationLogResponse,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Get generation runs per semester/unit
+#[utoipa::path(
+    get,
+    path = "/api/schedule_generation_logs",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ("search" = Option<String>, Query, description = "Search by batch id or algorithm"),
+        ("sort_by" = Option<String>, Query, description = "Sort field: created_at or success_rate"),
+        ("sort_order" = Option<String>, Query, description = "Sort order: asc or desc (default: desc)"),
+        GenerationLogFilters,
+    ),
+    responses(
+        (status = 200, description = "List of generation runs", body = PaginatedResponse<GenerationLogResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule Generation Log "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<GenerationLogFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_generation_log_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get generation run by ID
+#[utoipa::path(
+    get,
+    path = "/api/schedule_generation_logs/{id}",
+    params(
+        ("id" = i64, Path, description = "Generation log ID")
+    ),
+    responses(
+        (status = 200, description = "Generation run found", body = GenerationLogResponse),
+        (status = 404, description = "Generation log not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule Generation Log "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_generation_log_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get conflicts detected during a generation run
+#[utoipa::path(
+    get,
+    path = "/api/schedule_generation_logs/{id}/conflicts",
+    params(
+        ("id" = i64, Path, description = "Generation log ID")
+    ),
+    responses(
+        (status = 200, description = "Detected conflicts", body = GenerationConflictsResponse),
+        (status = 404, description = "Generation log not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule Generation Log "
+)]
+pub async fn get_conflicts(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_generation_log_service
+        .get_conflicts(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Publish the schedules of a generation batch
+#[utoipa::path(
+    post,
+    path = "/api/schedule_generation_logs/{id}/publish",
+    params(
+        ("id" = i64, Path, description = "Generation log ID")
+    ),
+    responses(
+        (status = 200, description = "Batch published", body = BatchActionResponse),
+        (status = 403, description = "Missing schedule_generation_logs.publish permission"),
+        (status = 404, description = "Generation log not found"),
+        (status = 409, description = "Batch is already published"),
+        (status = 422, description = "Batch has no schedules to publish")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule Generation Log "
+)]
+pub async fn publish(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_generation_log_service
+        .publish(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Rollback a generation batch (delete its schedules)
+#[utoipa::path(
+    post,
+    path = "/api/schedule_generation_logs/{id}/rollback",
+    params(
+        ("id" = i64, Path, description = "Generation log ID")
+    ),
+    responses(
+        (status = 200, description = "Batch rolled back", body = BatchActionResponse),
+        (status = 403, description = "Missing schedule_generation_logs.publish permission"),
+        (status = 404, description = "Generation log not found"),
+        (status = 409, description = "Batch is already rolled back")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule Generation Log "
+)]
+pub async fn rollback(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_generation_log_service
+        .rollback(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
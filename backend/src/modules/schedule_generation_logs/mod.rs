@@ -0,0 +1,21 @@
+// backend/src/modules/schedule_generation_logs/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::ScheduleGenerationLogRepository;
+pub use service::ScheduleGenerationLogService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> ScheduleGenerationLogService {
+    let repository = ScheduleGenerationLogRepository::new(db);
+    ScheduleGenerationLogService::new(repository)
+}
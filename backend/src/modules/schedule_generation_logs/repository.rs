@@ -0,0 +1,285 @@
+// ============================================================================
+// backend/src/modules/schedule_generation_logs/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use entity::schedule_generation_logs::{self, Entity as ScheduleGenerationLog};
+use entity::sea_orm_active_enums::{GenerationStatus, ScheduleStatus};
+use entity::{class_schedules, class_subjects, semesters};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct ScheduleGenerationLogRepository {
+    db: Database,
+}
+
+impl ScheduleGenerationLogRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find by ID
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+    ) -> Result<Option<schedule_generation_logs::Model>, AppError> {
+        ScheduleGenerationLog::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all runs with pagination, limited to the given semesters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        semester_ids: Vec<i64>,
+        unit_id: Option<i64>,
+    ) -> Result<(Vec<schedule_generation_logs::Model>, u64), AppError> {
+        let mut query = ScheduleGenerationLog::find()
+            .filter(schedule_generation_logs::Column::SemesterId.is_in(semester_ids));
+
+        if let Some(unit_id) = unit_id {
+            query = query.filter(schedule_generation_logs::Column::UnitId.eq(unit_id));
+        }
+
+        // Apply search filter if provided
+        if let Some(ref search) = params.search {
+            query = query.filter(
+                Condition::any()
+                    .add(schedule_generation_logs::Column::BatchId.contains(search))
+                    .add(schedule_generation_logs::Column::Algorithm.contains(search)),
+            );
+        }
+
+        query = match params.sort_by.as_deref() {
+            Some("success_rate") => {
+                if params.sort_order.as_deref() == Some("asc") {
+                    query.order_by_asc(schedule_generation_logs::Column::SuccessRate)
+                } else {
+                    query.order_by_desc(schedule_generation_logs::Column::SuccessRate)
+                }
+            }
+            _ => {
+                if params.sort_order.as_deref() == Some("asc") {
+                    query.order_by_asc(schedule_generation_logs::Column::CreatedAt)
+                } else {
+                    query.order_by_desc(schedule_generation_logs::Column::CreatedAt)
+                }
+            }
+        };
+
+        // Paginate dengan validasi
+        let per_page = params.per_page();
+        let paginator = query.paginate(self.conn(), per_page);
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find semester by ID
+    pub async fn find_semester(&self, id: i64) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semester IDs of a foundation, optionally by semester ID
+    pub async fn find_semester_ids(
+        &self,
+        foundation_id: i64,
+        semester_id: Option<i64>,
+    ) -> Result<Vec<i64>, AppError> {
+        let mut query =
+            semesters::Entity::find().filter(semesters::Column::FoundationId.eq(foundation_id));
+
+        if let Some(semester_id) = semester_id {
+            query = query.filter(semesters::Column::Id.eq(semester_id));
+        }
+
+        let rows = query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|s| s.id).collect())
+    }
+
+    /// Find class subject IDs of a semester
+    pub async fn find_class_subject_ids(&self, semester_id: i64) -> Result<Vec<i64>, AppError> {
+        let rows = class_subjects::Entity::find()
+            .filter(class_subjects::Column::SemesterId.eq(semester_id))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|cs| cs.id).collect())
+    }
+
+    /// Find published runs of a semester and unit
+    pub async fn find_published(
+        &self,
+        semester_id: i64,
+        unit_id: i64,
+    ) -> Result<Vec<schedule_generation_logs::Model>, AppError> {
+        ScheduleGenerationLog::find()
+            .filter(schedule_generation_logs::Column::SemesterId.eq(semester_id))
+            .filter(schedule_generation_logs::Column::UnitId.eq(unit_id))
+            .filter(schedule_generation_logs::Column::Status.eq(GenerationStatus::Published))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Publish a batch: demote other generated, published rows of the semester back to draft,
+    /// promote the batch rows and update the affected run logs. Jadwal manual (tanpa batch) tidak disentuh
+    pub async fn publish_batch(
+        &self,
+        log_id: i64,
+        batch_id: &str,
+        class_subject_ids: Vec<i64>,
+        superseded: Vec<(i64, GenerationStatus)>,
+    ) -> Result<u64, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let now = chrono::Utc::now();
+
+        class_schedules::Entity::update_many()
+            .col_expr(
+                class_schedules::Column::Status,
+                Expr::value(ScheduleStatus::Draft),
+            )
+            .col_expr(class_schedules::Column::UpdatedAt, Expr::value(now))
+            .filter(class_schedules::Column::ClassSubjectId.is_in(class_subject_ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+            .filter(class_schedules::Column::GenerationBatchId.is_not_null())
+            .filter(class_schedules::Column::GenerationBatchId.ne(batch_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let result = class_schedules::Entity::update_many()
+            .col_expr(
+                class_schedules::Column::Status,
+                Expr::value(ScheduleStatus::Published),
+            )
+            .col_expr(class_schedules::Column::UpdatedAt, Expr::value(now))
+            .filter(class_schedules::Column::GenerationBatchId.eq(batch_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        for (id, status) in superseded {
+            ScheduleGenerationLog::update_many()
+                .col_expr(
+                    schedule_generation_logs::Column::Status,
+                    Expr::value(status),
+                )
+                .filter(schedule_generation_logs::Column::Id.eq(id))
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        ScheduleGenerationLog::update_many()
+            .col_expr(
+                schedule_generation_logs::Column::Status,
+                Expr::value(GenerationStatus::Published),
+            )
+            .filter(schedule_generation_logs::Column::Id.eq(log_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+
+    /// Rollback a batch: delete every class_schedules row of the batch
+    pub async fn rollback_batch(&self, log_id: i64, batch_id: &str) -> Result<u64, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let result = class_schedules::Entity::delete_many()
+            .filter(class_schedules::Column::GenerationBatchId.eq(batch_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        ScheduleGenerationLog::update_many()
+            .col_expr(
+                schedule_generation_logs::Column::Status,
+                Expr::value(GenerationStatus::RolledBack),
+            )
+            .filter(schedule_generation_logs::Column::Id.eq(log_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_publish_keeps_manual_schedules() {
+        let connection = MockDatabase::new(DatabaseBackend::MySql)
+            .append_exec_results((0..3).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+        let repository = ScheduleGenerationLogRepository::new(Database {
+            connection: Arc::new(connection),
+        });
+
+        repository
+            .publish_batch(3, "batch-b", vec![10], vec![])
+            .await
+            .unwrap();
+
+        let log = repository.conn().clone().into_transaction_log();
+        let demote = &log[0].statements()[1].sql;
+        assert!(demote.contains("`class_schedules`.`generation_batch_id` IS NOT NULL"));
+        assert!(demote.contains("`class_schedules`.`generation_batch_id` <> ?"));
+        assert!(!demote.contains("IS NULL OR"));
+    }
+}
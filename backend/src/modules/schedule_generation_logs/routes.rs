@@ -0,0 +1,31 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use super::service::PUBLISH_PERMISSION;
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::schedule_generation_logs::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/schedule_generation_logs")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}/conflicts", web::get().to(handler::get_conflicts))
+            .route(
+                "/{id}/publish",
+                web::post()
+                    .to(handler::publish)
+                    .wrap(require_permission(PUBLISH_PERMISSION)),
+            )
+            .route(
+                "/{id}/rollback",
+                web::post()
+                    .to(handler::rollback)
+                    .wrap(require_permission(PUBLISH_PERMISSION)),
+            ),
+    );
+}
@@ -0,0 +1,202 @@
+// ============================================================================
+// backend/src/modules/schedule_generation_logs/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    BatchActionResponse, GenerationConflictsResponse, GenerationLogFilters, GenerationLogResponse,
+};
+use super::repository::ScheduleGenerationLogRepository;
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use entity::schedule_generation_logs;
+use entity::sea_orm_active_enums::GenerationStatus;
+use validator::Validate;
+
+/// Publish & rollback mengubah jadwal aktif, jadi tidak cukup hanya login
+pub const PUBLISH_PERMISSION: &str = "schedule_generation_logs.publish";
+
+#[derive(Clone)]
+pub struct ScheduleGenerationLogService {
+    repository: ScheduleGenerationLogRepository,
+}
+
+impl ScheduleGenerationLogService {
+    pub fn new(repository: ScheduleGenerationLogRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Get generation runs per semester/unit with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: GenerationLogFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<GenerationLogResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let semester_ids = self
+            .repository
+            .find_semester_ids(foundation_id, filters.semester_id)
+            .await?;
+
+        if semester_ids.is_empty() {
+            return Ok(PaginatedResponse::new(
+                Vec::new(),
+                0,
+                params.page(),
+                params.per_page(),
+            ));
+        }
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, semester_ids, filters.unit_id)
+            .await?;
+
+        let responses: Vec<GenerationLogResponse> =
+            items.into_iter().map(GenerationLogResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Get generation run by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<GenerationLogResponse, AppError> {
+        let log = self.find_scoped(id, foundation_id).await?;
+        Ok(GenerationLogResponse::from(log))
+    }
+
+    /// Get conflicts detected during a generation run
+    pub async fn get_conflicts(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<GenerationConflictsResponse, AppError> {
+        let log = self.find_scoped(id, foundation_id).await?;
+
+        Ok(GenerationConflictsResponse {
+            log_id: log.id,
+            batch_id: log.batch_id,
+            conflicts: log
+                .conflicts_detected
+                .unwrap_or_else(|| serde_json::json!([])),
+        })
+    }
+
+    /// Promote a batch from draft to published; the previously published batch
+    /// of the same semester goes back to draft
+    pub async fn publish(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<BatchActionResponse, AppError> {
+        let log = self.find_scoped(id, foundation_id).await?;
+
+        match log.status {
+            Some(GenerationStatus::Published) => {
+                return Err(AppError::ConflictError(
+                    "Batch is already published".to_string(),
+                ))
+            }
+            Some(GenerationStatus::RolledBack) | Some(GenerationStatus::Failed) => {
+                return Err(AppError::unprocessable_entity(
+                    "Batch has no schedules to publish".to_string(),
+                ))
+            }
+            _ => {}
+        }
+
+        let class_subject_ids = self
+            .repository
+            .find_class_subject_ids(log.semester_id)
+            .await?;
+
+        let superseded = self
+            .repository
+            .find_published(log.semester_id, log.unit_id)
+            .await?
+            .into_iter()
+            .map(|previous| (previous.id, completion_status(&previous)))
+            .collect();
+
+        let affected_rows = self
+            .repository
+            .publish_batch(log.id, &log.batch_id, class_subject_ids, superseded)
+            .await?;
+
+        Ok(BatchActionResponse {
+            log_id: log.id,
+            batch_id: log.batch_id,
+            affected_rows,
+            status: GenerationStatus::Published,
+        })
+    }
+
+    /// Rollback a batch by deleting every class schedule generated in it
+    pub async fn rollback(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<BatchActionResponse, AppError> {
+        let log = self.find_scoped(id, foundation_id).await?;
+
+        if log.status == Some(GenerationStatus::RolledBack) {
+            return Err(AppError::ConflictError(
+                "Batch is already rolled back".to_string(),
+            ));
+        }
+
+        let affected_rows = self
+            .repository
+            .rollback_batch(log.id, &log.batch_id)
+            .await?;
+
+        Ok(BatchActionResponse {
+            log_id: log.id,
+            batch_id: log.batch_id,
+            affected_rows,
+            status: GenerationStatus::RolledBack,
+        })
+    }
+
+    /// Find a run and make sure its semester belongs to the caller's foundation
+    async fn find_scoped(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<schedule_generation_logs::Model, AppError> {
+        let log = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Generation log not found".to_string()))?;
+
+        self.repository
+            .find_semester(log.semester_id)
+            .await?
+            .filter(|s| s.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Generation log not found".to_string()))?;
+
+        Ok(log)
+    }
+}
+
+/// Status run sebelum dipublish (dipakai saat batch lain menggantikannya)
+fn completion_status(log: &schedule_generation_logs::Model) -> GenerationStatus {
+    if log.total_slots_filled.unwrap_or(0) >= log.total_slots_needed.unwrap_or(0) {
+        GenerationStatus::Success
+    } else {
+        GenerationStatus::Partial
+    }
+}
@@ -0,0 +1,334 @@
+// ============================================================================
+// backend/src/modules/schedules/conflicts.rs
+// conflicts.rs - Schedule Conflict Detector (pure, tanpa akses database)
+// ============================================================================
+use super::solver::{preference_penalty, SAME_DAY_PENALTY};
+use entity::sea_orm_active_enums::PreferenceLevel;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use utoipa::ToSchema;
+
+/// Bobot untuk setiap pelanggaran hard constraint pada `conflict_score`
+pub const HARD_CONFLICT_WEIGHT: i32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    TeacherDoubleBooked,
+    RoomDoubleBooked,
+    ClassOverlap,
+    RoomCapacity,
+    MissingFacility,
+    TeacherUnavailable,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    pub time_slot_id: i64,
+    pub schedule_ids: Vec<i64>,
+    pub message: String,
+}
+
+/// Satu baris `class_schedules` beserta kelas & mapel dari `class_subjects`
+#[derive(Debug, Clone)]
+pub struct ScheduleRow {
+    pub id: i64,
+    pub class_subject_id: i64,
+    pub class_id: i64,
+    pub subject_id: i64,
+    pub time_slot_id: i64,
+    pub room_id: Option<i64>,
+    pub teacher_id: Option<i64>,
+}
+
+/// Facility yang diminta `subject_room_requirements.required_facilities`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FacilityRef {
+    Id(i64),
+    Name(String),
+}
+
+impl FacilityRef {
+    /// Parse `required_facilities`: array berisi ID, nama, atau object
+    /// `{ "facility_id": .. }` / `{ "id": .. }` / `{ "name": .. }`
+    pub fn parse_list(value: &serde_json::Value) -> Vec<FacilityRef> {
+        let items = match value {
+            serde_json::Value::Array(items) => items.iter().collect(),
+            other => vec![other],
+        };
+
+        items
+            .into_iter()
+            .filter_map(|item| match item {
+                serde_json::Value::Number(n) => n.as_i64().map(FacilityRef::Id),
+                serde_json::Value::String(s) => Some(FacilityRef::Name(s.trim().to_lowercase())),
+                serde_json::Value::Object(map) => map
+                    .get("facility_id")
+                    .or_else(|| map.get("id"))
+                    .and_then(|v| v.as_i64())
+                    .map(FacilityRef::Id)
+                    .or_else(|| {
+                        map.get("name")
+                            .and_then(|v| v.as_str())
+                            .map(|s| FacilityRef::Name(s.trim().to_lowercase()))
+                    }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn label(&self) -> String {
+        match self {
+            FacilityRef::Id(id) => format!("#{}", id),
+            FacilityRef::Name(name) => name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConflictContext {
+    pub class_sizes: HashMap<i64, i32>,
+    pub room_capacities: HashMap<i64, i32>,
+    /// room_id -> facility yang dimiliki (ID & nama lowercase)
+    pub room_facilities: HashMap<i64, HashSet<FacilityRef>>,
+    pub required_facilities: HashMap<i64, Vec<FacilityRef>>,
+    pub teacher_unavailable: HashSet<(i64, i64)>,
+    pub subject_preferences: HashMap<(i64, i64), PreferenceLevel>,
+    /// time_slot_id -> (day_of_week, order)
+    pub slot_positions: HashMap<i64, (i8, i32)>,
+}
+
+/// Deteksi semua pelanggaran hard constraint pada satu set jadwal
+pub fn detect(rows: &[ScheduleRow], ctx: &ConflictContext) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+
+    // Double booking: guru, ruangan, kelas pada slot yang sama
+    let mut by_teacher: HashMap<(i64, i64), Vec<i64>> = HashMap::new();
+    let mut by_room: HashMap<(i64, i64), Vec<i64>> = HashMap::new();
+    let mut by_class: HashMap<(i64, i64), Vec<i64>> = HashMap::new();
+    for row in rows {
+        if let Some(teacher_id) = row.teacher_id {
+            by_teacher
+                .entry((teacher_id, row.time_slot_id))
+                .or_default()
+                .push(row.id);
+        }
+        if let Some(room_id) = row.room_id {
+            by_room
+                .entry((room_id, row.time_slot_id))
+                .or_default()
+                .push(row.id);
+        }
+        by_class
+            .entry((row.class_id, row.time_slot_id))
+            .or_default()
+            .push(row.id);
+    }
+
+    let groups = [
+        (ConflictKind::TeacherDoubleBooked, by_teacher, "Teacher"),
+        (ConflictKind::RoomDoubleBooked, by_room, "Room"),
+        (ConflictKind::ClassOverlap, by_class, "Class"),
+    ];
+    for (kind, group, label) in groups {
+        for ((owner_id, time_slot_id), mut ids) in group {
+            if ids.len() > 1 {
+                ids.sort_unstable();
+                conflicts.push(Conflict {
+                    kind,
+                    time_slot_id,
+                    message: format!(
+                        "{} {} is scheduled {} times in time slot {}",
+                        label,
+                        owner_id,
+                        ids.len(),
+                        time_slot_id
+                    ),
+                    schedule_ids: ids,
+                });
+            }
+        }
+    }
+
+    for row in rows {
+        // Kapasitas ruangan
+        if let Some(room_id) = row.room_id {
+            let class_size = ctx.class_sizes.get(&row.class_id).copied().unwrap_or(0);
+            if let Some(capacity) = ctx.room_capacities.get(&room_id) {
+                if *capacity < class_size {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::RoomCapacity,
+                        time_slot_id: row.time_slot_id,
+                        schedule_ids: vec![row.id],
+                        message: format!(
+                            "Room {} holds {} students but class {} has {}",
+                            room_id, capacity, row.class_id, class_size
+                        ),
+                    });
+                }
+            }
+
+            // Facility yang dibutuhkan mapel
+            if let Some(required) = ctx.required_facilities.get(&row.subject_id) {
+                let available = ctx.room_facilities.get(&room_id);
+                let missing: Vec<String> = required
+                    .iter()
+                    .filter(|f| !available.is_some_and(|set| set.contains(f)))
+                    .map(FacilityRef::label)
+                    .collect();
+                if !missing.is_empty() {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::MissingFacility,
+                        time_slot_id: row.time_slot_id,
+                        schedule_ids: vec![row.id],
+                        message: format!(
+                            "Room {} is missing required facilities: {}",
+                            room_id,
+                            missing.join(", ")
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Ketersediaan guru
+        if let Some(teacher_id) = row.teacher_id {
+            if ctx
+                .teacher_unavailable
+                .contains(&(teacher_id, row.time_slot_id))
+            {
+                conflicts.push(Conflict {
+                    kind: ConflictKind::TeacherUnavailable,
+                    time_slot_id: row.time_slot_id,
+                    schedule_ids: vec![row.id],
+                    message: format!(
+                        "Teacher {} is marked unavailable in time slot {}",
+                        teacher_id, row.time_slot_id
+                    ),
+                });
+            }
+        }
+    }
+
+    conflicts.sort_by_key(|c| {
+        (
+            c.time_slot_id,
+            c.schedule_ids.first().copied(),
+            c.kind as u8,
+        )
+    });
+    conflicts
+}
+
+/// Hitung ulang `conflict_score` tiap baris: bobot hard conflict ditambah penalti
+/// soft constraint yang sama dengan yang dipakai solver
+pub fn score(
+    rows: &[ScheduleRow],
+    conflicts: &[Conflict],
+    ctx: &ConflictContext,
+) -> HashMap<i64, i32> {
+    let mut scores: HashMap<i64, i32> = rows
+        .iter()
+        .map(|row| {
+            let preference = ctx
+                .subject_preferences
+                .get(&(row.subject_id, row.time_slot_id));
+            (row.id, preference_penalty(preference))
+        })
+        .collect();
+
+    for conflict in conflicts {
+        for id in &conflict.schedule_ids {
+            *scores.entry(*id).or_insert(0) += HARD_CONFLICT_WEIGHT;
+        }
+    }
+
+    // Jam ke-n dari mapel yang sama di hari yang sama mendapat n * SAME_DAY_PENALTY
+    let mut same_day: HashMap<(i64, i8), Vec<(i32, i64)>> = HashMap::new();
+    for row in rows {
+        if let Some((day, order)) = ctx.slot_positions.get(&row.time_slot_id) {
+            same_day
+                .entry((row.class_subject_id, *day))
+                .or_default()
+                .push((*order, row.id));
+        }
+    }
+    for mut entries in same_day.into_values() {
+        entries.sort_unstable();
+        for (nth, (_, id)) in entries.into_iter().enumerate() {
+            *scores.entry(id).or_insert(0) += nth as i32 * SAME_DAY_PENALTY;
+        }
+    }
+
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: i64, class_id: i64, slot: i64, room: i64, teacher: i64) -> ScheduleRow {
+        ScheduleRow {
+            id,
+            class_subject_id: id,
+            class_id,
+            subject_id: id,
+            time_slot_id: slot,
+            room_id: Some(room),
+            teacher_id: Some(teacher),
+        }
+    }
+
+    #[test]
+    fn test_detects_double_bookings() {
+        let rows = vec![
+            row(1, 10, 1, 5, 7),
+            row(2, 11, 1, 5, 7),
+            row(3, 10, 1, 6, 8),
+        ];
+
+        let conflicts = detect(&rows, &ConflictContext::default());
+        let kinds: HashSet<ConflictKind> = conflicts.iter().map(|c| c.kind).collect();
+
+        assert_eq!(conflicts.len(), 3);
+        assert!(kinds.contains(&ConflictKind::TeacherDoubleBooked));
+        assert!(kinds.contains(&ConflictKind::RoomDoubleBooked));
+        assert!(kinds.contains(&ConflictKind::ClassOverlap));
+    }
+
+    #[test]
+    fn test_detects_capacity_facility_and_availability() {
+        let mut ctx = ConflictContext::default();
+        ctx.class_sizes.insert(10, 32);
+        ctx.room_capacities.insert(5, 30);
+        ctx.required_facilities.insert(
+            1,
+            FacilityRef::parse_list(&serde_json::json!(["Projector", 3])),
+        );
+        ctx.room_facilities.insert(
+            5,
+            HashSet::from([FacilityRef::Name("projector".to_string())]),
+        );
+        ctx.teacher_unavailable.insert((7, 1));
+
+        let conflicts = detect(&[row(1, 10, 1, 5, 7)], &ctx);
+        let kinds: Vec<ConflictKind> = conflicts.iter().map(|c| c.kind).collect();
+
+        assert_eq!(kinds.len(), 3);
+        assert!(kinds.contains(&ConflictKind::RoomCapacity));
+        assert!(kinds.contains(&ConflictKind::MissingFacility));
+        assert!(kinds.contains(&ConflictKind::TeacherUnavailable));
+    }
+
+    #[test]
+    fn test_score_adds_hard_weight_per_conflict() {
+        let rows = vec![row(1, 10, 1, 5, 7), row(2, 11, 1, 6, 7)];
+        let ctx = ConflictContext::default();
+
+        let scores = score(&rows, &detect(&rows, &ctx), &ctx);
+
+        assert_eq!(scores[&1], HARD_CONFLICT_WEIGHT);
+        assert_eq!(scores[&2], HARD_CONFLICT_WEIGHT);
+    }
+}
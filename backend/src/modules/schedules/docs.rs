@@ -0,0 +1,56 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::conflicts::{Conflict, ConflictKind};
+use super::dto::{
+    ClassScheduleResponse, ConflictReportResponse, GenerateScheduleRequest,
+    GenerateScheduleResponse, UnplacedLessonResponse, UpdateScheduleRequest,
+    UpdateScheduleResponse,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::generate,
+        handler::get_all,
+        handler::update,
+        handler::conflict_report,
+    ),
+    components(
+        schemas(
+            ClassScheduleResponse,
+            GenerateScheduleRequest,
+            GenerateScheduleResponse,
+            UnplacedLessonResponse,
+            UpdateScheduleRequest,
+            UpdateScheduleResponse,
+            ConflictReportResponse,
+            Conflict,
+            ConflictKind,
+        )
+    ),
+    tags(
+        (name = "Schedule ", description = "Class schedule generation and conflict validation endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct SchedulesApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// backend/src/modules/schedules/dto.rs
+use super::conflicts::Conflict;
+use entity::sea_orm_active_enums::ScheduleStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClassScheduleResponse {
+    pub id: i64,
+    pub class_subject_id: i64,
+    pub time_slot_id: i64,
+    pub room_id: Option<i64>,
+    pub teacher_id: Option<i64>,
+    pub is_auto_generated: bool,
+    pub generation_batch_id: Option<String>,
+    pub conflict_score: Option<i32>,
+    pub status: Option<ScheduleStatus>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GenerateScheduleRequest {
+    #[validate(range(min = 1))]
+    pub semester_id: i64,
+    #[validate(range(min = 1))]
+    pub unit_id: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnplacedLessonResponse {
+    pub class_subject_id: i64,
+    pub missing_hours: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GenerateScheduleResponse {
+    pub batch_id: String,
+    pub semester_id: i64,
+    pub unit_id: i64,
+    pub algorithm: String,
+    pub total_slots_needed: i32,
+    pub total_slots_filled: i32,
+    pub total_penalty: i32,
+    pub execution_time_ms: i64,
+    pub unplaced: Vec<UnplacedLessonResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ScheduleFilters {
+    pub batch_id: Option<String>,
+    pub semester_id: Option<i64>,
+    pub class_id: Option<i64>,
+    pub teacher_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateScheduleRequest {
+    #[validate(range(min = 1))]
+    pub time_slot_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub room_id: Option<i64>,
+    #[validate(range(min = 1))]
+    pub teacher_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateScheduleResponse {
+    pub schedule: ClassScheduleResponse,
+    /// Conflicts involving the edited schedule after revalidation
+    pub conflicts: Vec<Conflict>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ConflictReportQuery {
+    pub semester_id: i64,
+    /// Draft batch to validate; without it the published timetable is checked
+    pub batch_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConflictReportResponse {
+    pub semester_id: i64,
+    pub batch_id: Option<String>,
+    pub total_schedules: usize,
+    pub total_conflicts: usize,
+    pub conflicts: Vec<Conflict>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::class_schedules::Model> for ClassScheduleResponse {
+    fn from(model: entity::class_schedules::Model) -> Self {
+        Self {
+            id: model.id,
+            class_subject_id: model.class_subject_id,
+            time_slot_id: model.time_slot_id,
+            room_id: model.room_id,
+            teacher_id: model.teacher_id,
+            is_auto_generated: model.is_auto_generated.unwrap_or(0) != 0,
+            generation_batch_id: model.generation_batch_id,
+            conflict_score: model.conflict_score,
+            status: model.status,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
+impl ClassScheduleResponse {
+    #[warn(unused_imports)]
+    pub fn from_vec(dto: Vec<entity::class_schedules::Model>) -> Vec<Self> {
+        dto.into_iter().map(Self::from).collect()
+    }
+}
@@ -0,0 +1,113 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    ClassScheduleResponse, ConflictReportQuery, ConflictReportResponse, GenerateScheduleRequest,
+    GenerateScheduleResponse, ScheduleFilters, UpdateScheduleRequest, UpdateScheduleResponse,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use actix_web::{web, HttpResponse};
+
+/// Generate timetable for a semester and unit
+#[utoipa::path(
+    post,
+    path = "/api/schedules/generate",
+    request_body = GenerateScheduleRequest,
+    responses(
+        (status = 201, description = "Schedule generated as a new draft batch", body = GenerateScheduleResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Semester not found"),
+        (status = 422, description = "Nothing to schedule")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule "
+)]
+pub async fn generate(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<GenerateScheduleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_service
+        .generate(request.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get class schedules
+#[utoipa::path(
+    get,
+    path = "/api/schedules",
+    params(ScheduleFilters),
+    responses(
+        (status = 200, description = "List of class schedules", body = Vec<ClassScheduleResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    filters: web::Query<ScheduleFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_service
+        .get_all(filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update a schedule manually and revalidate its timetable
+#[utoipa::path(
+    put,
+    path = "/api/schedules/{id}",
+    params(
+        ("id" = i64, Path, description = "Class schedule ID")
+    ),
+    request_body = UpdateScheduleRequest,
+    responses(
+        (status = 200, description = "Schedule updated; conflicts of the edited row are returned", body = UpdateScheduleResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Schedule not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateScheduleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_service
+        .update(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get conflict report of a semester timetable
+#[utoipa::path(
+    get,
+    path = "/api/schedules/conflicts",
+    params(ConflictReportQuery),
+    responses(
+        (status = 200, description = "Conflict report", body = ConflictReportResponse),
+        (status = 404, description = "Semester not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Schedule "
+)]
+pub async fn conflict_report(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<ConflictReportQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .schedule_service
+        .conflict_report(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -0,0 +1,23 @@
+// backend/src/modules/schedules/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod conflicts;
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+pub mod solver;
+
+pub use repository::ScheduleRepository;
+pub use service::ScheduleService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> ScheduleService {
+    let repository = ScheduleRepository::new(db);
+    ScheduleService::new(repository)
+}
@@ -0,0 +1,433 @@
+// ============================================================================
+// backend/src/modules/schedules/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use crate::config::database::Database;
+use crate::errors::AppError;
+use entity::class_schedules::{self, Entity as ClassSchedule};
+use entity::sea_orm_active_enums::{ScheduleStatus, SlotType};
+use entity::{
+    class_subjects, facilities, room_facilities, room_types, rooms, schedule_generation_logs,
+    semesters, students, subject_preferences, subject_room_requirements, teacher_assignments,
+    teacher_availability, teacher_subjects, teachers, time_slots,
+};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct ScheduleRepository {
+    db: Database,
+}
+
+impl ScheduleRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find semester by ID (not deleted)
+    pub async fn find_semester(&self, id: i64) -> Result<Option<semesters::Model>, AppError> {
+        semesters::Entity::find_by_id(id)
+            .filter(semesters::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semesters by IDs
+    pub async fn find_semesters_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<semesters::Model>, AppError> {
+        semesters::Entity::find()
+            .filter(semesters::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a semester (not deleted)
+    pub async fn find_class_subjects(
+        &self,
+        semester_id: i64,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find()
+            .filter(class_subjects::Column::SemesterId.eq(semester_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .order_by_asc(class_subjects::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects by IDs
+    pub async fn find_class_subjects_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find()
+            .filter(class_subjects::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active students of the given classes
+    pub async fn find_students_by_classes(
+        &self,
+        class_ids: Vec<i64>,
+    ) -> Result<Vec<students::Model>, AppError> {
+        students::Entity::find()
+            .filter(students::Column::ClassId.is_in(class_ids))
+            .filter(students::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active lesson time slots of a foundation for a unit (unit-specific
+    /// and foundation-wide slots), ordered by day and order
+    pub async fn find_lesson_slots(
+        &self,
+        foundation_id: i64,
+        unit_id: i64,
+    ) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find()
+            .filter(time_slots::Column::FoundationId.eq(foundation_id))
+            .filter(
+                Condition::any()
+                    .add(time_slots::Column::UnitId.is_null())
+                    .add(time_slots::Column::UnitId.eq(unit_id)),
+            )
+            .filter(time_slots::Column::DeletedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(time_slots::Column::IsActive.is_null())
+                    .add(time_slots::Column::IsActive.ne(0)),
+            )
+            .filter(
+                Condition::any()
+                    .add(time_slots::Column::SlotType.is_null())
+                    .add(time_slots::Column::SlotType.eq(SlotType::Lesson)),
+            )
+            .order_by_asc(time_slots::Column::DayOfWeek)
+            .order_by_asc(time_slots::Column::Order)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find available rooms of a unit
+    pub async fn find_available_rooms(&self, unit_id: i64) -> Result<Vec<rooms::Model>, AppError> {
+        rooms::Entity::find()
+            .filter(rooms::Column::UnitId.eq(unit_id))
+            .filter(
+                Condition::any()
+                    .add(rooms::Column::IsAvailable.is_null())
+                    .add(rooms::Column::IsAvailable.ne(0)),
+            )
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find room types by IDs
+    pub async fn find_room_types(&self, ids: Vec<i64>) -> Result<Vec<room_types::Model>, AppError> {
+        room_types::Entity::find()
+            .filter(room_types::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find room requirements for the given subjects
+    pub async fn find_room_requirements(
+        &self,
+        subject_ids: Vec<i64>,
+    ) -> Result<Vec<subject_room_requirements::Model>, AppError> {
+        subject_room_requirements::Entity::find()
+            .filter(subject_room_requirements::Column::SubjectId.is_in(subject_ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find slot preferences for the given subjects
+    pub async fn find_subject_preferences(
+        &self,
+        subject_ids: Vec<i64>,
+    ) -> Result<Vec<subject_preferences::Model>, AppError> {
+        subject_preferences::Entity::find()
+            .filter(subject_preferences::Column::SubjectId.is_in(subject_ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher availability rows marked unavailable for the given slots
+    pub async fn find_unavailable_teachers(
+        &self,
+        slot_ids: Vec<i64>,
+    ) -> Result<Vec<teacher_availability::Model>, AppError> {
+        teacher_availability::Entity::find()
+            .filter(teacher_availability::Column::TimeSlotId.is_in(slot_ids))
+            .filter(teacher_availability::Column::IsAvailable.eq(0))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active teacher_subjects for the given subjects and semester
+    pub async fn find_teacher_subjects(
+        &self,
+        subject_ids: Vec<i64>,
+        semester_id: i64,
+    ) -> Result<Vec<teacher_subjects::Model>, AppError> {
+        teacher_subjects::Entity::find()
+            .filter(teacher_subjects::Column::SubjectId.is_in(subject_ids))
+            .filter(
+                Condition::any()
+                    .add(teacher_subjects::Column::SemesterId.is_null())
+                    .add(teacher_subjects::Column::SemesterId.eq(semester_id)),
+            )
+            .filter(
+                Condition::any()
+                    .add(teacher_subjects::Column::IsActive.is_null())
+                    .add(teacher_subjects::Column::IsActive.ne(0)),
+            )
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher assignments by IDs
+    pub async fn find_teacher_assignments(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teacher_assignments::Model>, AppError> {
+        teacher_assignments::Entity::find()
+            .filter(teacher_assignments::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find published schedules occupying the given slots, excluding some class subjects
+    pub async fn find_published_in_slots(
+        &self,
+        slot_ids: Vec<i64>,
+        exclude_class_subject_ids: Vec<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        ClassSchedule::find()
+            .filter(class_schedules::Column::TimeSlotId.is_in(slot_ids))
+            .filter(class_schedules::Column::ClassSubjectId.is_not_in(exclude_class_subject_ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert all schedule rows of one generation run and its log in a single transaction
+    pub async fn insert_batch(
+        &self,
+        models: Vec<class_schedules::ActiveModel>,
+        log: schedule_generation_logs::ActiveModel,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut inserted = Vec::with_capacity(models.len());
+        for model in models {
+            let row = model
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            inserted.push(row);
+        }
+
+        log.insert(&txn)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted)
+    }
+
+    /// Find schedules with optional filters
+    pub async fn find_filtered(
+        &self,
+        batch_id: Option<String>,
+        class_subject_ids: Vec<i64>,
+        teacher_id: Option<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        let mut query = ClassSchedule::find();
+
+        if let Some(batch_id) = batch_id {
+            query = query.filter(class_schedules::Column::GenerationBatchId.eq(batch_id));
+        }
+        query = query.filter(class_schedules::Column::ClassSubjectId.is_in(class_subject_ids));
+        if let Some(teacher_id) = teacher_id {
+            query = query.filter(class_schedules::Column::TeacherId.eq(teacher_id));
+        }
+
+        query
+            .order_by_asc(class_schedules::Column::TimeSlotId)
+            .order_by_asc(class_schedules::Column::ClassSubjectId)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subject IDs of a foundation, optionally by semester and/or class
+    pub async fn find_class_subject_ids(
+        &self,
+        foundation_id: i64,
+        semester_id: Option<i64>,
+        class_id: Option<i64>,
+    ) -> Result<Vec<i64>, AppError> {
+        let mut query = class_subjects::Entity::find()
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id));
+
+        if let Some(semester_id) = semester_id {
+            query = query.filter(class_subjects::Column::SemesterId.eq(semester_id));
+        }
+        if let Some(class_id) = class_id {
+            query = query.filter(class_subjects::Column::ClassId.eq(class_id));
+        }
+
+        let rows = query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.id).collect())
+    }
+
+    /// Find schedule by ID
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<class_schedules::Model>, AppError> {
+        ClassSchedule::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update schedule
+    pub async fn update(
+        &self,
+        active_model: class_schedules::ActiveModel,
+    ) -> Result<class_schedules::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find the rows forming one timetable: a draft batch, or (without batch)
+    /// the published rows plus manually created rows
+    pub async fn find_in_view(
+        &self,
+        class_subject_ids: Vec<i64>,
+        batch_id: Option<String>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        let mut query = ClassSchedule::find()
+            .filter(class_schedules::Column::ClassSubjectId.is_in(class_subject_ids));
+
+        query = match batch_id {
+            Some(batch_id) => query.filter(class_schedules::Column::GenerationBatchId.eq(batch_id)),
+            None => query.filter(
+                Condition::any()
+                    .add(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+                    .add(class_schedules::Column::GenerationBatchId.is_null()),
+            ),
+        };
+
+        query
+            .order_by_asc(class_schedules::Column::Id)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Persist recomputed conflict scores in a single transaction
+    pub async fn update_scores(&self, scores: Vec<(i64, i32)>) -> Result<(), AppError> {
+        if scores.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        for (id, score) in scores {
+            ClassSchedule::update_many()
+                .col_expr(class_schedules::Column::ConflictScore, Expr::value(score))
+                .filter(class_schedules::Column::Id.eq(id))
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots by IDs
+    pub async fn find_time_slots_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find rooms by IDs
+    pub async fn find_rooms_by_ids(&self, ids: Vec<i64>) -> Result<Vec<rooms::Model>, AppError> {
+        rooms::Entity::find()
+            .filter(rooms::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher by ID (not deleted)
+    pub async fn find_teacher(&self, id: i64) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find facilities installed in the given rooms
+    pub async fn find_room_facilities(
+        &self,
+        room_ids: Vec<i64>,
+    ) -> Result<Vec<(room_facilities::Model, Option<facilities::Model>)>, AppError> {
+        room_facilities::Entity::find()
+            .filter(room_facilities::Column::RoomId.is_in(room_ids))
+            .filter(room_facilities::Column::DeletedAt.is_null())
+            .find_also_related(facilities::Entity)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
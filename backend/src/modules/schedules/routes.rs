@@ -0,0 +1,16 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{middleware::auth::JwtMiddleware, modules::schedules::handler};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/schedules")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("/generate", web::post().to(handler::generate))
+            .route("/conflicts", web::get().to(handler::conflict_report))
+            .route("/{id}", web::put().to(handler::update)),
+    );
+}
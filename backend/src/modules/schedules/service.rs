@@ -0,0 +1,661 @@
+// ============================================================================
+// backend/src/modules/schedules/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::conflicts::{self, Conflict, ConflictContext, FacilityRef, ScheduleRow};
+use super::dto::{
+    ClassScheduleResponse, ConflictReportQuery, ConflictReportResponse, GenerateScheduleRequest,
+    GenerateScheduleResponse, ScheduleFilters, UnplacedLessonResponse, UpdateScheduleRequest,
+    UpdateScheduleResponse,
+};
+use super::repository::ScheduleRepository;
+use super::solver::{self, LessonInput, Problem, RoomInput, SlotInput};
+use crate::errors::AppError;
+use entity::sea_orm_active_enums::{GenerationStatus, ScheduleStatus};
+use entity::{class_schedules, schedule_generation_logs};
+use sea_orm::prelude::Decimal;
+use sea_orm::{ActiveEnum, Set};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+use validator::Validate;
+
+/// Nama algoritma yang dicatat pada hasil generate
+pub const ALGORITHM_BACKTRACKING: &str = "backtracking-csp";
+pub const ALGORITHM_GREEDY: &str = "greedy-fallback";
+
+#[derive(Clone)]
+pub struct ScheduleService {
+    repository: ScheduleRepository,
+}
+
+impl ScheduleService {
+    pub fn new(repository: ScheduleRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Generate timetable untuk satu semester & unit, simpan sebagai batch draft baru
+    /// beserta log run-nya di `schedule_generation_logs`
+    pub async fn generate(
+        &self,
+        request: GenerateScheduleRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<GenerateScheduleResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let started = Instant::now();
+
+        let semester = self
+            .repository
+            .find_semester(request.semester_id)
+            .await?
+            .filter(|s| s.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+
+        if semester.unit_id != request.unit_id {
+            return Err(AppError::bad_request(
+                "Semester does not belong to the given unit".to_string(),
+            ));
+        }
+
+        let class_subjects = self.repository.find_class_subjects(semester.id).await?;
+        if class_subjects.is_empty() {
+            return Err(AppError::unprocessable_entity(
+                "Semester has no class subjects to schedule".to_string(),
+            ));
+        }
+
+        let problem = self.build_problem(&semester, &class_subjects).await?;
+        if problem.slots.is_empty() {
+            return Err(AppError::unprocessable_entity(
+                "No active lesson time slots configured".to_string(),
+            ));
+        }
+
+        // Pencarian backtracking berat di CPU; jalankan di thread blocking agar worker tidak tertahan
+        let (problem, solution) = tokio::task::spawn_blocking(move || {
+            let solution = solver::solve(&problem);
+            (problem, solution)
+        })
+        .await
+        .map_err(|e| AppError::internal(format!("Schedule solver failed: {}", e)))?;
+
+        let batch_id = uuid::Uuid::new_v4().to_string();
+        let algorithm = if solution.complete {
+            ALGORITHM_BACKTRACKING
+        } else {
+            ALGORITHM_GREEDY
+        };
+        let total_slots_needed: i32 = problem.lessons.iter().map(|l| l.hours.max(0)).sum();
+        let total_slots_filled = solution.placements.len() as i32;
+        let unplaced: Vec<UnplacedLessonResponse> = solution
+            .unplaced
+            .iter()
+            .map(|u| UnplacedLessonResponse {
+                class_subject_id: u.class_subject_id,
+                missing_hours: u.missing_hours,
+                reason: u.reason.clone(),
+            })
+            .collect();
+        let execution_time_ms = started.elapsed().as_millis() as i64;
+
+        // Persist hasil sebagai batch baru
+        let now = chrono::Utc::now();
+        let models: Vec<class_schedules::ActiveModel> = solution
+            .placements
+            .iter()
+            .map(|p| class_schedules::ActiveModel {
+                class_subject_id: Set(p.class_subject_id),
+                time_slot_id: Set(p.time_slot_id),
+                room_id: Set(Some(p.room_id)),
+                teacher_id: Set(Some(p.teacher_id)),
+                is_auto_generated: Set(Some(1)),
+                generation_batch_id: Set(Some(batch_id.clone())),
+                conflict_score: Set(Some(p.penalty)),
+                status: Set(Some(ScheduleStatus::Draft)),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .collect();
+
+        let status = if total_slots_filled == 0 {
+            GenerationStatus::Failed
+        } else if solution.unplaced.is_empty() {
+            GenerationStatus::Success
+        } else {
+            GenerationStatus::Partial
+        };
+        let log = schedule_generation_logs::ActiveModel {
+            batch_id: Set(batch_id.clone()),
+            semester_id: Set(semester.id),
+            unit_id: Set(semester.unit_id),
+            algorithm: Set(Some(algorithm.to_string())),
+            total_slots_needed: Set(Some(total_slots_needed)),
+            total_slots_filled: Set(Some(total_slots_filled)),
+            success_rate: Set(Some(success_rate(total_slots_filled, total_slots_needed))),
+            conflicts_detected: Set(Some(serde_json::json!(unplaced))),
+            execution_time_ms: Set(Some(execution_time_ms as i32)),
+            status: Set(Some(status)),
+            error_message: Set(None),
+            created_by: Set(Some(user_id)),
+            created_at: Set(now),
+            ..Default::default()
+        };
+
+        self.repository.insert_batch(models, log).await?;
+
+        Ok(GenerateScheduleResponse {
+            batch_id,
+            semester_id: semester.id,
+            unit_id: semester.unit_id,
+            algorithm: algorithm.to_string(),
+            total_slots_needed,
+            total_slots_filled,
+            total_penalty: solution.total_penalty(),
+            execution_time_ms,
+            unplaced,
+        })
+    }
+
+    /// Get schedules with filters (scoped to foundation)
+    pub async fn get_all(
+        &self,
+        filters: ScheduleFilters,
+        foundation_id: i64,
+    ) -> Result<Vec<ClassScheduleResponse>, AppError> {
+        let class_subject_ids = self
+            .repository
+            .find_class_subject_ids(foundation_id, filters.semester_id, filters.class_id)
+            .await?;
+
+        if class_subject_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let items = self
+            .repository
+            .find_filtered(filters.batch_id, class_subject_ids, filters.teacher_id)
+            .await?;
+
+        Ok(ClassScheduleResponse::from_vec(items))
+    }
+
+    /// Update jadwal secara manual lalu validasi ulang timetable tempat jadwal itu berada
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateScheduleRequest,
+        foundation_id: i64,
+    ) -> Result<UpdateScheduleResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Schedule not found".to_string()))?;
+
+        let class_subject = self
+            .repository
+            .find_class_subjects_by_ids(vec![existing.class_subject_id])
+            .await?
+            .into_iter()
+            .find(|cs| cs.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Schedule not found".to_string()))?;
+
+        // Pastikan referensi baru milik foundation yang sama
+        if let Some(time_slot_id) = request.time_slot_id {
+            self.repository
+                .find_time_slots_by_ids(vec![time_slot_id])
+                .await?
+                .into_iter()
+                .find(|s| s.foundation_id == foundation_id && s.deleted_at.is_none())
+                .ok_or_else(|| AppError::bad_request("Time slot not found".to_string()))?;
+        }
+        if let Some(room_id) = request.room_id {
+            self.repository
+                .find_rooms_by_ids(vec![room_id])
+                .await?
+                .into_iter()
+                .find(|r| r.foundation_id == foundation_id)
+                .ok_or_else(|| AppError::bad_request("Room not found".to_string()))?;
+        }
+        if let Some(teacher_id) = request.teacher_id {
+            self.repository
+                .find_teacher(teacher_id)
+                .await?
+                .filter(|t| t.foundation_id == foundation_id)
+                .ok_or_else(|| AppError::bad_request("Teacher not found".to_string()))?;
+        }
+
+        let mut active_model = class_schedules::ActiveModel {
+            id: Set(id),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        if let Some(time_slot_id) = request.time_slot_id {
+            active_model.time_slot_id = Set(time_slot_id);
+        }
+        if let Some(room_id) = request.room_id {
+            active_model.room_id = Set(Some(room_id));
+        }
+        if let Some(teacher_id) = request.teacher_id {
+            active_model.teacher_id = Set(Some(teacher_id));
+        }
+        let mut updated = self.repository.update(active_model).await?;
+
+        // Draft batch divalidasi sendiri, selain itu terhadap timetable published
+        let view = match (&updated.status, &updated.generation_batch_id) {
+            (Some(ScheduleStatus::Draft), Some(batch_id)) => Some(batch_id.clone()),
+            _ => None,
+        };
+        let (rows, conflicts) = self.revalidate(class_subject.semester_id, view).await?;
+
+        if let Some(row) = rows.into_iter().find(|r| r.id == id) {
+            updated.conflict_score = row.conflict_score;
+        }
+
+        Ok(UpdateScheduleResponse {
+            schedule: ClassScheduleResponse::from(updated),
+            conflicts: conflicts
+                .into_iter()
+                .filter(|c| c.schedule_ids.contains(&id))
+                .collect(),
+        })
+    }
+
+    /// Laporan conflict per semester (draft batch tertentu atau timetable published)
+    pub async fn conflict_report(
+        &self,
+        query: ConflictReportQuery,
+        foundation_id: i64,
+    ) -> Result<ConflictReportResponse, AppError> {
+        let semester = self
+            .repository
+            .find_semester(query.semester_id)
+            .await?
+            .filter(|s| s.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Semester not found".to_string()))?;
+
+        let class_subjects = self.repository.find_class_subjects(semester.id).await?;
+        let models = self
+            .repository
+            .find_in_view(
+                class_subjects.iter().map(|cs| cs.id).collect(),
+                query.batch_id.clone(),
+            )
+            .await?;
+        let (rows, ctx) = self.conflict_input(&models, &class_subjects).await?;
+        let conflicts = conflicts::detect(&rows, &ctx);
+
+        Ok(ConflictReportResponse {
+            semester_id: semester.id,
+            batch_id: query.batch_id,
+            total_schedules: rows.len(),
+            total_conflicts: conflicts.len(),
+            conflicts,
+        })
+    }
+
+    /// Deteksi conflict pada satu timetable dan simpan `conflict_score` yang berubah
+    async fn revalidate(
+        &self,
+        semester_id: i64,
+        batch_id: Option<String>,
+    ) -> Result<(Vec<class_schedules::Model>, Vec<Conflict>), AppError> {
+        let class_subjects = self.repository.find_class_subjects(semester_id).await?;
+        let mut models = self
+            .repository
+            .find_in_view(class_subjects.iter().map(|cs| cs.id).collect(), batch_id)
+            .await?;
+
+        let (rows, ctx) = self.conflict_input(&models, &class_subjects).await?;
+        let conflicts = conflicts::detect(&rows, &ctx);
+        let scores = conflicts::score(&rows, &conflicts, &ctx);
+
+        let mut changed = Vec::new();
+        for model in models.iter_mut() {
+            let score = scores.get(&model.id).copied().unwrap_or(0);
+            if model.conflict_score != Some(score) {
+                model.conflict_score = Some(score);
+                changed.push((model.id, score));
+            }
+        }
+        self.repository.update_scores(changed).await?;
+
+        Ok((models, conflicts))
+    }
+
+    /// Kumpulkan data yang dibutuhkan conflict detector untuk sekumpulan jadwal
+    async fn conflict_input(
+        &self,
+        models: &[class_schedules::Model],
+        class_subjects: &[entity::class_subjects::Model],
+    ) -> Result<(Vec<ScheduleRow>, ConflictContext), AppError> {
+        let class_subject_map: HashMap<i64, &entity::class_subjects::Model> =
+            class_subjects.iter().map(|cs| (cs.id, cs)).collect();
+
+        let rows: Vec<ScheduleRow> = models
+            .iter()
+            .filter_map(|m| {
+                class_subject_map
+                    .get(&m.class_subject_id)
+                    .map(|cs| ScheduleRow {
+                        id: m.id,
+                        class_subject_id: m.class_subject_id,
+                        class_id: cs.class_id,
+                        subject_id: cs.subject_id,
+                        time_slot_id: m.time_slot_id,
+                        room_id: m.room_id,
+                        teacher_id: m.teacher_id,
+                    })
+            })
+            .collect();
+
+        let mut ctx = ConflictContext::default();
+        if rows.is_empty() {
+            return Ok((rows, ctx));
+        }
+
+        let class_ids = unique(rows.iter().map(|r| r.class_id));
+        let subject_ids = unique(rows.iter().map(|r| r.subject_id));
+        let slot_ids = unique(rows.iter().map(|r| r.time_slot_id));
+        let room_ids = unique(rows.iter().filter_map(|r| r.room_id));
+
+        for student in self.repository.find_students_by_classes(class_ids).await? {
+            if let Some(class_id) = student.class_id {
+                *ctx.class_sizes.entry(class_id).or_insert(0) += 1;
+            }
+        }
+
+        ctx.room_capacities = self
+            .repository
+            .find_rooms_by_ids(room_ids.clone())
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.capacity))
+            .collect();
+
+        for (room_facility, facility) in self.repository.find_room_facilities(room_ids).await? {
+            let set = ctx
+                .room_facilities
+                .entry(room_facility.room_id)
+                .or_default();
+            set.insert(FacilityRef::Id(room_facility.facility_id));
+            if let Some(facility) = facility {
+                set.insert(FacilityRef::Name(facility.name.trim().to_lowercase()));
+            }
+        }
+
+        ctx.required_facilities = self
+            .repository
+            .find_room_requirements(subject_ids.clone())
+            .await?
+            .into_iter()
+            .filter_map(|r| {
+                r.required_facilities
+                    .map(|value| (r.subject_id, FacilityRef::parse_list(&value)))
+            })
+            .filter(|(_, required)| !required.is_empty())
+            .collect();
+
+        ctx.teacher_unavailable = self
+            .repository
+            .find_unavailable_teachers(slot_ids.clone())
+            .await?
+            .into_iter()
+            .map(|a| (a.teacher_id, a.time_slot_id))
+            .collect();
+
+        ctx.subject_preferences = self
+            .repository
+            .find_subject_preferences(subject_ids)
+            .await?
+            .into_iter()
+            .filter_map(|p| {
+                p.preference_level
+                    .map(|level| ((p.subject_id, p.time_slot_id), level))
+            })
+            .collect();
+
+        ctx.slot_positions = self
+            .repository
+            .find_time_slots_by_ids(slot_ids)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, (s.day_of_week, s.order)))
+            .collect();
+
+        Ok((rows, ctx))
+    }
+
+    /// Kumpulkan semua input solver dari database
+    async fn build_problem(
+        &self,
+        semester: &entity::semesters::Model,
+        class_subjects: &[entity::class_subjects::Model],
+    ) -> Result<Problem, AppError> {
+        let class_subject_ids: Vec<i64> = class_subjects.iter().map(|cs| cs.id).collect();
+        let subject_ids: Vec<i64> = unique(class_subjects.iter().map(|cs| cs.subject_id));
+        let class_ids: Vec<i64> = unique(class_subjects.iter().map(|cs| cs.class_id));
+
+        // Time slots: grid khusus unit dipakai jika ada, selain itu grid foundation
+        let mut slot_models = self
+            .repository
+            .find_lesson_slots(semester.foundation_id, semester.unit_id)
+            .await?;
+        if slot_models.iter().any(|s| s.unit_id.is_some()) {
+            slot_models.retain(|s| s.unit_id.is_some());
+        }
+        let slot_ids: Vec<i64> = slot_models.iter().map(|s| s.id).collect();
+        let slots: Vec<SlotInput> = slot_models
+            .iter()
+            .map(|s| SlotInput {
+                id: s.id,
+                day_of_week: s.day_of_week,
+                order: s.order,
+            })
+            .collect();
+
+        // Rooms & room types
+        let room_models = self
+            .repository
+            .find_available_rooms(semester.unit_id)
+            .await?;
+        let room_type_names: HashMap<i64, String> = self
+            .repository
+            .find_room_types(unique(room_models.iter().map(|r| r.room_type_id)))
+            .await?
+            .into_iter()
+            .filter_map(|rt| rt.name.map(|name| (rt.id, name.trim().to_lowercase())))
+            .collect();
+        let rooms: Vec<RoomInput> = room_models
+            .iter()
+            .map(|r| RoomInput {
+                id: r.id,
+                capacity: r.capacity,
+                room_type: room_type_names.get(&r.room_type_id).cloned(),
+            })
+            .collect();
+
+        // Class sizes
+        let mut class_sizes: HashMap<i64, i32> = HashMap::new();
+        for student in self.repository.find_students_by_classes(class_ids).await? {
+            if let Some(class_id) = student.class_id {
+                *class_sizes.entry(class_id).or_insert(0) += 1;
+            }
+        }
+
+        // Room type requirements
+        let required_room_types: HashMap<i64, String> = self
+            .repository
+            .find_room_requirements(subject_ids.clone())
+            .await?
+            .into_iter()
+            .map(|r| (r.subject_id, r.required_room_type.to_value()))
+            .collect();
+
+        // Qualified teachers: teacher_subjects -> teacher_assignments (unit yang sama)
+        let teacher_subjects = self
+            .repository
+            .find_teacher_subjects(subject_ids.clone(), semester.id)
+            .await?;
+        let assignment_teacher: HashMap<i64, i64> = self
+            .repository
+            .find_teacher_assignments(unique(
+                teacher_subjects.iter().map(|ts| ts.teacher_assignment_id),
+            ))
+            .await?
+            .into_iter()
+            .filter(|a| a.unit_id == semester.unit_id && a.is_active.unwrap_or(1) != 0)
+            .map(|a| (a.id, a.teacher_id))
+            .collect();
+
+        let lessons: Vec<LessonInput> = class_subjects
+            .iter()
+            .map(|cs| {
+                let candidate_teachers = match cs.teacher_id {
+                    Some(teacher_id) => vec![teacher_id],
+                    None => {
+                        let qualified = |same_class: bool| {
+                            unique(
+                                teacher_subjects
+                                    .iter()
+                                    .filter(|ts| ts.subject_id == cs.subject_id)
+                                    .filter(|ts| !same_class || ts.classroom_id == cs.class_id)
+                                    .filter_map(|ts| {
+                                        assignment_teacher.get(&ts.teacher_assignment_id).copied()
+                                    }),
+                            )
+                        };
+                        // Guru yang sudah ditugaskan ke kelas ini didahulukan
+                        let same_class = qualified(true);
+                        if same_class.is_empty() {
+                            qualified(false)
+                        } else {
+                            same_class
+                        }
+                    }
+                };
+
+                LessonInput {
+                    class_subject_id: cs.id,
+                    class_id: cs.class_id,
+                    subject_id: cs.subject_id,
+                    hours: cs.credit_hours.unwrap_or(0),
+                    class_size: class_sizes.get(&cs.class_id).copied().unwrap_or(0),
+                    candidate_teachers,
+                    required_room_type: required_room_types.get(&cs.subject_id).cloned(),
+                }
+            })
+            .collect();
+
+        // Soft constraint: preferensi slot per mapel
+        let subject_preferences = self
+            .repository
+            .find_subject_preferences(subject_ids)
+            .await?
+            .into_iter()
+            .filter_map(|p| {
+                p.preference_level
+                    .map(|level| ((p.subject_id, p.time_slot_id), level))
+            })
+            .collect();
+
+        // Hard constraint: ketidaktersediaan guru
+        let teacher_unavailable = self
+            .repository
+            .find_unavailable_teachers(slot_ids.clone())
+            .await?
+            .into_iter()
+            .map(|a| (a.teacher_id, a.time_slot_id))
+            .collect();
+
+        // Jadwal published dari semester lain yang beririsan tanggalnya
+        let (busy_teachers, busy_rooms) = self
+            .published_occupancy(semester, slot_ids, class_subject_ids)
+            .await?;
+
+        Ok(Problem {
+            slots,
+            rooms,
+            lessons,
+            teacher_unavailable,
+            subject_preferences,
+            busy_teachers,
+            busy_rooms,
+        })
+    }
+
+    /// Slot guru & ruangan yang sudah dipakai jadwal published di semester yang beririsan
+    async fn published_occupancy(
+        &self,
+        semester: &entity::semesters::Model,
+        slot_ids: Vec<i64>,
+        class_subject_ids: Vec<i64>,
+    ) -> Result<(HashSet<(i64, i64)>, HashSet<(i64, i64)>), AppError> {
+        let published = self
+            .repository
+            .find_published_in_slots(slot_ids, class_subject_ids)
+            .await?;
+        if published.is_empty() {
+            return Ok((HashSet::new(), HashSet::new()));
+        }
+
+        let other_class_subjects = self
+            .repository
+            .find_class_subjects_by_ids(unique(published.iter().map(|s| s.class_subject_id)))
+            .await?;
+        let overlapping_semesters: HashSet<i64> = self
+            .repository
+            .find_semesters_by_ids(unique(other_class_subjects.iter().map(|cs| cs.semester_id)))
+            .await?
+            .into_iter()
+            .filter(|s| s.start_date <= semester.end_date && s.end_date >= semester.start_date)
+            .map(|s| s.id)
+            .collect();
+        let relevant: HashSet<i64> = other_class_subjects
+            .into_iter()
+            .filter(|cs| overlapping_semesters.contains(&cs.semester_id))
+            .map(|cs| cs.id)
+            .collect();
+
+        let mut busy_teachers = HashSet::new();
+        let mut busy_rooms = HashSet::new();
+        for schedule in published
+            .into_iter()
+            .filter(|s| relevant.contains(&s.class_subject_id))
+        {
+            if let Some(teacher_id) = schedule.teacher_id {
+                busy_teachers.insert((teacher_id, schedule.time_slot_id));
+            }
+            if let Some(room_id) = schedule.room_id {
+                busy_rooms.insert((room_id, schedule.time_slot_id));
+            }
+        }
+
+        Ok((busy_teachers, busy_rooms))
+    }
+}
+
+/// Persentase slot terisi dengan 2 digit desimal
+fn success_rate(filled: i32, needed: i32) -> Decimal {
+    if needed <= 0 {
+        return Decimal::new(10000, 2);
+    }
+    Decimal::new(filled as i64 * 10000 / needed as i64, 2)
+}
+
+fn unique(ids: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut ids: Vec<i64> = ids.collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
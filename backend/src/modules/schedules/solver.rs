@@ -0,0 +1,531 @@
+// ============================================================================
+// backend/src/modules/schedules/solver.rs
+// solver.rs - Timetable Constraint Solver (pure, tanpa akses database)
+// ============================================================================
+use entity::sea_orm_active_enums::PreferenceLevel;
+use std::collections::{HashMap, HashSet};
+
+/// Penalti soft constraint: mapel dijadwalkan di slot yang ditandai `avoid`
+pub const AVOID_SLOT_PENALTY: i32 = 10;
+/// Penalti soft constraint: jam mapel yang sama menumpuk di hari yang sama
+pub const SAME_DAY_PENALTY: i32 = 3;
+
+/// Batas jumlah percobaan backtracking sebelum jatuh ke mode greedy
+const SEARCH_BUDGET: usize = 200_000;
+
+#[derive(Debug, Clone)]
+pub struct SlotInput {
+    pub id: i64,
+    pub day_of_week: i8,
+    pub order: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoomInput {
+    pub id: i64,
+    pub capacity: i32,
+    /// Nama tipe ruangan (lowercase), dicocokkan dengan `RequiredRoomType`
+    pub room_type: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LessonInput {
+    pub class_subject_id: i64,
+    pub class_id: i64,
+    pub subject_id: i64,
+    pub hours: i32,
+    pub class_size: i32,
+    pub candidate_teachers: Vec<i64>,
+    pub required_room_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Problem {
+    pub slots: Vec<SlotInput>,
+    pub rooms: Vec<RoomInput>,
+    pub lessons: Vec<LessonInput>,
+    /// Pasangan (teacher_id, time_slot_id) yang ditandai tidak tersedia
+    pub teacher_unavailable: HashSet<(i64, i64)>,
+    /// (subject_id, time_slot_id) -> preferensi slot
+    pub subject_preferences: HashMap<(i64, i64), PreferenceLevel>,
+    /// (teacher_id, time_slot_id) yang sudah terisi jadwal published lain
+    pub busy_teachers: HashSet<(i64, i64)>,
+    /// (room_id, time_slot_id) yang sudah terisi jadwal published lain
+    pub busy_rooms: HashSet<(i64, i64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placement {
+    pub class_subject_id: i64,
+    pub time_slot_id: i64,
+    pub room_id: i64,
+    pub teacher_id: i64,
+    pub penalty: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unplaced {
+    pub class_subject_id: i64,
+    pub missing_hours: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Solution {
+    pub placements: Vec<Placement>,
+    pub unplaced: Vec<Unplaced>,
+    /// true jika backtracking menemukan solusi lengkap (bukan hasil greedy)
+    pub complete: bool,
+}
+
+impl Solution {
+    pub fn total_penalty(&self) -> i32 {
+        self.placements.iter().map(|p| p.penalty).sum()
+    }
+}
+
+/// Penalti soft constraint untuk preferensi slot sebuah mapel
+pub fn preference_penalty(level: Option<&PreferenceLevel>) -> i32 {
+    match level {
+        Some(PreferenceLevel::Avoid) => AVOID_SLOT_PENALTY,
+        _ => 0,
+    }
+}
+
+/// Selesaikan timetable: coba backtracking penuh dulu, jika gagal atau budget habis
+/// gunakan greedy yang melewati jam pelajaran yang tidak bisa ditempatkan.
+pub fn solve(problem: &Problem) -> Solution {
+    let mut search = Search::new(problem);
+
+    if search.backtrack(0) {
+        return search.into_solution(true);
+    }
+
+    let mut greedy = Search::new(problem);
+    greedy.greedy();
+    greedy.into_solution(false)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    cost: i32,
+    slot: usize,
+    teacher: i64,
+    room: usize,
+}
+
+#[derive(Default)]
+struct State {
+    class_busy: HashSet<(i64, i64)>,
+    teacher_busy: HashSet<(i64, i64)>,
+    room_busy: HashSet<(i64, i64)>,
+    /// lesson index -> (teacher terkunci, jumlah jam yang sudah ditempatkan)
+    lesson_teacher: HashMap<usize, (i64, usize)>,
+    lesson_days: HashMap<(usize, i8), i32>,
+}
+
+struct Search<'a> {
+    problem: &'a Problem,
+    /// Satu entry per jam pelajaran (index ke `problem.lessons`)
+    units: Vec<usize>,
+    /// Index ruangan yang memenuhi kapasitas & tipe per lesson, urut kapasitas
+    lesson_rooms: Vec<Vec<usize>>,
+    assignment: Vec<Option<Candidate>>,
+    failures: HashMap<usize, (i32, String)>,
+    state: State,
+    budget: usize,
+}
+
+impl<'a> Search<'a> {
+    fn new(problem: &'a Problem) -> Self {
+        let lesson_rooms: Vec<Vec<usize>> = problem
+            .lessons
+            .iter()
+            .map(|lesson| {
+                let mut rooms: Vec<usize> = problem
+                    .rooms
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, room)| room.capacity >= lesson.class_size)
+                    .filter(|(_, room)| match &lesson.required_room_type {
+                        Some(required) => room.room_type.as_deref() == Some(required.as_str()),
+                        None => true,
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect();
+                rooms.sort_by_key(|idx| problem.rooms[*idx].capacity);
+                rooms
+            })
+            .collect();
+
+        // Most-constrained-first: lesson dengan domain terkecil ditempatkan lebih dulu
+        let domain = |idx: usize| -> usize {
+            let lesson = &problem.lessons[idx];
+            let free_pairs = problem
+                .slots
+                .iter()
+                .flat_map(|slot| {
+                    lesson
+                        .candidate_teachers
+                        .iter()
+                        .map(move |teacher| (*teacher, slot.id))
+                })
+                .filter(|pair| {
+                    !problem.teacher_unavailable.contains(pair)
+                        && !problem.busy_teachers.contains(pair)
+                })
+                .count();
+            free_pairs * lesson_rooms[idx].len()
+        };
+
+        let mut order: Vec<usize> = (0..problem.lessons.len())
+            .filter(|idx| problem.lessons[*idx].hours > 0)
+            .collect();
+        order.sort_by_key(|idx| (domain(*idx), -problem.lessons[*idx].hours));
+
+        let units: Vec<usize> = order
+            .into_iter()
+            .flat_map(|idx| std::iter::repeat_n(idx, problem.lessons[idx].hours as usize))
+            .collect();
+
+        Self {
+            problem,
+            assignment: vec![None; units.len()],
+            units,
+            lesson_rooms,
+            failures: HashMap::new(),
+            state: State::default(),
+            budget: SEARCH_BUDGET,
+        }
+    }
+
+    fn candidates(&self, lesson_idx: usize) -> Vec<Candidate> {
+        let problem = self.problem;
+        let lesson = &problem.lessons[lesson_idx];
+        let teachers: Vec<i64> = match self.state.lesson_teacher.get(&lesson_idx) {
+            Some((teacher, _)) => vec![*teacher],
+            None => lesson.candidate_teachers.clone(),
+        };
+
+        let mut candidates = Vec::new();
+        for (slot_idx, slot) in problem.slots.iter().enumerate() {
+            if self.state.class_busy.contains(&(lesson.class_id, slot.id)) {
+                continue;
+            }
+
+            let same_day = self
+                .state
+                .lesson_days
+                .get(&(lesson_idx, slot.day_of_week))
+                .copied()
+                .unwrap_or(0);
+            let cost = preference_penalty(
+                problem
+                    .subject_preferences
+                    .get(&(lesson.subject_id, slot.id)),
+            ) + same_day * SAME_DAY_PENALTY;
+
+            for teacher in &teachers {
+                let pair = (*teacher, slot.id);
+                if problem.teacher_unavailable.contains(&pair)
+                    || problem.busy_teachers.contains(&pair)
+                    || self.state.teacher_busy.contains(&pair)
+                {
+                    continue;
+                }
+
+                let room = self.lesson_rooms[lesson_idx].iter().copied().find(|idx| {
+                    let key = (problem.rooms[*idx].id, slot.id);
+                    !problem.busy_rooms.contains(&key) && !self.state.room_busy.contains(&key)
+                });
+
+                if let Some(room) = room {
+                    candidates.push(Candidate {
+                        cost,
+                        slot: slot_idx,
+                        teacher: *teacher,
+                        room,
+                    });
+                }
+            }
+        }
+
+        // Preferred slot didahulukan saat biaya sama
+        candidates.sort_by_key(|c| {
+            let slot = &problem.slots[c.slot];
+            let preferred = matches!(
+                problem
+                    .subject_preferences
+                    .get(&(lesson.subject_id, slot.id)),
+                Some(PreferenceLevel::Preferred)
+            );
+            (c.cost, !preferred, slot.day_of_week, slot.order)
+        });
+        candidates
+    }
+
+    fn apply(&mut self, lesson_idx: usize, candidate: &Candidate) {
+        let lesson = &self.problem.lessons[lesson_idx];
+        let slot = &self.problem.slots[candidate.slot];
+        let room_id = self.problem.rooms[candidate.room].id;
+
+        self.state.class_busy.insert((lesson.class_id, slot.id));
+        self.state.teacher_busy.insert((candidate.teacher, slot.id));
+        self.state.room_busy.insert((room_id, slot.id));
+        self.state
+            .lesson_teacher
+            .entry(lesson_idx)
+            .or_insert((candidate.teacher, 0))
+            .1 += 1;
+        *self
+            .state
+            .lesson_days
+            .entry((lesson_idx, slot.day_of_week))
+            .or_insert(0) += 1;
+    }
+
+    fn undo(&mut self, lesson_idx: usize, candidate: &Candidate) {
+        let lesson = &self.problem.lessons[lesson_idx];
+        let slot = &self.problem.slots[candidate.slot];
+        let room_id = self.problem.rooms[candidate.room].id;
+
+        self.state.class_busy.remove(&(lesson.class_id, slot.id));
+        self.state
+            .teacher_busy
+            .remove(&(candidate.teacher, slot.id));
+        self.state.room_busy.remove(&(room_id, slot.id));
+        if let Some(entry) = self.state.lesson_teacher.get_mut(&lesson_idx) {
+            entry.1 -= 1;
+            if entry.1 == 0 {
+                self.state.lesson_teacher.remove(&lesson_idx);
+            }
+        }
+        if let Some(count) = self
+            .state
+            .lesson_days
+            .get_mut(&(lesson_idx, slot.day_of_week))
+        {
+            *count -= 1;
+        }
+    }
+
+    fn backtrack(&mut self, position: usize) -> bool {
+        if position == self.units.len() {
+            return true;
+        }
+
+        let lesson_idx = self.units[position];
+        for candidate in self.candidates(lesson_idx) {
+            if self.budget == 0 {
+                return false;
+            }
+            self.budget -= 1;
+
+            self.apply(lesson_idx, &candidate);
+            self.assignment[position] = Some(candidate);
+
+            if self.backtrack(position + 1) {
+                return true;
+            }
+
+            self.assignment[position] = None;
+            self.undo(lesson_idx, &candidate);
+        }
+
+        false
+    }
+
+    fn greedy(&mut self) {
+        for position in 0..self.units.len() {
+            let lesson_idx = self.units[position];
+            match self.candidates(lesson_idx).into_iter().next() {
+                Some(candidate) => {
+                    self.apply(lesson_idx, &candidate);
+                    self.assignment[position] = Some(candidate);
+                }
+                None => {
+                    let reason = self.failure_reason(lesson_idx);
+                    let entry = self.failures.entry(lesson_idx).or_insert((0, reason));
+                    entry.0 += 1;
+                }
+            }
+        }
+    }
+
+    fn failure_reason(&self, lesson_idx: usize) -> String {
+        let lesson = &self.problem.lessons[lesson_idx];
+        if lesson.candidate_teachers.is_empty() {
+            "No qualified teacher for this subject".to_string()
+        } else if self.lesson_rooms[lesson_idx].is_empty() {
+            "No room satisfies the class size and required room type".to_string()
+        } else {
+            "No free time slot for the class, teacher and room".to_string()
+        }
+    }
+
+    fn into_solution(self, complete: bool) -> Solution {
+        let problem = self.problem;
+        let placements = self
+            .units
+            .iter()
+            .zip(self.assignment.iter())
+            .filter_map(|(lesson_idx, candidate)| {
+                candidate.map(|c| (&problem.lessons[*lesson_idx], c))
+            })
+            .map(|(lesson, c)| Placement {
+                class_subject_id: lesson.class_subject_id,
+                time_slot_id: problem.slots[c.slot].id,
+                room_id: problem.rooms[c.room].id,
+                teacher_id: c.teacher,
+                penalty: c.cost,
+            })
+            .collect();
+
+        let mut unplaced: Vec<Unplaced> = self
+            .failures
+            .into_iter()
+            .map(|(lesson_idx, (missing_hours, reason))| Unplaced {
+                class_subject_id: problem.lessons[lesson_idx].class_subject_id,
+                missing_hours,
+                reason,
+            })
+            .collect();
+        unplaced.sort_by_key(|u| u.class_subject_id);
+
+        Solution {
+            placements,
+            unplaced,
+            complete,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slots(days: i8, per_day: i32) -> Vec<SlotInput> {
+        let mut id = 0;
+        let mut slots = Vec::new();
+        for day in 1..=days {
+            for order in 1..=per_day {
+                id += 1;
+                slots.push(SlotInput {
+                    id,
+                    day_of_week: day,
+                    order,
+                });
+            }
+        }
+        slots
+    }
+
+    fn lesson(class_subject_id: i64, class_id: i64, hours: i32, teacher: i64) -> LessonInput {
+        LessonInput {
+            class_subject_id,
+            class_id,
+            subject_id: class_subject_id,
+            hours,
+            class_size: 30,
+            candidate_teachers: vec![teacher],
+            required_room_type: None,
+        }
+    }
+
+    fn classroom(id: i64) -> RoomInput {
+        RoomInput {
+            id,
+            capacity: 36,
+            room_type: Some("classroom".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_places_all_hours_without_double_booking() {
+        let problem = Problem {
+            slots: slots(2, 3),
+            rooms: vec![classroom(1), classroom(2)],
+            lessons: vec![lesson(1, 10, 3, 7), lesson(2, 11, 3, 7)],
+            ..Default::default()
+        };
+
+        let solution = solve(&problem);
+
+        assert!(solution.complete);
+        assert_eq!(solution.placements.len(), 6);
+        let teacher_slots: HashSet<(i64, i64)> = solution
+            .placements
+            .iter()
+            .map(|p| (p.teacher_id, p.time_slot_id))
+            .collect();
+        assert_eq!(teacher_slots.len(), 6);
+    }
+
+    #[test]
+    fn test_respects_unavailability_and_room_type() {
+        let mut problem = Problem {
+            slots: slots(1, 2),
+            rooms: vec![
+                classroom(1),
+                RoomInput {
+                    id: 2,
+                    capacity: 40,
+                    room_type: Some("lab".to_string()),
+                },
+            ],
+            lessons: vec![LessonInput {
+                required_room_type: Some("lab".to_string()),
+                ..lesson(1, 10, 1, 7)
+            }],
+            ..Default::default()
+        };
+        problem.teacher_unavailable.insert((7, 1));
+
+        let solution = solve(&problem);
+
+        assert_eq!(solution.placements.len(), 1);
+        assert_eq!(solution.placements[0].time_slot_id, 2);
+        assert_eq!(solution.placements[0].room_id, 2);
+    }
+
+    #[test]
+    fn test_prefers_slots_not_marked_avoid() {
+        let mut problem = Problem {
+            slots: slots(1, 2),
+            rooms: vec![classroom(1)],
+            lessons: vec![lesson(1, 10, 1, 7)],
+            ..Default::default()
+        };
+        problem
+            .subject_preferences
+            .insert((1, 1), PreferenceLevel::Avoid);
+
+        let solution = solve(&problem);
+
+        assert_eq!(solution.placements[0].time_slot_id, 2);
+        assert_eq!(solution.total_penalty(), 0);
+    }
+
+    #[test]
+    fn test_reports_hours_that_cannot_be_placed() {
+        let problem = Problem {
+            slots: slots(1, 2),
+            rooms: vec![classroom(1)],
+            lessons: vec![lesson(1, 10, 3, 7)],
+            ..Default::default()
+        };
+
+        let solution = solve(&problem);
+
+        assert!(!solution.complete);
+        assert_eq!(solution.placements.len(), 2);
+        assert_eq!(
+            solution.unplaced,
+            vec![Unplaced {
+                class_subject_id: 1,
+                missing_hours: 1,
+                reason: "No free time slot for the class, teacher and room".to_string(),
+            }]
+        );
+    }
+}
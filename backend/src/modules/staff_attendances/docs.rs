@@ -0,0 +1,57 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    ClockRequest, MonthlyStaffQuery, RecordStaffAttendanceRequest, StaffAttendanceFilters,
+    StaffAttendanceResponse, StaffMonthlySummary, StaffType,
+};
+use super::handler;
+use crate::utils::pagination::PaginatedResponse;
+use entity::sea_orm_active_enums::StaffAttendanceStatus;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::clock_in,
+        handler::clock_out,
+        handler::record,
+        handler::get_all,
+        handler::monthly,
+    ),
+    components(
+        schemas(
+            StaffAttendanceStatus,
+            StaffType,
+            StaffAttendanceResponse,
+            ClockRequest,
+            RecordStaffAttendanceRequest,
+            StaffAttendanceFilters,
+            MonthlyStaffQuery,
+            StaffMonthlySummary,
+            PaginatedResponse<StaffAttendanceResponse>,
+        )
+    ),
+    tags(
+        (name = "Staff Attendance ", description = "Staff clock-in/out and monthly presence for payroll")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct StaffAttendancesApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
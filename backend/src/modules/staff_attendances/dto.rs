@@ -0,0 +1,119 @@
+// backend/src/modules/staff_attendances/dto.rs
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::StaffAttendanceStatus;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StaffAttendanceResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub user_id: i64,
+    pub unit_id: Option<i64>,
+    pub date: NaiveDate,
+    pub status: StaffAttendanceStatus,
+    pub clock_in_at: Option<String>,
+    pub clock_out_at: Option<String>,
+    pub clock_in_latitude: Option<f64>,
+    pub clock_in_longitude: Option<f64>,
+    pub clock_out_latitude: Option<f64>,
+    pub clock_out_longitude: Option<f64>,
+    pub late_minutes: Option<i32>,
+    pub notes: Option<String>,
+    /// Admin yang mencatat manual (sakit/cuti/alpa)
+    pub recorded_by: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ClockRequest {
+    /// Wajib bila unit memakai geofence
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
+    #[validate(length(max = 500))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RecordStaffAttendanceRequest {
+    #[validate(range(min = 1))]
+    pub user_id: i64,
+    pub date: NaiveDate,
+    pub status: StaffAttendanceStatus,
+    #[validate(length(max = 500))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct StaffAttendanceFilters {
+    pub user_id: Option<i64>,
+    pub unit_id: Option<i64>,
+    pub status: Option<StaffAttendanceStatus>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams, ToSchema)]
+pub struct MonthlyStaffQuery {
+    #[validate(range(min = 2000, max = 2100))]
+    pub year: i32,
+    #[validate(range(min = 1, max = 12))]
+    pub month: u32,
+    pub unit_id: Option<i64>,
+    pub user_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StaffType {
+    Employee,
+    Teacher,
+}
+
+/// Rekap bulanan; kolomnya sama dengan `salary_payments`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StaffMonthlySummary {
+    pub user_id: i64,
+    pub name: String,
+    pub staff_type: StaffType,
+    pub unit_id: Option<i64>,
+    pub year: i32,
+    pub month: u32,
+    pub working_days: i32,
+    /// Termasuk hari terlambat
+    pub present_days: i32,
+    pub late_days: i32,
+    pub sick_days: i32,
+    pub leave_days: i32,
+    /// Hari kerja yang sudah lewat tanpa hadir, sakit atau cuti
+    pub absent_days: i32,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::staff_attendances::Model> for StaffAttendanceResponse {
+    fn from(model: entity::staff_attendances::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            user_id: model.user_id,
+            unit_id: model.unit_id,
+            date: model.date,
+            status: model.status,
+            clock_in_at: model.clock_in_at.map(|t| t.to_string()),
+            clock_out_at: model.clock_out_at.map(|t| t.to_string()),
+            clock_in_latitude: model.clock_in_latitude.and_then(|v| v.try_into().ok()),
+            clock_in_longitude: model.clock_in_longitude.and_then(|v| v.try_into().ok()),
+            clock_out_latitude: model.clock_out_latitude.and_then(|v| v.try_into().ok()),
+            clock_out_longitude: model.clock_out_longitude.and_then(|v| v.try_into().ok()),
+            late_minutes: model.late_minutes,
+            notes: model.notes,
+            recorded_by: model.recorded_by,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    ClockRequest, MonthlyStaffQuery, RecordStaffAttendanceRequest, StaffAttendanceFilters,
+    StaffAttendanceResponse, StaffMonthlySummary,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Clock in (employee or teacher)
+#[utoipa::path(
+    post,
+    path = "/api/staff_attendances/clock_in",
+    request_body = ClockRequest,
+    responses(
+        (status = 201, description = "Clocked in; status is late when past the unit start time", body = StaffAttendanceResponse),
+        (status = 400, description = "Coordinates missing for a geofenced unit"),
+        (status = 403, description = "Outside the unit area"),
+        (status = 404, description = "Not an employee or teacher of the foundation"),
+        (status = 409, description = "Already clocked in or attendance recorded for today")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Staff Attendance "
+)]
+pub async fn clock_in(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<ClockRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .staff_attendance_service
+        .clock_in(request.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Clock out (employee or teacher)
+#[utoipa::path(
+    post,
+    path = "/api/staff_attendances/clock_out",
+    request_body = ClockRequest,
+    responses(
+        (status = 200, description = "Clocked out", body = StaffAttendanceResponse),
+        (status = 400, description = "Not clocked in today or coordinates missing"),
+        (status = 403, description = "Outside the unit area"),
+        (status = 404, description = "Not an employee or teacher of the foundation"),
+        (status = 409, description = "Already clocked out today")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Staff Attendance "
+)]
+pub async fn clock_out(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<ClockRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .staff_attendance_service
+        .clock_out(request.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Record staff attendance manually (sick, leave, absent or corrections)
+#[utoipa::path(
+    put,
+    path = "/api/staff_attendances/records",
+    request_body = RecordStaffAttendanceRequest,
+    responses(
+        (status = 200, description = "Attendance recorded", body = StaffAttendanceResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Employee or teacher not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Staff Attendance "
+)]
+pub async fn record(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<RecordStaffAttendanceRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .staff_attendance_service
+        .record(request.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get staff attendances with pagination
+#[utoipa::path(
+    get,
+    path = "/api/staff_attendances",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        StaffAttendanceFilters,
+    ),
+    responses(
+        (status = 200, description = "List of staff attendances", body = PaginatedResponse<StaffAttendanceResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Staff Attendance "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<StaffAttendanceFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .staff_attendance_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Monthly day counts per staff member for payroll
+#[utoipa::path(
+    get,
+    path = "/api/staff_attendances/monthly",
+    params(MonthlyStaffQuery),
+    responses(
+        (status = 200, description = "Working, present, absent and late days per staff member", body = Vec<StaffMonthlySummary>),
+        (status = 400, description = "Invalid year or month")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Staff Attendance "
+)]
+pub async fn monthly(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<MonthlyStaffQuery>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .staff_attendance_service
+        .monthly(query.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -0,0 +1,22 @@
+// backend/src/modules/staff_attendances/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod presence;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::StaffAttendanceRepository;
+pub use service::StaffAttendanceService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> StaffAttendanceService {
+    let repository = StaffAttendanceRepository::new(db);
+    StaffAttendanceService::new(repository)
+}
@@ -0,0 +1,133 @@
+// ============================================================================
+// backend/src/modules/staff_attendances/presence.rs
+// presence.rs - Geofence, keterlambatan & rekap bulanan (pure, tanpa akses database)
+// ============================================================================
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use entity::sea_orm_active_enums::StaffAttendanceStatus;
+
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Jarak dua titik (lat, lon) dalam meter (haversine)
+pub fn distance_meters(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.1 - from.1).to_radians();
+
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
+/// Menit keterlambatan dari jam masuk; `None` bila masih dalam toleransi
+pub fn late_minutes(clock_in: NaiveTime, start: NaiveTime, tolerance_minutes: i32) -> Option<i32> {
+    let late = (clock_in - start).num_minutes();
+    (late > i64::from(tolerance_minutes)).then_some(late as i32)
+}
+
+/// 5 = Senin-Jumat, 6 = Senin-Sabtu
+pub fn is_working_day(date: NaiveDate, work_days_per_week: i8) -> bool {
+    date.weekday().num_days_from_monday() < u32::try_from(work_days_per_week).unwrap_or(5)
+}
+
+/// Tanggal pertama dan terakhir suatu bulan
+pub fn month_bounds(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some((first, next.pred_opt()?))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MonthlyCounts {
+    pub working_days: i32,
+    pub present_days: i32,
+    pub late_days: i32,
+    pub sick_days: i32,
+    pub leave_days: i32,
+    pub absent_days: i32,
+}
+
+/// Rekap untuk penggajian. Terlambat tetap dihitung hadir; hari kerja tanpa catatan
+/// sampai `until` dihitung alpa, sisa bulan yang belum lewat tidak.
+pub fn monthly_counts(
+    first: NaiveDate,
+    last: NaiveDate,
+    until: NaiveDate,
+    work_days_per_week: i8,
+    records: &[(NaiveDate, StaffAttendanceStatus)],
+) -> MonthlyCounts {
+    let mut counts = MonthlyCounts::default();
+    let mut elapsed = 0;
+
+    for date in first.iter_days().take_while(|d| *d <= last) {
+        if !is_working_day(date, work_days_per_week) {
+            continue;
+        }
+        counts.working_days += 1;
+        if date <= until {
+            elapsed += 1;
+        }
+
+        match records.iter().find(|(d, _)| *d == date).map(|(_, s)| s) {
+            Some(StaffAttendanceStatus::Present) => counts.present_days += 1,
+            Some(StaffAttendanceStatus::Late) => {
+                counts.present_days += 1;
+                counts.late_days += 1;
+            }
+            Some(StaffAttendanceStatus::Sick) => counts.sick_days += 1,
+            Some(StaffAttendanceStatus::Leave) => counts.leave_days += 1,
+            Some(StaffAttendanceStatus::Absent) | None => {}
+        }
+    }
+
+    counts.absent_days =
+        (elapsed - counts.present_days - counts.sick_days - counts.leave_days).max(0);
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geofence_and_late_detection() {
+        // Monas -> Stasiun Gambir, sekitar 400 m
+        let distance = distance_meters((-6.175392, 106.827153), (-6.176655, 106.830583));
+        assert!((350.0..450.0).contains(&distance), "{distance}");
+        assert_eq!(distance_meters((-6.2, 106.8), (-6.2, 106.8)), 0.0);
+
+        let start = NaiveTime::from_hms_opt(7, 0, 0).unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert_eq!(late_minutes(at(6, 45), start, 0), None);
+        assert_eq!(late_minutes(at(7, 10), start, 15), None);
+        assert_eq!(late_minutes(at(7, 20), start, 15), Some(20));
+    }
+
+    #[test]
+    fn test_monthly_counts_for_payroll() {
+        // Oktober 2026: 1 Okt = Kamis, 22 hari kerja Senin-Jumat
+        let (first, last) = month_bounds(2026, 10).unwrap();
+        assert_eq!(last, NaiveDate::from_ymd_opt(2026, 10, 31).unwrap());
+
+        let date = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let records = vec![
+            (date(1), StaffAttendanceStatus::Present),
+            (date(2), StaffAttendanceStatus::Late),
+            (date(5), StaffAttendanceStatus::Sick),
+            (date(6), StaffAttendanceStatus::Absent),
+            (date(10), StaffAttendanceStatus::Present), // Sabtu, bukan hari kerja
+        ];
+
+        // Sampai 7 Okt: hari kerja 1, 2, 5, 6, 7
+        let counts = monthly_counts(first, last, date(7), 5, &records);
+        assert_eq!(counts.working_days, 22);
+        assert_eq!(counts.present_days, 2);
+        assert_eq!(counts.late_days, 1);
+        assert_eq!(counts.sick_days, 1);
+        assert_eq!(counts.absent_days, 2);
+
+        assert_eq!(monthly_counts(first, last, last, 6, &[]).working_days, 27);
+    }
+}
@@ -0,0 +1,219 @@
+// ============================================================================
+// backend/src/modules/staff_attendances/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::StaffAttendanceFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::staff_attendances::{self, Entity as StaffAttendance};
+use entity::{employees, teachers, units};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+#[derive(Clone)]
+pub struct StaffAttendanceRepository {
+    db: Database,
+}
+
+impl StaffAttendanceRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create new staff attendance
+    pub async fn create(
+        &self,
+        active_model: staff_attendances::ActiveModel,
+    ) -> Result<staff_attendances::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update staff attendance
+    pub async fn update(
+        &self,
+        active_model: staff_attendances::ActiveModel,
+    ) -> Result<staff_attendances::Model, AppError> {
+        active_model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find attendance of a staff member on a date
+    pub async fn find_by_user_date(
+        &self,
+        user_id: i64,
+        date: NaiveDate,
+    ) -> Result<Option<staff_attendances::Model>, AppError> {
+        StaffAttendance::find()
+            .filter(staff_attendances::Column::UserId.eq(user_id))
+            .filter(staff_attendances::Column::Date.eq(date))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &StaffAttendanceFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<staff_attendances::Model>, u64), AppError> {
+        let mut query = StaffAttendance::find()
+            .filter(staff_attendances::Column::FoundationId.eq(foundation_id));
+
+        if let Some(user_id) = filters.user_id {
+            query = query.filter(staff_attendances::Column::UserId.eq(user_id));
+        }
+        if let Some(unit_id) = filters.unit_id {
+            query = query.filter(staff_attendances::Column::UnitId.eq(unit_id));
+        }
+        if let Some(ref status) = filters.status {
+            query = query.filter(staff_attendances::Column::Status.eq(status.clone()));
+        }
+        if let Some(date_from) = filters.date_from {
+            query = query.filter(staff_attendances::Column::Date.gte(date_from));
+        }
+        if let Some(date_to) = filters.date_to {
+            query = query.filter(staff_attendances::Column::Date.lte(date_to));
+        }
+
+        let paginator = query
+            .order_by_desc(staff_attendances::Column::Date)
+            .order_by_asc(staff_attendances::Column::UserId)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find attendance of a foundation between two dates
+    pub async fn find_between(
+        &self,
+        foundation_id: i64,
+        date_from: NaiveDate,
+        date_to: NaiveDate,
+    ) -> Result<Vec<staff_attendances::Model>, AppError> {
+        StaffAttendance::find()
+            .filter(staff_attendances::Column::FoundationId.eq(foundation_id))
+            .filter(staff_attendances::Column::Date.between(date_from, date_to))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find employee of a foundation by user account (not deleted)
+    pub async fn find_employee_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<employees::Model>, AppError> {
+        employees::Entity::find()
+            .filter(employees::Column::UserId.eq(user_id))
+            .filter(employees::Column::FoundationId.eq(foundation_id))
+            .filter(employees::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher of a foundation by user account (not deleted)
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find employees of a foundation, optionally of one unit
+    pub async fn find_employees(
+        &self,
+        foundation_id: i64,
+        unit_id: Option<i64>,
+    ) -> Result<Vec<employees::Model>, AppError> {
+        let mut query = employees::Entity::find()
+            .filter(employees::Column::FoundationId.eq(foundation_id))
+            .filter(employees::Column::DeletedAt.is_null());
+        if let Some(unit_id) = unit_id {
+            query = query.filter(employees::Column::UnitId.eq(unit_id));
+        }
+
+        query
+            .order_by_asc(employees::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers of a foundation, optionally of one unit
+    pub async fn find_teachers(
+        &self,
+        foundation_id: i64,
+        unit_id: Option<i64>,
+    ) -> Result<Vec<teachers::Model>, AppError> {
+        let mut query = teachers::Entity::find()
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null());
+        if let Some(unit_id) = unit_id {
+            query = query.filter(teachers::Column::UnitId.eq(unit_id));
+        }
+
+        query
+            .order_by_asc(teachers::Column::Name)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find unit of a foundation (not deleted)
+    pub async fn find_unit(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<units::Model>, AppError> {
+        units::Entity::find_by_id(id)
+            .filter(units::Column::FoundationId.eq(foundation_id))
+            .filter(units::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find units of a foundation (not deleted)
+    pub async fn find_units(&self, foundation_id: i64) -> Result<Vec<units::Model>, AppError> {
+        units::Entity::find()
+            .filter(units::Column::FoundationId.eq(foundation_id))
+            .filter(units::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
@@ -0,0 +1,17 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{middleware::auth::JwtMiddleware, modules::staff_attendances::handler};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/staff_attendances")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("/clock_in", web::post().to(handler::clock_in))
+            .route("/clock_out", web::post().to(handler::clock_out))
+            .route("/records", web::put().to(handler::record))
+            .route("/monthly", web::get().to(handler::monthly)),
+    );
+}
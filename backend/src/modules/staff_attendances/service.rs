@@ -0,0 +1,420 @@
+// ============================================================================
+// backend/src/modules/staff_attendances/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    ClockRequest, MonthlyStaffQuery, RecordStaffAttendanceRequest, StaffAttendanceFilters,
+    StaffAttendanceResponse, StaffMonthlySummary, StaffType,
+};
+use super::presence;
+use super::repository::StaffAttendanceRepository;
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use entity::sea_orm_active_enums::StaffAttendanceStatus;
+use entity::{staff_attendances, units};
+use sea_orm::prelude::Decimal;
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Dipakai bila pegawai tidak terikat ke unit mana pun (WIB)
+const DEFAULT_UTC_OFFSET_MINUTES: i32 = 420;
+const DEFAULT_WORK_DAYS_PER_WEEK: i8 = 5;
+
+/// Pegawai atau guru yang melakukan presensi
+struct Staff {
+    user_id: i64,
+    name: String,
+    staff_type: StaffType,
+    unit_id: Option<i64>,
+}
+
+#[derive(Clone)]
+pub struct StaffAttendanceService {
+    repository: StaffAttendanceRepository,
+}
+
+impl StaffAttendanceService {
+    pub fn new(repository: StaffAttendanceRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Clock in for today (local date of the unit)
+    pub async fn clock_in(
+        &self,
+        request: ClockRequest,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<StaffAttendanceResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let staff = self.find_staff(user_id, foundation_id).await?;
+        let unit = self.find_unit_of(&staff, foundation_id).await?;
+        check_geofence(unit.as_ref(), &request)?;
+
+        let now = Utc::now();
+        let local = local_time(now, unit.as_ref());
+        let date = local.date();
+
+        if let Some(existing) = self.repository.find_by_user_date(user_id, date).await? {
+            return Err(AppError::ConflictError(match existing.clock_in_at {
+                Some(_) => "Already clocked in today".to_string(),
+                None => format!(
+                    "Attendance for {} is already recorded as {}",
+                    date,
+                    sea_orm::ActiveEnum::to_value(&existing.status)
+                ),
+            }));
+        }
+
+        // Business rule: terlambat bila lewat jam masuk unit + toleransi
+        let late = unit.as_ref().and_then(|u| {
+            u.work_start_time.and_then(|start| {
+                presence::late_minutes(local.time(), start, u.late_tolerance_minutes)
+            })
+        });
+
+        let active_model = staff_attendances::ActiveModel {
+            foundation_id: Set(foundation_id),
+            user_id: Set(user_id),
+            unit_id: Set(staff.unit_id),
+            date: Set(date),
+            status: Set(if late.is_some() {
+                StaffAttendanceStatus::Late
+            } else {
+                StaffAttendanceStatus::Present
+            }),
+            clock_in_at: Set(Some(now)),
+            clock_in_latitude: Set(request.latitude.and_then(|v| Decimal::try_from(v).ok())),
+            clock_in_longitude: Set(request.longitude.and_then(|v| Decimal::try_from(v).ok())),
+            late_minutes: Set(late),
+            notes: Set(request.notes),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let created = self.repository.create(active_model).await?;
+
+        Ok(StaffAttendanceResponse::from(created))
+    }
+
+    /// Clock out of today's attendance
+    pub async fn clock_out(
+        &self,
+        request: ClockRequest,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<StaffAttendanceResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let staff = self.find_staff(user_id, foundation_id).await?;
+        let unit = self.find_unit_of(&staff, foundation_id).await?;
+        check_geofence(unit.as_ref(), &request)?;
+
+        let now = Utc::now();
+        let date = local_time(now, unit.as_ref()).date();
+
+        let existing = self
+            .repository
+            .find_by_user_date(user_id, date)
+            .await?
+            .filter(|a| a.clock_in_at.is_some())
+            .ok_or_else(|| AppError::validation("Not clocked in today".to_string()))?;
+        if existing.clock_out_at.is_some() {
+            return Err(AppError::ConflictError(
+                "Already clocked out today".to_string(),
+            ));
+        }
+
+        let mut active_model: staff_attendances::ActiveModel = existing.into();
+        active_model.clock_out_at = Set(Some(now));
+        active_model.clock_out_latitude =
+            Set(request.latitude.and_then(|v| Decimal::try_from(v).ok()));
+        active_model.clock_out_longitude =
+            Set(request.longitude.and_then(|v| Decimal::try_from(v).ok()));
+        if request.notes.is_some() {
+            active_model.notes = Set(request.notes);
+        }
+        active_model.updated_at = Set(now);
+
+        let updated = self.repository.update(active_model).await?;
+
+        Ok(StaffAttendanceResponse::from(updated))
+    }
+
+    /// Record attendance manually (sick, leave, absent or corrections)
+    pub async fn record(
+        &self,
+        request: RecordStaffAttendanceRequest,
+        recorded_by: i64,
+        foundation_id: i64,
+    ) -> Result<StaffAttendanceResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let staff = self.find_staff(request.user_id, foundation_id).await?;
+        let now = Utc::now();
+
+        let mut active_model = match self
+            .repository
+            .find_by_user_date(request.user_id, request.date)
+            .await?
+        {
+            Some(existing) => existing.into(),
+            None => staff_attendances::ActiveModel {
+                foundation_id: Set(foundation_id),
+                user_id: Set(staff.user_id),
+                unit_id: Set(staff.unit_id),
+                date: Set(request.date),
+                created_at: Set(now),
+                ..Default::default()
+            },
+        };
+        // Keterlambatan hanya relevan untuk status terlambat
+        if request.status != StaffAttendanceStatus::Late {
+            active_model.late_minutes = Set(None);
+        }
+        active_model.status = Set(request.status);
+        active_model.notes = Set(request.notes);
+        active_model.recorded_by = Set(Some(recorded_by));
+        active_model.updated_at = Set(now);
+
+        let saved = if !active_model.id.is_not_set() {
+            self.repository.update(active_model).await?
+        } else {
+            self.repository.create(active_model).await?
+        };
+
+        Ok(StaffAttendanceResponse::from(saved))
+    }
+
+    /// Get staff attendances with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: StaffAttendanceFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<StaffAttendanceResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let responses: Vec<StaffAttendanceResponse> = items
+            .into_iter()
+            .map(StaffAttendanceResponse::from)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Monthly working, present, absent and late days per staff member for payroll
+    pub async fn monthly(
+        &self,
+        query: MonthlyStaffQuery,
+        foundation_id: i64,
+    ) -> Result<Vec<StaffMonthlySummary>, AppError> {
+        query
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (first, last) = presence::month_bounds(query.year, query.month)
+            .ok_or_else(|| AppError::validation("Invalid year or month".to_string()))?;
+
+        let units: HashMap<i64, units::Model> = self
+            .repository
+            .find_units(foundation_id)
+            .await?
+            .into_iter()
+            .map(|u| (u.id, u))
+            .collect();
+
+        let mut records: HashMap<i64, Vec<(NaiveDate, StaffAttendanceStatus)>> = HashMap::new();
+        for row in self
+            .repository
+            .find_between(foundation_id, first, last)
+            .await?
+        {
+            records
+                .entry(row.user_id)
+                .or_default()
+                .push((row.date, row.status));
+        }
+
+        let now = Utc::now();
+        let summaries = self
+            .list_staff(foundation_id, query.unit_id)
+            .await?
+            .into_iter()
+            .filter(|s| query.user_id.is_none_or(|id| id == s.user_id))
+            .map(|staff| {
+                let unit = staff.unit_id.and_then(|id| units.get(&id));
+                let today = local_time(now, unit).date();
+                let counts = presence::monthly_counts(
+                    first,
+                    last,
+                    today,
+                    unit.map_or(DEFAULT_WORK_DAYS_PER_WEEK, |u| u.work_days_per_week),
+                    records
+                        .get(&staff.user_id)
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                );
+
+                StaffMonthlySummary {
+                    user_id: staff.user_id,
+                    name: staff.name,
+                    staff_type: staff.staff_type,
+                    unit_id: staff.unit_id,
+                    year: query.year,
+                    month: query.month,
+                    working_days: counts.working_days,
+                    present_days: counts.present_days,
+                    late_days: counts.late_days,
+                    sick_days: counts.sick_days,
+                    leave_days: counts.leave_days,
+                    absent_days: counts.absent_days,
+                }
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    /// Employee or teacher account of the foundation
+    async fn find_staff(&self, user_id: i64, foundation_id: i64) -> Result<Staff, AppError> {
+        if let Some(employee) = self
+            .repository
+            .find_employee_by_user(user_id, foundation_id)
+            .await?
+        {
+            return Ok(Staff {
+                user_id,
+                name: employee.name,
+                staff_type: StaffType::Employee,
+                unit_id: employee.unit_id,
+            });
+        }
+
+        let teacher = self
+            .repository
+            .find_teacher_by_user(user_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Employee or teacher not found".to_string()))?;
+
+        Ok(Staff {
+            user_id,
+            name: teacher.name,
+            staff_type: StaffType::Teacher,
+            unit_id: teacher.unit_id,
+        })
+    }
+
+    /// Employees and teachers; an account registered as both is listed once
+    async fn list_staff(
+        &self,
+        foundation_id: i64,
+        unit_id: Option<i64>,
+    ) -> Result<Vec<Staff>, AppError> {
+        let mut seen = HashSet::new();
+        let mut staff: Vec<Staff> = self
+            .repository
+            .find_employees(foundation_id, unit_id)
+            .await?
+            .into_iter()
+            .filter(|e| seen.insert(e.user_id))
+            .map(|e| Staff {
+                user_id: e.user_id,
+                name: e.name,
+                staff_type: StaffType::Employee,
+                unit_id: e.unit_id,
+            })
+            .collect();
+
+        staff.extend(
+            self.repository
+                .find_teachers(foundation_id, unit_id)
+                .await?
+                .into_iter()
+                .filter(|t| seen.insert(t.user_id))
+                .map(|t| Staff {
+                    user_id: t.user_id,
+                    name: t.name,
+                    staff_type: StaffType::Teacher,
+                    unit_id: t.unit_id,
+                }),
+        );
+        staff.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(staff)
+    }
+
+    async fn find_unit_of(
+        &self,
+        staff: &Staff,
+        foundation_id: i64,
+    ) -> Result<Option<units::Model>, AppError> {
+        match staff.unit_id {
+            Some(unit_id) => self.repository.find_unit(unit_id, foundation_id).await,
+            None => Ok(None),
+        }
+    }
+}
+
+/// Waktu lokal unit; tanggal presensi mengikuti tanggal lokal, bukan UTC
+fn local_time(now: DateTime<Utc>, unit: Option<&units::Model>) -> chrono::NaiveDateTime {
+    let offset = unit.map_or(DEFAULT_UTC_OFFSET_MINUTES, |u| u.utc_offset_minutes);
+    now.naive_utc() + Duration::minutes(i64::from(offset))
+}
+
+/// Business rule: bila unit memakai geofence, presensi harus dari dalam radius unit
+fn check_geofence(unit: Option<&units::Model>, request: &ClockRequest) -> Result<(), AppError> {
+    let Some(unit) = unit else {
+        return Ok(());
+    };
+    let (Some(lat), Some(lon), Some(radius)) =
+        (unit.latitude, unit.longitude, unit.geofence_radius_meters)
+    else {
+        return Ok(());
+    };
+
+    let (Some(latitude), Some(longitude)) = (request.latitude, request.longitude) else {
+        return Err(AppError::validation(
+            "latitude and longitude are required for this unit".to_string(),
+        ));
+    };
+
+    let center = (
+        f64::try_from(lat).unwrap_or_default(),
+        f64::try_from(lon).unwrap_or_default(),
+    );
+    let distance = presence::distance_meters(center, (latitude, longitude));
+    if distance > f64::from(radius) {
+        return Err(AppError::forbidden(format!(
+            "Outside the area of unit {} ({:.0} m away, max {} m)",
+            unit.name, distance, radius
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    PreferenceMatrixRequest, SetSubjectPreferenceRequest, SubjectPreferenceFilters,
+    SubjectPreferenceResponse,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_all,
+        handler::set,
+        handler::set_matrix,
+        handler::delete,
+    ),
+    components(
+        schemas(
+            SubjectPreferenceResponse,
+            SetSubjectPreferenceRequest,
+            PreferenceMatrixRequest,
+            SubjectPreferenceFilters,
+        )
+    ),
+    tags(
+        (name = "Subject Preference ", description = "Subject time slot preference endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct SubjectPreferencesApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
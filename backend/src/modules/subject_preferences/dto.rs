@@ -0,0 +1,53 @@
+// backend/src/modules/subject_preferences/dto.rs
+use entity::sea_orm_active_enums::PreferenceLevel;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubjectPreferenceResponse {
+    pub id: i64,
+    pub subject_id: i64,
+    pub time_slot_id: i64,
+    pub preference_level: Option<PreferenceLevel>,
+    pub reason: Option<String>,
+    pub created_at: String,
+}
+
+/// Preferensi satu mapel pada satu slot (create atau update)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SetSubjectPreferenceRequest {
+    #[validate(range(min = 1))]
+    pub subject_id: i64,
+    #[validate(range(min = 1))]
+    pub time_slot_id: i64,
+    pub preference_level: PreferenceLevel,
+    #[validate(length(max = 255))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PreferenceMatrixRequest {
+    #[validate(length(min = 1), nested)]
+    pub entries: Vec<SetSubjectPreferenceRequest>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SubjectPreferenceFilters {
+    pub subject_id: Option<i64>,
+    pub time_slot_id: Option<i64>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::subject_preferences::Model> for SubjectPreferenceResponse {
+    fn from(model: entity::subject_preferences::Model) -> Self {
+        Self {
+            id: model.id,
+            subject_id: model.subject_id,
+            time_slot_id: model.time_slot_id,
+            preference_level: model.preference_level,
+            reason: model.reason,
+            created_at: model.created_at.to_string(),
+        }
+    }
+}
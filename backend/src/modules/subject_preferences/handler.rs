@@ -0,0 +1,112 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    PreferenceMatrixRequest, SetSubjectPreferenceRequest, SubjectPreferenceFilters,
+    SubjectPreferenceResponse,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use actix_web::{web, HttpResponse};
+
+/// Get subject slot preferences
+#[utoipa::path(
+    get,
+    path = "/api/subject_preferences",
+    params(SubjectPreferenceFilters),
+    responses(
+        (status = 200, description = "List of subject preferences", body = Vec<SubjectPreferenceResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Subject Preference "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    filters: web::Query<SubjectPreferenceFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .subject_preference_service
+        .get_all(filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Set preference of a subject on a time slot
+#[utoipa::path(
+    put,
+    path = "/api/subject_preferences",
+    request_body = SetSubjectPreferenceRequest,
+    responses(
+        (status = 200, description = "Preference saved", body = SubjectPreferenceResponse),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Missing subject_preferences.update permission"),
+        (status = 404, description = "Subject or time slot not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Subject Preference "
+)]
+pub async fn set(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<SetSubjectPreferenceRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .subject_preference_service
+        .set(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Bulk update the subject x time slot preference matrix
+#[utoipa::path(
+    put,
+    path = "/api/subject_preferences/matrix",
+    request_body = PreferenceMatrixRequest,
+    responses(
+        (status = 200, description = "Preferences saved", body = Vec<SubjectPreferenceResponse>),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Missing subject_preferences.update permission"),
+        (status = 404, description = "Subject or time slot not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Subject Preference "
+)]
+pub async fn set_matrix(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<PreferenceMatrixRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .subject_preference_service
+        .set_matrix(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete subject preference
+#[utoipa::path(
+    delete,
+    path = "/api/subject_preferences/{id}",
+    params(
+        ("id" = i64, Path, description = "Subject preference ID")
+    ),
+    responses(
+        (status = 204, description = "Subject preference deleted"),
+        (status = 404, description = "Subject preference not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Subject Preference "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .subject_preference_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
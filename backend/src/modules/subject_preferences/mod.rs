@@ -0,0 +1,21 @@
+// backend/src/modules/subject_preferences/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::SubjectPreferenceRepository;
+pub use service::SubjectPreferenceService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> SubjectPreferenceService {
+    let repository = SubjectPreferenceRepository::new(db);
+    SubjectPreferenceService::new(repository)
+}
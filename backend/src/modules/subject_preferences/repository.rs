@@ -0,0 +1,157 @@
+// ============================================================================
+// backend/src/modules/subject_preferences/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::SetSubjectPreferenceRequest;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use entity::subject_preferences::{self, Entity as SubjectPreference};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{subjects, time_slots};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct SubjectPreferenceRepository {
+    db: Database,
+}
+
+impl SubjectPreferenceRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find by ID
+    pub async fn find_by_id(
+        &self,
+        id: i64,
+    ) -> Result<Option<subject_preferences::Model>, AppError> {
+        SubjectPreference::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find subjects (not deleted) of a foundation
+    pub async fn find_subjects(
+        &self,
+        foundation_id: i64,
+        ids: Option<Vec<i64>>,
+    ) -> Result<Vec<subjects::Model>, AppError> {
+        let mut query = subjects::Entity::find()
+            .filter(subjects::Column::FoundationId.eq(foundation_id))
+            .filter(subjects::Column::DeletedAt.is_null());
+
+        if let Some(ids) = ids {
+            query = query.filter(subjects::Column::Id.is_in(ids));
+        }
+
+        query
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots (not deleted) of a foundation by IDs
+    pub async fn find_time_slots(
+        &self,
+        foundation_id: i64,
+        ids: Vec<i64>,
+    ) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::FoundationId.eq(foundation_id))
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find preferences of the given subjects, optionally for one slot
+    pub async fn find_by_subjects(
+        &self,
+        subject_ids: Vec<i64>,
+        time_slot_id: Option<i64>,
+    ) -> Result<Vec<subject_preferences::Model>, AppError> {
+        let mut query = SubjectPreference::find()
+            .filter(subject_preferences::Column::SubjectId.is_in(subject_ids));
+
+        if let Some(time_slot_id) = time_slot_id {
+            query = query.filter(subject_preferences::Column::TimeSlotId.eq(time_slot_id));
+        }
+
+        query
+            .order_by_asc(subject_preferences::Column::SubjectId)
+            .order_by_asc(subject_preferences::Column::TimeSlotId)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert or update one row per (subject, time slot) in a single transaction
+    pub async fn upsert_many(
+        &self,
+        entries: Vec<SetSubjectPreferenceRequest>,
+    ) -> Result<Vec<subject_preferences::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let existing = SubjectPreference::find()
+                .filter(subject_preferences::Column::SubjectId.eq(entry.subject_id))
+                .filter(subject_preferences::Column::TimeSlotId.eq(entry.time_slot_id))
+                .one(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+            let row = match existing {
+                Some(model) => {
+                    let mut active: subject_preferences::ActiveModel = model.into();
+                    active.preference_level = Set(Some(entry.preference_level));
+                    active.reason = Set(entry.reason);
+                    active.update(&txn).await
+                }
+                None => {
+                    subject_preferences::ActiveModel {
+                        subject_id: Set(entry.subject_id),
+                        time_slot_id: Set(entry.time_slot_id),
+                        preference_level: Set(Some(entry.preference_level)),
+                        reason: Set(entry.reason),
+                        created_at: Set(chrono::Utc::now()),
+                        ..Default::default()
+                    }
+                    .insert(&txn)
+                    .await
+                }
+            }
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Delete preference
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        SubjectPreference::delete_by_id(id)
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::subject_preferences::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/subject_preferences")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route(
+                "",
+                web::put()
+                    .to(handler::set)
+                    .wrap(require_permission("subject_preferences.update")),
+            )
+            .route(
+                "/matrix",
+                web::put()
+                    .to(handler::set_matrix)
+                    .wrap(require_permission("subject_preferences.update")),
+            )
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("subject_preferences.delete")),
+            ),
+    );
+}
@@ -0,0 +1,161 @@
+// ============================================================================
+// backend/src/modules/subject_preferences/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    PreferenceMatrixRequest, SetSubjectPreferenceRequest, SubjectPreferenceFilters,
+    SubjectPreferenceResponse,
+};
+use super::repository::SubjectPreferenceRepository;
+use crate::errors::AppError;
+use std::collections::HashSet;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct SubjectPreferenceService {
+    repository: SubjectPreferenceRepository,
+}
+
+impl SubjectPreferenceService {
+    pub fn new(repository: SubjectPreferenceRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Get preferences of the foundation's subjects
+    pub async fn get_all(
+        &self,
+        filters: SubjectPreferenceFilters,
+        foundation_id: i64,
+    ) -> Result<Vec<SubjectPreferenceResponse>, AppError> {
+        let subject_ids: Vec<i64> = self
+            .repository
+            .find_subjects(foundation_id, filters.subject_id.map(|id| vec![id]))
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+
+        if subject_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let items = self
+            .repository
+            .find_by_subjects(subject_ids, filters.time_slot_id)
+            .await?;
+
+        Ok(items
+            .into_iter()
+            .map(SubjectPreferenceResponse::from)
+            .collect())
+    }
+
+    /// Set preference of one subject on one slot
+    pub async fn set(
+        &self,
+        request: SetSubjectPreferenceRequest,
+        foundation_id: i64,
+    ) -> Result<SubjectPreferenceResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let mut saved = self.save(vec![request], foundation_id).await?;
+        saved
+            .pop()
+            .ok_or_else(|| AppError::internal("Preference was not saved".to_string()))
+    }
+
+    /// Bulk update the subject x time slot preference matrix
+    pub async fn set_matrix(
+        &self,
+        request: PreferenceMatrixRequest,
+        foundation_id: i64,
+    ) -> Result<Vec<SubjectPreferenceResponse>, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = request
+            .entries
+            .iter()
+            .find(|e| !seen.insert((e.subject_id, e.time_slot_id)))
+        {
+            return Err(AppError::validation(format!(
+                "Subject {} / time slot {} is listed more than once",
+                duplicate.subject_id, duplicate.time_slot_id
+            )));
+        }
+
+        self.save(request.entries, foundation_id).await
+    }
+
+    /// Delete preference
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        let preference = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Subject preference not found".to_string()))?;
+
+        let owned = self
+            .repository
+            .find_subjects(foundation_id, Some(vec![preference.subject_id]))
+            .await?;
+        if owned.is_empty() {
+            return Err(AppError::not_found(
+                "Subject preference not found".to_string(),
+            ));
+        }
+
+        self.repository.delete(id).await
+    }
+
+    async fn save(
+        &self,
+        entries: Vec<SetSubjectPreferenceRequest>,
+        foundation_id: i64,
+    ) -> Result<Vec<SubjectPreferenceResponse>, AppError> {
+        // Business rule: mapel & slot harus milik foundation yang sama
+        let subject_ids: HashSet<i64> = entries.iter().map(|e| e.subject_id).collect();
+        let slot_ids: HashSet<i64> = entries.iter().map(|e| e.time_slot_id).collect();
+
+        let valid_subjects: HashSet<i64> = self
+            .repository
+            .find_subjects(foundation_id, Some(subject_ids.iter().copied().collect()))
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        if let Some(missing) = subject_ids.iter().find(|id| !valid_subjects.contains(id)) {
+            return Err(AppError::not_found(format!(
+                "Subject {} not found",
+                missing
+            )));
+        }
+
+        let valid_slots: HashSet<i64> = self
+            .repository
+            .find_time_slots(foundation_id, slot_ids.iter().copied().collect())
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        if let Some(missing) = slot_ids.iter().find(|id| !valid_slots.contains(id)) {
+            return Err(AppError::not_found(format!(
+                "Time slot {} not found",
+                missing
+            )));
+        }
+
+        let saved = self.repository.upsert_many(entries).await?;
+
+        Ok(saved
+            .into_iter()
+            .map(SubjectPreferenceResponse::from)
+            .collect())
+    }
+}
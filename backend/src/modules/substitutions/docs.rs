@@ -0,0 +1,53 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    AffectedLessonResponse, CreateSubstitutionRequest, FindSubstitutesRequest,
+    FindSubstitutesResponse, SubstituteCandidateResponse, SubstitutionFilters,
+    SubstitutionResponse,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::find_substitutes,
+        handler::create,
+        handler::get_all,
+        handler::delete,
+    ),
+    components(
+        schemas(
+            SubstitutionResponse,
+            FindSubstitutesRequest,
+            FindSubstitutesResponse,
+            AffectedLessonResponse,
+            SubstituteCandidateResponse,
+            CreateSubstitutionRequest,
+            SubstitutionFilters,
+        )
+    ),
+    tags(
+        (name = "Substitution ", description = "Substitute teacher endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct SubstitutionsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
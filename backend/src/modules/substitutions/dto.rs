@@ -0,0 +1,98 @@
+// backend/src/modules/substitutions/dto.rs
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubstitutionResponse {
+    pub id: i64,
+    pub class_schedule_id: i64,
+    pub time_slot_id: i64,
+    pub date: String,
+    pub absent_teacher_id: i64,
+    pub substitute_teacher_id: i64,
+    pub reason: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct FindSubstitutesRequest {
+    #[validate(range(min = 1))]
+    pub absent_teacher_id: i64,
+    pub date: NaiveDate,
+    /// Jam pelajaran yang perlu diganti; kosong = semua jadwal guru di tanggal tersebut
+    pub class_schedule_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubstituteCandidateResponse {
+    pub teacher_id: i64,
+    pub name: String,
+    /// Lebih kecil lebih baik
+    pub score: i32,
+    pub weekly_load: i32,
+    pub day_load: i32,
+    pub max_teaching_hours: Option<i32>,
+    pub teaches_class: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AffectedLessonResponse {
+    pub class_schedule_id: i64,
+    pub class_subject_id: i64,
+    pub class_id: i64,
+    pub subject_id: i64,
+    pub time_slot_id: i64,
+    pub start_time: String,
+    pub end_time: String,
+    /// Substitusi yang sudah tercatat untuk jam ini, jika ada
+    pub substitution_id: Option<i64>,
+    pub candidates: Vec<SubstituteCandidateResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FindSubstitutesResponse {
+    pub absent_teacher_id: i64,
+    pub date: String,
+    pub lessons: Vec<AffectedLessonResponse>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSubstitutionRequest {
+    #[validate(range(min = 1))]
+    pub class_schedule_id: i64,
+    pub date: NaiveDate,
+    #[validate(range(min = 1))]
+    pub substitute_teacher_id: i64,
+    #[validate(length(max = 255))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SubstitutionFilters {
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+    /// Guru yang diganti atau guru pengganti
+    pub teacher_id: Option<i64>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::substitutions::Model> for SubstitutionResponse {
+    fn from(model: entity::substitutions::Model) -> Self {
+        Self {
+            id: model.id,
+            class_schedule_id: model.class_schedule_id,
+            time_slot_id: model.time_slot_id,
+            date: model.date.to_string(),
+            absent_teacher_id: model.absent_teacher_id,
+            substitute_teacher_id: model.substitute_teacher_id,
+            reason: model.reason,
+            created_by: model.created_by,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// ============================================================================
+// backend/src/modules/substitutions/finder.rs
+// finder.rs - Substitute Teacher Ranking (pure, tanpa akses database)
+// ============================================================================
+
+/// Bonus untuk guru yang sudah mengajar mapel tersebut di kelas yang sama
+pub const SAME_CLASS_BONUS: i32 = 5;
+/// Bobot tiap jam mengajar di hari yang sama (hindari guru yang sudah penuh hari itu)
+pub const DAY_LOAD_WEIGHT: i32 = 2;
+
+/// Fakta satu calon guru pengganti untuk satu jam pelajaran
+#[derive(Debug, Clone)]
+pub struct CandidateFacts {
+    pub teacher_id: i64,
+    pub qualified: bool,
+    pub teaches_class: bool,
+    /// Jam mengajar minggu itu (jadwal published + substitusi yang sudah diterima)
+    pub weekly_load: i32,
+    /// Jam mengajar di tanggal substitusi
+    pub day_load: i32,
+    pub max_teaching_hours: Option<i32>,
+    pub busy: bool,
+    pub unavailable: bool,
+    pub absent: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    NotQualified,
+    Absent,
+    Busy,
+    Unavailable,
+    OverMaxHours,
+}
+
+impl Rejection {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Rejection::NotQualified => "Teacher is not qualified for this subject",
+            Rejection::Absent => "Teacher is absent on this date",
+            Rejection::Busy => "Teacher is already teaching at this time",
+            Rejection::Unavailable => "Teacher is marked unavailable in this time slot",
+            Rejection::OverMaxHours => "Teacher would exceed the maximum teaching hours",
+        }
+    }
+}
+
+/// Skor calon pengganti (lebih kecil lebih baik) atau alasan penolakan
+pub fn evaluate(facts: &CandidateFacts) -> Result<i32, Rejection> {
+    if !facts.qualified {
+        return Err(Rejection::NotQualified);
+    }
+    if facts.absent {
+        return Err(Rejection::Absent);
+    }
+    if facts.busy {
+        return Err(Rejection::Busy);
+    }
+    if facts.unavailable {
+        return Err(Rejection::Unavailable);
+    }
+    if let Some(max) = facts.max_teaching_hours {
+        if facts.weekly_load + 1 > max {
+            return Err(Rejection::OverMaxHours);
+        }
+    }
+
+    let bonus = if facts.teaches_class {
+        SAME_CLASS_BONUS
+    } else {
+        0
+    };
+    Ok(facts.weekly_load + facts.day_load * DAY_LOAD_WEIGHT - bonus)
+}
+
+/// Urutkan calon yang lolos semua syarat: skor, lalu beban mingguan, lalu ID
+pub fn rank(candidates: Vec<CandidateFacts>) -> Vec<(CandidateFacts, i32)> {
+    let mut ranked: Vec<(CandidateFacts, i32)> = candidates
+        .into_iter()
+        .filter_map(|facts| evaluate(&facts).ok().map(|score| (facts, score)))
+        .collect();
+
+    ranked.sort_by_key(|(facts, score)| (*score, facts.weekly_load, facts.teacher_id));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(teacher_id: i64, weekly_load: i32) -> CandidateFacts {
+        CandidateFacts {
+            teacher_id,
+            qualified: true,
+            teaches_class: false,
+            weekly_load,
+            day_load: 0,
+            max_teaching_hours: Some(24),
+            busy: false,
+            unavailable: false,
+            absent: false,
+        }
+    }
+
+    #[test]
+    fn test_rank_filters_ineligible_teachers() {
+        let mut busy = facts(2, 4);
+        busy.busy = true;
+        let mut full = facts(3, 24);
+        full.max_teaching_hours = Some(24);
+        let mut unqualified = facts(4, 0);
+        unqualified.qualified = false;
+
+        let ranked = rank(vec![facts(1, 10), busy, full, unqualified]);
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].0.teacher_id, 1);
+        assert_eq!(evaluate(&facts(3, 24)), Err(Rejection::OverMaxHours));
+    }
+
+    #[test]
+    fn test_rank_prefers_light_load_and_same_class() {
+        let mut same_class = facts(1, 12);
+        same_class.teaches_class = true;
+        let mut heavy_day = facts(2, 8);
+        heavy_day.day_load = 4;
+
+        let ranked = rank(vec![heavy_day, same_class, facts(3, 9)]);
+        let order: Vec<i64> = ranked.iter().map(|(f, _)| f.teacher_id).collect();
+
+        assert_eq!(order, vec![1, 3, 2]);
+    }
+}
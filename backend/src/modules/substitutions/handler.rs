@@ -0,0 +1,117 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    CreateSubstitutionRequest, FindSubstitutesRequest, FindSubstitutesResponse,
+    SubstitutionFilters, SubstitutionResponse,
+};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Rank substitute teachers for an absent teacher's lessons
+#[utoipa::path(
+    post,
+    path = "/api/substitutions/candidates",
+    request_body = FindSubstitutesRequest,
+    responses(
+        (status = 200, description = "Ranked substitute candidates per lesson", body = FindSubstitutesResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Teacher not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Substitution "
+)]
+pub async fn find_substitutes(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<FindSubstitutesRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .substitution_service
+        .find_substitutes(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Record a substitution
+#[utoipa::path(
+    post,
+    path = "/api/substitutions",
+    request_body = CreateSubstitutionRequest,
+    responses(
+        (status = 201, description = "Substitution recorded", body = SubstitutionResponse),
+        (status = 400, description = "Bad request"),
+        (status = 404, description = "Schedule or teacher not found"),
+        (status = 409, description = "Substitute is not eligible or lesson already covered")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Substitution "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateSubstitutionRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .substitution_service
+        .create(request.into_inner(), auth.foundation_id, auth.user_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get all substitutions with pagination
+#[utoipa::path(
+    get,
+    path = "/api/substitutions",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        SubstitutionFilters,
+    ),
+    responses(
+        (status = 200, description = "List of substitutions", body = PaginatedResponse<SubstitutionResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Substitution "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<SubstitutionFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .substitution_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Cancel substitution
+#[utoipa::path(
+    delete,
+    path = "/api/substitutions/{id}",
+    params(
+        ("id" = i64, Path, description = "Substitution ID")
+    ),
+    responses(
+        (status = 204, description = "Substitution cancelled"),
+        (status = 404, description = "Substitution not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Substitution "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .substitution_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,22 @@
+// backend/src/modules/substitutions/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod finder;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::SubstitutionRepository;
+pub use service::SubstitutionService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> SubstitutionService {
+    let repository = SubstitutionRepository::new(db);
+    SubstitutionService::new(repository)
+}
@@ -0,0 +1,272 @@
+// ============================================================================
+// backend/src/modules/substitutions/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::SubstitutionFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use chrono::NaiveDate;
+use entity::sea_orm_active_enums::ScheduleStatus;
+use entity::substitutions::{self, Entity as Substitution};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{
+    class_schedules, class_subjects, semesters, teacher_assignments, teacher_availability,
+    teacher_subjects, teachers, time_slots,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set,
+};
+
+#[derive(Clone)]
+pub struct SubstitutionRepository {
+    db: Database,
+}
+
+impl SubstitutionRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create substitution
+    pub async fn create(
+        &self,
+        model: substitutions::ActiveModel,
+    ) -> Result<substitutions::Model, AppError> {
+        model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find substitution by ID (not deleted)
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<substitutions::Model>, AppError> {
+        Substitution::find_not_deleted()
+            .filter(substitutions::Column::Id.eq(id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all substitutions with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &SubstitutionFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<substitutions::Model>, u64), AppError> {
+        let mut query = Substitution::find_not_deleted()
+            .filter(substitutions::Column::FoundationId.eq(foundation_id));
+
+        if let Some(date_from) = filters.date_from {
+            query = query.filter(substitutions::Column::Date.gte(date_from));
+        }
+        if let Some(date_to) = filters.date_to {
+            query = query.filter(substitutions::Column::Date.lte(date_to));
+        }
+        if let Some(teacher_id) = filters.teacher_id {
+            query = query.filter(
+                Condition::any()
+                    .add(substitutions::Column::AbsentTeacherId.eq(teacher_id))
+                    .add(substitutions::Column::SubstituteTeacherId.eq(teacher_id)),
+            );
+        }
+
+        let paginator = query
+            .order_by_desc(substitutions::Column::Date)
+            .order_by_asc(substitutions::Column::TimeSlotId)
+            .paginate(self.conn(), params.per_page());
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find substitutions of a foundation within a date range (not deleted)
+    pub async fn find_between(
+        &self,
+        foundation_id: i64,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<substitutions::Model>, AppError> {
+        Substitution::find_not_deleted()
+            .filter(substitutions::Column::FoundationId.eq(foundation_id))
+            .filter(substitutions::Column::Date.between(from, to))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Soft delete substitution
+    pub async fn soft_delete(&self, id: i64) -> Result<(), AppError> {
+        let model = substitutions::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Find teacher of a foundation
+    pub async fn find_teacher(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teachers of a foundation by IDs
+    pub async fn find_teachers(
+        &self,
+        ids: Vec<i64>,
+        foundation_id: i64,
+    ) -> Result<Vec<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::Id.is_in(ids))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find published schedules by IDs
+    pub async fn find_published_by_ids(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        class_schedules::Entity::find()
+            .filter(class_schedules::Column::Id.is_in(ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find published schedules taught by the given teachers
+    pub async fn find_published_by_teachers(
+        &self,
+        teacher_ids: Vec<i64>,
+    ) -> Result<Vec<class_schedules::Model>, AppError> {
+        class_schedules::Entity::find()
+            .filter(class_schedules::Column::TeacherId.is_in(teacher_ids))
+            .filter(class_schedules::Column::Status.eq(ScheduleStatus::Published))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find class subjects of a foundation by IDs (not deleted)
+    pub async fn find_class_subjects(
+        &self,
+        ids: Vec<i64>,
+        foundation_id: i64,
+    ) -> Result<Vec<class_subjects::Model>, AppError> {
+        class_subjects::Entity::find()
+            .filter(class_subjects::Column::Id.is_in(ids))
+            .filter(class_subjects::Column::FoundationId.eq(foundation_id))
+            .filter(class_subjects::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find semesters covering a date
+    pub async fn find_semesters_on(
+        &self,
+        ids: Vec<i64>,
+        date: NaiveDate,
+    ) -> Result<Vec<semesters::Model>, AppError> {
+        semesters::Entity::find()
+            .filter(semesters::Column::Id.is_in(ids))
+            .filter(semesters::Column::StartDate.lte(date))
+            .filter(semesters::Column::EndDate.gte(date))
+            .filter(semesters::Column::DeletedAt.is_null())
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots by IDs (not deleted)
+    pub async fn find_time_slots(&self, ids: Vec<i64>) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find active teacher_subjects for the given subjects and semesters
+    pub async fn find_teacher_subjects(
+        &self,
+        subject_ids: Vec<i64>,
+        semester_ids: Vec<i64>,
+    ) -> Result<Vec<teacher_subjects::Model>, AppError> {
+        teacher_subjects::Entity::find()
+            .filter(teacher_subjects::Column::SubjectId.is_in(subject_ids))
+            .filter(
+                Condition::any()
+                    .add(teacher_subjects::Column::SemesterId.is_null())
+                    .add(teacher_subjects::Column::SemesterId.is_in(semester_ids)),
+            )
+            .filter(
+                Condition::any()
+                    .add(teacher_subjects::Column::IsActive.is_null())
+                    .add(teacher_subjects::Column::IsActive.ne(0)),
+            )
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher assignments by IDs
+    pub async fn find_teacher_assignments(
+        &self,
+        ids: Vec<i64>,
+    ) -> Result<Vec<teacher_assignments::Model>, AppError> {
+        teacher_assignments::Entity::find()
+            .filter(teacher_assignments::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher availability rows marked unavailable for the given slots
+    pub async fn find_unavailable_teachers(
+        &self,
+        slot_ids: Vec<i64>,
+    ) -> Result<Vec<teacher_availability::Model>, AppError> {
+        teacher_availability::Entity::find()
+            .filter(teacher_availability::Column::TimeSlotId.is_in(slot_ids))
+            .filter(teacher_availability::Column::IsAvailable.eq(0))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+}
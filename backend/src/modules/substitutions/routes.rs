@@ -0,0 +1,24 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::substitutions::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/substitutions")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_all))
+            .route("", web::post().to(handler::create))
+            .route("/candidates", web::post().to(handler::find_substitutes))
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("substitutions.delete")),
+            ),
+    );
+}
@@ -0,0 +1,487 @@
+// ============================================================================
+// backend/src/modules/substitutions/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    AffectedLessonResponse, CreateSubstitutionRequest, FindSubstitutesRequest,
+    FindSubstitutesResponse, SubstituteCandidateResponse, SubstitutionFilters,
+    SubstitutionResponse,
+};
+use super::finder::{self, CandidateFacts};
+use super::repository::SubstitutionRepository;
+use crate::errors::AppError;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use crate::utils::time::overlaps;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use entity::{class_schedules, class_subjects, time_slots};
+use sea_orm::Set;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+/// Satu jam pelajaran guru yang absen beserta fakta semua calon pengganti
+struct AssessedLesson {
+    schedule: class_schedules::Model,
+    class_subject: class_subjects::Model,
+    slot: time_slots::Model,
+    substitution_id: Option<i64>,
+    candidates: Vec<CandidateFacts>,
+}
+
+#[derive(Clone)]
+pub struct SubstitutionService {
+    repository: SubstitutionRepository,
+}
+
+impl SubstitutionService {
+    pub fn new(repository: SubstitutionRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Rank substitute teachers for the lessons of an absent teacher on a date
+    pub async fn find_substitutes(
+        &self,
+        request: FindSubstitutesRequest,
+        foundation_id: i64,
+    ) -> Result<FindSubstitutesResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        self.repository
+            .find_teacher(request.absent_teacher_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Teacher not found".to_string()))?;
+
+        let lessons = match &request.class_schedule_ids {
+            Some(ids) if !ids.is_empty() => {
+                let lessons = self.repository.find_published_by_ids(ids.clone()).await?;
+                if let Some(id) = ids.iter().find(|id| {
+                    !lessons
+                        .iter()
+                        .any(|l| l.id == **id && l.teacher_id == Some(request.absent_teacher_id))
+                }) {
+                    return Err(AppError::validation(format!(
+                        "Schedule {} is not a published lesson of teacher {}",
+                        id, request.absent_teacher_id
+                    )));
+                }
+                lessons
+            }
+            _ => {
+                self.repository
+                    .find_published_by_teachers(vec![request.absent_teacher_id])
+                    .await?
+            }
+        };
+
+        let (assessed, names) = self
+            .assess(
+                foundation_id,
+                request.date,
+                request.absent_teacher_id,
+                lessons,
+                None,
+            )
+            .await?;
+
+        if let Some(ids) = &request.class_schedule_ids {
+            if let Some(id) = ids
+                .iter()
+                .find(|id| !assessed.iter().any(|a| a.schedule.id == **id))
+            {
+                return Err(AppError::validation(format!(
+                    "Schedule {} does not take place on {}",
+                    id, request.date
+                )));
+            }
+        }
+
+        let lessons = assessed
+            .into_iter()
+            .map(|lesson| AffectedLessonResponse {
+                class_schedule_id: lesson.schedule.id,
+                class_subject_id: lesson.class_subject.id,
+                class_id: lesson.class_subject.class_id,
+                subject_id: lesson.class_subject.subject_id,
+                time_slot_id: lesson.slot.id,
+                start_time: lesson.slot.start_time.to_string(),
+                end_time: lesson.slot.end_time.to_string(),
+                substitution_id: lesson.substitution_id,
+                candidates: finder::rank(lesson.candidates)
+                    .into_iter()
+                    .map(|(facts, score)| SubstituteCandidateResponse {
+                        teacher_id: facts.teacher_id,
+                        name: names.get(&facts.teacher_id).cloned().unwrap_or_default(),
+                        score,
+                        weekly_load: facts.weekly_load,
+                        day_load: facts.day_load,
+                        max_teaching_hours: facts.max_teaching_hours,
+                        teaches_class: facts.teaches_class,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(FindSubstitutesResponse {
+            absent_teacher_id: request.absent_teacher_id,
+            date: request.date.to_string(),
+            lessons,
+        })
+    }
+
+    /// Record a dated substitution; the base timetable is left untouched
+    pub async fn create(
+        &self,
+        request: CreateSubstitutionRequest,
+        foundation_id: i64,
+        user_id: i64,
+    ) -> Result<SubstitutionResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let schedule = self
+            .repository
+            .find_published_by_ids(vec![request.class_schedule_id])
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::not_found("Published schedule not found".to_string()))?;
+        let absent_teacher_id = schedule.teacher_id.ok_or_else(|| {
+            AppError::validation("Schedule has no teacher to substitute".to_string())
+        })?;
+        if absent_teacher_id == request.substitute_teacher_id {
+            return Err(AppError::validation(
+                "Substitute must be a different teacher".to_string(),
+            ));
+        }
+
+        let (mut assessed, _) = self
+            .assess(
+                foundation_id,
+                request.date,
+                absent_teacher_id,
+                vec![schedule],
+                Some(request.substitute_teacher_id),
+            )
+            .await?;
+        let lesson = assessed.pop().ok_or_else(|| {
+            AppError::validation(format!(
+                "Schedule {} does not take place on {}",
+                request.class_schedule_id, request.date
+            ))
+        })?;
+
+        if lesson.substitution_id.is_some() {
+            return Err(AppError::ConflictError(format!(
+                "Schedule {} already has a substitute on {}",
+                request.class_schedule_id, request.date
+            )));
+        }
+
+        // Business rule: pengganti harus lolos semua syarat yang sama dengan pencarian
+        let facts = lesson
+            .candidates
+            .iter()
+            .find(|c| c.teacher_id == request.substitute_teacher_id)
+            .ok_or_else(|| AppError::not_found("Substitute teacher not found".to_string()))?;
+        finder::evaluate(facts).map_err(|r| AppError::ConflictError(r.message().to_string()))?;
+
+        let now = chrono::Utc::now();
+        let model = entity::substitutions::ActiveModel {
+            foundation_id: Set(foundation_id),
+            class_schedule_id: Set(lesson.schedule.id),
+            time_slot_id: Set(lesson.slot.id),
+            date: Set(request.date),
+            absent_teacher_id: Set(absent_teacher_id),
+            substitute_teacher_id: Set(request.substitute_teacher_id),
+            reason: Set(request.reason),
+            created_by: Set(Some(user_id)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let substitution = self.repository.create(model).await?;
+        Ok(SubstitutionResponse::from(substitution))
+    }
+
+    /// Get all substitutions with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: SubstitutionFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<SubstitutionResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let responses: Vec<SubstitutionResponse> =
+            items.into_iter().map(SubstitutionResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Cancel substitution (soft delete)
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        let substitution = self
+            .repository
+            .find_by_id(id)
+            .await?
+            .filter(|s| s.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Substitution not found".to_string()))?;
+
+        self.repository.soft_delete(substitution.id).await
+    }
+
+    /// Kumpulkan fakta calon pengganti untuk setiap jam pelajaran yang jatuh pada `date`
+    async fn assess(
+        &self,
+        foundation_id: i64,
+        date: NaiveDate,
+        absent_teacher_id: i64,
+        lessons: Vec<class_schedules::Model>,
+        extra_teacher_id: Option<i64>,
+    ) -> Result<(Vec<AssessedLesson>, HashMap<i64, String>), AppError> {
+        let weekday = date.weekday().number_from_monday() as i8;
+        let week_start = date - Duration::days(weekday as i64 - 1);
+        let week_end = week_start + Duration::days(6);
+
+        // Jam pelajaran guru yang absen: semester aktif di tanggal itu & hari yang sama
+        let class_subjects: HashMap<i64, class_subjects::Model> = self
+            .repository
+            .find_class_subjects(
+                unique(lessons.iter().map(|l| l.class_subject_id)),
+                foundation_id,
+            )
+            .await?
+            .into_iter()
+            .map(|cs| (cs.id, cs))
+            .collect();
+        let semesters: HashMap<i64, entity::semesters::Model> = self
+            .repository
+            .find_semesters_on(
+                unique(class_subjects.values().map(|cs| cs.semester_id)),
+                date,
+            )
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+        // Substitusi minggu ini: beban pengganti, guru absen & jam yang sudah ditangani
+        let week_substitutions = self
+            .repository
+            .find_between(foundation_id, week_start, week_end)
+            .await?;
+        let mut absent: HashSet<i64> = HashSet::from([absent_teacher_id]);
+        let mut covered: HashMap<i64, i64> = HashMap::new();
+        for s in week_substitutions.iter().filter(|s| s.date == date) {
+            absent.insert(s.absent_teacher_id);
+            covered.insert(s.class_schedule_id, s.id);
+        }
+
+        // Guru yang memenuhi kualifikasi mapel (teacher_subjects -> teacher_assignments)
+        let subject_ids = unique(class_subjects.values().map(|cs| cs.subject_id));
+        let teacher_subjects = self
+            .repository
+            .find_teacher_subjects(subject_ids, semesters.keys().copied().collect())
+            .await?;
+        let assignments: HashMap<i64, entity::teacher_assignments::Model> = self
+            .repository
+            .find_teacher_assignments(unique(
+                teacher_subjects.iter().map(|ts| ts.teacher_assignment_id),
+            ))
+            .await?
+            .into_iter()
+            .filter(|a| a.is_active.unwrap_or(1) != 0)
+            .map(|a| (a.id, a))
+            .collect();
+
+        let mut candidate_ids = unique(
+            assignments
+                .values()
+                .map(|a| a.teacher_id)
+                .chain(extra_teacher_id),
+        );
+        candidate_ids.retain(|id| *id != absent_teacher_id);
+        let names: HashMap<i64, String> = self
+            .repository
+            .find_teachers(candidate_ids, foundation_id)
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+        let candidate_ids: Vec<i64> = names.keys().copied().collect();
+
+        // Jadwal published calon pengganti yang berlaku di tanggal itu
+        let taught = self
+            .repository
+            .find_published_by_teachers(candidate_ids.clone())
+            .await?;
+        let taught_class_subjects: HashMap<i64, i64> = self
+            .repository
+            .find_class_subjects(
+                unique(taught.iter().map(|l| l.class_subject_id)),
+                foundation_id,
+            )
+            .await?
+            .into_iter()
+            .map(|cs| (cs.id, cs.semester_id))
+            .collect();
+        let active_semesters: HashSet<i64> = self
+            .repository
+            .find_semesters_on(unique(taught_class_subjects.values().copied()), date)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        let taught: Vec<&class_schedules::Model> = taught
+            .iter()
+            .filter(|l| {
+                taught_class_subjects
+                    .get(&l.class_subject_id)
+                    .is_some_and(|semester_id| active_semesters.contains(semester_id))
+            })
+            .collect();
+
+        let slots: HashMap<i64, time_slots::Model> = self
+            .repository
+            .find_time_slots(unique(
+                lessons
+                    .iter()
+                    .map(|l| l.time_slot_id)
+                    .chain(taught.iter().map(|l| l.time_slot_id))
+                    .chain(week_substitutions.iter().map(|s| s.time_slot_id)),
+            ))
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+        let mut weekly_load: HashMap<i64, i32> = HashMap::new();
+        let mut day_load: HashMap<i64, i32> = HashMap::new();
+        let mut day_intervals: HashMap<i64, Vec<(NaiveTime, NaiveTime)>> = HashMap::new();
+        let mut count = |teacher_id: i64, slot_id: i64, on_date: bool| {
+            *weekly_load.entry(teacher_id).or_insert(0) += 1;
+            if let Some(slot) = slots.get(&slot_id).filter(|_| on_date) {
+                *day_load.entry(teacher_id).or_insert(0) += 1;
+                day_intervals
+                    .entry(teacher_id)
+                    .or_default()
+                    .push((slot.start_time, slot.end_time));
+            }
+        };
+        for lesson in &taught {
+            if let Some(teacher_id) = lesson.teacher_id {
+                let on_date = slots
+                    .get(&lesson.time_slot_id)
+                    .is_some_and(|s| s.day_of_week == weekday);
+                // Jam yang sudah digantikan orang lain tidak dihitung di hari itu
+                let covered_today = on_date && covered.contains_key(&lesson.id);
+                if !covered_today {
+                    count(teacher_id, lesson.time_slot_id, on_date);
+                }
+            }
+        }
+        for s in &week_substitutions {
+            count(s.substitute_teacher_id, s.time_slot_id, s.date == date);
+        }
+
+        let unavailable: HashSet<(i64, i64)> = self
+            .repository
+            .find_unavailable_teachers(unique(lessons.iter().map(|l| l.time_slot_id)))
+            .await?
+            .into_iter()
+            .map(|a| (a.teacher_id, a.time_slot_id))
+            .collect();
+
+        let mut assessed: Vec<AssessedLesson> = Vec::new();
+        for schedule in lessons {
+            let Some(class_subject) = class_subjects.get(&schedule.class_subject_id) else {
+                continue;
+            };
+            let Some(semester) = semesters.get(&class_subject.semester_id) else {
+                continue;
+            };
+            let Some(slot) = slots
+                .get(&schedule.time_slot_id)
+                .filter(|s| s.day_of_week == weekday)
+            else {
+                continue;
+            };
+
+            let candidates = candidate_ids
+                .iter()
+                .map(|teacher_id| {
+                    // Kualifikasi: teacher_subjects aktif untuk mapel ini di unit semester
+                    let matching: Vec<_> = teacher_subjects
+                        .iter()
+                        .filter(|ts| ts.subject_id == class_subject.subject_id)
+                        .filter(|ts| {
+                            ts.semester_id
+                                .is_none_or(|id| id == class_subject.semester_id)
+                        })
+                        .filter_map(|ts| {
+                            assignments
+                                .get(&ts.teacher_assignment_id)
+                                .filter(|a| a.teacher_id == *teacher_id)
+                                .filter(|a| a.unit_id == semester.unit_id)
+                                .map(|a| (ts, a))
+                        })
+                        .collect();
+
+                    CandidateFacts {
+                        teacher_id: *teacher_id,
+                        qualified: !matching.is_empty(),
+                        teaches_class: matching
+                            .iter()
+                            .any(|(ts, _)| ts.classroom_id == class_subject.class_id),
+                        weekly_load: weekly_load.get(teacher_id).copied().unwrap_or(0),
+                        day_load: day_load.get(teacher_id).copied().unwrap_or(0),
+                        max_teaching_hours: matching
+                            .iter()
+                            .filter_map(|(_, a)| a.max_teaching_hours)
+                            .max(),
+                        busy: day_intervals.get(teacher_id).is_some_and(|intervals| {
+                            intervals.iter().any(|interval| {
+                                overlaps(interval.0, interval.1, slot.start_time, slot.end_time)
+                            })
+                        }),
+                        unavailable: unavailable.contains(&(*teacher_id, slot.id)),
+                        absent: absent.contains(teacher_id),
+                    }
+                })
+                .collect();
+
+            assessed.push(AssessedLesson {
+                substitution_id: covered.get(&schedule.id).copied(),
+                class_subject: class_subject.clone(),
+                slot: slot.clone(),
+                schedule,
+                candidates,
+            });
+        }
+
+        assessed.sort_by_key(|a| (a.slot.start_time, a.schedule.id));
+        Ok((assessed, names))
+    }
+}
+
+fn unique(ids: impl Iterator<Item = i64>) -> Vec<i64> {
+    let set: HashSet<i64> = ids.collect();
+    set.into_iter().collect()
+}
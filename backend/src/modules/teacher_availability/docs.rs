@@ -0,0 +1,48 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    AvailabilityEntry, AvailabilityFilters, SetAvailabilityRequest, TeacherAvailabilityResponse,
+};
+use super::handler;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_mine,
+        handler::set_mine,
+        handler::reset_mine,
+        handler::get_by_teacher,
+    ),
+    components(
+        schemas(
+            TeacherAvailabilityResponse,
+            AvailabilityEntry,
+            AvailabilityFilters,
+            SetAvailabilityRequest,
+        )
+    ),
+    tags(
+        (name = "Teacher Availability ", description = "Teacher weekly availability endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct TeacherAvailabilityApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
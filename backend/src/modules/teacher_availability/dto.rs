@@ -0,0 +1,50 @@
+// backend/src/modules/teacher_availability/dto.rs
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeacherAvailabilityResponse {
+    pub id: i64,
+    pub teacher_id: i64,
+    pub time_slot_id: i64,
+    pub is_available: bool,
+    pub reason: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AvailabilityEntry {
+    #[validate(range(min = 1))]
+    pub time_slot_id: i64,
+    pub is_available: bool,
+    #[validate(length(max = 255))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetAvailabilityRequest {
+    #[validate(length(min = 1), nested)]
+    pub entries: Vec<AvailabilityEntry>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct AvailabilityFilters {
+    pub teacher_id: i64,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::teacher_availability::Model> for TeacherAvailabilityResponse {
+    fn from(model: entity::teacher_availability::Model) -> Self {
+        Self {
+            id: model.id,
+            teacher_id: model.teacher_id,
+            time_slot_id: model.time_slot_id,
+            is_available: model.is_available.unwrap_or(1) != 0,
+            reason: model.reason,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{AvailabilityFilters, SetAvailabilityRequest, TeacherAvailabilityResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use actix_web::{web, HttpResponse};
+
+/// Get availability of the current teacher
+#[utoipa::path(
+    get,
+    path = "/api/teacher_availability/me",
+    responses(
+        (status = 200, description = "Availability of the current teacher", body = Vec<TeacherAvailabilityResponse>),
+        (status = 403, description = "Current user has no teacher profile")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Teacher Availability "
+)]
+pub async fn get_mine(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .teacher_availability_service
+        .get_mine(auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Set availability of the current teacher per time slot
+#[utoipa::path(
+    put,
+    path = "/api/teacher_availability/me",
+    request_body = SetAvailabilityRequest,
+    responses(
+        (status = 200, description = "Availability saved", body = Vec<TeacherAvailabilityResponse>),
+        (status = 400, description = "Bad request"),
+        (status = 403, description = "Current user has no teacher profile"),
+        (status = 404, description = "Time slot not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Teacher Availability "
+)]
+pub async fn set_mine(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<SetAvailabilityRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .teacher_availability_service
+        .set_mine(request.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Reset availability of the current teacher for a time slot
+#[utoipa::path(
+    delete,
+    path = "/api/teacher_availability/me/{time_slot_id}",
+    params(
+        ("time_slot_id" = i64, Path, description = "Time slot ID")
+    ),
+    responses(
+        (status = 204, description = "Availability entry removed"),
+        (status = 404, description = "Availability entry not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Teacher Availability "
+)]
+pub async fn reset_mine(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    time_slot_id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .teacher_availability_service
+        .reset_mine(time_slot_id.into_inner(), auth.user_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Get availability of a teacher
+#[utoipa::path(
+    get,
+    path = "/api/teacher_availability",
+    params(AvailabilityFilters),
+    responses(
+        (status = 200, description = "Availability of the teacher", body = Vec<TeacherAvailabilityResponse>),
+        (status = 404, description = "Teacher not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Teacher Availability "
+)]
+pub async fn get_by_teacher(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<AvailabilityFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .teacher_availability_service
+        .get_by_teacher(query.teacher_id, auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -0,0 +1,21 @@
+// backend/src/modules/teacher_availability/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::TeacherAvailabilityRepository;
+pub use service::TeacherAvailabilityService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> TeacherAvailabilityService {
+    let repository = TeacherAvailabilityRepository::new(db);
+    TeacherAvailabilityService::new(repository)
+}
@@ -0,0 +1,147 @@
+// ============================================================================
+// backend/src/modules/teacher_availability/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::AvailabilityEntry;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use entity::teacher_availability::{self, Entity as TeacherAvailability};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{teachers, time_slots};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct TeacherAvailabilityRepository {
+    db: Database,
+}
+
+impl TeacherAvailabilityRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Find the teacher profile of a user within a foundation
+    pub async fn find_teacher_by_user(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find()
+            .filter(teachers::Column::UserId.eq(user_id))
+            .filter(teachers::Column::FoundationId.eq(foundation_id))
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find teacher by ID (not deleted)
+    pub async fn find_teacher(&self, id: i64) -> Result<Option<teachers::Model>, AppError> {
+        teachers::Entity::find_by_id(id)
+            .filter(teachers::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find time slots (not deleted) by IDs
+    pub async fn find_time_slots(&self, ids: Vec<i64>) -> Result<Vec<time_slots::Model>, AppError> {
+        time_slots::Entity::find_not_deleted()
+            .filter(time_slots::Column::Id.is_in(ids))
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all availability rows of a teacher
+    pub async fn find_by_teacher(
+        &self,
+        teacher_id: i64,
+    ) -> Result<Vec<teacher_availability::Model>, AppError> {
+        TeacherAvailability::find()
+            .filter(teacher_availability::Column::TeacherId.eq(teacher_id))
+            .order_by_asc(teacher_availability::Column::TimeSlotId)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Insert or update one row per (teacher, time slot) in a single transaction
+    pub async fn upsert_many(
+        &self,
+        teacher_id: i64,
+        entries: Vec<AvailabilityEntry>,
+    ) -> Result<Vec<teacher_availability::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let now = chrono::Utc::now();
+
+        let mut saved = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let existing = TeacherAvailability::find()
+                .filter(teacher_availability::Column::TeacherId.eq(teacher_id))
+                .filter(teacher_availability::Column::TimeSlotId.eq(entry.time_slot_id))
+                .one(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+            let row = match existing {
+                Some(model) => {
+                    let mut active: teacher_availability::ActiveModel = model.into();
+                    active.is_available = Set(Some(entry.is_available as i8));
+                    active.reason = Set(entry.reason);
+                    active.updated_at = Set(now);
+                    active.update(&txn).await
+                }
+                None => {
+                    teacher_availability::ActiveModel {
+                        teacher_id: Set(teacher_id),
+                        time_slot_id: Set(entry.time_slot_id),
+                        is_available: Set(Some(entry.is_available as i8)),
+                        reason: Set(entry.reason),
+                        created_at: Set(now),
+                        updated_at: Set(now),
+                        ..Default::default()
+                    }
+                    .insert(&txn)
+                    .await
+                }
+            }
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+            saved.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(saved)
+    }
+
+    /// Delete the row of a teacher for one time slot
+    pub async fn delete_by_slot(
+        &self,
+        teacher_id: i64,
+        time_slot_id: i64,
+    ) -> Result<u64, AppError> {
+        let result = TeacherAvailability::delete_many()
+            .filter(teacher_availability::Column::TeacherId.eq(teacher_id))
+            .filter(teacher_availability::Column::TimeSlotId.eq(time_slot_id))
+            .exec(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected)
+    }
+}
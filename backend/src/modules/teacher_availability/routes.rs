@@ -0,0 +1,16 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{middleware::auth::JwtMiddleware, modules::teacher_availability::handler};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/teacher_availability")
+            .wrap(JwtMiddleware)
+            .route("", web::get().to(handler::get_by_teacher))
+            .route("/me", web::get().to(handler::get_mine))
+            .route("/me", web::put().to(handler::set_mine))
+            .route("/me/{time_slot_id}", web::delete().to(handler::reset_mine)),
+    );
+}
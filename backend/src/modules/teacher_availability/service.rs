@@ -0,0 +1,143 @@
+// ============================================================================
+// backend/src/modules/teacher_availability/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{SetAvailabilityRequest, TeacherAvailabilityResponse};
+use super::repository::TeacherAvailabilityRepository;
+use crate::errors::AppError;
+use entity::teachers;
+use std::collections::HashSet;
+use validator::Validate;
+
+#[derive(Clone)]
+pub struct TeacherAvailabilityService {
+    repository: TeacherAvailabilityRepository,
+}
+
+impl TeacherAvailabilityService {
+    pub fn new(repository: TeacherAvailabilityRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Get availability of the teacher linked to the current user
+    pub async fn get_mine(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Vec<TeacherAvailabilityResponse>, AppError> {
+        let teacher = self.current_teacher(user_id, foundation_id).await?;
+        self.list(teacher.id).await
+    }
+
+    /// Get availability of a teacher (staff view)
+    pub async fn get_by_teacher(
+        &self,
+        teacher_id: i64,
+        foundation_id: i64,
+    ) -> Result<Vec<TeacherAvailabilityResponse>, AppError> {
+        self.repository
+            .find_teacher(teacher_id)
+            .await?
+            .filter(|t| t.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Teacher not found".to_string()))?;
+
+        self.list(teacher_id).await
+    }
+
+    /// Set weekly availability of the current teacher per time slot
+    pub async fn set_mine(
+        &self,
+        request: SetAvailabilityRequest,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<Vec<TeacherAvailabilityResponse>, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let teacher = self.current_teacher(user_id, foundation_id).await?;
+
+        // Satu entry per slot
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = request
+            .entries
+            .iter()
+            .find(|e| !seen.insert(e.time_slot_id))
+        {
+            return Err(AppError::validation(format!(
+                "Time slot {} is listed more than once",
+                duplicate.time_slot_id
+            )));
+        }
+
+        // Business rule: slot harus milik foundation yang sama
+        let slot_ids: Vec<i64> = seen.into_iter().collect();
+        let valid: HashSet<i64> = self
+            .repository
+            .find_time_slots(slot_ids.clone())
+            .await?
+            .into_iter()
+            .filter(|s| s.foundation_id == foundation_id)
+            .map(|s| s.id)
+            .collect();
+        if let Some(missing) = slot_ids.iter().find(|id| !valid.contains(id)) {
+            return Err(AppError::not_found(format!(
+                "Time slot {} not found",
+                missing
+            )));
+        }
+
+        let saved = self
+            .repository
+            .upsert_many(teacher.id, request.entries)
+            .await?;
+
+        Ok(saved
+            .into_iter()
+            .map(TeacherAvailabilityResponse::from)
+            .collect())
+    }
+
+    /// Reset the current teacher's availability for a slot (back to available)
+    pub async fn reset_mine(
+        &self,
+        time_slot_id: i64,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<(), AppError> {
+        let teacher = self.current_teacher(user_id, foundation_id).await?;
+
+        let deleted = self
+            .repository
+            .delete_by_slot(teacher.id, time_slot_id)
+            .await?;
+        if deleted == 0 {
+            return Err(AppError::not_found(
+                "Availability entry not found".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn list(&self, teacher_id: i64) -> Result<Vec<TeacherAvailabilityResponse>, AppError> {
+        let items = self.repository.find_by_teacher(teacher_id).await?;
+        Ok(items
+            .into_iter()
+            .map(TeacherAvailabilityResponse::from)
+            .collect())
+    }
+
+    /// Teacher profile dari `user_id` di JWT
+    async fn current_teacher(
+        &self,
+        user_id: i64,
+        foundation_id: i64,
+    ) -> Result<teachers::Model, AppError> {
+        self.repository
+            .find_teacher_by_user(user_id, foundation_id)
+            .await?
+            .ok_or_else(|| AppError::forbidden("Current user has no teacher profile".to_string()))
+    }
+}
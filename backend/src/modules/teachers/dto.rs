@@ -19,6 +19,10 @@ pub struct TeacherResponse {
     pub end_date: Option<String>,  // ← Tambah ini (good practice)
     pub salary: Option<String>,
     pub employment_status: Option<EmploymentStatus>,
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bank_account_number: Option<String>,
+    pub bank_account_name: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -37,6 +41,15 @@ pub struct CreateTeacherRequest {
     pub hire_date: Option<NaiveDate>,
     pub salary: Option<String>,
     pub employment_status: Option<EmploymentStatus>,
+    /// Rekening tujuan transfer gaji
+    #[validate(length(max = 10))]
+    pub bank_code: Option<String>,
+    #[validate(length(max = 100))]
+    pub bank_name: Option<String>,
+    #[validate(length(max = 30))]
+    pub bank_account_number: Option<String>,
+    #[validate(length(max = 100))]
+    pub bank_account_name: Option<String>,
     pub end_date: Option<NaiveDate>, // ← Tambah ini (good practice)
     pub created_at: String,
     pub updated_at: String,
@@ -57,6 +70,15 @@ pub struct UpdateTeacherRequest {
     pub end_date: Option<NaiveDate>, // ← Tambah ini (good practice)
     pub salary: Option<String>,
     pub employment_status: Option<EmploymentStatus>,
+    /// Rekening tujuan transfer gaji
+    #[validate(length(max = 10))]
+    pub bank_code: Option<String>,
+    #[validate(length(max = 100))]
+    pub bank_name: Option<String>,
+    #[validate(length(max = 30))]
+    pub bank_account_number: Option<String>,
+    #[validate(length(max = 100))]
+    pub bank_account_name: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -86,6 +108,10 @@ impl From<entity::teachers::Model> for TeacherResponse {
             end_date: model.end_date.map(|ed| ed.to_string().parse().unwrap()),
             salary: model.salary,
             employment_status: model.employment_status,
+            bank_code: model.bank_code,
+            bank_name: model.bank_name,
+            bank_account_number: model.bank_account_number,
+            bank_account_name: model.bank_account_name,
             created_at: model.created_at.to_string(), // jika tipe data string maka harus di casting ke string
             updated_at: model.updated_at.to_string(),
         }
@@ -56,6 +56,10 @@ impl TeacherService {
         active_model.qualification = Set(request.qualification);
         active_model.salary = Set(request.salary);
         active_model.employment_status = Set(request.employment_status);
+        active_model.bank_code = Set(request.bank_code);
+        active_model.bank_name = Set(request.bank_name);
+        active_model.bank_account_number = Set(request.bank_account_number);
+        active_model.bank_account_name = Set(request.bank_account_name);
         active_model.end_date = Set(request.end_date); // ← Tambah ini (good practice)
         active_model.hire_date = Set(request.hire_date);
         active_model.created_at = Set(chrono::Utc::now());
@@ -147,6 +151,10 @@ impl TeacherService {
         active_model.qualification = Set(request.qualification);
         active_model.salary = Set(request.salary);
         active_model.employment_status = Set(request.employment_status);
+        active_model.bank_code = Set(request.bank_code);
+        active_model.bank_name = Set(request.bank_name);
+        active_model.bank_account_number = Set(request.bank_account_number);
+        active_model.bank_account_name = Set(request.bank_account_name);
         active_model.end_date = Set(request.end_date); // ← Tambah ini (good practice)
         active_model.hire_date = Set(request.hire_date);
         active_model.created_at = Set(chrono::Utc::now());
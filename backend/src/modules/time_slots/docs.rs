@@ -0,0 +1,59 @@
+// ============================================================================
+// docs.rs - OpenAPI Documentation
+// ============================================================================
+use super::dto::{
+    BulkCreateTimeSlotRequest, BulkTimeSlotResponse, CloneTimeSlotsRequest, CreateTimeSlotRequest,
+    TemplateSlot, TimeSlotFilters, TimeSlotResponse, UpdateTimeSlotRequest,
+};
+use super::handler;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handler::get_all,
+        handler::get_by_id,
+        handler::create,
+        handler::update,
+        handler::delete,
+        handler::bulk_create,
+        handler::clone_grid,
+    ),
+    components(
+        schemas(
+            TimeSlotResponse,
+            CreateTimeSlotRequest,
+            UpdateTimeSlotRequest,
+            TimeSlotFilters,
+            TemplateSlot,
+            BulkCreateTimeSlotRequest,
+            CloneTimeSlotsRequest,
+            BulkTimeSlotResponse,
+            PaginatedResponse<TimeSlotResponse>,
+            PaginationParams,
+        )
+    ),
+    tags(
+        (name = "Time Slot ", description = "Time slot (bell schedule) management endpoints")
+    ),
+    modifiers(&SecurityAddon)
+)]
+pub struct TimeSlotsApiDoc;
+
+struct SecurityAddon;
+
+impl utoipa::Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "bearer_auth",
+                utoipa::openapi::security::SecurityScheme::Http(
+                    utoipa::openapi::security::Http::new(
+                        utoipa::openapi::security::HttpAuthScheme::Bearer,
+                    ),
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// backend/src/modules/time_slots/dto.rs
+use entity::sea_orm_active_enums::SlotType;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/// `day_of_week` mengikuti ISO-8601: 1 = Senin ... 7 = Minggu
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeSlotResponse {
+    pub id: i64,
+    pub foundation_id: i64,
+    pub unit_id: Option<i64>,
+    pub name: String,
+    pub day_of_week: i8,
+    pub start_time: String,
+    pub end_time: String,
+    pub slot_type: Option<SlotType>,
+    pub order: i32,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTimeSlotRequest {
+    pub unit_id: Option<i64>,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(range(min = 1, max = 7))]
+    pub day_of_week: i8,
+    /// Format HH:MM atau HH:MM:SS
+    pub start_time: String,
+    pub end_time: String,
+    pub slot_type: Option<SlotType>,
+    pub order: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTimeSlotRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(range(min = 1, max = 7))]
+    pub day_of_week: Option<i8>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub slot_type: Option<SlotType>,
+    pub order: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct TimeSlotFilters {
+    pub unit_id: Option<i64>,
+    pub day_of_week: Option<i8>,
+    pub slot_type: Option<SlotType>,
+}
+
+/// Satu baris "bell schedule" harian
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TemplateSlot {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub slot_type: Option<SlotType>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct BulkCreateTimeSlotRequest {
+    pub unit_id: Option<i64>,
+    /// Hari yang akan diisi template (1 = Senin ... 7 = Minggu)
+    #[validate(length(min = 1, max = 7))]
+    pub days: Vec<i8>,
+    #[validate(length(min = 1), nested)]
+    pub slots: Vec<TemplateSlot>,
+    /// Hapus (soft delete) slot lama di hari yang sama sebelum membuat yang baru
+    pub replace_existing: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CloneTimeSlotsRequest {
+    /// Grid sumber milik foundation user; kosong = grid level foundation
+    pub source_unit_id: Option<i64>,
+    /// Default: foundation user. Foundation lain butuh permission `time_slots.clone`
+    /// di foundation asal & tujuan
+    pub target_foundation_id: Option<i64>,
+    pub target_unit_id: Option<i64>,
+    pub replace_existing: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkTimeSlotResponse {
+    pub created: usize,
+    pub replaced: usize,
+    pub data: Vec<TimeSlotResponse>,
+}
+
+// Implement From trait untuk convert entity ke response
+impl From<entity::time_slots::Model> for TimeSlotResponse {
+    fn from(model: entity::time_slots::Model) -> Self {
+        Self {
+            id: model.id,
+            foundation_id: model.foundation_id,
+            unit_id: model.unit_id,
+            name: model.name,
+            day_of_week: model.day_of_week,
+            start_time: model.start_time.format("%H:%M").to_string(),
+            end_time: model.end_time.format("%H:%M").to_string(),
+            slot_type: model.slot_type,
+            order: model.order,
+            is_active: model.is_active.unwrap_or(1) != 0,
+            created_at: model.created_at.to_string(),
+            updated_at: model.updated_at.to_string(),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// ============================================================================
+// handler.rs - HTTP Handlers
+// ============================================================================
+use super::dto::{
+    BulkCreateTimeSlotRequest, BulkTimeSlotResponse, CloneTimeSlotsRequest, CreateTimeSlotRequest,
+    TimeSlotFilters, TimeSlotResponse, UpdateTimeSlotRequest,
+};
+use super::service::CLONE_PERMISSION;
+use crate::app_state::AppState;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthContext;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use actix_web::{web, HttpResponse};
+
+/// Create time slot
+#[utoipa::path(
+    post,
+    path = "/api/time_slots",
+    request_body = CreateTimeSlotRequest,
+    responses(
+        (status = 201, description = "Time slot created successfully", body = TimeSlotResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Conflict - overlapping time slot")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CreateTimeSlotRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .create(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Get time slot by ID
+#[utoipa::path(
+    get,
+    path = "/api/time_slots/{id}",
+    params(
+        ("id" = i64, Path, description = "Time slot ID")
+    ),
+    responses(
+        (status = 200, description = "Time slot found", body = TimeSlotResponse),
+        (status = 404, description = "Time slot not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn get_by_id(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .get_by_id(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get all time slots with pagination
+#[utoipa::path(
+    get,
+    path = "/api/time_slots",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default: 10, max: 100)"),
+        ("search" = Option<String>, Query, description = "Search query"),
+        ("sort_by" = Option<String>, Query, description = "Sort field: name or created_at (default: weekly grid order)"),
+        ("sort_order" = Option<String>, Query, description = "Sort order: asc or desc"),
+        TimeSlotFilters,
+    ),
+    responses(
+        (status = 200, description = "List of time slots", body = PaginatedResponse<TimeSlotResponse>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn get_all(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    query: web::Query<PaginationParams>,
+    filters: web::Query<TimeSlotFilters>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .get_all(query.into_inner(), filters.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Update time slot
+#[utoipa::path(
+    put,
+    path = "/api/time_slots/{id}",
+    params(
+        ("id" = i64, Path, description = "Time slot ID")
+    ),
+    request_body = UpdateTimeSlotRequest,
+    responses(
+        (status = 200, description = "Time slot updated", body = TimeSlotResponse),
+        (status = 404, description = "Time slot not found"),
+        (status = 409, description = "Conflict - overlapping time slot")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn update(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+    request: web::Json<UpdateTimeSlotRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .update(id.into_inner(), request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Delete time slot
+#[utoipa::path(
+    delete,
+    path = "/api/time_slots/{id}",
+    params(
+        ("id" = i64, Path, description = "Time slot ID")
+    ),
+    responses(
+        (status = 204, description = "Time slot deleted"),
+        (status = 404, description = "Time slot not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn delete(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    id: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .time_slot_service
+        .delete(id.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Create time slots from a daily bell schedule template
+#[utoipa::path(
+    post,
+    path = "/api/time_slots/bulk",
+    request_body = BulkCreateTimeSlotRequest,
+    responses(
+        (status = 201, description = "Time slots created", body = BulkTimeSlotResponse),
+        (status = 400, description = "Bad request"),
+        (status = 409, description = "Conflict - template overlaps existing slots")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn bulk_create(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<BulkCreateTimeSlotRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .bulk_create(request.into_inner(), auth.foundation_id)
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
+
+/// Clone the time slot grid to another foundation or unit
+#[utoipa::path(
+    post,
+    path = "/api/time_slots/clone",
+    request_body = CloneTimeSlotsRequest,
+    responses(
+        (status = 201, description = "Time slot grid cloned", body = BulkTimeSlotResponse),
+        (status = 400, description = "Source and target grid are the same"),
+        (status = 403, description = "Missing time_slots.clone permission on the source or target foundation"),
+        (status = 404, description = "Foundation or unit not found"),
+        (status = 409, description = "Target grid already has time slots")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Time Slot "
+)]
+pub async fn clone_grid(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    request: web::Json<CloneTimeSlotsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let result = app_state
+        .time_slot_service
+        .clone_grid(
+            request.into_inner(),
+            auth.foundation_id,
+            auth.user_id,
+            auth.has_permission(CLONE_PERMISSION),
+        )
+        .await?;
+    Ok(HttpResponse::Created().json(result))
+}
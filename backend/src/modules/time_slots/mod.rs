@@ -0,0 +1,21 @@
+// backend/src/modules/time_slots/mod.rs
+// ============================================================================
+// mod.rs - Module Entry Point
+// ============================================================================
+pub mod docs;
+pub mod dto;
+pub mod handler;
+pub mod repository;
+pub mod routes;
+pub mod service;
+
+pub use repository::TimeSlotRepository;
+pub use service::TimeSlotService;
+
+// Helper untuk initialize service dengan dependencies
+use crate::config::database::Database;
+
+pub fn init_service(db: Database) -> TimeSlotService {
+    let repository = TimeSlotRepository::new(db);
+    TimeSlotService::new(repository)
+}
@@ -0,0 +1,229 @@
+// ============================================================================
+// backend/src/modules/time_slots/repository.rs
+// repository.rs - Database Operations Only
+// ============================================================================
+use super::dto::TimeSlotFilters;
+use crate::config::database::Database;
+use crate::errors::AppError;
+use crate::utils::pagination::PaginationParams;
+use entity::time_slots::{self, Entity as TimeSlot};
+use entity::traits::soft_delete::SoftDelete;
+use entity::{foundations, units};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set, TransactionTrait,
+};
+
+#[derive(Clone)]
+pub struct TimeSlotRepository {
+    db: Database,
+}
+
+impl TimeSlotRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    // Helper method untuk akses connection
+    pub fn conn(&self) -> &sea_orm::DatabaseConnection {
+        self.db.get_connection()
+    }
+
+    /// Create new time slot
+    pub async fn create(
+        &self,
+        active_model: time_slots::ActiveModel,
+    ) -> Result<time_slots::Model, AppError> {
+        active_model
+            .insert(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find by ID (not deleted)
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<time_slots::Model>, AppError> {
+        TimeSlot::find_not_deleted()
+            .filter(time_slots::Column::Id.eq(id))
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find all with pagination and filters
+    pub async fn find_all(
+        &self,
+        params: &PaginationParams,
+        filters: &TimeSlotFilters,
+        foundation_id: i64,
+    ) -> Result<(Vec<time_slots::Model>, u64), AppError> {
+        let mut query =
+            TimeSlot::find_not_deleted().filter(time_slots::Column::FoundationId.eq(foundation_id));
+
+        if let Some(unit_id) = filters.unit_id {
+            query = query.filter(time_slots::Column::UnitId.eq(unit_id));
+        }
+        if let Some(day) = filters.day_of_week {
+            query = query.filter(time_slots::Column::DayOfWeek.eq(day));
+        }
+        if let Some(ref slot_type) = filters.slot_type {
+            query = query.filter(time_slots::Column::SlotType.eq(slot_type.clone()));
+        }
+
+        // Apply search filter if provided
+        if let Some(ref search) = params.search {
+            query = query.filter(Condition::any().add(time_slots::Column::Name.contains(search)));
+        }
+
+        // Default: urut sesuai grid mingguan
+        query = match params.sort_by.as_deref() {
+            Some("name") => {
+                if params.sort_order.as_deref() == Some("desc") {
+                    query.order_by_desc(time_slots::Column::Name)
+                } else {
+                    query.order_by_asc(time_slots::Column::Name)
+                }
+            }
+            Some("created_at") => {
+                if params.sort_order.as_deref() == Some("desc") {
+                    query.order_by_desc(time_slots::Column::CreatedAt)
+                } else {
+                    query.order_by_asc(time_slots::Column::CreatedAt)
+                }
+            }
+            _ => query
+                .order_by_asc(time_slots::Column::DayOfWeek)
+                .order_by_asc(time_slots::Column::StartTime),
+        };
+
+        // Paginate dengan validasi
+        let per_page = params.per_page();
+        let paginator = query.paginate(self.conn(), per_page);
+
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let items = paginator
+            .fetch_page(params.page() - 1)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((items, total))
+    }
+
+    /// Find slots of one grid (foundation + unit, null unit = foundation grid),
+    /// optionally limited to some days
+    pub async fn find_by_scope(
+        &self,
+        foundation_id: i64,
+        unit_id: Option<i64>,
+        days: Option<Vec<i8>>,
+    ) -> Result<Vec<time_slots::Model>, AppError> {
+        let mut query =
+            TimeSlot::find_not_deleted().filter(time_slots::Column::FoundationId.eq(foundation_id));
+
+        query = match unit_id {
+            Some(unit_id) => query.filter(time_slots::Column::UnitId.eq(unit_id)),
+            None => query.filter(time_slots::Column::UnitId.is_null()),
+        };
+
+        if let Some(days) = days {
+            query = query.filter(time_slots::Column::DayOfWeek.is_in(days));
+        }
+
+        query
+            .order_by_asc(time_slots::Column::DayOfWeek)
+            .order_by_asc(time_slots::Column::StartTime)
+            .all(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find foundation by ID
+    pub async fn find_foundation(&self, id: i64) -> Result<Option<foundations::Model>, AppError> {
+        foundations::Entity::find_by_id(id)
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Find unit by ID (not deleted)
+    pub async fn find_unit(&self, id: i64) -> Result<Option<units::Model>, AppError> {
+        units::Entity::find_by_id(id)
+            .filter(units::Column::DeletedAt.is_null())
+            .one(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Update time slot
+    pub async fn update(
+        &self,
+        id: i64,
+        active_model: time_slots::ActiveModel,
+    ) -> Result<time_slots::Model, AppError> {
+        let mut model = active_model;
+        model.id = Set(id);
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Soft delete time slot
+    pub async fn soft_delete(&self, id: i64) -> Result<(), AppError> {
+        let model = time_slots::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(chrono::Utc::now())),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+        model
+            .update(self.conn())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Soft delete `replace_ids` and insert `models` in a single transaction
+    pub async fn replace_many(
+        &self,
+        replace_ids: Vec<i64>,
+        models: Vec<time_slots::ActiveModel>,
+    ) -> Result<Vec<time_slots::Model>, AppError> {
+        let txn = self
+            .conn()
+            .begin()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if !replace_ids.is_empty() {
+            let now = chrono::Utc::now();
+            TimeSlot::update_many()
+                .col_expr(time_slots::Column::DeletedAt, Expr::value(now))
+                .col_expr(time_slots::Column::UpdatedAt, Expr::value(now))
+                .filter(time_slots::Column::Id.is_in(replace_ids))
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        let mut inserted = Vec::with_capacity(models.len());
+        for model in models {
+            let row = model
+                .insert(&txn)
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            inserted.push(row);
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(inserted)
+    }
+}
@@ -0,0 +1,27 @@
+// ============================================================================
+// routes.rs - Route Configuration
+// ============================================================================
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::time_slots::handler,
+};
+use actix_web::web;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api/time_slots")
+            .wrap(JwtMiddleware)
+            .route("", web::post().to(handler::create))
+            .route("", web::get().to(handler::get_all))
+            .route("/bulk", web::post().to(handler::bulk_create))
+            .route("/clone", web::post().to(handler::clone_grid))
+            .route("/{id}", web::get().to(handler::get_by_id))
+            .route("/{id}", web::put().to(handler::update))
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete)
+                    .wrap(require_permission("time_slots.delete")),
+            ),
+    );
+}
@@ -0,0 +1,591 @@
+// ============================================================================
+// backend/src/modules/time_slots/service.rs
+// service.rs - Business Logic Only
+// ============================================================================
+use super::dto::{
+    BulkCreateTimeSlotRequest, BulkTimeSlotResponse, CloneTimeSlotsRequest, CreateTimeSlotRequest,
+    TemplateSlot, TimeSlotFilters, TimeSlotResponse, UpdateTimeSlotRequest,
+};
+use super::repository::TimeSlotRepository;
+use crate::errors::AppError;
+use crate::modules::permissions::PermissionService;
+use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use crate::utils::time::overlaps;
+use chrono::NaiveTime;
+use entity::sea_orm_active_enums::SlotType;
+use entity::time_slots;
+use sea_orm::Set;
+use validator::Validate;
+
+/// Permission untuk menyalin grid ke foundation lain; wajib dimiliki di foundation asal & tujuan
+pub const CLONE_PERMISSION: &str = "time_slots.clone";
+
+#[derive(Clone)]
+pub struct TimeSlotService {
+    repository: TimeSlotRepository,
+}
+
+impl TimeSlotService {
+    pub fn new(repository: TimeSlotRepository) -> Self {
+        Self { repository }
+    }
+
+    /// Create new time slot with overlap validation
+    pub async fn create(
+        &self,
+        request: CreateTimeSlotRequest,
+        foundation_id: i64,
+    ) -> Result<TimeSlotResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let start_time = parse_time(&request.start_time, "start_time")?;
+        let end_time = parse_time(&request.end_time, "end_time")?;
+        ensure_range(start_time, end_time)?;
+
+        if let Some(unit_id) = request.unit_id {
+            self.ensure_unit(unit_id, foundation_id).await?;
+        }
+
+        let same_day = self
+            .repository
+            .find_by_scope(
+                foundation_id,
+                request.unit_id,
+                Some(vec![request.day_of_week]),
+            )
+            .await?;
+        ensure_no_overlap(&same_day, start_time, end_time, None)?;
+
+        let order = request.order.unwrap_or_else(|| {
+            same_day
+                .iter()
+                .filter(|s| s.start_time < start_time)
+                .count() as i32
+                + 1
+        });
+
+        let active_model = time_slots::ActiveModel {
+            foundation_id: Set(foundation_id),
+            unit_id: Set(request.unit_id),
+            name: Set(request.name),
+            day_of_week: Set(request.day_of_week),
+            start_time: Set(start_time),
+            end_time: Set(end_time),
+            slot_type: Set(Some(request.slot_type.unwrap_or(SlotType::Lesson))),
+            order: Set(order),
+            is_active: Set(Some(request.is_active.unwrap_or(true) as i8)),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let created = self.repository.create(active_model).await?;
+
+        Ok(TimeSlotResponse::from(created))
+    }
+
+    /// Get time slot by ID
+    pub async fn get_by_id(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<TimeSlotResponse, AppError> {
+        let slot = self.find_scoped(id, foundation_id).await?;
+        Ok(TimeSlotResponse::from(slot))
+    }
+
+    /// Get all time slots of the foundation with pagination
+    pub async fn get_all(
+        &self,
+        params: PaginationParams,
+        filters: TimeSlotFilters,
+        foundation_id: i64,
+    ) -> Result<PaginatedResponse<TimeSlotResponse>, AppError> {
+        // Validate pagination params
+        params
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let (items, total) = self
+            .repository
+            .find_all(&params, &filters, foundation_id)
+            .await?;
+
+        let responses: Vec<TimeSlotResponse> =
+            items.into_iter().map(TimeSlotResponse::from).collect();
+
+        Ok(PaginatedResponse::new(
+            responses,
+            total,
+            params.page(),
+            params.per_page(),
+        ))
+    }
+
+    /// Update time slot
+    pub async fn update(
+        &self,
+        id: i64,
+        request: UpdateTimeSlotRequest,
+        foundation_id: i64,
+    ) -> Result<TimeSlotResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let existing = self.find_scoped(id, foundation_id).await?;
+
+        let day_of_week = request.day_of_week.unwrap_or(existing.day_of_week);
+        let start_time = match request.start_time {
+            Some(ref value) => parse_time(value, "start_time")?,
+            None => existing.start_time,
+        };
+        let end_time = match request.end_time {
+            Some(ref value) => parse_time(value, "end_time")?,
+            None => existing.end_time,
+        };
+        ensure_range(start_time, end_time)?;
+
+        // Business rule: slot di hari yang sama tidak boleh beririsan
+        let same_day = self
+            .repository
+            .find_by_scope(foundation_id, existing.unit_id, Some(vec![day_of_week]))
+            .await?;
+        ensure_no_overlap(&same_day, start_time, end_time, Some(id))?;
+
+        // Build update model
+        let mut active_model = time_slots::ActiveModel {
+            id: Set(id),
+            day_of_week: Set(day_of_week),
+            start_time: Set(start_time),
+            end_time: Set(end_time),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        if let Some(name) = request.name {
+            active_model.name = Set(name);
+        }
+        if let Some(slot_type) = request.slot_type {
+            active_model.slot_type = Set(Some(slot_type));
+        }
+        if let Some(order) = request.order {
+            active_model.order = Set(order);
+        }
+        if let Some(is_active) = request.is_active {
+            active_model.is_active = Set(Some(is_active as i8));
+        }
+
+        let updated = self.repository.update(id, active_model).await?;
+
+        Ok(TimeSlotResponse::from(updated))
+    }
+
+    /// Delete time slot (soft delete)
+    pub async fn delete(&self, id: i64, foundation_id: i64) -> Result<(), AppError> {
+        self.find_scoped(id, foundation_id).await?;
+        self.repository.soft_delete(id).await
+    }
+
+    /// Buat slot dari template bell schedule harian untuk beberapa hari sekaligus
+    pub async fn bulk_create(
+        &self,
+        request: BulkCreateTimeSlotRequest,
+        foundation_id: i64,
+    ) -> Result<BulkTimeSlotResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let days = normalize_days(&request.days)?;
+
+        if let Some(unit_id) = request.unit_id {
+            self.ensure_unit(unit_id, foundation_id).await?;
+        }
+
+        let template = parse_template(request.slots)?;
+
+        let existing = self
+            .repository
+            .find_by_scope(foundation_id, request.unit_id, Some(days.clone()))
+            .await?;
+        let replace_existing = request.replace_existing.unwrap_or(false);
+
+        if !replace_existing {
+            for (name, start_time, end_time, _) in &template {
+                if let Some(clash) = existing
+                    .iter()
+                    .find(|s| overlaps(s.start_time, s.end_time, *start_time, *end_time))
+                {
+                    return Err(AppError::ConflictError(format!(
+                        "Template slot '{}' overlaps existing slot '{}' on day {}",
+                        name, clash.name, clash.day_of_week
+                    )));
+                }
+            }
+        }
+
+        let models = expand_template(&days, &template, foundation_id, request.unit_id);
+
+        let replace_ids: Vec<i64> = if replace_existing {
+            existing.iter().map(|s| s.id).collect()
+        } else {
+            Vec::new()
+        };
+        let replaced = replace_ids.len();
+
+        let created = self.repository.replace_many(replace_ids, models).await?;
+
+        Ok(BulkTimeSlotResponse {
+            created: created.len(),
+            replaced,
+            data: created.into_iter().map(TimeSlotResponse::from).collect(),
+        })
+    }
+
+    /// Salin seluruh grid slot milik foundation user ke foundation/unit lain
+    pub async fn clone_grid(
+        &self,
+        request: CloneTimeSlotsRequest,
+        foundation_id: i64,
+        user_id: i64,
+        can_clone: bool,
+    ) -> Result<BulkTimeSlotResponse, AppError> {
+        // Validate request
+        request
+            .validate()
+            .map_err(|e| AppError::validation(e.to_string()))?;
+
+        let target_foundation_id = request.target_foundation_id.unwrap_or(foundation_id);
+        if target_foundation_id == foundation_id && request.target_unit_id == request.source_unit_id
+        {
+            return Err(AppError::bad_request(
+                "Source and target grid are the same".to_string(),
+            ));
+        }
+
+        if let Some(unit_id) = request.source_unit_id {
+            self.ensure_unit(unit_id, foundation_id).await?;
+        }
+        if target_foundation_id != foundation_id {
+            self.ensure_can_clone_to(target_foundation_id, user_id, can_clone)
+                .await?;
+        }
+        if let Some(unit_id) = request.target_unit_id {
+            self.ensure_unit(unit_id, target_foundation_id).await?;
+        }
+
+        let source = self
+            .repository
+            .find_by_scope(foundation_id, request.source_unit_id, None)
+            .await?;
+        if source.is_empty() {
+            return Err(AppError::unprocessable_entity(
+                "Source grid has no time slots".to_string(),
+            ));
+        }
+
+        let existing = self
+            .repository
+            .find_by_scope(target_foundation_id, request.target_unit_id, None)
+            .await?;
+        let replace_ids: Vec<i64> = match (existing.is_empty(), request.replace_existing) {
+            (true, _) => Vec::new(),
+            (false, Some(true)) => existing.iter().map(|s| s.id).collect(),
+            (false, _) => {
+                return Err(AppError::ConflictError(
+                    "Target grid already has time slots; set replace_existing to overwrite"
+                        .to_string(),
+                ))
+            }
+        };
+        let replaced = replace_ids.len();
+
+        let now = chrono::Utc::now();
+        let models: Vec<time_slots::ActiveModel> = source
+            .into_iter()
+            .map(|slot| time_slots::ActiveModel {
+                foundation_id: Set(target_foundation_id),
+                unit_id: Set(request.target_unit_id),
+                name: Set(slot.name),
+                day_of_week: Set(slot.day_of_week),
+                start_time: Set(slot.start_time),
+                end_time: Set(slot.end_time),
+                slot_type: Set(slot.slot_type),
+                order: Set(slot.order),
+                is_active: Set(slot.is_active),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            })
+            .collect();
+
+        let created = self.repository.replace_many(replace_ids, models).await?;
+
+        Ok(BulkTimeSlotResponse {
+            created: created.len(),
+            replaced,
+            data: created.into_iter().map(TimeSlotResponse::from).collect(),
+        })
+    }
+
+    async fn find_scoped(
+        &self,
+        id: i64,
+        foundation_id: i64,
+    ) -> Result<time_slots::Model, AppError> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .filter(|s| s.foundation_id == foundation_id)
+            .ok_or_else(|| AppError::not_found("Time slot not found".to_string()))
+    }
+
+    /// Grid foundation lain hanya boleh ditimpa oleh user yang memegang permission clone
+    /// di foundation asal maupun foundation tujuan
+    async fn ensure_can_clone_to(
+        &self,
+        target_foundation_id: i64,
+        user_id: i64,
+        can_clone: bool,
+    ) -> Result<(), AppError> {
+        self.repository
+            .find_foundation(target_foundation_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Target foundation not found".to_string()))?;
+
+        let target_permissions = PermissionService::resolve_user_permissions(
+            self.repository.conn(),
+            user_id,
+            target_foundation_id,
+        )
+        .await?;
+        if !can_clone || !target_permissions.contains(CLONE_PERMISSION) {
+            return Err(AppError::forbidden(format!(
+                "Missing permission: {} on both foundations",
+                CLONE_PERMISSION
+            )));
+        }
+        Ok(())
+    }
+
+    async fn ensure_unit(&self, unit_id: i64, foundation_id: i64) -> Result<(), AppError> {
+        self.repository
+            .find_unit(unit_id)
+            .await?
+            .filter(|u| u.foundation_id == foundation_id)
+            .map(|_| ())
+            .ok_or_else(|| AppError::not_found("Unit not found".to_string()))
+    }
+}
+
+/// Parse jam dengan format HH:MM atau HH:MM:SS
+fn parse_time(value: &str, field: &str) -> Result<NaiveTime, AppError> {
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map_err(|_| AppError::ValidationError(format!("Invalid {} format. Use HH:MM", field)))
+}
+
+fn ensure_range(start_time: NaiveTime, end_time: NaiveTime) -> Result<(), AppError> {
+    if end_time <= start_time {
+        return Err(AppError::ValidationError(
+            "End time must be after start time".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Hari unik & terurut (1 = Senin ... 7 = Minggu)
+fn normalize_days(days: &[i8]) -> Result<Vec<i8>, AppError> {
+    let mut days = days.to_vec();
+    days.sort_unstable();
+    days.dedup();
+    if days.iter().any(|d| !(1..=7).contains(d)) {
+        return Err(AppError::validation(
+            "days must be between 1 (Monday) and 7 (Sunday)".to_string(),
+        ));
+    }
+    Ok(days)
+}
+
+/// Satu baris template yang sudah di-parse: (nama, mulai, selesai, jenis)
+type ParsedSlot = (String, NaiveTime, NaiveTime, Option<SlotType>);
+
+/// Parse & validasi template itu sendiri, diurutkan berdasarkan jam mulai
+fn parse_template(slots: Vec<TemplateSlot>) -> Result<Vec<ParsedSlot>, AppError> {
+    let mut template = Vec::with_capacity(slots.len());
+    for slot in slots {
+        let start_time = parse_time(&slot.start_time, "start_time")?;
+        let end_time = parse_time(&slot.end_time, "end_time")?;
+        ensure_range(start_time, end_time)?;
+        template.push((slot.name, start_time, end_time, slot.slot_type));
+    }
+    template.sort_by_key(|(_, start, _, _)| *start);
+    if let Some(pair) = template.windows(2).find(|w| w[1].1 < w[0].2) {
+        return Err(AppError::validation(format!(
+            "Template slots '{}' and '{}' overlap",
+            pair[0].0, pair[1].0
+        )));
+    }
+    Ok(template)
+}
+
+/// Template diulang untuk setiap hari; urutan slot dihitung ulang per hari
+fn expand_template(
+    days: &[i8],
+    template: &[ParsedSlot],
+    foundation_id: i64,
+    unit_id: Option<i64>,
+) -> Vec<time_slots::ActiveModel> {
+    let now = chrono::Utc::now();
+    days.iter()
+        .flat_map(|day| {
+            template.iter().enumerate().map(
+                move |(idx, (name, start_time, end_time, slot_type))| time_slots::ActiveModel {
+                    foundation_id: Set(foundation_id),
+                    unit_id: Set(unit_id),
+                    name: Set(name.clone()),
+                    day_of_week: Set(*day),
+                    start_time: Set(*start_time),
+                    end_time: Set(*end_time),
+                    slot_type: Set(Some(slot_type.clone().unwrap_or(SlotType::Lesson))),
+                    order: Set(idx as i32 + 1),
+                    is_active: Set(Some(1)),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                },
+            )
+        })
+        .collect()
+}
+
+fn ensure_no_overlap(
+    same_day: &[time_slots::Model],
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+    exclude_id: Option<i64>,
+) -> Result<(), AppError> {
+    match same_day
+        .iter()
+        .filter(|s| Some(s.id) != exclude_id)
+        .find(|s| overlaps(s.start_time, s.end_time, start_time, end_time))
+    {
+        Some(clash) => Err(AppError::ConflictError(format!(
+            "Time slot overlaps '{}' ({} - {})",
+            clash.name,
+            clash.start_time.format("%H:%M"),
+            clash.end_time.format("%H:%M")
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveTime {
+        parse_time(value, "time").unwrap()
+    }
+
+    fn template_slot(name: &str, start_time: &str, end_time: &str) -> TemplateSlot {
+        TemplateSlot {
+            name: name.to_string(),
+            start_time: start_time.to_string(),
+            end_time: end_time.to_string(),
+            slot_type: None,
+        }
+    }
+
+    #[test]
+    fn test_overlap_detection() {
+        // Slot yang bersambung tidak dianggap bentrok
+        assert!(!overlaps(
+            time("07:00"),
+            time("07:40"),
+            time("07:40"),
+            time("08:20")
+        ));
+        assert!(overlaps(
+            time("07:00"),
+            time("07:40"),
+            time("07:30"),
+            time("08:10")
+        ));
+
+        let now = chrono::Utc::now();
+        let existing = vec![time_slots::Model {
+            id: 1,
+            foundation_id: 1,
+            unit_id: None,
+            name: "Jam 1".to_string(),
+            day_of_week: 1,
+            start_time: time("07:00"),
+            end_time: time("07:40"),
+            slot_type: Some(SlotType::Lesson),
+            order: 1,
+            is_active: Some(1),
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        }];
+        assert!(matches!(
+            ensure_no_overlap(&existing, time("07:20"), time("08:00"), None),
+            Err(AppError::ConflictError(_))
+        ));
+        // Slot yang sedang diubah tidak bentrok dengan dirinya sendiri
+        assert!(ensure_no_overlap(&existing, time("07:20"), time("08:00"), Some(1)).is_ok());
+
+        assert!(parse_template(vec![
+            template_slot("Jam 1", "07:00", "07:40"),
+            template_slot("Jam 2", "07:30", "08:10"),
+        ])
+        .is_err());
+        assert!(parse_template(vec![template_slot("Jam 1", "08:00", "07:00")]).is_err());
+    }
+
+    #[test]
+    fn test_template_expansion() {
+        let days = normalize_days(&[3, 1, 3]).unwrap();
+        assert_eq!(days, vec![1, 3]);
+        assert!(normalize_days(&[0, 2]).is_err());
+
+        let template = parse_template(vec![
+            template_slot("Istirahat", "09:00", "09:15"),
+            template_slot("Jam 1", "07:00", "07:40"),
+        ])
+        .unwrap();
+        assert_eq!(template[0].0, "Jam 1");
+
+        let models = expand_template(&days, &template, 9, Some(4));
+        assert_eq!(models.len(), 4);
+        let rows = models
+            .iter()
+            .map(|m| {
+                (
+                    *m.day_of_week.as_ref(),
+                    *m.order.as_ref(),
+                    m.name.as_ref().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (1, 1, "Jam 1".to_string()),
+                (1, 2, "Istirahat".to_string()),
+                (3, 1, "Jam 1".to_string()),
+                (3, 2, "Istirahat".to_string()),
+            ]
+        );
+        assert!(models
+            .iter()
+            .all(|m| *m.foundation_id.as_ref() == 9 && *m.unit_id.as_ref() == Some(4)));
+    }
+}
@@ -8,6 +8,17 @@ pub struct UnitResponse {
     pub id: i64,
     pub foundation_id: i64,
     pub name: String,
+    /// Employee heading the unit (approves grade change requests)
+    pub head_employee_id: Option<i64>,
+    /// Geofence for staff clock-in
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub geofence_radius_meters: Option<i32>,
+    /// Staff start time "HH:MM" (local time of the unit)
+    pub work_start_time: Option<String>,
+    pub late_tolerance_minutes: i32,
+    pub utc_offset_minutes: i32,
+    pub work_days_per_week: i8,
     pub created_at: String, // ← Tambah ini (good practice)
     pub updated_at: String, // ← Tambah ini
 }
@@ -17,6 +28,8 @@ pub struct CreateUnitRequest {
     pub foundation_id: i64,
     #[validate(length(min = 3, max = 100))]
     pub name: String,
+    #[validate(range(min = 1))]
+    pub head_employee_id: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -24,6 +37,22 @@ pub struct UpdateUnitRequest {
     #[validate(length(min = 3, max = 100))]
     pub name: Option<String>,
     pub foundation_id: i64,
+    #[validate(range(min = 1))]
+    pub head_employee_id: Option<i64>,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
+    #[validate(range(min = 10, max = 10000))]
+    pub geofence_radius_meters: Option<i32>,
+    /// "HH:MM"
+    pub work_start_time: Option<String>,
+    #[validate(range(min = 0, max = 240))]
+    pub late_tolerance_minutes: Option<i32>,
+    #[validate(range(min = -720, max = 840))]
+    pub utc_offset_minutes: Option<i32>,
+    #[validate(range(min = 5, max = 6))]
+    pub work_days_per_week: Option<i8>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -41,6 +70,14 @@ impl From<entity::units::Model> for UnitResponse {
             id: model.id,
             foundation_id: model.foundation_id,
             name: model.name,
+            head_employee_id: model.head_employee_id,
+            latitude: model.latitude.and_then(|v| v.try_into().ok()),
+            longitude: model.longitude.and_then(|v| v.try_into().ok()),
+            geofence_radius_meters: model.geofence_radius_meters,
+            work_start_time: model.work_start_time.map(|t| t.format("%H:%M").to_string()),
+            late_tolerance_minutes: model.late_tolerance_minutes,
+            utc_offset_minutes: model.utc_offset_minutes,
+            work_days_per_week: model.work_days_per_week,
             created_at: model.created_at.to_string(),
             updated_at: model.updated_at.to_string(),
         }
@@ -6,7 +6,9 @@ use super::dto::{CreateUnitRequest, UnitResponse, UpdateUnitRequest};
 use super::repository::UnitRepository;
 use crate::errors::AppError;
 use crate::utils::pagination::{PaginatedResponse, PaginationParams};
+use chrono::NaiveTime;
 use entity::units;
+use sea_orm::prelude::Decimal;
 use sea_orm::Set;
 use validator::Validate;
 
@@ -43,6 +45,7 @@ impl UnitService {
         let active_model = units::ActiveModel {
             foundation_id: Set(request.foundation_id),
             name: Set(request.name),
+            head_employee_id: Set(request.head_employee_id),
             created_at: Set(chrono::Utc::now()),
             updated_at: Set(chrono::Utc::now()),
             ..Default::default()
@@ -131,6 +134,35 @@ impl UnitService {
         if let Some(name) = request.name {
             active_model.name = Set(name);
         }
+        if let Some(head_employee_id) = request.head_employee_id {
+            active_model.head_employee_id = Set(Some(head_employee_id));
+        }
+
+        // Pengaturan presensi pegawai
+        if let Some(latitude) = request.latitude {
+            active_model.latitude = Set(Decimal::try_from(latitude).ok());
+        }
+        if let Some(longitude) = request.longitude {
+            active_model.longitude = Set(Decimal::try_from(longitude).ok());
+        }
+        if let Some(radius) = request.geofence_radius_meters {
+            active_model.geofence_radius_meters = Set(Some(radius));
+        }
+        if let Some(ref start) = request.work_start_time {
+            let start = NaiveTime::parse_from_str(start, "%H:%M").map_err(|_| {
+                AppError::validation("Invalid work_start_time format. Use HH:MM".to_string())
+            })?;
+            active_model.work_start_time = Set(Some(start));
+        }
+        if let Some(tolerance) = request.late_tolerance_minutes {
+            active_model.late_tolerance_minutes = Set(tolerance);
+        }
+        if let Some(offset) = request.utc_offset_minutes {
+            active_model.utc_offset_minutes = Set(offset);
+        }
+        if let Some(days) = request.work_days_per_week {
+            active_model.work_days_per_week = Set(days);
+        }
 
         // Delegate to repository
         let updated = self.repository.update(id, active_model).await?;
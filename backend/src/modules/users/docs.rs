@@ -13,6 +13,7 @@ use utoipa::OpenApi;
         handler::update_user,
         handler::change_password,
         handler::delete_user,
+        handler::revoke_tokens,
     ),
     components(
         schemas(
@@ -5,6 +5,7 @@ use crate::utils::pagination::PaginationParams;
 use crate::{
     app_state::AppState,
     errors::AppError,
+    middleware::auth::AuthContext,
     modules::users::dto::{
         ChangePasswordRequest, CreateUserRequest, UpdateUserRequest, UserListResponse, UserResponse,
     },
@@ -329,3 +330,32 @@ pub async fn force_delete_user(
         Err(e) => Ok(HttpResponse::BadRequest().json(ApiResponse::<()>::error(e.to_string()))),
     }
 }
+
+/// Force-invalidate all refresh tokens of a user (e.g. after a role change)
+#[utoipa::path(
+    post,
+    path = "/api/users/{id}/revoke_tokens",
+    tag = "users",
+    params(
+        ("id" = i64, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "All sessions of the user revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Missing permission users.manage"),
+        (status = 404, description = "User not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_tokens(
+    app_state: web::Data<AppState>,
+    auth: web::ReqData<AuthContext>,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AppError> {
+    app_state
+        .auth_service
+        .revoke_user_tokens(path.into_inner(), auth.foundation_id)
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
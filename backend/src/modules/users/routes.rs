@@ -1,5 +1,8 @@
 // backend/src/modules/users/routes.rs
-use crate::{middleware::auth::JwtMiddleware, modules::users::handler};
+use crate::{
+    middleware::{auth::JwtMiddleware, permission::require_permission},
+    modules::users::handler,
+};
 use actix_web::web;
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -12,6 +15,17 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/me", web::put().to(handler::update_user))
             .route("/change-password", web::post().to(handler::change_password))
             .route("/{id}", web::get().to(handler::get_by_id))
-            .route("/{id}", web::delete().to(handler::delete_user)),
+            .route(
+                "/{id}/revoke_tokens",
+                web::post()
+                    .to(handler::revoke_tokens)
+                    .wrap(require_permission("users.manage")),
+            )
+            .route(
+                "/{id}",
+                web::delete()
+                    .to(handler::delete_user)
+                    .wrap(require_permission("users.delete")),
+            ),
     );
 }
@@ -18,14 +18,26 @@ pub fn init(cfg: &mut web::ServiceConfig) {
     crate::modules::user_profiles::routes::configure(cfg);
     crate::modules::semesters::routes::configure(cfg);
     crate::modules::settings::routes::configure(cfg);
-    // crate::modules::character_traits::routes::configure(cfg);
-    // crate::modules::report_cards::routes::configure(cfg);
-    // crate::modules::schedule_generation_logs::routes::configure(cfg);
+    crate::modules::character_traits::routes::configure(cfg);
+    crate::modules::report_cards::routes::configure(cfg);
+    crate::modules::schedule_generation_logs::routes::configure(cfg);
     crate::modules::rooms::routes::configure(cfg);
     crate::modules::employees::routes::configure(cfg);
     crate::modules::positions::routes::configure(cfg);
     crate::modules::unit_types::routes::configure(cfg);
     crate::modules::user_profiles::routes::configure(cfg);
+    crate::modules::schedules::routes::configure(cfg);
+    crate::modules::time_slots::routes::configure(cfg);
+    crate::modules::teacher_availability::routes::configure(cfg);
+    crate::modules::subject_preferences::routes::configure(cfg);
+    crate::modules::calendars::routes::configure(cfg);
+    crate::modules::substitutions::routes::configure(cfg);
+    crate::modules::room_bookings::routes::configure(cfg);
+    crate::modules::grades::routes::configure(cfg);
+    crate::modules::extracurriculars::routes::configure(cfg);
+    crate::modules::staff_attendances::routes::configure(cfg);
+    crate::modules::payrolls::routes::configure(cfg);
+    crate::modules::salary_grades::routes::configure(cfg);
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
@@ -25,6 +25,21 @@ pub fn init_app(db: Database) -> Result<web::Data<AppState>, Box<dyn std::error:
     let unit_type_service = crate::modules::unit_types::init_service(db.clone());
     let user_profile_service = crate::modules::user_profiles::init_service(db.clone());
     let user_service = crate::modules::users::init_service(db.clone());
+    let schedule_service = crate::modules::schedules::init_service(db.clone());
+    let schedule_generation_log_service = crate::modules::schedule_generation_logs::init_service(db.clone());
+    let time_slot_service = crate::modules::time_slots::init_service(db.clone());
+    let teacher_availability_service = crate::modules::teacher_availability::init_service(db.clone());
+    let subject_preference_service = crate::modules::subject_preferences::init_service(db.clone());
+    let calendar_service = crate::modules::calendars::init_service(db.clone());
+    let substitution_service = crate::modules::substitutions::init_service(db.clone());
+    let room_booking_service = crate::modules::room_bookings::init_service(db.clone());
+    let grade_service = crate::modules::grades::init_service(db.clone());
+    let report_card_service = crate::modules::report_cards::init_service(db.clone());
+    let character_trait_service = crate::modules::character_traits::init_service(db.clone());
+    let extracurricular_service = crate::modules::extracurriculars::init_service(db.clone());
+    let staff_attendance_service = crate::modules::staff_attendances::init_service(db.clone());
+    let payroll_service = crate::modules::payrolls::init_service(db.clone());
+    let salary_grade_service = crate::modules::salary_grades::init_service(db.clone());
     // ✨ Create AppState
 
     Ok(web::Data::new(AppState::new(
@@ -50,5 +65,20 @@ pub fn init_app(db: Database) -> Result<web::Data<AppState>, Box<dyn std::error:
         unit_type_service,
         user_profile_service,
         user_service,
+        schedule_service,
+        schedule_generation_log_service,
+        time_slot_service,
+        teacher_availability_service,
+        subject_preference_service,
+        calendar_service,
+        substitution_service,
+        room_booking_service,
+        grade_service,
+        report_card_service,
+        character_trait_service,
+        extracurricular_service,
+        staff_attendance_service,
+        payroll_service,
+        salary_grade_service,
     )))
 }
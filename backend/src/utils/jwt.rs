@@ -13,6 +13,9 @@ pub struct Claims {
     pub iat: usize,
     pub token_type: String,
     pub permissions: Vec<String>,
+    // ID unik token; refresh token dicocokkan ke tabel refresh_tokens lewat jti
+    #[serde(default)]
+    pub jti: String,
 }
 
 impl Claims {
@@ -45,10 +48,27 @@ impl Claims {
             exp: exp.timestamp() as usize,
             token_type,
             permissions,
+            jti: uuid::Uuid::new_v4().to_string(),
         }
     }
 }
 
+/// Refresh token ditandatangani dengan secret terpisah agar tidak bisa dipakai sebagai access token
+fn refresh_secret() -> String {
+    env::var("JWT_REFRESH_SECRET").expect("JWT_REFRESH_SECRET must be set")
+}
+
+/// Dipanggil saat startup: kedua secret wajib ada dan tidak boleh sama
+pub fn ensure_secrets() -> Result<(), String> {
+    let access = env::var("JWT_SECRET").map_err(|_| "JWT_SECRET must be set".to_string())?;
+    let refresh =
+        env::var("JWT_REFRESH_SECRET").map_err(|_| "JWT_REFRESH_SECRET must be set".to_string())?;
+    if access == refresh {
+        return Err("JWT_REFRESH_SECRET must differ from JWT_SECRET".to_string());
+    }
+    Ok(())
+}
+
 // ✅ FIX: Ganti &claims jadi &Claims (huruf besar)
 pub fn create_token(claims: &Claims) -> Result<String, jsonwebtoken::errors::Error> {
     let secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
@@ -61,8 +81,7 @@ pub fn create_token(claims: &Claims) -> Result<String, jsonwebtoken::errors::Err
 }
 
 pub fn create_refresh_token(claims: &Claims) -> Result<String, jsonwebtoken::errors::Error> {
-    let secret = env::var("JWT_REFRESH_SECRET")
-        .unwrap_or_else(|_| env::var("JWT_SECRET").expect("JWT_SECRET must be set"));
+    let secret = refresh_secret();
 
     encode(
         &Header::default(),
@@ -80,12 +99,18 @@ pub fn verify_token(token: &str) -> Result<Claims, jsonwebtoken::errors::Error>
         &Validation::default(),
     )?;
 
+    // Hanya access token yang boleh dipakai sebagai bearer token
+    if token_data.claims.token_type != "access" {
+        return Err(jsonwebtoken::errors::Error::from(
+            jsonwebtoken::errors::ErrorKind::InvalidToken,
+        ));
+    }
+
     Ok(token_data.claims)
 }
 
 pub fn verify_refresh_token(token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
-    let secret = env::var("JWT_REFRESH_SECRET")
-        .unwrap_or_else(|_| env::var("JWT_SECRET").expect("JWT_SECRET must be set"));
+    let secret = refresh_secret();
 
     let token_data = decode::<Claims>(
         token,
@@ -101,3 +126,85 @@ pub fn verify_refresh_token(token: &str) -> Result<Claims, jsonwebtoken::errors:
 
     Ok(token_data.claims)
 }
+
+/// Token sekali pakai yang dikirim lewat email (reset password, verifikasi email).
+/// `purpose` mencegah token untuk satu alur dipakai di alur lain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionClaims {
+    pub sub: String,
+    pub user_id: i64,
+    pub purpose: String,
+    pub jti: String,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+impl ActionClaims {
+    pub fn new(user_id: i64, purpose: &str, ttl_seconds: i64) -> Self {
+        let iat = Utc::now();
+        let exp = iat + Duration::seconds(ttl_seconds);
+
+        Self {
+            sub: user_id.to_string(),
+            user_id,
+            purpose: purpose.to_string(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            iat: iat.timestamp() as usize,
+            exp: exp.timestamp() as usize,
+        }
+    }
+}
+
+pub fn create_action_token(claims: &ActionClaims) -> Result<String, jsonwebtoken::errors::Error> {
+    let secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+
+    encode(
+        &Header::default(),
+        claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+pub fn verify_action_token(
+    token: &str,
+    purpose: &str,
+) -> Result<ActionClaims, jsonwebtoken::errors::Error> {
+    let secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+
+    let token_data = decode::<ActionClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )?;
+
+    if token_data.claims.purpose != purpose {
+        return Err(jsonwebtoken::errors::Error::from(
+            jsonwebtoken::errors::ErrorKind::InvalidToken,
+        ));
+    }
+
+    Ok(token_data.claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_types_are_not_interchangeable() {
+        env::set_var("JWT_SECRET", "test-access-secret");
+        env::set_var("JWT_REFRESH_SECRET", "test-refresh-secret");
+
+        let access = create_token(&Claims::new(1, 1, "access".into(), vec![])).unwrap();
+        let refresh = create_refresh_token(&Claims::new(1, 1, "refresh".into(), vec![])).unwrap();
+
+        assert!(verify_token(&access).is_ok());
+        assert!(verify_token(&refresh).is_err());
+        assert!(verify_refresh_token(&refresh).is_ok());
+        assert!(verify_refresh_token(&access).is_err());
+
+        // Token bertipe "access" yang ditandatangani secret refresh tetap ditolak
+        let forged = create_refresh_token(&Claims::new(1, 1, "access".into(), vec![])).unwrap();
+        assert!(verify_token(&forged).is_err());
+    }
+}
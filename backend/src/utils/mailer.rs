@@ -0,0 +1,179 @@
+// backend/src/utils/mailer.rs
+// Pengiriman email keluar. Driver dipilih lewat MAIL_DRIVER:
+//   smtp - SmtpMailer (SMTP_HOST, SMTP_PORT, SMTP_USERNAME, SMTP_PASSWORD, MAIL_FROM)
+//   log  - LogMailer (default): hanya penerima & subjek yang dicatat, plus file .eml bila MAIL_DIR diisi
+// Driver yang salah konfigurasi gagal saat startup, tidak diam-diam jatuh ke LogMailer.
+use crate::errors::AppError;
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use std::{env, path::PathBuf, sync::Arc};
+
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub to: String,
+    pub to_name: Option<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: Email) -> Result<(), AppError>;
+}
+
+pub fn from_env() -> Result<Arc<dyn Mailer>, AppError> {
+    let driver = env::var("MAIL_DRIVER").unwrap_or_else(|_| "log".to_string());
+
+    match driver.as_str() {
+        "smtp" => Ok(Arc::new(SmtpMailer::from_env()?)),
+        "log" => Ok(Arc::new(LogMailer::new(
+            env::var("MAIL_DIR").ok().map(PathBuf::from),
+        ))),
+        other => Err(AppError::internal(format!(
+            "Unknown MAIL_DRIVER: {}",
+            other
+        ))),
+    }
+}
+
+fn from_address() -> String {
+    env::var("MAIL_FROM").unwrap_or_else(|_| "no-reply@localhost".to_string())
+}
+
+/// Nama tampilan diberikan terpisah agar karakter seperti `<`, `,` atau tanda kutip tidak ikut di-parse
+fn mailbox(address: &str, name: Option<&str>) -> Result<Mailbox, AppError> {
+    let address = address
+        .parse()
+        .map_err(|_| AppError::validation(format!("Invalid email address: {}", address)))?;
+
+    Ok(Mailbox::new(name.map(str::to_string), address))
+}
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn from_env() -> Result<Self, AppError> {
+        let host = env::var("SMTP_HOST").map_err(|_| AppError::internal("SMTP_HOST is not set"))?;
+        let port = match env::var("SMTP_PORT") {
+            Ok(port) => port
+                .parse()
+                .map_err(|_| AppError::internal(format!("Invalid SMTP_PORT: {}", port)))?,
+            Err(_) => 587,
+        };
+
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+            .map_err(|e| AppError::internal(format!("Invalid SMTP host: {}", e)))?
+            .port(port);
+
+        match (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+            (Ok(username), Ok(password)) => {
+                builder = builder.credentials(Credentials::new(username, password));
+            }
+            (Err(_), Err(_)) => {}
+            _ => {
+                return Err(AppError::internal(
+                    "SMTP_USERNAME and SMTP_PASSWORD must be set together",
+                ))
+            }
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from: mailbox(&from_address(), None)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: Email) -> Result<(), AppError> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(mailbox(&email.to, email.to_name.as_deref())?)
+            .subject(email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body)
+            .map_err(|e| AppError::internal(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to send email: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// Untuk pengembangan lokal & pengujian: email tidak benar-benar dikirim
+pub struct LogMailer {
+    dir: Option<PathBuf>,
+}
+
+impl LogMailer {
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        Self { dir }
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: Email) -> Result<(), AppError> {
+        // Isi email memuat tautan bertoken, jadi tidak boleh masuk log
+        log::info!("📧 Mail to {} - {}", email.to, email.subject);
+
+        if let Some(dir) = &self.dir {
+            tokio::fs::create_dir_all(dir).await?;
+
+            let path = dir.join(format!(
+                "{}-{}.eml",
+                chrono::Utc::now().format("%Y%m%d%H%M%S"),
+                uuid::Uuid::new_v4().simple()
+            ));
+            let content = format!(
+                "From: {}\r\nTo: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+                from_address(),
+                email.to,
+                email.subject,
+                email.body
+            );
+            tokio::fs::write(path, content).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mailbox_keeps_display_name_intact() {
+        let to = mailbox("budi@example.com", Some("Budi, S.Pd <Wali \"7A\">")).unwrap();
+        assert_eq!(to.name.as_deref(), Some("Budi, S.Pd <Wali \"7A\">"));
+        assert_eq!(to.email.to_string(), "budi@example.com");
+
+        assert!(mailbox("not-an-email", Some("Budi")).is_err());
+    }
+
+    #[test]
+    fn test_misconfigured_driver_is_rejected() {
+        env::set_var("MAIL_DRIVER", "smtp");
+        env::remove_var("SMTP_HOST");
+        assert!(from_env().is_err());
+
+        env::set_var("MAIL_DRIVER", "smpt");
+        assert!(from_env().is_err());
+
+        env::set_var("MAIL_DRIVER", "log");
+        assert!(from_env().is_ok());
+        env::remove_var("MAIL_DRIVER");
+    }
+}
@@ -1,4 +1,7 @@
 pub mod jwt;
+pub mod mailer;
 pub mod pagination;
+pub mod pdf;
 pub mod password;
 pub mod response;
+pub mod time;
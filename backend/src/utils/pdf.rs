@@ -0,0 +1,273 @@
+// backend/src/utils/pdf.rs
+// Minimal PDF 1.4 writer: halaman A4, font standar Helvetica, teks & garis.
+// Tanpa kompresi dan tanpa dependency tambahan.
+
+pub const A4_WIDTH: f32 = 595.0;
+pub const A4_HEIGHT: f32 = 842.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+}
+
+/// Satu halaman A4; koordinat dalam point dengan titik (0, 0) di kiri bawah
+#[derive(Debug, Clone, Default)]
+pub struct PdfPage {
+    content: String,
+}
+
+impl PdfPage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, size: f32, font: Font, text: &str) {
+        self.content.push_str(&format!(
+            "BT /{} {} Tf {} {} Td ({}) Tj ET\n",
+            font.resource(),
+            num(size),
+            num(x),
+            num(y),
+            escape(text)
+        ));
+    }
+
+    pub fn text_centered(&mut self, center_x: f32, y: f32, size: f32, font: Font, text: &str) {
+        let x = center_x - text_width(text, size, font) / 2.0;
+        self.text(x, y, size, font, text);
+    }
+
+    pub fn text_right(&mut self, right_x: f32, y: f32, size: f32, font: Font, text: &str) {
+        let x = right_x - text_width(text, size, font);
+        self.text(x, y, size, font, text);
+    }
+
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+        self.content.push_str(&format!(
+            "{} w {} {} m {} {} l S\n",
+            num(width),
+            num(x1),
+            num(y1),
+            num(x2),
+            num(y2)
+        ));
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PdfDocument {
+    title: Option<String>,
+    pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn add_page(&mut self, page: PdfPage) {
+        self.pages.push(page);
+    }
+
+    /// Gabungkan halaman dokumen lain ke akhir dokumen ini
+    pub fn append(&mut self, other: PdfDocument) {
+        self.pages.extend(other.pages);
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Objek: 1 catalog, 2 pages, 3-4 font, 5 info, lalu (page, content) per halaman
+        let page_ids: Vec<usize> = (0..self.pages.len()).map(|i| 6 + i * 2).collect();
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                page_ids
+                    .iter()
+                    .map(|id| format!("{} 0 R", id))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                self.pages.len()
+            )
+            .into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            format!(
+                "<< /Title ({}) >>",
+                escape(self.title.as_deref().unwrap_or(""))
+            )
+            .into_bytes(),
+        ];
+
+        for (page, id) in self.pages.iter().zip(&page_ids) {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    num(A4_WIDTH),
+                    num(A4_HEIGHT),
+                    id + 1
+                )
+                .into_bytes(),
+            );
+
+            let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+            stream.extend_from_slice(page.content.as_bytes());
+            stream.extend_from_slice(b"endstream");
+            objects.push(stream);
+        }
+
+        let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, body) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            out.extend_from_slice(body);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_at = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n", objects.len() + 1).as_bytes());
+        out.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_at
+            )
+            .as_bytes(),
+        );
+        out
+    }
+}
+
+/// Perkiraan lebar teks Helvetica (cukup untuk rata tengah/kanan dan word wrap)
+pub fn text_width(text: &str, size: f32, font: Font) -> f32 {
+    let em: f32 = text
+        .chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '|' | '!' | 'I' => 0.28,
+            ' ' | 'f' | 't' | 'r' | '(' | ')' | '-' | '/' => 0.33,
+            'm' | 'w' | 'M' | 'W' => 0.83,
+            '0'..='9' => 0.556,
+            c if c.is_ascii_uppercase() => 0.67,
+            _ => 0.53,
+        })
+        .sum();
+    let bold = if font == Font::Bold { 1.06 } else { 1.0 };
+    em * size * bold
+}
+
+/// Pecah teks menjadi beberapa baris yang muat dalam `max_width`
+pub fn wrap(text: &str, max_width: f32, size: f32, font: Font) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+            if !current.is_empty() && text_width(&candidate, size, font) > max_width {
+                lines.push(std::mem::replace(&mut current, word.to_string()));
+            } else {
+                current = candidate;
+            }
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// Escape string literal PDF; karakter Latin-1 ditulis oktal (WinAnsi), sisanya jadi '?'
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            '\u{a0}'..='\u{ff}' => out.push_str(&format!("\\{:03o}", c as u32)),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+fn num(value: f32) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xref_offsets_point_at_objects() {
+        let mut page = PdfPage::new();
+        page.text(50.0, 800.0, 12.0, Font::Bold, "Rapor (Semester 1)");
+        page.line(50.0, 790.0, 545.0, 790.0, 0.5);
+        let mut doc = PdfDocument::new().with_title("Rapor");
+        doc.add_page(page.clone());
+        doc.add_page(page);
+
+        let bytes = doc.to_bytes();
+        let tail = String::from_utf8_lossy(&bytes[bytes.len() - 16..]).to_string();
+        let startxref: usize = tail.lines().rev().nth(1).unwrap().parse().unwrap();
+        let xref = std::str::from_utf8(&bytes[startxref..]).unwrap();
+
+        assert!(bytes.starts_with(b"%PDF-1.4"));
+        assert!(xref.starts_with("xref\n0 10\n"));
+        assert!(xref.ends_with("%%EOF\n"));
+        for (index, entry) in xref.lines().skip(3).take(9).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            let object = format!("{} 0 obj", index + 1);
+            assert!(bytes[offset..].starts_with(object.as_bytes()));
+        }
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("(Rapor \\(Semester 1\\)) Tj"));
+    }
+
+    #[test]
+    fn test_escape_and_wrap() {
+        assert_eq!(escape("a\\b é ✓"), "a\\\\b \\351 ?");
+
+        let lines = wrap(
+            "Ananda menunjukkan perkembangan yang sangat baik",
+            100.0,
+            10.0,
+            Font::Regular,
+        );
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|l| text_width(l, 10.0, Font::Regular) <= 100.0 || !l.contains(' ')));
+    }
+}
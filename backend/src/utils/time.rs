@@ -0,0 +1,13 @@
+// backend/src/utils/time.rs
+// Helper rentang jam yang dipakai lintas modul (slot, booking ruang, guru pengganti)
+use chrono::NaiveTime;
+
+/// Dua rentang waktu di hari yang sama saling beririsan; rentang yang bersambung tidak
+pub fn overlaps(
+    start_a: NaiveTime,
+    end_a: NaiveTime,
+    start_b: NaiveTime,
+    end_b: NaiveTime,
+) -> bool {
+    start_a < end_b && start_b < end_a
+}